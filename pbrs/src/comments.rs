@@ -0,0 +1,169 @@
+//! The comments attached to declarations. The parser skips comments as whitespace, so
+//! they are found again from the source once it is parsed, the way protoc attaches them.
+
+use std::ops::Range;
+
+use crate::types::Comments;
+
+/// Every comment in a proto source, in order.
+pub(crate) struct SourceComments<'a> {
+    source: &'a str,
+    comments: Vec<Range<usize>>,
+}
+
+impl<'a> SourceComments<'a> {
+    pub(crate) fn new(source: &'a str) -> Self {
+        let bytes = source.as_bytes();
+        let mut comments = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            match (bytes[i], bytes.get(i + 1)) {
+                (b'"' | b'\'', _) => i = end_of_string(bytes, i),
+                (b'/', Some(b'/')) => {
+                    let end = source[i..].find('\n').map_or(source.len(), |n| i + n);
+                    comments.push(i..end);
+                    i = end;
+                }
+                (b'/', Some(b'*')) => {
+                    let end = source[i + 2..]
+                        .find("*/")
+                        .map_or(source.len(), |n| i + 2 + n + 2);
+                    comments.push(i..end);
+                    i = end;
+                }
+                _ => i += 1,
+            }
+        }
+        SourceComments { source, comments }
+    }
+
+    /// The comments of the declaration starting at `offset`.
+    ///
+    /// Its leading comment is the run of comments right above it, each on lines of their
+    /// own, with no blank line in between. Its trailing one follows the `;` or `{` that
+    /// ends it: on the same line, or as a run on the lines right below that a blank line
+    /// separates from what comes next, unless that is the end of the enclosing block.
+    pub(crate) fn attached(&self, offset: usize) -> Comments {
+        Comments {
+            leading: self.leading(offset),
+            trailing: self.trailing(offset),
+        }
+    }
+
+    fn leading(&self, offset: usize) -> Option<String> {
+        let before = self.comments.partition_point(|c| c.end <= offset);
+        let mut run = Vec::new();
+        let mut next = offset;
+        for c in self.comments[..before].iter().rev() {
+            if newlines(&self.source[c.end..next]) != Some(1) || !self.starts_line(c.start) {
+                break;
+            }
+            run.push(c);
+            next = c.start;
+        }
+        run.reverse();
+        self.text(&run)
+    }
+
+    fn trailing(&self, offset: usize) -> Option<String> {
+        let end = self.end_of_declaration(offset)?;
+        let after = self.comments.partition_point(|c| c.start < end);
+        let mut rest = self.comments[after..].iter();
+        let first = rest.next()?;
+        match newlines(&self.source[end..first.start])? {
+            0 => self.text(&[first]),
+            1 => {
+                let mut run = vec![first];
+                for c in rest {
+                    let last = run[run.len() - 1];
+                    if newlines(&self.source[last.end..c.start]) != Some(1) {
+                        break;
+                    }
+                    run.push(c);
+                }
+                let tail = &self.source[run[run.len() - 1].end..];
+                let next = tail.trim_start();
+                let space = &tail[..tail.len() - next.len()];
+                (next.is_empty() || next.starts_with('}') || space.matches('\n').count() > 1)
+                    .then(|| self.text(&run))
+                    .flatten()
+            }
+            _ => None,
+        }
+    }
+
+    /// Just past the `;` or `{` ending the declaration at `offset`.
+    fn end_of_declaration(&self, offset: usize) -> Option<usize> {
+        let bytes = self.source.as_bytes();
+        let first = self.comments.partition_point(|c| c.start < offset);
+        let mut comments = self.comments[first..].iter().peekable();
+        let mut i = offset;
+        while i < bytes.len() {
+            if let Some(c) = comments.next_if(|c| c.start == i) {
+                i = c.end;
+                continue;
+            }
+            match bytes[i] {
+                b'"' | b'\'' => i = end_of_string(bytes, i),
+                b';' | b'{' => return Some(i + 1),
+                _ => i += 1,
+            }
+        }
+        None
+    }
+
+    fn starts_line(&self, offset: usize) -> bool {
+        let line_start = self.source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        self.source[line_start..offset].trim().is_empty()
+    }
+
+    /// The text of a run of comments, without the comment markers.
+    fn text(&self, run: &[&Range<usize>]) -> Option<String> {
+        if run.is_empty() {
+            return None;
+        }
+        let mut text = String::new();
+        for c in run {
+            let comment = &self.source[(*c).clone()];
+            if let Some(line) = comment.strip_prefix("//") {
+                text.push_str(line.trim_end_matches('\r'));
+                text.push('\n');
+                continue;
+            }
+            // As protoc has it: the first line as is, the rest without the indentation
+            // and `*` that line them up.
+            let body = &comment[2..];
+            let body = body.strip_suffix("*/").unwrap_or(body);
+            for (i, line) in body.split('\n').enumerate() {
+                let line = line.trim_end_matches('\r');
+                if i > 0 {
+                    text.push('\n');
+                    let line = line.trim_start_matches([' ', '\t']);
+                    text.push_str(line.strip_prefix('*').unwrap_or(line));
+                } else {
+                    text.push_str(line);
+                }
+            }
+        }
+        Some(text)
+    }
+}
+
+/// How many line breaks `gap` spans, or `None` if it isn't only whitespace.
+fn newlines(gap: &str) -> Option<usize> {
+    gap.trim().is_empty().then(|| gap.matches('\n').count())
+}
+
+/// Just past the string literal whose opening quote is at `start`.
+fn end_of_string(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'\n' => return i,
+            quote if quote == bytes[start] => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
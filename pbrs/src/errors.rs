@@ -1,4 +1,7 @@
 use std::io;
+use std::path::PathBuf;
+
+use crate::types::Span;
 
 /// An error enum
 #[derive(Debug)]
@@ -35,6 +38,14 @@ pub enum Error {
     Cycle(Vec<String>),
     /// `--output` and `--output_directory` both used
     OutputAndOutputDir,
+    /// A `features` option set to something it can't be, or where it can't be
+    InvalidFeature(String),
+    /// An import found under none of the directories searched, which it lists
+    ImportNotFound(PathBuf, Vec<PathBuf>),
+    /// A type used from a file that isn't imported, with the file it is declared in
+    NotImported(String, PathBuf),
+    /// Another error, at a place in a proto file
+    Located(Span, Box<Error>),
 }
 
 /// A wrapper for `Result<T, Error>`
@@ -51,6 +62,7 @@ impl std::error::Error for Error {
         match self {
             Error::Io(e) => Some(e),
             Error::Nom(e) => Some(e),
+            Error::Located(_, e) => Some(e),
             _ => None,
         }
     }
@@ -99,6 +111,28 @@ impl std::fmt::Display for Error {
             Error::OutputAndOutputDir => {
                 write!(f, "only one of --output or --output_directory allowed")
             }
+            Error::InvalidFeature(message) => write!(f, "invalid feature: {}", message),
+            Error::ImportNotFound(import, searched) if searched.is_empty() => write!(
+                f,
+                "import \"{}\" not found: there are no include paths to look in",
+                import.display()
+            ),
+            Error::ImportNotFound(import, searched) => {
+                let searched: Vec<_> = searched.iter().map(|d| d.display().to_string()).collect();
+                write!(
+                    f,
+                    "import \"{}\" not found in {}",
+                    import.display(),
+                    searched.join(", ")
+                )
+            }
+            Error::NotImported(name, file) => write!(
+                f,
+                "`{}` is declared in \"{}\", which isn't imported here",
+                name,
+                file.display()
+            ),
+            Error::Located(span, e) => write!(f, "{}: {}", span, e),
         }
     }
 }
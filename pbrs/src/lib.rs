@@ -1,3 +1,4 @@
+mod comments;
 pub mod errors;
 mod parser;
 pub mod types;
@@ -2,15 +2,17 @@ use std::path::PathBuf;
 use std::str;
 
 use crate::types::{
-    Enumerator, Extend, Extensions, Field, FieldType, FileDescriptor, Frequency, Message, OneOf,
-    RpcFunctionDeclaration, RpcService, Syntax,
+    find_option, Enumerator, Extend, Extensions, Field, FieldType, FileDescriptor, Frequency,
+    Import, ImportKind, Message, OneOf, OptionValue, ProtoOption, RpcFunctionDeclaration,
+    RpcService, Span, Syntax,
 };
 
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_until},
     character::complete::{
-        alpha1, alphanumeric1, anychar, digit1, hex_digit1, multispace1, not_line_ending,
+        alpha1, alphanumeric1, anychar, digit0, digit1, hex_digit1, multispace1, none_of,
+        not_line_ending, one_of as one_char_of,
     },
     combinator::{map, map_res, opt, recognize, value, verify},
     multi::{many0, many1, separated_list0, separated_list1},
@@ -33,25 +35,36 @@ enum MessageEvent {
     ReservedNums(Vec<i32>),
     ReservedNames(Vec<String>),
     OneOf(OneOf),
-    Extensions(Extensions),
+    Extensions(Vec<Extensions>),
+    Option(ProtoOption),
     Ignore,
 }
 
+#[derive(Debug, Clone)]
+enum OneOfEvent {
+    Field(Field),
+    Option(ProtoOption),
+}
+
 #[derive(Debug, Clone)]
 enum EnumEvent {
-    Field((String, i32)),
+    Field((String, i32), Vec<ProtoOption>, Span),
+    ReservedNums(Vec<i32>),
+    ReservedNames(Vec<String>),
+    Option(ProtoOption),
     Ignore,
 }
 
 #[derive(Debug, Clone)]
 enum Event {
     Syntax(Syntax),
-    Import(PathBuf),
+    Import(Import),
     Package(String),
     Message(Message),
     Enum(Enumerator),
     RpcService(RpcService),
     Extend(Extend),
+    Option(ProtoOption),
     Ignore,
 }
 
@@ -131,23 +144,34 @@ fn syntax(input: &str) -> IResult<&str, Syntax> {
 fn edition(input: &str) -> IResult<&str, Syntax> {
     delimited(
         tuple((tag("edition"), many0(br), tag("="), many0(br))),
-        map(string, |year| Syntax::Edition(year)),
+        map(string, Syntax::Edition),
         pair(many0(br), tag(";")),
     )(input)
 }
 
-fn import(input: &str) -> IResult<&str, PathBuf> {
-    delimited(
-        tuple((
-            tag("import"),
-            many1(br),
-            opt(pair(
-                alt((tag("option"), tag("public"), tag("weak"))),
-                many1(br),
-            )),
-        )),
-        map(string, PathBuf::from),
-        pair(many0(br), tag(";")),
+fn import(input: &str) -> IResult<&str, Import> {
+    let span = Span::at(input);
+    map(
+        delimited(
+            pair(tag("import"), many1(br)),
+            pair(
+                opt(terminated(
+                    alt((
+                        value(ImportKind::Option, tag("option")),
+                        value(ImportKind::Public, tag("public")),
+                        value(ImportKind::Weak, tag("weak")),
+                    )),
+                    many1(br),
+                )),
+                string,
+            ),
+            pair(many0(br), tag(";")),
+        ),
+        move |(kind, path)| Import {
+            path: PathBuf::from(path),
+            kind: kind.unwrap_or_default(),
+            span: span.clone(),
+        },
     )(input)
 }
 
@@ -159,26 +183,26 @@ fn package(input: &str) -> IResult<&str, String> {
     )(input)
 }
 
-fn extensions(input: &str) -> IResult<&str, Extensions> {
-    map(
-        delimited(
-            pair(tag("extensions"), many1(br)),
-            pair(
-                integer,
-                preceded(pair(many0(br), pair(tag("to"), many1(br))), take_until(";")),
-            ),
-            tag(";"),
+/// `extensions 100 to 199, 500, 1000 to max;`: each range, as a single number is one of
+/// its own.
+fn extensions(input: &str) -> IResult<&str, Vec<Extensions>> {
+    let range = map(
+        pair(
+            integer,
+            opt(preceded(
+                tuple((many1(br), tag("to"), many1(br))),
+                alt((value(Extensions::max(), tag("max")), integer)),
+            )),
         ),
-        |(from, to)| {
-            // TODO: is there a better way to parse "max" or a number?
-            let s = to.trim();
-            let to = if s == "max" {
-                Extensions::max()
-            } else {
-                s.parse().unwrap()
-            };
-            Extensions { from, to }
+        |(from, to)| Extensions {
+            from,
+            to: to.unwrap_or(from),
         },
+    );
+    delimited(
+        pair(tag("extensions"), many1(br)),
+        separated_list1(tuple((many0(br), tag(","), many0(br))), range),
+        pair(many0(br), tag(";")),
     )(input)
 }
 
@@ -211,28 +235,179 @@ fn reserved_names(input: &str) -> IResult<&str, Vec<String>> {
     )(input)
 }
 
-fn option_key(input: &str) -> IResult<&str, &str> {
-    recognize(separated_list1(
-        tag("."),
-        alt((
-            recognize(tuple((tag("("), take_until(")"), tag(")")))),
-            word_ref,
+/// An option's name: `packed`, `(my.ext)`, `(my.ext).flag`, `features.field_presence`.
+fn option_name(input: &str) -> IResult<&str, String> {
+    map(
+        separated_list1(
+            delimited(many0(br), tag("."), many0(br)),
+            alt((
+                map(
+                    delimited(
+                        pair(tag("("), many0(br)),
+                        qualifiable_name,
+                        pair(many0(br), tag(")")),
+                    ),
+                    |name| format!("({name})"),
+                ),
+                word,
+            )),
+        ),
+        |parts| parts.join("."),
+    )(input)
+}
+
+fn number_literal(input: &str) -> IResult<&str, &str> {
+    alt((
+        recognize(pair(alt((tag("0x"), tag("0X"))), hex_digit1)),
+        recognize(pair(
+            alt((
+                recognize(pair(digit1, opt(pair(tag("."), digit0)))),
+                recognize(pair(tag("."), digit1)),
+            )),
+            opt(tuple((one_char_of("eE"), opt(one_char_of("+-")), digit1))),
         )),
     ))(input)
 }
 
-fn key_val(input: &str) -> IResult<&str, (&str, &str)> {
-    delimited(
-        pair(tag("["), many0(br)),
+fn signed_number(input: &str) -> IResult<&str, String> {
+    alt((
+        map(
+            separated_pair(
+                one_char_of("+-"),
+                many0(br),
+                alt((number_literal, tag("inf"), tag("nan"))),
+            ),
+            |(sign, n)| format!("{sign}{n}"),
+        ),
+        map(number_literal, str::to_owned),
+    ))(input)
+}
+
+/// A string literal's contents, escapes and all.
+fn quoted(input: &str) -> IResult<&str, &str> {
+    let contents = |quote: &'static str| {
+        delimited(
+            tag(quote),
+            recognize(many0(alt((
+                recognize(pair(tag("\\"), anychar)),
+                recognize(none_of(if quote == "\"" { "\"\\\n" } else { "'\\\n" })),
+            )))),
+            tag(quote),
+        )
+    };
+    alt((contents("\""), contents("'")))(input)
+}
+
+/// Adjacent string literals, as the one string they make.
+fn strings(input: &str) -> IResult<&str, String> {
+    map(
+        pair(quoted, many0(preceded(many0(br), quoted))),
+        |(first, rest)| rest.into_iter().fold(first.to_owned(), |s, r| s + r),
+    )(input)
+}
+
+fn option_value(input: &str) -> IResult<&str, OptionValue> {
+    alt((
+        map(aggregate, OptionValue::Aggregate),
+        map(signed_number, OptionValue::Number),
+        map(strings, OptionValue::String),
+        map(word, OptionValue::Ident),
+    ))(input)
+}
+
+/// A message literal in text format: `{ a: 1, b { c: "x" } d: [2, 3] }`.
+fn aggregate(input: &str) -> IResult<&str, Vec<(String, OptionValue)>> {
+    let fields = |input| {
+        map(
+            many0(terminated(
+                aggregate_field,
+                tuple((many0(br), opt(one_char_of(",;")), many0(br))),
+            )),
+            |fields| fields.into_iter().flatten().collect(),
+        )(input)
+    };
+    alt((
+        delimited(pair(tag("{"), many0(br)), fields, tag("}")),
+        delimited(pair(tag("<"), many0(br)), fields, tag(">")),
+    ))(input)
+}
+
+/// One field of a message literal, once per value it is given.
+fn aggregate_field(input: &str) -> IResult<&str, Vec<(String, OptionValue)>> {
+    let (input, name) = alt((
+        word,
+        // An extension, or an `Any`'s type URL.
+        map(
+            delimited(tag("["), recognize(many1(none_of("]"))), tag("]")),
+            |name: &str| format!("[{}]", name.trim()),
+        ),
+    ))(input)?;
+    let (input, values) = alt((
+        map(
+            preceded(tuple((many0(br), opt(tag(":")), many0(br))), aggregate),
+            |fields| vec![OptionValue::Aggregate(fields)],
+        ),
+        preceded(
+            tuple((many0(br), tag(":"), many0(br))),
+            alt((
+                delimited(
+                    pair(tag("["), many0(br)),
+                    separated_list0(tuple((many0(br), tag(","), many0(br))), option_value),
+                    pair(many0(br), tag("]")),
+                ),
+                map(option_value, |value| vec![value]),
+            )),
+        ),
+    ))(input)?;
+    let fields = values.into_iter().map(|v| (name.clone(), v)).collect();
+    Ok((input, fields))
+}
+
+fn option_assignment(input: &str) -> IResult<&str, ProtoOption> {
+    map(
         separated_pair(
-            option_key,
-            delimited(many0(br), tag("="), many0(br)),
-            map(take_until("]"), |v: &str| v.trim()),
+            option_name,
+            tuple((many0(br), tag("="), many0(br))),
+            option_value,
         ),
-        tag("]"),
+        |(name, value)| ProtoOption { name, value },
     )(input)
 }
 
+/// `option name = value;`, in a file or a block.
+fn option_statement(input: &str) -> IResult<&str, ProtoOption> {
+    delimited(
+        pair(tag("option"), many1(br)),
+        option_assignment,
+        pair(many0(br), tag(";")),
+    )(input)
+}
+
+/// The `[name = value, ...]` after a field or an enum value.
+fn option_list(input: &str) -> IResult<&str, Vec<ProtoOption>> {
+    delimited(
+        pair(tag("["), many0(br)),
+        separated_list1(tuple((many0(br), tag(","), many0(br))), option_assignment),
+        pair(many0(br), tag("]")),
+    )(input)
+}
+
+/// A `bool` option, failing the parse if it is set to anything else.
+fn bool_option<'a>(
+    options: &[ProtoOption],
+    name: &str,
+    error: &'static str,
+) -> Result<Option<bool>, nom::Err<nom::error::Error<&'a str>>> {
+    find_option(options, name)
+        .map(|v| {
+            v.as_bool().ok_or(nom::Err::Failure(nom::error::Error::new(
+                error,
+                nom::error::ErrorKind::Verify,
+            )))
+        })
+        .transpose()
+}
+
 fn frequency(input: &str) -> IResult<&str, ParsingStageFrequencyToken> {
     alt((
         value(ParsingStageFrequencyToken::Optional, tag("optional")),
@@ -279,38 +454,32 @@ fn map_field(input: &str) -> IResult<&str, (FieldType, FieldType)> {
     )(input)
 }
 
-fn default_check<'a>(
+fn default_check(
     syntax: Syntax,
     typ: FieldType,
-    key_vals: &[(&'a str, &'a str)],
-) -> Result<Option<String>, &'a str> {
-    for &(k, v) in key_vals.iter() {
-        if k == "default" {
-            return match (syntax, typ) {
-                (Syntax::Proto2, FieldType::String | FieldType::Bytes) => {
-                    let remove_compulsory_inverted_commas: IResult<&str, &str> =
-                        alt((
-                            delimited(tag("\""), take_until("\""), tag("\"")),
-                            delimited(tag("\'"), take_until("\'"), tag("\'")),
-                        ))(v);
-                    remove_compulsory_inverted_commas
-                        .map(|(_, s)| Some(s.to_owned()))
-                        .map_err(|_| "Default value must be wrapped in inverted commas!")
-                }
-                (Syntax::Proto2, _) => Ok(Some(v.to_owned())),
-                (Syntax::Proto3, _) => Ok(Some(v.to_owned())),
-                (Syntax::Edition(_), _) => Ok(Some(v.to_owned())),
-            };
+    options: &[ProtoOption],
+) -> Result<Option<String>, &'static str> {
+    let Some(value) = find_option(options, "default") else {
+        return Ok(None);
+    };
+    match (syntax, typ, value) {
+        (Syntax::Proto3, _, _) => Err("Explicit default values are not allowed in proto3"),
+        // The quotes go, the escapes inside them stay: the code generator decides what
+        // `\x80` means for `string` versus `bytes`.
+        (_, FieldType::String | FieldType::Bytes, OptionValue::String(s)) => Ok(Some(s.clone())),
+        (_, FieldType::String | FieldType::Bytes, _) => {
+            Err("Default value must be wrapped in inverted commas!")
         }
+        (_, _, OptionValue::Number(v) | OptionValue::Ident(v)) => Ok(Some(v.clone())),
+        _ => Err("Default value must be a number or an identifier"),
     }
-    Ok(None)
 }
 
 fn frequencies(
     syntax: Syntax,
     typ: FieldType,
     pf: Option<ParsingStageFrequencyToken>,
-    packed: bool,
+    packed: Option<bool>,
 ) -> Option<Frequency> {
     // keep the parsed frequency as packed until we resolve the types, as enums (which are just varints) can be packed, but are unknown here
     // we'll error out later if this is wrong.
@@ -323,11 +492,13 @@ fn frequencies(
         (Syntax::Proto2, _, Some(ParsingStageFrequencyToken::Optional)) => {
             Some(Frequency::Optional)
         }
-        (Syntax::Proto2, _, Some(ParsingStageFrequencyToken::Repeated)) => Some(if packed {
-            Frequency::Packed
-        } else {
-            Frequency::Repeated
-        }),
+        (Syntax::Proto2, _, Some(ParsingStageFrequencyToken::Repeated)) => {
+            Some(if packed == Some(true) {
+                Frequency::Packed
+            } else {
+                Frequency::Repeated
+            })
+        }
         (Syntax::Proto2, _, None) => None, //only allowed within oneOfs
 
         (Syntax::Proto3, _, Some(ParsingStageFrequencyToken::Required)) => {
@@ -336,7 +507,13 @@ fn frequencies(
         (Syntax::Proto3, _, Some(ParsingStageFrequencyToken::Optional)) => {
             Some(Frequency::Optional)
         }
-        (Syntax::Proto3, _, Some(ParsingStageFrequencyToken::Repeated)) => Some(Frequency::Packed),
+        (Syntax::Proto3, _, Some(ParsingStageFrequencyToken::Repeated)) => {
+            Some(if packed == Some(false) {
+                Frequency::Repeated
+            } else {
+                Frequency::Packed
+            })
+        }
         (Syntax::Proto3, _, None) => Some(Frequency::Plain),
 
         // Edition defaults: EXPLICIT presence (Optional), PACKED repeated
@@ -356,6 +533,7 @@ fn frequencies(
 fn field_generic(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Field> {
     let is_edition = matches!(syntax, Syntax::Edition(_));
     move |input| -> IResult<&str, Field> {
+        let span = Span::at(input);
         // Parse frequency label — editions only allow `repeated`
         let (input, freq) = if is_edition {
             opt(terminated(edition_frequency, many1(br)))(input)?
@@ -368,51 +546,21 @@ fn field_generic(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Field> {
             delimited(many0(br), tag("="), many0(br)),
             alt((integer, hex_integer)),
         )(input)?;
-        let (input, key_vals) =
-            delimited(many0(br), many0(key_val), pair(many0(br), tag(";")))(input)?;
-
-        let packed = key_vals
-            .iter()
-            .find_map(|&(k, v)| {
-                if k == "packed" {
-                    Some(v.parse().expect("Cannot parse Packed value"))
-                } else {
-                    None
-                }
-            })
-            .unwrap_or_default();
-
-        let mut frequency = frequencies(syntax.clone(), typ.clone(), freq.clone(), packed);
-
-        // Apply field-level feature overrides for editions
-        if is_edition {
-            for &(k, v) in &key_vals {
-                match k {
-                    "features.field_presence" => {
-                        frequency = match v.trim() {
-                            "IMPLICIT" => Some(Frequency::Plain),
-                            "EXPLICIT" => Some(Frequency::Optional),
-                            "LEGACY_REQUIRED" => Some(Frequency::Required),
-                            _ => frequency,
-                        };
-                    }
-                    "features.repeated_field_encoding" => {
-                        if freq.is_some() {
-                            frequency = match v.trim() {
-                                "PACKED" => Some(Frequency::Packed),
-                                "EXPANDED" => Some(Frequency::Repeated),
-                                _ => frequency,
-                            };
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        }
+        let (input, options) = delimited(
+            many0(br),
+            map(opt(option_list), Option::unwrap_or_default),
+            pair(many0(br), tag(";")),
+        )(input)?;
 
-        let default = default_check(syntax.clone(), typ.clone(), &key_vals).map_err(|e| {
+        let packed = bool_option(&options, "packed", "`packed` must be true or false")?;
+        // An edition's features are resolved once the whole file is parsed: they can be set
+        // on anything the field is declared in.
+        let frequency = frequencies(syntax.clone(), typ.clone(), freq.clone(), packed);
+
+        let default = default_check(syntax.clone(), typ.clone(), &options).map_err(|e| {
             nom::Err::Failure(nom::error::Error::new(e, nom::error::ErrorKind::Verify))
         })?;
+        let deprecated = bool_option(&options, "deprecated", "`deprecated` must be true or false")?;
 
         Ok((
             input,
@@ -422,16 +570,11 @@ fn field_generic(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Field> {
                 number,
                 default,
                 typ,
-                deprecated: key_vals
-                    .iter()
-                    .find_map(|&(k, v)| {
-                        if k == "deprecated" {
-                            Some(v.parse().expect("Cannot parse Deprecated value"))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(false),
+                span,
+                deprecated: deprecated.unwrap_or_default(),
+                options,
+                comments: Default::default(),
+                features: Default::default(),
             },
         ))
     }
@@ -454,13 +597,24 @@ fn one_of(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, OneOf> {
                     pair(many0(br), tag("{")),
                     many1(delimited(
                         many0(br),
-                        oneof_message_field(syntax.clone()),
+                        alt((
+                            map(oneof_message_field(syntax.clone()), OneOfEvent::Field),
+                            map(option_statement, OneOfEvent::Option),
+                        )),
                         many0(br),
                     )),
                     tag("}"),
                 ),
             ),
-            |(name, mut fields)| {
+            |(name, events)| {
+                let mut fields = Vec::new();
+                let mut options = Vec::new();
+                for event in events {
+                    match event {
+                        OneOfEvent::Field(f) => fields.push(f),
+                        OneOfEvent::Option(o) => options.push(o),
+                    }
+                }
                 for field in &mut fields {
                     match syntax {
                         Syntax::Proto2 | Syntax::Edition(_) => {
@@ -472,6 +626,7 @@ fn one_of(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, OneOf> {
                 OneOf {
                     name,
                     fields,
+                    options,
                     package: "".to_string(),
                     module: "".to_string(),
                     imported: false,
@@ -481,14 +636,27 @@ fn one_of(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, OneOf> {
     }
 }
 
+/// An rpc's request or response type, with whether it is a `stream` of them.
+fn rpc_type(input: &str) -> IResult<&str, (bool, String)> {
+    pair(
+        map(opt(pair(tag("stream"), many1(br))), |s| s.is_some()),
+        qualifiable_name,
+    )(input)
+}
+
 fn rpc_function_declaration(input: &str) -> IResult<&str, RpcFunctionDeclaration> {
+    let span = Span::at(input);
     map(
         tuple((
             delimited(pair(tag("rpc"), many1(br)), word, many0(br)),
-            delimited(pair(tag("("), many0(br)), word, pair(many0(br), tag(")"))),
             delimited(
-                tuple((many1(br), tag("returns"), many0(br), tag("("), many0(br))),
-                word,
+                pair(tag("("), many0(br)),
+                rpc_type,
+                pair(many0(br), tag(")")),
+            ),
+            delimited(
+                tuple((many0(br), tag("returns"), many0(br), tag("("), many0(br))),
+                rpc_type,
                 pair(many0(br), tag(")")),
             ),
             preceded(
@@ -498,7 +666,7 @@ fn rpc_function_declaration(input: &str) -> IResult<&str, RpcFunctionDeclaration
                         (),
                         delimited(
                             pair(tag("{"), many0(br)),
-                            many0(alt((option_ignore, value((), tag(";"))))),
+                            many0(alt((value((), option_statement), value((), tag(";"))))),
                             pair(many0(br), tag("}")),
                         ),
                     ),
@@ -506,11 +674,20 @@ fn rpc_function_declaration(input: &str) -> IResult<&str, RpcFunctionDeclaration
                 )),
             ),
         )),
-        |(name, arg, ret, _)| RpcFunctionDeclaration { name, arg, ret },
+        move |(name, (client_streaming, arg), (server_streaming, ret), _)| RpcFunctionDeclaration {
+            name,
+            arg,
+            ret,
+            client_streaming,
+            server_streaming,
+            span: span.clone(),
+            ..Default::default()
+        },
     )(input)
 }
 
 fn rpc_service(input: &str) -> IResult<&str, RpcService> {
+    let span = Span::at(input);
     map(
         pair(
             delimited(pair(tag("service"), many1(br)), word, many0(br)),
@@ -520,9 +697,11 @@ fn rpc_service(input: &str) -> IResult<&str, RpcService> {
                 tag("}"),
             ),
         ),
-        |(service_name, functions)| RpcService {
+        move |(service_name, functions)| RpcService {
             service_name,
             functions,
+            span: span.clone(),
+            ..Default::default()
         },
     )(input)
 }
@@ -549,7 +728,7 @@ fn message_event(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, MessageEve
             ),
             map(one_of(syntax.clone()), MessageEvent::OneOf),
             map(extensions, MessageEvent::Extensions),
-            value(MessageEvent::Ignore, option_ignore),
+            map(option_statement, MessageEvent::Option),
             value(MessageEvent::Ignore, br),
         ))(input)
     }
@@ -557,6 +736,7 @@ fn message_event(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, MessageEve
 
 fn message(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Message> {
     move |input| {
+        let span = Span::at(input);
         map(
             terminated(
                 pair(
@@ -565,20 +745,27 @@ fn message(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Message> {
                 ),
                 opt(pair(many0(br), tag(";"))),
             ),
-            |(name, events)| {
+            move |(name, events)| {
                 let mut msg = Message {
                     name,
+                    span: span.clone(),
                     ..Default::default()
                 };
                 for e in events {
                     match e {
                         MessageEvent::Field(f) => msg.fields.push(f),
-                        MessageEvent::ReservedNums(r) => msg.reserved_nums = Some(r),
-                        MessageEvent::ReservedNames(r) => msg.reserved_names = Some(r),
+                        // Each `reserved` statement adds to the ones before it.
+                        MessageEvent::ReservedNums(r) => {
+                            msg.reserved_nums.get_or_insert_with(Vec::new).extend(r)
+                        }
+                        MessageEvent::ReservedNames(r) => {
+                            msg.reserved_names.get_or_insert_with(Vec::new).extend(r)
+                        }
                         MessageEvent::Message(m) => msg.messages.push(m),
                         MessageEvent::Enumerator(e) => msg.enums.push(e),
                         MessageEvent::OneOf(o) => msg.oneofs.push(o),
-                        MessageEvent::Extensions(e) => msg.extensions = Some(e),
+                        MessageEvent::Extensions(e) => msg.extensions.extend(e),
+                        MessageEvent::Option(o) => msg.options.push(o),
                         MessageEvent::Ignore => (),
                     }
                 }
@@ -588,47 +775,35 @@ fn message(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Message> {
     }
 }
 
-fn enum_field(input: &str) -> IResult<&str, (String, i32)> {
+fn enum_field(input: &str) -> IResult<&str, ((String, i32), Vec<ProtoOption>)> {
     terminated(
-        separated_pair(
-            word,
-            tuple((many0(br), tag("="), many0(br))),
-            alt((hex_integer, integer)),
-        ),
         pair(
-            many0(alt((
-                br,
-                // TODO: add proper deprecation later. We ignore deprecated enum
-                // fields for now
-                value(
-                    (),
-                    tuple((
-                        tag("["),
-                        many0(multispace1),
-                        tag("deprecated"),
-                        many0(multispace1),
-                        tag("="),
-                        many0(multispace1),
-                        word,
-                        many0(multispace1),
-                        tag("]"),
-                    )),
-                ),
-            ))),
-            tag(";"),
+            separated_pair(
+                word,
+                tuple((many0(br), tag("="), many0(br))),
+                alt((hex_integer, integer)),
+            ),
+            preceded(many0(br), map(opt(option_list), Option::unwrap_or_default)),
         ),
+        pair(many0(br), tag(";")),
     )(input)
 }
 
 fn enum_event(input: &str) -> IResult<&str, EnumEvent> {
+    let span = Span::at(input);
     alt((
-        map(enum_field, EnumEvent::Field),
-        value(EnumEvent::Ignore, option_ignore),
+        map(enum_field, move |(f, options)| {
+            EnumEvent::Field(f, options, span.clone())
+        }),
+        map(reserved_nums, EnumEvent::ReservedNums),
+        map(reserved_names, EnumEvent::ReservedNames),
+        map(option_statement, EnumEvent::Option),
         value(EnumEvent::Ignore, br),
     ))(input)
 }
 
 fn enumerator(input: &str) -> IResult<&str, Enumerator> {
+    let span = Span::at(input);
     map_res(
         terminated(
             pair(
@@ -637,28 +812,40 @@ fn enumerator(input: &str) -> IResult<&str, Enumerator> {
             ),
             opt(pair(many0(br), tag(";"))),
         ),
-        |(name, events)| {
+        move |(name, events)| {
             let mut enumerator = Enumerator {
                 name,
+                span: span.clone(),
                 ..Default::default()
             };
             for event in events {
-                if let EnumEvent::Field(f) = event {
-                    enumerator.fields.push(f);
+                match event {
+                    EnumEvent::Field(f, options, span) => {
+                        enumerator.fields.push(f);
+                        enumerator.value_options.push(options);
+                        enumerator.value_spans.push(span);
+                    }
+                    EnumEvent::ReservedNums(r) => enumerator
+                        .reserved_nums
+                        .get_or_insert_with(Vec::new)
+                        .extend(r),
+                    EnumEvent::ReservedNames(r) => enumerator
+                        .reserved_names
+                        .get_or_insert_with(Vec::new)
+                        .extend(r),
+                    EnumEvent::Option(o) => enumerator.options.push(o),
+                    EnumEvent::Ignore => (),
                 }
             }
+            enumerator.allow_alias = find_option(&enumerator.options, "allow_alias")
+                .map(|v| v.as_bool().ok_or("`allow_alias` must be true or false"))
+                .transpose()?
+                .unwrap_or_default();
             Ok::<Enumerator, &str>(enumerator)
         },
     )(input)
 }
 
-fn option_ignore(input: &str) -> IResult<&str, ()> {
-    value(
-        (),
-        delimited(pair(tag("option"), many1(br)), take_until(";"), tag(";")),
-    )(input)
-}
-
 fn extend(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Extend> {
     move |input| {
         map(
@@ -677,7 +864,11 @@ fn extend(syntax: Syntax) -> impl FnMut(&str) -> IResult<&str, Extend> {
                 ),
                 opt(pair(many0(br), tag(";"))),
             ),
-            |(name, fields)| Extend { name, fields },
+            |(name, fields)| Extend {
+                name,
+                fields,
+                ..Default::default()
+            },
         )(input)
     }
 }
@@ -724,7 +915,7 @@ pub fn file_descriptor<'a>(
                     ),
                     map(rpc_service, Event::RpcService),
                     map(extend(got_syntax), Event::Extend),
-                    value(Event::Ignore, option_ignore),
+                    map(option_statement, Event::Option),
                     value(Event::Ignore, br),
                 ))),
                 |events| {
@@ -732,12 +923,13 @@ pub fn file_descriptor<'a>(
                     for event in events {
                         match event {
                             Event::Syntax(s) => desc.syntax = s,
-                            Event::Import(i) => desc.import_paths.push(i),
+                            Event::Import(i) => desc.imports.push(i),
                             Event::Package(p) => desc.package = p,
                             Event::Message(m) => desc.messages.push(m),
                             Event::Enum(e) => desc.enums.push(e),
                             Event::RpcService(r) => desc.rpc_services.push(r),
                             Event::Extend(e) => desc.message_extends.push(e),
+                            Event::Option(o) => desc.options.push(o),
                             Event::Ignore => (),
                         }
                     }
@@ -752,6 +944,7 @@ pub fn file_descriptor<'a>(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::types::{Comments, Features, FieldPresence, RepeatedFieldEncoding, Utf8Validation};
 
     use std::path::Path;
 
@@ -782,8 +975,9 @@ mod test {
     }
 
     fn assert_desc(msg: &str) -> Result<FileDescriptor, &str> {
-        let (rem, obj) = file_descriptor(msg).expect("valid parse");
+        let (rem, mut obj) = file_descriptor(msg).expect("valid parse");
         result_assert_eq("", rem, Some("expected no trailing data"))?;
+        obj.resolve_features().expect("valid features");
         Ok(obj)
     }
 
@@ -852,22 +1046,143 @@ mod test {
     }
 
     #[test]
-    fn test_ignore() {
+    fn test_file_options() {
         let msg = r#"option optimize_for = SPEED;"#;
-
-        match option_ignore(msg) {
-            ::nom::IResult::Ok(_) => (),
-            e => panic!("Expecting done {:?}", e),
-        }
+        let (rest, option) = option_statement(msg).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(option.name, "optimize_for");
+        assert_eq!(option.value, OptionValue::Ident("SPEED".to_string()));
 
         let msg2 = r#"option (parenthesized) = 123;"#;
+        let (_, option) = option_statement(msg2).unwrap();
+        assert_eq!(option.name, "(parenthesized)");
+        assert_eq!(option.value, OptionValue::Number("123".to_string()));
+
+        let desc = assert_desc(&format!("{msg}\n{msg2}")).unwrap();
+        assert_eq!(desc.options.len(), 2);
+        assert_eq!(
+            find_option(&desc.options, "(parenthesized)"),
+            Some(&OptionValue::Number("123".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_option_values() {
+        let value = |s| assert_complete(option_value(s)).unwrap();
+        assert_eq!(value("-1"), OptionValue::Number("-1".to_string()));
+        assert_eq!(value("- inf"), OptionValue::Number("-inf".to_string()));
+        assert_eq!(value("2.5e-3"), OptionValue::Number("2.5e-3".to_string()));
+        assert_eq!(value("0x1F"), OptionValue::Number("0x1F".to_string()));
+        assert_eq!(value("inf"), OptionValue::Ident("inf".to_string()));
+        assert_eq!(value("true").as_bool(), Some(true));
+        assert_eq!(value(r#""a\"b" 'c'"#).as_str(), Some(r#"a\"bc"#));
+
+        let aggregate = value(
+            r#"{
+                name: "x"
+                limits { low: 1, high: 2 }
+                tags: ["a", "b"];
+                nested: < flag: true >
+                [my.ext]: 3
+            }"#,
+        );
+        let OptionValue::Aggregate(fields) = &aggregate else {
+            panic!("not an aggregate: {aggregate:?}");
+        };
+        let keys: Vec<_> = fields.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(
+            keys,
+            ["name", "limits", "tags", "tags", "nested", "[my.ext]"]
+        );
+        assert_eq!(
+            aggregate.get("name").and_then(OptionValue::as_str),
+            Some("x")
+        );
+        assert_eq!(
+            aggregate.get("limits").and_then(|l| l.get("high")),
+            Some(&OptionValue::Number("2".to_string()))
+        );
+        assert_eq!(fields[3].1.as_str(), Some("b"));
+        assert_eq!(
+            aggregate.get("nested").and_then(|n| n.get("flag")),
+            Some(&OptionValue::Ident("true".to_string()))
+        );
+    }
 
-        match option_ignore(msg2) {
-            ::nom::IResult::Ok(_) => (),
-            e => panic!("Expecting done {:?}", e),
+    #[test]
+    fn test_declaration_options() {
+        let msg = r#"syntax = "proto2";
+        message Foo {
+            option deprecated = true;
+            option (my.ext) = { a: 1 };
+            repeated int32 a = 1 [packed = true, deprecated = true];
+            optional string b_c = 2 [json_name = "bee", default = "x\n"];
+            optional int32 some_field_2 = 3 [(my.rule).min = -5];
+            oneof choice {
+                option (my.oneof_flag) = true;
+                int32 d = 4;
+            }
         }
-        assert_desc(msg).unwrap();
-        assert_desc(msg2).unwrap();
+        enum E {
+            option allow_alias = true;
+            A = 0 [deprecated = true, (my.label) = "a"];
+            B = 0;
+        }"#;
+        let desc = assert_desc(msg).unwrap();
+        let foo = &desc.messages[0];
+        assert_eq!(foo.options.len(), 2);
+        assert_eq!(
+            find_option(&foo.options, "deprecated").and_then(OptionValue::as_bool),
+            Some(true)
+        );
+        assert_eq!(
+            find_option(&foo.options, "(my.ext)"),
+            Some(&OptionValue::Aggregate(vec![(
+                "a".to_string(),
+                OptionValue::Number("1".to_string())
+            )]))
+        );
+
+        let [a, b, c] = &foo.fields[..] else {
+            panic!("{:?}", foo.fields);
+        };
+        assert_eq!(a.frequency, Some(Frequency::Packed));
+        assert!(a.deprecated);
+        assert_eq!(a.options.len(), 2);
+        assert_eq!(b.default.as_deref(), Some("x\\n"));
+        assert_eq!(b.json_name(), "bee");
+        assert_eq!(a.json_name(), "a");
+        assert_eq!(c.json_name(), "someField2");
+        assert_eq!(
+            find_option(&c.options, "(my.rule).min"),
+            Some(&OptionValue::Number("-5".to_string()))
+        );
+
+        assert_eq!(foo.oneofs[0].options[0].name, "(my.oneof_flag)");
+        assert_eq!(foo.oneofs[0].fields.len(), 1);
+
+        let e = &desc.enums[0];
+        assert!(e.allow_alias);
+        assert_eq!(e.value_options.len(), 2);
+        assert_eq!(
+            find_option(&e.value_options[0], "(my.label)").and_then(OptionValue::as_str),
+            Some("a")
+        );
+        assert!(e.value_options[1].is_empty());
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        let msg = r#"syntax = "proto2";
+        message Foo {
+            repeated int32 a = 1 [packed = yes];
+        }"#;
+        assert!(file_descriptor(msg).is_err());
+        let msg = r#"syntax = "proto2";
+        message Foo {
+            optional string a = 1 [default = x];
+        }"#;
+        assert!(file_descriptor(msg).is_err());
     }
 
     #[test]
@@ -939,10 +1254,12 @@ mod test {
     }
     "#;
         let desc = file_descriptor(msg).unwrap().1;
+        let paths: Vec<_> = desc.imports.iter().map(|i| &i.path).collect();
         assert_eq!(
             vec![Path::new("test_import_nested_imported_pb.proto")],
-            desc.import_paths
+            paths
         );
+        assert_eq!(ImportKind::Plain, desc.imports[0].kind);
         assert_desc(msg).unwrap();
     }
 
@@ -995,6 +1312,29 @@ mod test {
         assert_eq!(1, desc.enums.len());
     }
 
+    #[test]
+    fn test_custom_defaults() {
+        let msg = r#"syntax = "proto2";
+        message A {
+            optional string s = 1 [default = "say \"hi\"\n"];
+            optional bytes b = 2 [default = '\x00\377'];
+            optional double d = 3 [default = -inf];
+            optional int32 i = 4;
+        }"#;
+        let desc = file_descriptor(msg).unwrap().1;
+        let fields = &desc.messages[0].fields;
+        assert_eq!(fields[0].default.as_deref(), Some(r#"say \"hi\"\n"#));
+        assert_eq!(fields[1].default.as_deref(), Some(r"\x00\377"));
+        assert_eq!(fields[2].default.as_deref(), Some("-inf"));
+        assert_eq!(fields[3].default, None);
+
+        let proto3 = r#"syntax = "proto3";
+        message A {
+            int32 i = 1 [default = 3];
+        }"#;
+        assert!(file_descriptor(proto3).is_err());
+    }
+
     #[test]
     fn test_extend() {
         let msg = r#"message A {
@@ -1052,22 +1392,30 @@ mod test {
         }
         message c {
             optional int32 c = 1;
+        }
+        message D {
+            extensions 100 to 199, 300;
+            optional int32 d = 1;
+            extensions 500 to max;
         }"#;
 
         let desc = file_descriptor(msg).unwrap().1;
-        assert_eq!(3, desc.messages.len());
-        let a = &desc.messages[0].extensions;
-        let b = &desc.messages[1].extensions;
-        let c = &desc.messages[2].extensions;
-        assert!(a.is_some());
-        assert!(b.is_some());
-        assert!(c.is_none());
-        let a = a.as_ref().unwrap();
-        let b = b.as_ref().unwrap();
-        assert_eq!(a.from, 1300);
-        assert_eq!(a.to, Extensions::max());
-        assert_eq!(b.from, 10321);
-        assert_eq!(b.to, 11000);
+        assert_eq!(4, desc.messages.len());
+        let ranges = |i: usize| -> Vec<(i32, i32)> {
+            desc.messages[i]
+                .extensions
+                .iter()
+                .map(|e| (e.from, e.to))
+                .collect()
+        };
+        assert_eq!(ranges(0), [(1300, Extensions::max())]);
+        assert_eq!(ranges(1), [(10321, 11000)]);
+        assert!(ranges(2).is_empty());
+        // Every range, from every statement.
+        assert_eq!(
+            ranges(3),
+            [(100, 199), (300, 300), (500, Extensions::max())]
+        );
     }
 
     #[test]
@@ -1175,6 +1523,34 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_reserved_statements_add_up() {
+        let msg = r#"syntax = "proto3";
+        message Sample {
+            reserved 2;
+            reserved 5 to 6;
+            reserved "old";
+            string name = 1;
+            reserved "older";
+        }
+        enum Kind {
+            KIND_UNKNOWN = 0;
+            reserved 1, 3 to 4;
+            reserved "KIND_GONE";
+        }"#;
+        let desc = file_descriptor(msg).unwrap().1;
+        let sample = &desc.messages[0];
+        assert_eq!(sample.reserved_nums, Some(vec![2, 5, 6]));
+        assert_eq!(
+            sample.reserved_names,
+            Some(vec!["old".to_string(), "older".to_string()])
+        );
+        let kind = &desc.enums[0];
+        assert_eq!(kind.fields, [("KIND_UNKNOWN".to_string(), 0)]);
+        assert_eq!(kind.reserved_nums, Some(vec![1, 3, 4]));
+        assert_eq!(kind.reserved_names, Some(vec!["KIND_GONE".to_string()]));
+    }
+
     #[test]
     fn enum_comments() {
         test_syntaxes(move |_syntax: Syntax| -> Result<(), &str> {
@@ -1191,6 +1567,24 @@ mod test {
         });
     }
 
+    #[test]
+    fn enum_allow_alias() {
+        let msg = r#"enum Status {
+            option allow_alias = true;
+            UNKNOWN = 0;
+            STARTED = 1;
+            RUNNING = 1;
+        }"#;
+        let en = assert_complete(enumerator(msg)).unwrap();
+        assert!(en.allow_alias);
+        assert_eq!(en.fields.len(), 3);
+
+        let en = assert_complete(enumerator("enum E { option allow_alias = false; A = 0; }"));
+        assert!(!en.unwrap().allow_alias);
+        let en = assert_complete(enumerator("enum E { option deprecated = true; A = 0; }"));
+        assert!(!en.unwrap().allow_alias);
+    }
+
     #[test]
     fn enum_semicolon() {
         let msg = r#"message Foo { enum Bar { BAZ = 1; }; Bar boop = 1; }"#;
@@ -1235,8 +1629,8 @@ mod test {
         match file_descriptor(msg) {
             ::nom::IResult::Ok((_, descriptor)) => {
                 println!("Services found: {:?}", descriptor.rpc_services);
-                let service = &descriptor.rpc_services.get(0).expect("Service not found!");
-                let func0 = service.functions.get(0).expect("Function 0 not returned!");
+                let service = &descriptor.rpc_services.first().expect("Service not found!");
+                let func0 = service.functions.first().expect("Function 0 not returned!");
                 let func1 = service.functions.get(1).expect("Function 1 not returned!");
                 let func2 = service.functions.get(2).expect("Function 2 not returned!");
                 assert_eq!("RpcService", service.service_name);
@@ -1255,6 +1649,30 @@ mod test {
         assert_desc(msg).unwrap();
     }
 
+    #[test]
+    fn test_rpc_streaming() {
+        let msg = r#"
+            package routes;
+            message Point {}
+            message Summary {}
+            service RouteGuide {
+                rpc RecordRoute(stream Point) returns (Summary);
+                rpc RouteChat(stream .routes.Point) returns (stream routes.Point);
+            }
+        "#;
+        let desc = FileDescriptor::read_proto_str(msg, Path::new("routes.proto"), &[]).unwrap();
+        let [record, chat] = &desc.rpc_services[0].functions[..] else {
+            panic!("{:?}", desc.rpc_services);
+        };
+        assert!(record.client_streaming && !record.server_streaming);
+        assert!(chat.client_streaming && chat.server_streaming);
+        assert_eq!(chat.arg, ".routes.Point");
+        let output = record.output.as_ref().unwrap();
+        assert_eq!(output.get_message(&desc).name, "Summary");
+        assert_eq!(chat.input, chat.output);
+        assert_eq!(chat.span.line, 7);
+    }
+
     #[test]
     fn test_rpc_function() {
         let msg = r#"rpc function_name(Arg) returns (Ret);"#;
@@ -1793,19 +2211,19 @@ mod test {
     }
 
     #[test]
-    fn test_edition_key_val_dotted() {
-        let (rem, (k, v)) = key_val("[features.field_presence = IMPLICIT]").unwrap();
+    fn test_edition_option_dotted() {
+        let (rem, options) = option_list("[features.field_presence = IMPLICIT]").unwrap();
         assert_eq!(rem, "");
-        assert_eq!(k, "features.field_presence");
-        assert_eq!(v, "IMPLICIT");
+        assert_eq!(options[0].name, "features.field_presence");
+        assert_eq!(options[0].value.as_ident(), Some("IMPLICIT"));
     }
 
     #[test]
-    fn test_edition_key_val_parenthesized() {
-        let (rem, (k, v)) = key_val("[features.(pb.cpp).string_type = STRING]").unwrap();
+    fn test_edition_option_parenthesized() {
+        let (rem, options) = option_list("[features.(pb.cpp).string_type = STRING]").unwrap();
         assert_eq!(rem, "");
-        assert_eq!(k, "features.(pb.cpp).string_type");
-        assert_eq!(v, "STRING");
+        assert_eq!(options[0].name, "features.(pb.cpp).string_type");
+        assert_eq!(options[0].value.as_ident(), Some("STRING"));
     }
 
     #[test]
@@ -1850,16 +2268,18 @@ mod test {
 
     #[test]
     fn test_edition_import_option() {
-        let (rem, path) = import(r#"import option "some/features.proto";"#).unwrap();
+        let (rem, import) = import(r#"import option "some/features.proto";"#).unwrap();
         assert_eq!(rem, "");
-        assert_eq!(path, PathBuf::from("some/features.proto"));
+        assert_eq!(import.path, PathBuf::from("some/features.proto"));
+        assert_eq!(import.kind, ImportKind::Option);
     }
 
     #[test]
     fn test_edition_import_public() {
-        let (rem, path) = import(r#"import public "other.proto";"#).unwrap();
+        let (rem, import) = import(r#"import public "other.proto";"#).unwrap();
         assert_eq!(rem, "");
-        assert_eq!(path, PathBuf::from("other.proto"));
+        assert_eq!(import.path, PathBuf::from("other.proto"));
+        assert_eq!(import.kind, ImportKind::Public);
     }
 
     #[test]
@@ -1951,22 +2371,105 @@ mod test {
     }
 
     #[test]
-    fn test_edition_file_level_options_ignored() {
+    fn test_edition_file_level_options_inherited() {
         let msg = r#"edition = "2023";
         option features.field_presence = IMPLICIT;
         option features.enum_type = CLOSED;
         option features.utf8_validation = NONE;
         message Foo {
             int32 bar = 1;
+            string name = 2;
+            int32 baz = 3 [features.field_presence = EXPLICIT];
+            enum Kind {
+                option features.enum_type = OPEN;
+                KIND_UNKNOWN = 0;
+            }
+        }
+        enum Status {
+            STATUS_UNKNOWN = 0;
         }"#;
         let desc = assert_desc(msg).unwrap();
-        assert_eq!(1, desc.messages.len());
-        // File-level options are silently ignored (v1 limitation);
-        // field still gets edition default (Optional/EXPLICIT)
+        let foo = &desc.messages[0];
+        assert_eq!(foo.fields[0].frequency, Some(Frequency::Plain));
+        assert_eq!(foo.fields[1].features.utf8_validation, Utf8Validation::None);
+        assert_eq!(foo.fields[2].frequency, Some(Frequency::Optional));
+        assert!(!foo.enums[0].closed);
+        assert!(desc.enums[0].closed);
+    }
+
+    #[test]
+    fn test_edition_features_inherited() {
+        let msg = r#"edition = "2023";
+        message Outer {
+            option features.repeated_field_encoding = EXPANDED;
+            option features = { field_presence: IMPLICIT };
+            repeated int32 expanded = 1;
+            message Inner {
+                repeated int32 still_expanded = 1;
+                repeated int32 packed = 2 [features.repeated_field_encoding = PACKED];
+                int32 implicit = 3;
+            }
+            oneof choice {
+                int32 a = 4;
+            }
+        }
+        message Required {
+            int32 id = 1 [features.field_presence = LEGACY_REQUIRED];
+        }"#;
+        let desc = assert_desc(msg).unwrap();
+        let outer = &desc.messages[0];
+        let inner = &outer.messages[0];
+        assert_eq!(outer.fields[0].frequency, Some(Frequency::Repeated));
+        assert_eq!(inner.fields[0].frequency, Some(Frequency::Repeated));
+        assert_eq!(inner.fields[1].frequency, Some(Frequency::Packed));
+        assert_eq!(inner.fields[2].frequency, Some(Frequency::Plain));
+        // Which field of a oneof is set is its presence, whatever the features say.
         assert_eq!(
-            desc.messages[0].fields[0].frequency,
+            outer.oneofs[0].fields[0].frequency,
             Some(Frequency::Optional)
         );
+        assert_eq!(
+            desc.messages[1].fields[0].frequency,
+            Some(Frequency::Required)
+        );
+    }
+
+    #[test]
+    fn test_invalid_features() {
+        let resolve = |msg: &str| file_descriptor(msg).unwrap().1.resolve_features();
+        let bad_value = r#"edition = "2023";
+        message Foo {
+            int32 bar = 1 [features.field_presence = SOMETIMES];
+        }"#;
+        let err = resolve(bad_value).unwrap_err().to_string();
+        assert!(
+            err.contains(
+                "`features.field_presence` must be one of EXPLICIT, IMPLICIT, LEGACY_REQUIRED"
+            ),
+            "{err}"
+        );
+        let proto3 = r#"syntax = "proto3";
+        message Foo {
+            int32 bar = 1 [features.field_presence = EXPLICIT];
+        }"#;
+        let err = resolve(proto3).unwrap_err().to_string();
+        assert!(err.contains("`features` are for editions"), "{err}");
+    }
+
+    #[test]
+    fn test_syntax_features() {
+        let proto2 = assert_desc("syntax = \"proto2\";\nenum E { A = 1; }").unwrap();
+        assert!(proto2.enums[0].closed);
+        let proto3 = assert_desc("syntax = \"proto3\";\nenum E { A = 0; }").unwrap();
+        assert!(!proto3.enums[0].closed);
+        assert_eq!(
+            Features::of(&Syntax::Proto3).field_presence,
+            FieldPresence::Implicit
+        );
+        assert_eq!(
+            Features::of(&Syntax::Proto2).repeated_field_encoding,
+            RepeatedFieldEncoding::Expanded
+        );
     }
 
     #[test]
@@ -1985,4 +2488,108 @@ mod test {
         assert_eq!(player.fields[1].frequency, Some(Frequency::Required));
         assert_eq!(player.fields[2].frequency, Some(Frequency::Repeated));
     }
+
+    #[test]
+    fn test_spans() {
+        let msg = "syntax = \"proto3\";\nmessage Outer {\n  message Inner {}\n  enum Kind { A = 0; }\n  Inner inner = 1;\n}\n";
+        let file = Path::new("spans.proto");
+        let desc = FileDescriptor::read_proto_str(msg, file, &[]).unwrap();
+        let outer = &desc.messages[0];
+        assert_eq!((outer.span.line, outer.span.column), (2, 1));
+        assert_eq!(outer.span.file, file);
+        let inner = &outer.messages[0];
+        assert_eq!((inner.span.line, inner.span.column), (3, 3));
+        assert_eq!(
+            (outer.enums[0].span.line, outer.enums[0].span.column),
+            (4, 3)
+        );
+        let field = &outer.fields[0];
+        assert_eq!((field.span.line, field.span.column), (5, 3));
+        assert_eq!(field.span.line_text, "  Inner inner = 1;");
+        assert_eq!(field.span.to_string(), "spans.proto:5:3");
+    }
+
+    #[test]
+    fn test_attached_comments() {
+        let msg = r#"syntax = "proto2";
+
+// A message.
+// Two lines of it.
+message Foo {
+  /* Block, not a "string" */
+  optional int32 a = 1; // Trailing a.
+  // Detached: a blank line follows.
+
+  // Leading b.
+  optional string b = 2 [default = "; // not a comment"];
+  // Below b, then the end of the message.
+}
+
+/**
+ * Kinds.
+ */
+enum Kind {
+  KIND_A = 0;
+  // Leading KIND_B.
+  KIND_B = 1; /* Trailing KIND_B. */
+}
+
+// The service.
+service Greeter { // On the same line.
+  rpc Hello(Foo) returns (Foo);
+  // Below Hello.
+}
+"#;
+        let desc = FileDescriptor::read_proto_str(msg, Path::new("c.proto"), &[]).unwrap();
+        let text = |s: &str| Some(s.to_string());
+
+        let foo = &desc.messages[0];
+        assert_eq!(
+            foo.comments.leading,
+            text(" A message.\n Two lines of it.\n")
+        );
+        assert_eq!(foo.comments.trailing, None);
+        let (a, b) = (&foo.fields[0], &foo.fields[1]);
+        assert_eq!(a.comments.leading, text(" Block, not a \"string\" "));
+        assert_eq!(a.comments.trailing, text(" Trailing a.\n"));
+        assert_eq!(b.comments.leading, text(" Leading b.\n"));
+        assert_eq!(
+            b.comments.trailing,
+            text(" Below b, then the end of the message.\n")
+        );
+
+        let kind = &desc.enums[0];
+        assert_eq!(kind.comments.leading, text("*\n Kinds.\n"));
+        assert_eq!(kind.value_comments[0], Comments::default());
+        assert_eq!(kind.value_comments[1].leading, text(" Leading KIND_B.\n"));
+        assert_eq!(kind.value_comments[1].trailing, text(" Trailing KIND_B. "));
+        assert_eq!(kind.value_spans[1].line, 21);
+
+        let greeter = &desc.rpc_services[0];
+        assert_eq!(greeter.comments.leading, text(" The service.\n"));
+        assert_eq!(greeter.comments.trailing, text(" On the same line.\n"));
+        let hello = &greeter.functions[0].comments;
+        assert_eq!(hello.leading, None);
+        assert_eq!(hello.trailing, text(" Below Hello.\n"));
+    }
+
+    #[test]
+    fn test_located_errors() {
+        let file = Path::new("errors.proto");
+        let msg = "syntax = \"proto3\";\nmessage Foo {\n    Bar bar = 1;\n}\n";
+        let err = FileDescriptor::read_proto_str(msg, file, &[]).unwrap_err();
+        let crate::errors::Error::Located(span, e) = err else {
+            panic!("{err}");
+        };
+        assert_eq!((span.line, span.column), (3, 5));
+        assert!(matches!(*e, crate::errors::Error::MessageOrEnumNotFound(ref n) if n == "Bar"));
+
+        let msg = "syntax = \"proto3\";\nmessage Foo {\n    int32 a = ;\n}\n";
+        let err = FileDescriptor::read_proto_str(msg, file, &[]).unwrap_err();
+        let crate::errors::Error::Located(span, _) = err else {
+            panic!("{err}");
+        };
+        assert_eq!(span.line, 2);
+        assert!(span.line_text.starts_with("message Foo"));
+    }
 }
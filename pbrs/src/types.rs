@@ -1,25 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs::File;
 use std::io::{BufReader, Write};
 use std::path::{Path, PathBuf};
 
+use crate::comments::SourceComments;
 use crate::errors::{Error, Result};
 use crate::parser::file_descriptor;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum Syntax {
+    #[default]
     Proto2,
     Proto3,
     Edition(String),
 }
 
-impl Default for Syntax {
-    fn default() -> Syntax {
-        Syntax::Proto2
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Frequency {
     Optional,
@@ -29,6 +25,308 @@ pub enum Frequency {
     Plain,
 }
 
+/// Where a message, field or enum is declared: its file, and the 1-based line and column
+/// its declaration starts at.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Span {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    /// The whole of that line, for quoting in diagnostics.
+    pub line_text: String,
+    /// How much of the source was left to parse at the declaration: all the parser knows
+    /// of where it is, until `locate` works the rest out from the whole source.
+    remaining: usize,
+}
+
+impl Span {
+    pub(crate) fn at(input: &str) -> Span {
+        Span {
+            remaining: input.len(),
+            ..Default::default()
+        }
+    }
+
+    /// The span of what starts with `rest`, a suffix of `source`, the contents of `file`.
+    pub fn in_source(source: &str, rest: &str, file: &Path) -> Span {
+        let mut span = Span::at(rest);
+        span.locate(source, file);
+        span
+    }
+
+    /// Where in `source`, the whole of it, the declaration starts.
+    fn offset(&self, source: &str) -> usize {
+        source.len().saturating_sub(self.remaining)
+    }
+
+    fn locate(&mut self, source: &str, file: &Path) {
+        let offset = self.offset(source);
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.file = file.to_path_buf();
+        self.line = before.matches('\n').count() + 1;
+        self.column = before[line_start..].chars().count() + 1;
+        self.line_text = source[line_start..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+    }
+}
+
+/// The comments attached to a declaration, as protoc attaches them: `leading` right above
+/// it, `trailing` after it on the same line, or right below it when a blank line follows.
+/// Each is the comments' text without their markers, a line per line: `// Foo` is
+/// `" Foo\n"`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Comments {
+    pub leading: Option<String>,
+    pub trailing: Option<String>,
+}
+
+/// An option set on a declaration: `option optimize_for = SPEED;` in a body, or one of the
+/// `[deprecated = true, json_name = "id"]` after a field or enum value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtoOption {
+    /// The name as written, without whitespace: `packed`, `features.field_presence`,
+    /// `(my.ext).flag`.
+    pub name: String,
+    pub value: OptionValue,
+}
+
+/// What an option is set to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionValue {
+    /// A numeric literal with its sign, as written: `-1`, `0x1F`, `2.5e3`, `-inf`.
+    Number(String),
+    /// An identifier: `true`, `SPEED`, `IMPLICIT`.
+    Ident(String),
+    /// A string literal's contents, escapes kept and adjacent literals joined.
+    String(String),
+    /// A `{ ... }` message literal's fields, in order. A repeated field, or one given a
+    /// `[...]` list, appears once per value.
+    Aggregate(Vec<(String, OptionValue)>),
+}
+
+impl OptionValue {
+    /// `true` or `false`, if that is what it is.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.as_ident()? {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn as_ident(&self) -> Option<&str> {
+        match self {
+            OptionValue::Ident(ident) => Some(ident),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            OptionValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The value of `key`, or its first one, in an aggregate.
+    pub fn get(&self, key: &str) -> Option<&OptionValue> {
+        match self {
+            OptionValue::Aggregate(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// What the option called `name` is set to among `options`.
+pub fn find_option<'a>(options: &'a [ProtoOption], name: &str) -> Option<&'a OptionValue> {
+    options.iter().find(|o| o.name == name).map(|o| &o.value)
+}
+
+/// How a declaration is encoded, as editions spell it out: the features set on it, or on
+/// what it is declared in, or the edition's defaults. A `syntax` file has the features its
+/// syntax stands for, and can't set any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Features {
+    pub field_presence: FieldPresence,
+    pub repeated_field_encoding: RepeatedFieldEncoding,
+    pub enum_type: EnumType,
+    pub utf8_validation: Utf8Validation,
+    pub message_encoding: MessageEncoding,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldPresence {
+    /// `optional`: set or not, whatever the value.
+    Explicit,
+    /// proto3's unlabeled fields: unset is the default value, and isn't written.
+    Implicit,
+    /// proto2's `required`.
+    LegacyRequired,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatedFieldEncoding {
+    Packed,
+    Expanded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumType {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Utf8Validation {
+    Verify,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageEncoding {
+    LengthPrefixed,
+    /// Between start- and end-group tags, as proto2's groups are.
+    Delimited,
+}
+
+/// Edition 2023's, which 2024 keeps for all of these.
+impl Default for Features {
+    fn default() -> Self {
+        Features {
+            field_presence: FieldPresence::Explicit,
+            repeated_field_encoding: RepeatedFieldEncoding::Packed,
+            enum_type: EnumType::Open,
+            utf8_validation: Utf8Validation::Verify,
+            message_encoding: MessageEncoding::LengthPrefixed,
+        }
+    }
+}
+
+impl Features {
+    /// What a file starts from before any of its `features` options.
+    pub fn of(syntax: &Syntax) -> Features {
+        match syntax {
+            Syntax::Proto2 => Features {
+                field_presence: FieldPresence::Explicit,
+                repeated_field_encoding: RepeatedFieldEncoding::Expanded,
+                enum_type: EnumType::Closed,
+                // protoc doesn't check proto2 strings; tacky always has.
+                utf8_validation: Utf8Validation::Verify,
+                message_encoding: MessageEncoding::LengthPrefixed,
+            },
+            Syntax::Proto3 => Features {
+                field_presence: FieldPresence::Implicit,
+                ..Features::default()
+            },
+            Syntax::Edition(_) => Features::default(),
+        }
+    }
+
+    /// These features, with what `options` sets, as `features.enum_type = CLOSED` or
+    /// `features = { enum_type: CLOSED }`, in place of them.
+    fn with(mut self, options: &[ProtoOption]) -> std::result::Result<Features, String> {
+        fn set<T: Copy>(
+            options: &[ProtoOption],
+            name: &str,
+            values: &[(&str, T)],
+            slot: &mut T,
+        ) -> std::result::Result<(), String> {
+            let Some(value) = feature(options, name) else {
+                return Ok(());
+            };
+            match values.iter().find(|(v, _)| Some(*v) == value.as_ident()) {
+                Some((_, value)) => {
+                    *slot = *value;
+                    Ok(())
+                }
+                None => {
+                    let names: Vec<&str> = values.iter().map(|(v, _)| *v).collect();
+                    Err(format!(
+                        "`features.{name}` must be one of {}",
+                        names.join(", ")
+                    ))
+                }
+            }
+        }
+        set(
+            options,
+            "field_presence",
+            &[
+                ("EXPLICIT", FieldPresence::Explicit),
+                ("IMPLICIT", FieldPresence::Implicit),
+                ("LEGACY_REQUIRED", FieldPresence::LegacyRequired),
+            ],
+            &mut self.field_presence,
+        )?;
+        set(
+            options,
+            "repeated_field_encoding",
+            &[
+                ("PACKED", RepeatedFieldEncoding::Packed),
+                ("EXPANDED", RepeatedFieldEncoding::Expanded),
+            ],
+            &mut self.repeated_field_encoding,
+        )?;
+        set(
+            options,
+            "enum_type",
+            &[("OPEN", EnumType::Open), ("CLOSED", EnumType::Closed)],
+            &mut self.enum_type,
+        )?;
+        set(
+            options,
+            "utf8_validation",
+            &[
+                ("VERIFY", Utf8Validation::Verify),
+                ("NONE", Utf8Validation::None),
+            ],
+            &mut self.utf8_validation,
+        )?;
+        set(
+            options,
+            "message_encoding",
+            &[
+                ("LENGTH_PREFIXED", MessageEncoding::LengthPrefixed),
+                ("DELIMITED", MessageEncoding::Delimited),
+            ],
+            &mut self.message_encoding,
+        )?;
+        Ok(self)
+    }
+}
+
+/// What `options` sets the feature `name` to, either way it can be spelled.
+fn feature<'a>(options: &'a [ProtoOption], name: &str) -> Option<&'a OptionValue> {
+    find_option(options, &format!("features.{name}"))
+        .or_else(|| find_option(options, "features")?.get(name))
+}
+
+/// `parent`'s features, as `options` changes them for what they are set on.
+fn inherit(
+    parent: Features,
+    options: &[ProtoOption],
+    edition: bool,
+) -> std::result::Result<Features, String> {
+    let sets = |o: &ProtoOption| o.name == "features" || o.name.starts_with("features.");
+    if !edition && options.iter().any(sets) {
+        return Err("`features` are for editions, not `syntax` files".to_string());
+    }
+    parent.with(options)
+}
+
+/// Turns what [`Features`] reject into an error at the declaration they were set on.
+type Invalid<'a> = &'a dyn Fn(&Span, String) -> Error;
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Default)]
 pub struct MessageIndex {
     indexes: Vec<usize>,
@@ -52,16 +350,22 @@ impl MessageIndex {
             .expect("Message index not found")
     }
 
-    /// Compute a flattened qualified name (e.g. `OuterInner`) by walking the index path.
-    pub fn qualified_name(&self, desc: &FileDescriptor) -> String {
-        let mut name = String::new();
+    /// The names of the messages this one is nested in, outermost first, then its own
+    /// (e.g. `["Outer", "Inner"]`).
+    pub fn path<'a>(&self, desc: &'a FileDescriptor) -> Vec<&'a str> {
+        let mut path = Vec::with_capacity(self.indexes.len());
         let mut current_messages = &desc.messages;
         for &idx in &self.indexes {
             let msg = &current_messages[idx];
-            name.push_str(&msg.name);
+            path.push(msg.name.as_str());
             current_messages = &msg.messages;
         }
-        name
+        path
+    }
+
+    /// The module of the top-level message this one is nested in, or is.
+    pub fn module<'a>(&self, desc: &'a FileDescriptor) -> &'a str {
+        &desc.messages[self.indexes[0]].module
     }
 
     // fn get_message_mut<'a>(&self, desc: &'a mut FileDescriptor) -> &'a mut Message {
@@ -103,13 +407,20 @@ impl EnumIndex {
         enums.get(self.index).expect("Enum index not found")
     }
 
-    /// Compute a flattened qualified name (e.g. `OuterStatus`) by walking the parent path.
-    pub fn qualified_name(&self, desc: &FileDescriptor) -> String {
-        let enum_name = &self.get_enum(desc).name;
+    /// The names of the messages this enum is nested in, outermost first, then its own
+    /// (e.g. `["Outer", "Status"]`).
+    pub fn path<'a>(&self, desc: &'a FileDescriptor) -> Vec<&'a str> {
+        let mut path = self.msg_index.path(desc);
+        path.push(&self.get_enum(desc).name);
+        path
+    }
+
+    /// The module of the top-level message this enum is nested in, or its own if top-level.
+    pub fn module<'a>(&self, desc: &'a FileDescriptor) -> &'a str {
         if self.msg_index.indexes.is_empty() {
-            enum_name.clone()
+            &self.get_enum(desc).module
         } else {
-            format!("{}{}", self.msg_index.qualified_name(desc), enum_name)
+            self.msg_index.module(desc)
         }
     }
 }
@@ -182,6 +493,69 @@ pub struct Field {
     pub number: i32,
     pub default: Option<String>,
     pub deprecated: bool,
+    pub options: Vec<ProtoOption>,
+    pub span: Span,
+    pub comments: Comments,
+    /// As resolved for the field; in an edition, what its `frequency` comes from.
+    pub features: Features,
+}
+
+impl Field {
+    /// Settles the field's features, from `parent`'s and its own. In an edition, they
+    /// decide its label, besides `repeated`, which only it can say.
+    fn resolve_features(
+        &mut self,
+        parent: Features,
+        edition: bool,
+        in_oneof: bool,
+    ) -> std::result::Result<(), String> {
+        self.features = inherit(parent, &self.options, edition)?;
+        if !edition || matches!(self.typ, FieldType::Map(..)) {
+            return Ok(());
+        }
+        self.frequency = match self.frequency {
+            Some(Frequency::Packed | Frequency::Repeated) => {
+                Some(match self.features.repeated_field_encoding {
+                    RepeatedFieldEncoding::Packed => Frequency::Packed,
+                    RepeatedFieldEncoding::Expanded => Frequency::Repeated,
+                })
+            }
+            // Which of a oneof's fields is set is all the presence they have.
+            frequency if in_oneof => frequency,
+            _ => Some(match self.features.field_presence {
+                FieldPresence::Explicit => Frequency::Optional,
+                FieldPresence::Implicit => Frequency::Plain,
+                FieldPresence::LegacyRequired => Frequency::Required,
+            }),
+        };
+        Ok(())
+    }
+
+    /// The field's name in JSON: its `json_name` option, or else [`json_name`] of its name.
+    pub fn json_name(&self) -> String {
+        match find_option(&self.options, "json_name").and_then(OptionValue::as_str) {
+            Some(name) => name.to_string(),
+            None => json_name(&self.name),
+        }
+    }
+}
+
+/// A field's default name in JSON, as protoc has it: `name` with each `_` dropped and the
+/// letter after it capitalized.
+pub fn json_name(name: &str) -> String {
+    let mut json = String::with_capacity(name.len());
+    let mut capitalize = false;
+    for c in name.chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            json.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            json.push(c);
+        }
+    }
+    json
 }
 
 // fn get_modules(module: &str, imported: bool, desc: &FileDescriptor) -> String {
@@ -200,6 +574,8 @@ pub struct Extend {
     pub name: String,
     /// All fields that are being added to the extended message.
     pub fields: Vec<Field>,
+    /// The extended message, once types are resolved.
+    pub extendee: Option<MessageIndex>,
 }
 
 impl Extend {}
@@ -219,11 +595,43 @@ pub struct Message {
     pub path: PathBuf,
     pub import: PathBuf,
     pub index: MessageIndex,
-    /// Allowed extensions for this message, None if no extensions.
-    pub extensions: Option<Extensions>,
+    /// Allowed extension ranges for this message, as declared; empty if no extensions.
+    pub extensions: Vec<Extensions>,
+    pub options: Vec<ProtoOption>,
+    pub span: Span,
+    pub comments: Comments,
 }
 
 impl Message {
+    fn resolve_features(
+        &mut self,
+        parent: Features,
+        edition: bool,
+        invalid: Invalid,
+    ) -> Result<()> {
+        let features =
+            inherit(parent, &self.options, edition).map_err(|e| invalid(&self.span, e))?;
+        for f in &mut self.fields {
+            f.resolve_features(features, edition, false)
+                .map_err(|e| invalid(&f.span, e))?;
+        }
+        for o in &mut self.oneofs {
+            let features =
+                inherit(features, &o.options, edition).map_err(|e| invalid(&self.span, e))?;
+            for f in &mut o.fields {
+                f.resolve_features(features, edition, true)
+                    .map_err(|e| invalid(&f.span, e))?;
+            }
+        }
+        for m in &mut self.messages {
+            m.resolve_features(features, edition, invalid)?;
+        }
+        for e in &mut self.enums {
+            e.resolve_features(features, edition, invalid)?;
+        }
+        Ok(())
+    }
+
     fn set_imported(&mut self) {
         self.imported = true;
         for o in self.oneofs.iter_mut() {
@@ -247,33 +655,49 @@ impl Message {
     //         && self.messages.iter().all(|m| m.is_unit())
     // }
 
-    fn sanity_checks(&self, _desc: &FileDescriptor) -> Result<()> {
+    fn sanity_checks(&self, desc: &FileDescriptor) -> Result<()> {
+        // Conflicts with `reserved` are for the code generator to report, with the rest of
+        // what protoc would reject.
         for f in self.all_fields() {
-            // check reserved
-            if self
-                .reserved_names
-                .as_ref()
-                .map_or(false, |names| names.contains(&f.name))
-                || self
-                    .reserved_nums
-                    .as_ref()
-                    .map_or(false, |nums| nums.contains(&f.number))
+            // A message field always knows whether it is set. Inheriting `IMPLICIT` leaves
+            // it be, as proto3 does; protoc only rejects asking for it on the field.
+            if matches!(f.typ, FieldType::Message(_))
+                && feature(&f.options, "field_presence").and_then(OptionValue::as_ident)
+                    == Some("IMPLICIT")
             {
-                return Err(Error::InvalidMessage(format!(
-                    "Error in message {}\n\
-                     Field {:?} conflict with reserved fields",
-                    self.name, f
-                )));
+                return Err(Error::Located(
+                    f.span.clone(),
+                    Box::new(Error::InvalidFeature(format!(
+                        "message field `{}` can't have implicit presence",
+                        f.name
+                    ))),
+                ));
             }
-
-            // check default enums
-            if let Some(_) = f.default.as_ref() {
-                return Err(Error::InvalidDefaultEnum(format!(
-                    "Error in message {}\n custom defaults are not supported in tacky",
-                    self.name
-                )));
+            if let Some(default) = &f.default {
+                // Only singular scalars have a value to fall back to.
+                if matches!(f.frequency, Some(Frequency::Repeated | Frequency::Packed))
+                    || matches!(f.typ, FieldType::Message(_) | FieldType::Map(..))
+                {
+                    return Err(Error::InvalidMessage(format!(
+                        "Error in message {}\n\
+                         Field {} cannot have a default value",
+                        self.name, f.name
+                    )));
+                }
+                // check default enums
+                if let FieldType::Enum(e) = &f.typ {
+                    if !e.get_enum(desc).fields.iter().any(|(n, _)| n == default) {
+                        return Err(Error::InvalidDefaultEnum(format!(
+                            "{}.{} = {}",
+                            self.name, f.name, default
+                        )));
+                    }
+                }
             }
         }
+        for m in &self.messages {
+            m.sanity_checks(desc)?;
+        }
         Ok(())
     }
 
@@ -328,12 +752,23 @@ pub struct RpcFunctionDeclaration {
     pub name: String,
     pub arg: String,
     pub ret: String,
+    /// Whether the request is `stream`.
+    pub client_streaming: bool,
+    /// Whether the response is `stream`.
+    pub server_streaming: bool,
+    /// `arg` and `ret`, once types are resolved.
+    pub input: Option<MessageIndex>,
+    pub output: Option<MessageIndex>,
+    pub span: Span,
+    pub comments: Comments,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct RpcService {
     pub service_name: String,
     pub functions: Vec<RpcFunctionDeclaration>,
+    pub span: Span,
+    pub comments: Comments,
 }
 
 impl RpcService {
@@ -371,9 +806,34 @@ pub struct Enumerator {
     pub path: PathBuf,
     pub import: PathBuf,
     pub index: EnumIndex,
+    pub span: Span,
+    pub comments: Comments,
+    /// Where each of `fields` is declared, its comments and its options, in the same order.
+    pub value_spans: Vec<Span>,
+    pub value_comments: Vec<Comments>,
+    pub value_options: Vec<Vec<ProtoOption>>,
+    pub reserved_nums: Option<Vec<i32>>,
+    pub reserved_names: Option<Vec<String>>,
+    pub options: Vec<ProtoOption>,
+    /// `option allow_alias = true;`: several names may share a number.
+    pub allow_alias: bool,
+    /// Declared in a proto2 file, so a number it doesn't declare is not one of its values.
+    pub closed: bool,
 }
 
 impl Enumerator {
+    fn resolve_features(
+        &mut self,
+        parent: Features,
+        edition: bool,
+        invalid: Invalid,
+    ) -> Result<()> {
+        let features =
+            inherit(parent, &self.options, edition).map_err(|e| invalid(&self.span, e))?;
+        self.closed = features.enum_type == EnumType::Closed;
+        Ok(())
+    }
+
     fn set_package(&mut self, package: &str, module: &str) {
         self.package = package.to_string();
         self.module = module.to_string();
@@ -405,6 +865,7 @@ impl Enumerator {
 pub struct OneOf {
     pub name: String,
     pub fields: Vec<Field>,
+    pub options: Vec<ProtoOption>,
     pub package: String,
     pub module: String,
     pub imported: bool,
@@ -421,6 +882,28 @@ impl OneOf {
     // }
 }
 
+/// An `import` statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    /// As written: relative to whichever include root has the file.
+    pub path: PathBuf,
+    pub kind: ImportKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportKind {
+    #[default]
+    Plain,
+    /// `import public`: files importing this one can use what the import declares too.
+    Public,
+    /// `import weak`: read if it is there, and left out if it isn't.
+    Weak,
+    /// `import option`: only for the option definitions in it, so its types can't be used.
+    /// Left out, as a weak one is, if it isn't there.
+    Option,
+}
+
 pub struct Config {
     pub in_file: PathBuf,
     pub out_file: PathBuf,
@@ -433,7 +916,7 @@ pub struct Config {
 
 #[derive(Debug, Default, Clone)]
 pub struct FileDescriptor {
-    pub import_paths: Vec<PathBuf>,
+    pub imports: Vec<Import>,
     pub package: String,
     pub syntax: Syntax,
     pub messages: Vec<Message>,
@@ -441,6 +924,13 @@ pub struct FileDescriptor {
     pub enums: Vec<Enumerator>,
     pub module: String,
     pub rpc_services: Vec<RpcService>,
+    /// The file-level `option ...;` statements.
+    pub options: Vec<ProtoOption>,
+    /// Every file an import resolved to, transitively, in the order they were read.
+    pub resolved_imports: Vec<PathBuf>,
+    /// The fully-qualified names of the top-level types a file importing this one can use:
+    /// its own, and those its `import public`s export.
+    pub exports: Vec<String>,
 }
 
 impl FileDescriptor {
@@ -515,18 +1005,47 @@ impl FileDescriptor {
     /// Opens a proto file, reads it and returns raw parsed data
     pub fn read_proto(in_file: &Path, import_search_path: &[PathBuf]) -> Result<FileDescriptor> {
         let file = std::fs::read_to_string(in_file)?;
-        let (rem, mut desc) = file_descriptor(&file).map_err(Error::Nom)?;
+        Self::read_proto_str(&file, in_file, import_search_path)
+    }
+
+    /// Like `read_proto`, but parses `source` rather than reading `in_file`. The path still
+    /// matters: imports resolve relative to it, and a file without a package is named after it.
+    pub fn read_proto_str(
+        source: &str,
+        in_file: &Path,
+        import_search_path: &[PathBuf],
+    ) -> Result<FileDescriptor> {
+        let (rem, mut desc) = file_descriptor(source).map_err(|e| {
+            let at = match &e {
+                nom::Err::Error(e) | nom::Err::Failure(e) => Some(e.input.clone()),
+                nom::Err::Incomplete(_) => None,
+            };
+            match at.filter(|at| source.ends_with(at.as_str())) {
+                Some(at) => Error::Located(
+                    Span::in_source(source, &at, in_file),
+                    Box::new(Error::Nom(e)),
+                ),
+                None => Error::Nom(e),
+            }
+        })?;
         let rem = rem.trim();
         if !rem.is_empty() {
-            return Err(Error::TrailingGarbage(rem.chars().take(50).collect()));
+            return Err(Error::Located(
+                Span::in_source(source, rem, in_file),
+                Box::new(Error::TrailingGarbage(rem.chars().take(50).collect())),
+            ));
         }
+        desc.locate_spans(source, in_file);
+        desc.resolve_features()?;
+        // What an import of this file would call it: its path from the root it is under.
+        let virtual_path = import_search_path
+            .iter()
+            .find_map(|root| in_file.strip_prefix(root).ok());
         for m in &mut desc.messages {
             if m.path.as_os_str().is_empty() {
                 m.path = in_file.to_path_buf();
-                if !import_search_path.is_empty() {
-                    if let Ok(p) = m.path.clone().strip_prefix(&import_search_path[0]) {
-                        m.import = p.to_path_buf();
-                    }
+                if let Some(p) = virtual_path {
+                    m.import = p.to_path_buf();
                 }
             }
         }
@@ -538,12 +1057,157 @@ impl FileDescriptor {
             desc.package.clone()
         };
 
-        desc.fetch_imports(in_file, import_search_path)?;
+        let visible = desc.fetch_imports(import_search_path)?;
         desc.resolve_types()?;
+        desc.check_imported(&visible)?;
         desc.sanity_checks()?;
         Ok(desc)
     }
 
+    /// Works out where in `source` each of the file's own declarations is, and the comments
+    /// attached to it.
+    fn locate_spans(&mut self, source: &str, in_file: &Path) {
+        let comments = SourceComments::new(source);
+        let locate = |span: &mut Span, attached: &mut Comments| {
+            span.locate(source, in_file);
+            *attached = comments.attached(span.offset(source));
+        };
+        let locate_enum = |e: &mut Enumerator| {
+            locate(&mut e.span, &mut e.comments);
+            e.value_comments = e
+                .value_spans
+                .iter_mut()
+                .map(|span| {
+                    let mut attached = Comments::default();
+                    locate(span, &mut attached);
+                    attached
+                })
+                .collect();
+        };
+        fn locate_message(
+            m: &mut Message,
+            locate: &impl Fn(&mut Span, &mut Comments),
+            locate_enum: &impl Fn(&mut Enumerator),
+        ) {
+            locate(&mut m.span, &mut m.comments);
+            for f in m
+                .fields
+                .iter_mut()
+                .chain(m.oneofs.iter_mut().flat_map(|o| o.fields.iter_mut()))
+            {
+                locate(&mut f.span, &mut f.comments);
+            }
+            for e in &mut m.enums {
+                locate_enum(e);
+            }
+            for m in &mut m.messages {
+                locate_message(m, locate, locate_enum);
+            }
+        }
+        for m in &mut self.messages {
+            locate_message(m, &locate, &locate_enum);
+        }
+        for e in &mut self.enums {
+            locate_enum(e);
+        }
+        for f in self.message_extends.iter_mut().flat_map(|e| &mut e.fields) {
+            locate(&mut f.span, &mut f.comments);
+        }
+        for i in &mut self.imports {
+            i.span.locate(source, in_file);
+        }
+        for s in &mut self.rpc_services {
+            locate(&mut s.span, &mut s.comments);
+            for f in &mut s.functions {
+                locate(&mut f.span, &mut f.comments);
+            }
+        }
+    }
+
+    /// Works out the [`Features`] of every declaration. In an edition, they decide what is
+    /// `closed` and each field's `frequency`; otherwise the syntax already has.
+    pub fn resolve_features(&mut self) -> Result<()> {
+        let edition = matches!(self.syntax, Syntax::Edition(_));
+        let invalid = |span: &Span, e: String| {
+            Error::Located(span.clone(), Box::new(Error::InvalidFeature(e)))
+        };
+        let features = inherit(Features::of(&self.syntax), &self.options, edition)
+            .map_err(Error::InvalidFeature)?;
+        for m in &mut self.messages {
+            m.resolve_features(features, edition, &invalid)?;
+        }
+        for e in &mut self.enums {
+            e.resolve_features(features, edition, &invalid)?;
+        }
+        for f in self.message_extends.iter_mut().flat_map(|e| &mut e.fields) {
+            f.resolve_features(features, edition, false)
+                .map_err(|e| invalid(&f.span, e))?;
+        }
+        Ok(())
+    }
+
+    /// Fails at the first of the file's own fields or rpcs using a type from a file it
+    /// doesn't import, directly or through an `import public`: what protoc rejects, and
+    /// what makes `public` mean anything.
+    fn check_imported(&self, visible: &HashSet<String>) -> Result<()> {
+        // The top-level type a type is, or is nested in: its name, and the file it is from.
+        let declared = |package: &str, name: &str, import: &Path, path: &Path| {
+            let file = if import.as_os_str().is_empty() {
+                path
+            } else {
+                import
+            };
+            (full_name(package, name), file.to_path_buf())
+        };
+        let message = |index: &MessageIndex| {
+            let m = &self.messages[*index.indexes.first()?];
+            Some(declared(&m.package, &m.name, &m.import, &m.path))
+        };
+        let top_level = |typ: &FieldType| match typ {
+            FieldType::Message(index) => message(index),
+            FieldType::Enum(index) if index.msg_index.indexes.is_empty() => {
+                let e = &self.enums[index.index];
+                Some(declared(&e.package, &e.name, &e.import, &e.path))
+            }
+            FieldType::Enum(index) => message(&index.msg_index),
+            _ => None,
+        };
+        let check = |declared: Option<(String, PathBuf)>, span: &Span| match declared {
+            Some((name, file)) if !visible.contains(&name) => Err(Error::Located(
+                span.clone(),
+                Box::new(Error::NotImported(name, file)),
+            )),
+            _ => Ok(()),
+        };
+
+        fn own_fields<'a>(m: &'a Message, fields: &mut Vec<&'a Field>) {
+            fields.extend(m.all_fields());
+            for nested in &m.messages {
+                own_fields(nested, fields);
+            }
+        }
+        let mut fields = Vec::new();
+        for m in self.messages.iter().filter(|m| !m.imported) {
+            own_fields(m, &mut fields);
+        }
+        fields.extend(self.message_extends.iter().flat_map(|e| &e.fields));
+        for f in fields {
+            match &f.typ {
+                FieldType::Map(key, value) => {
+                    check(top_level(key), &f.span)?;
+                    check(top_level(value), &f.span)?;
+                }
+                typ => check(top_level(typ), &f.span)?,
+            }
+        }
+        for f in self.rpc_services.iter().flat_map(|s| &s.functions) {
+            for index in f.input.iter().chain(&f.output) {
+                check(message(index), &f.span)?;
+            }
+        }
+        Ok(())
+    }
+
     fn sanity_checks(&self) -> Result<()> {
         for m in &self.messages {
             m.sanity_checks(self)?;
@@ -608,41 +1272,57 @@ impl FileDescriptor {
         }
     }
 
-    /// Get messages and enums from imports
-    fn fetch_imports(&mut self, in_file: &Path, import_search_path: &[PathBuf]) -> Result<()> {
+    /// Get messages and enums from imports, found as protoc finds them: under each root of
+    /// `import_search_path` in turn, relative ones taken from the working directory, and
+    /// nowhere else. Returns the fully-qualified names of the top-level types the file can
+    /// use.
+    fn fetch_imports(&mut self, import_search_path: &[PathBuf]) -> Result<HashSet<String>> {
         for m in &mut self.messages {
             m.set_package(&self.package, &self.module);
         }
         for m in &mut self.enums {
             m.set_package(&self.package, &self.module);
         }
-
-        for import in &self.import_paths {
-            // this is the same logic as the C preprocessor;
-            // if the include path item is absolute, then append the filename,
-            // otherwise it is always relative to the file.
-            let mut matching_file = None;
-            for path in import_search_path {
-                let candidate = if path.is_absolute() {
-                    path.join(import)
-                } else {
-                    in_file
-                        .parent()
-                        .map_or_else(|| path.join(import), |p| p.join(path).join(import))
-                };
-                if candidate.exists() {
-                    matching_file = Some(candidate);
-                    break;
+        self.exports = self
+            .messages
+            .iter()
+            .map(|m| (&m.package, &m.name))
+            .chain(self.enums.iter().map(|e| (&e.package, &e.name)))
+            .map(|(package, name)| full_name(package, name))
+            .collect();
+        let mut visible: HashSet<String> = self.exports.iter().cloned().collect();
+
+        for import in &self.imports {
+            let found = import_search_path
+                .iter()
+                .map(|dir| dir.join(&import.path))
+                .find(|candidate| candidate.is_file());
+            let Some(proto_file) = found else {
+                match import.kind {
+                    ImportKind::Weak | ImportKind::Option => continue,
+                    ImportKind::Plain | ImportKind::Public => {
+                        return Err(Error::Located(
+                            import.span.clone(),
+                            Box::new(Error::ImportNotFound(
+                                import.path.clone(),
+                                import_search_path.to_vec(),
+                            )),
+                        ))
+                    }
+                }
+            };
+            let mut f = FileDescriptor::read_proto(&proto_file, import_search_path)?;
+            for path in std::iter::once(&proto_file).chain(&f.resolved_imports) {
+                if !self.resolved_imports.contains(path) {
+                    self.resolved_imports.push(path.clone());
                 }
             }
-            if matching_file.is_none() {
-                return Err(Error::InvalidImport(format!(
-                    "file {} not found on import path",
-                    import.display()
-                )));
+            if import.kind != ImportKind::Option {
+                visible.extend(f.exports.iter().cloned());
+            }
+            if import.kind == ImportKind::Public {
+                self.exports.extend(f.exports.iter().cloned());
             }
-            let proto_file = matching_file.unwrap();
-            let mut f = FileDescriptor::read_proto(&proto_file, import_search_path)?;
 
             // Reset resolved indices before merging — they reference the imported
             // file's local descriptor and would be stale in the combined one.
@@ -666,7 +1346,7 @@ impl FileDescriptor {
                         m.path = proto_file.clone();
                     }
                     if m.import.as_os_str().is_empty() {
-                        m.import = import.clone();
+                        m.import = import.path.clone();
                     }
                     m.set_imported();
                     m
@@ -692,7 +1372,7 @@ impl FileDescriptor {
                         e.path = proto_file.clone();
                     }
                     if e.import.as_os_str().is_empty() {
-                        e.import = import.clone();
+                        e.import = import.path.clone();
                     }
                     e.imported = true;
                     e
@@ -708,7 +1388,7 @@ impl FileDescriptor {
                 }
             }
         }
-        Ok(())
+        Ok(visible)
     }
 
     fn get_full_names(&mut self) -> (HashMap<String, MessageIndex>, HashMap<String, EnumIndex>) {
@@ -784,44 +1464,18 @@ impl FileDescriptor {
             // fields here: writing out the field traversal as below lets Rust
             // split m's mutable borrow, permitting the loop body to use fields
             // of `m` other than `fields` and `oneofs`.
-            'types: for typ in m
+            for f in m
                 .fields
                 .iter_mut()
                 .chain(m.oneofs.iter_mut().flat_map(|o| o.fields.iter_mut()))
-                .map(|f| &mut f.typ)
-                .flat_map(|typ| match *typ {
-                    FieldType::Map(ref mut key, ref mut value) => {
-                        vec![&mut **key, &mut **value].into_iter()
-                    }
-                    _ => vec![typ].into_iter(),
-                })
             {
-                if let FieldType::MessageOrEnum(name) = typ.clone() {
-                    let test_names: Vec<String> = if name.starts_with('.') {
-                        vec![name.clone().split_off(1)]
-                    } else if m.package.is_empty() {
-                        vec![format!("{}.{}", m.name, name), name.clone()]
-                    } else {
-                        let mut v = vec![
-                            format!("{}.{}.{}", m.package, m.name, name),
-                            format!("{}.{}", m.package, name),
-                        ];
-                        for (index, _) in m.package.match_indices('.').rev() {
-                            v.push(format!("{}.{}", &m.package[..index], name));
-                        }
-                        v.push(name.clone());
-                        v
-                    };
-                    for name in &test_names {
-                        if let Some(msg) = full_msgs.get(name) {
-                            *typ = FieldType::Message(msg.clone());
-                            continue 'types;
-                        } else if let Some(e) = full_enums.get(name) {
-                            *typ = FieldType::Enum(e.clone());
-                            continue 'types;
-                        }
-                    }
-                    return Err(Error::MessageOrEnumNotFound(name));
+                let types = match f.typ {
+                    FieldType::Map(ref mut key, ref mut value) => vec![&mut **key, &mut **value],
+                    ref mut typ => vec![typ],
+                };
+                for typ in types {
+                    resolve_field_type(typ, &m.package, Some(&m.name), full_msgs, full_enums)
+                        .map_err(|e| Error::Located(f.span.clone(), Box::new(e)))?;
                 }
             }
 
@@ -847,10 +1501,102 @@ impl FileDescriptor {
         for m in self.messages.iter_mut() {
             rec_resolve_types(m, &full_msgs, &full_enums)?;
         }
+
+        // `extend` blocks only appear at file scope here, so both the extendee and the
+        // added fields resolve against the package alone.
+        for e in self.message_extends.iter_mut() {
+            let extendee = scoped_names(&self.package, None, &e.name)
+                .into_iter()
+                .find_map(|n| full_msgs.get(&n))
+                .ok_or_else(|| Error::MessageOrEnumNotFound(e.name.clone()))?;
+            e.extendee = Some(extendee.clone());
+            for f in e.fields.iter_mut() {
+                resolve_field_type(&mut f.typ, &self.package, None, &full_msgs, &full_enums)
+                    .map_err(|e| Error::Located(f.span.clone(), Box::new(e)))?;
+                if f.frequency == Some(Frequency::Packed) && !f.typ.is_primitive() {
+                    f.frequency = Some(Frequency::Repeated);
+                }
+            }
+        }
+
+        // As are services' request and response types, which can only be messages.
+        for f in self.rpc_services.iter_mut().flat_map(|s| &mut s.functions) {
+            let resolve = |name: &str| {
+                scoped_names(&self.package, None, name)
+                    .into_iter()
+                    .find_map(|n| full_msgs.get(&n).cloned())
+                    .ok_or_else(|| {
+                        Error::Located(
+                            f.span.clone(),
+                            Box::new(Error::MessageOrEnumNotFound(name.to_string())),
+                        )
+                    })
+            };
+            let (input, output) = (resolve(&f.arg)?, resolve(&f.ret)?);
+            f.input = Some(input);
+            f.output = Some(output);
+        }
         Ok(())
     }
 }
 
+/// `name`, in `package`.
+fn full_name(package: &str, name: &str) -> String {
+    if package.is_empty() {
+        name.to_string()
+    } else {
+        format!("{package}.{name}")
+    }
+}
+
+/// Candidate fully-qualified names for a type reference made from inside `scope` (a
+/// message name, or `None` at file level), innermost first, following protobuf's
+/// scoping rules. A leading `.` makes the reference absolute.
+fn scoped_names(package: &str, scope: Option<&str>, name: &str) -> Vec<String> {
+    if let Some(absolute) = name.strip_prefix('.') {
+        return vec![absolute.to_string()];
+    }
+    let mut v = Vec::new();
+    if let Some(scope) = scope {
+        if package.is_empty() {
+            v.push(format!("{scope}.{name}"));
+        } else {
+            v.push(format!("{package}.{scope}.{name}"));
+        }
+    }
+    if !package.is_empty() {
+        v.push(format!("{package}.{name}"));
+        for (index, _) in package.match_indices('.').rev() {
+            v.push(format!("{}.{}", &package[..index], name));
+        }
+    }
+    v.push(name.to_string());
+    v
+}
+
+/// Replaces an unresolved `MessageOrEnum` with the message or enum it names.
+fn resolve_field_type(
+    typ: &mut FieldType,
+    package: &str,
+    scope: Option<&str>,
+    full_msgs: &HashMap<String, MessageIndex>,
+    full_enums: &HashMap<String, EnumIndex>,
+) -> Result<()> {
+    if let FieldType::MessageOrEnum(name) = typ.clone() {
+        for name in &scoped_names(package, scope, &name) {
+            if let Some(msg) = full_msgs.get(name) {
+                *typ = FieldType::Message(msg.clone());
+                return Ok(());
+            } else if let Some(e) = full_enums.get(name) {
+                *typ = FieldType::Enum(e.clone());
+                return Ok(());
+            }
+        }
+        return Err(Error::MessageOrEnumNotFound(name));
+    }
+    Ok(())
+}
+
 /// "" is ("",""), "a" is ("","a"), "a.b" is ("a"."b"), and so forth.
 fn split_package(package: &str) -> (&str, &str) {
     if package.is_empty() {
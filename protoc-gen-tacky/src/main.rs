@@ -0,0 +1,17 @@
+//! tacky's code generator as a protoc plugin, for `protoc` and `buf generate` pipelines
+//! that have no Rust build script to call `tacky-build` from:
+//!
+//! ```text
+//! protoc --plugin=protoc-gen-tacky --tacky_out=src/gen --tacky_opt=owned api.proto
+//! ```
+//!
+//! See `tacky_build::plugin` for the output layout and parameters.
+
+use std::io::{Read, Write};
+
+fn main() -> std::io::Result<()> {
+    let mut request = Vec::new();
+    std::io::stdin().read_to_end(&mut request)?;
+    let response = tacky_build::plugin::run(&request);
+    std::io::stdout().write_all(&response)
+}
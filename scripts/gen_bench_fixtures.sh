@@ -66,8 +66,8 @@ protoc -Itesting/protos --include_imports --include_source_info \
     opentelemetry/proto/trace/v1/trace.proto
 
 # Note `trace.proto`, not `collector/.../trace_service.proto`: that one declares a
-# `service`, tacky does not generate service definitions, and so the writer would drop
-# it and the round-trip assert would fail. Its imports pull in common and resource
+# `service`, which the bench's writer does not cover, and so the round-trip assert would
+# fail. Its imports pull in common and resource
 # anyway, which is the bulk of the OTLP schema.
 
 wc -c "$OUT"/*.fds
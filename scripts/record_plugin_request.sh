@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+# Records the `CodeGeneratorRequest` protoc hands a plugin, for the `protoc-gen-tacky`
+# tests in `testing/src/plugin.rs`. Checked in so those tests need no protoc.
+#
+# The "plugin" saves its stdin and answers with a response that generates nothing and
+# declares proto3 `optional` support (`supported_features = 1`, bytes 10 01), without
+# which protoc refuses proto3_message.proto. Re-run this when the protos listed change;
+# the tests compare the plugin's output with what the build script generates from the
+# `.proto` source, so a stale request shows up as a failure.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+OUT=testing/data
+TMP=$(mktemp -d)
+trap 'rm -rf "$TMP"' EXIT
+
+echo "protoc: $(protoc --version)"
+
+cat > "$TMP/protoc-gen-record" <<SH
+#!/usr/bin/env bash
+cat > "$PWD/$OUT/plugin_request.pb"
+printf '\\x10\\x01'
+SH
+chmod +x "$TMP/protoc-gen-record"
+
+protoc -Itesting/protos --plugin="$TMP/protoc-gen-record" --record_out="$TMP" \
+    simple_message.proto \
+    importing.proto \
+    proto3_message.proto
+
+wc -c "$OUT/plugin_request.pb"
@@ -0,0 +1,53 @@
+//! Fails when a new version of a schema breaks compatibility with the old one on the wire:
+//!
+//! ```text
+//! tacky-breaking <old-root> <new-root> [file.proto ...]
+//! ```
+//!
+//! Files are relative to each root, which imports resolve against; without any, every
+//! `.proto` under each root is compared. A file the new root doesn't have is taken as
+//! deleted. Exits with 1 when anything breaks, and 2 when a schema can't be read.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use tacky_build::breaking::{check, read_schema};
+
+fn main() -> ExitCode {
+    let args: Vec<PathBuf> = std::env::args_os().skip(1).map(PathBuf::from).collect();
+    let [old_root, new_root, files @ ..] = &args[..] else {
+        eprintln!("usage: tacky-breaking <old-root> <new-root> [file.proto ...]");
+        return ExitCode::from(2);
+    };
+    let files: Vec<&Path> = files.iter().map(PathBuf::as_path).collect();
+    let kept: Vec<&Path> = files
+        .iter()
+        .copied()
+        .filter(|file| new_root.join(file).exists())
+        .collect();
+    if !files.is_empty() && kept.is_empty() {
+        eprintln!("{}: none of the files are left", new_root.display());
+        return ExitCode::from(2);
+    }
+
+    let read = |root: &Path, files: &[&Path]| {
+        read_schema(root, files).map_err(|e| eprintln!("{}: {e}", root.display()))
+    };
+    let (Ok(old), Ok(new)) = (read(old_root, &files), read(new_root, &kept)) else {
+        return ExitCode::from(2);
+    };
+    let breaking = check(&old, &new);
+    for (i, change) in breaking.iter().enumerate() {
+        if i > 0 {
+            eprintln!();
+        }
+        eprintln!("{change}");
+    }
+    if breaking.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        let s = if breaking.len() == 1 { "" } else { "s" };
+        eprintln!("\n{} breaking change{s}", breaking.len());
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,320 @@
+//! [`Config`], for builds that need more say over the output than the `write_*` functions
+//! give them.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tacky_codegen::lint::{Level, Style};
+use tacky_codegen::{
+    expand_packages, format_tokens, OptionAttribute, OptionValue, PackageOptions, StringDecoding,
+};
+
+/// Code generation for a set of proto files, written as one file with a module per proto
+/// package, as [`write_protos`](crate::write_protos) writes it:
+///
+/// ```no_run
+/// tacky_build::Config::new()
+///     .files(["protos/api/v1/service.proto", "protos/api/v1/admin.proto"])
+///     .include("protos")
+///     .include_file("api.rs")
+///     .enum_attribute(".api", "#[derive(Hash)]")
+///     .compile()
+///     .unwrap();
+/// ```
+///
+/// Attribute paths are fully-qualified proto names, and cover everything under them: `.`
+/// for everything, `.api.v1` for a package, `.api.v1.User` for a message and its nested
+/// types, `.api.v1.User.email` for a field.
+#[derive(Debug, Clone)]
+pub struct Config {
+    files: Vec<PathBuf>,
+    includes: Vec<PathBuf>,
+    out_dir: Option<PathBuf>,
+    include_file: String,
+    options: PackageOptions,
+    rerun_if_changed: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            files: Vec::new(),
+            includes: Vec::new(),
+            out_dir: None,
+            include_file: "protos.rs".to_string(),
+            options: PackageOptions::default(),
+            rerun_if_changed: true,
+        }
+    }
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A proto file to generate code for, along with everything it imports.
+    pub fn file(&mut self, file: impl AsRef<Path>) -> &mut Self {
+        self.files.push(file.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn files(&mut self, files: impl IntoIterator<Item = impl AsRef<Path>>) -> &mut Self {
+        for file in files {
+            self.file(file);
+        }
+        self
+    }
+
+    /// A directory imports are resolved against. Relative ones are taken from the working
+    /// directory, which for a build script is the package root. Without any, that is
+    /// where imports are looked for.
+    pub fn include(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        self.includes.push(dir.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn includes(&mut self, dirs: impl IntoIterator<Item = impl AsRef<Path>>) -> &mut Self {
+        for dir in dirs {
+            self.include(dir);
+        }
+        self
+    }
+
+    /// Where the output goes. Defaults to `OUT_DIR`.
+    pub fn out_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        self.out_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// The name of the file written into the output directory, `protos.rs` by default.
+    /// It holds every package's module: `include!` it once.
+    pub fn include_file(&mut self, name: impl Into<String>) -> &mut Self {
+        self.include_file = name.into();
+        self
+    }
+
+    /// Where generated code finds the tacky runtime, if not at `::tacky`: for a crate
+    /// that re-exports it and doesn't want its users depending on tacky themselves.
+    pub fn runtime_path(&mut self, path: impl Into<String>) -> &mut Self {
+        self.options.runtime_path = Some(path.into());
+        self
+    }
+
+    /// Also generate a `<Message>Owned` struct per message, as
+    /// [`write_proto_with_owned`](crate::write_proto_with_owned) does.
+    pub fn owned(&mut self, owned: bool) -> &mut Self {
+        self.options.owned = owned;
+        self
+    }
+
+    /// Also generate `<Message>Owned::random`, which makes up a message and a scrambled but
+    /// valid encoding of it, for property tests. Implies [`owned`](Self::owned).
+    pub fn random(&mut self, random: bool) -> &mut Self {
+        self.options.random = random;
+        self
+    }
+
+    /// Leave a package, and those under it, to the crate that already generates it, at
+    /// `rust_path`; see [`write_protos_with_extern`](crate::write_protos_with_extern).
+    pub fn extern_path(
+        &mut self,
+        proto_package: impl Into<String>,
+        rust_path: impl Into<String>,
+    ) -> &mut Self {
+        self.options
+            .extern_paths
+            .push((proto_package.into(), rust_path.into()));
+        self
+    }
+
+    /// An attribute for the schema struct of every message, and every enum, under `path`.
+    pub fn type_attribute(
+        &mut self,
+        path: impl Into<String>,
+        attr: impl Into<String>,
+    ) -> &mut Self {
+        self.options
+            .type_attributes
+            .push((path.into(), attr.into()));
+        self
+    }
+
+    /// An attribute for every schema struct field under `path`. A oneof's field is named
+    /// after the oneof.
+    pub fn field_attribute(
+        &mut self,
+        path: impl Into<String>,
+        attr: impl Into<String>,
+    ) -> &mut Self {
+        self.options
+            .field_attributes
+            .push((path.into(), attr.into()));
+        self
+    }
+
+    /// How every `string` field under `path` checks its UTF-8: as it is decoded, never, or
+    /// when it is read as text. The last call matching a field wins.
+    pub fn string_decoding(
+        &mut self,
+        path: impl Into<String>,
+        decoding: StringDecoding,
+    ) -> &mut Self {
+        self.options.string_decoding.push((path.into(), decoding));
+        self
+    }
+
+    /// An attribute for every enum under `path`.
+    pub fn enum_attribute(
+        &mut self,
+        path: impl Into<String>,
+        attr: impl Into<String>,
+    ) -> &mut Self {
+        self.options
+            .enum_attributes
+            .push((path.into(), attr.into()));
+        self
+    }
+
+    /// An attribute made from the value of `option` wherever it is set: on a message's
+    /// schema struct, a field of one (a oneof's, for a oneof option) or an enum. `attribute`
+    /// returns `None` for values that get none.
+    ///
+    /// ```no_run
+    /// tacky_build::Config::new()
+    ///     .file("protos/api.proto")
+    ///     .option_attribute("(api.sensitive)", |value| {
+    ///         (value.as_bool() == Some(true)).then(|| "#[doc(alias = \"sensitive\")]".into())
+    ///     })
+    ///     .compile()
+    ///     .unwrap();
+    /// ```
+    pub fn option_attribute(
+        &mut self,
+        option: impl Into<String>,
+        attribute: impl Fn(&OptionValue) -> Option<String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.options.option_attributes.push(OptionAttribute {
+            option: option.into(),
+            attribute: Arc::new(attribute),
+        });
+        self
+    }
+
+    /// Check names against a rule of the proto style guide: at [`Level::Warn`], what
+    /// breaks it is printed as a cargo warning; at [`Level::Deny`], `compile` fails on it,
+    /// along with anything else the lint pass finds. Style lints are off by default.
+    pub fn style_lint(&mut self, lint: Style, level: Level) -> &mut Self {
+        self.options.style_lints.push((lint, level));
+        self
+    }
+
+    /// Whether to print `cargo:rerun-if-changed` for each input file and everything it
+    /// imports, which is on by default.
+    pub fn emit_rerun_if_changed(&mut self, emit: bool) -> &mut Self {
+        self.rerun_if_changed = emit;
+        self
+    }
+
+    /// Generates and writes the output. A file that already has exactly what would be
+    /// written is left alone, so that nothing including it rebuilds for nothing.
+    pub fn compile(&self) -> Result<(), Error> {
+        let out_dir = match &self.out_dir {
+            Some(dir) => dir.clone(),
+            None => std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or(Error::NoOutDir)?,
+        };
+        if let Some((path, _)) = self
+            .options
+            .extern_paths
+            .iter()
+            .find(|(path, _)| !path.starts_with('.'))
+        {
+            return Err(Error::ExternPath(path.clone()));
+        }
+        // Canonical, so that one that isn't there fails here rather than being searched in
+        // vain for every import.
+        let includes = if self.includes.is_empty() {
+            vec![PathBuf::from(".")]
+        } else {
+            self.includes.clone()
+        };
+        let includes = includes
+            .iter()
+            .map(|dir| {
+                let dir = std::fs::canonicalize(dir).map_err(|source| Error::Io {
+                    path: dir.clone(),
+                    source,
+                })?;
+                Ok(dir.to_string_lossy().into_owned())
+            })
+            .collect::<Result<Vec<String>, Error>>()?;
+        let includes: Vec<&str> = includes.iter().map(String::as_str).collect();
+        let files: Vec<&Path> = self.files.iter().map(PathBuf::as_path).collect();
+
+        let expansion =
+            expand_packages(&files, &includes, &self.options).map_err(Error::Codegen)?;
+        let source = format_tokens(expansion.tokens);
+        let inputs = expansion.files;
+        for warning in &expansion.warnings {
+            println!("cargo:warning={}: {}", warning.span, warning.message);
+        }
+
+        if self.rerun_if_changed {
+            for file in &inputs {
+                println!("cargo:rerun-if-changed={}", file.display());
+            }
+        }
+
+        let output = out_dir.join(&self.include_file);
+        if std::fs::read(&output).is_ok_and(|existing| existing == source.as_bytes()) {
+            return Ok(());
+        }
+        std::fs::write(&output, source).map_err(|source| Error::Io {
+            path: output,
+            source,
+        })
+    }
+}
+
+/// Why [`Config::compile`] failed.
+#[derive(Debug)]
+pub enum Error {
+    /// No output directory was given, and `OUT_DIR` isn't set: not a build script.
+    NoOutDir,
+    /// An extern path whose proto package isn't fully-qualified, with a leading `.`.
+    ExternPath(String),
+    /// A proto file, or one it imports, couldn't be read or parsed, or has something
+    /// tacky can't generate code for; or an attribute didn't parse, or the lint pass found
+    /// problems. Displays with the file, line and column when there is one.
+    Codegen(tacky_codegen::error::Error),
+    /// An include directory that couldn't be resolved, or output that couldn't be written.
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoOutDir => f.write_str("no output directory given, and OUT_DIR is not set"),
+            Error::ExternPath(path) => write!(
+                f,
+                "extern path `{path}` should be a fully-qualified package, with a leading `.`"
+            ),
+            Error::Codegen(e) => write!(f, "{e}"),
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Codegen(e) => Some(e),
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
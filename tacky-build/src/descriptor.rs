@@ -0,0 +1,10618 @@
+// @generated by tacky-build from testing/protos/plugin.proto, with owned structs. Imports are
+// inlined, so this is descriptor.proto's messages (protobuf v3.20.3) as well as the plugin
+// protocol's. Regenerated and compared by the testing crate's `test_descriptor_current`.
+
+#[allow(unused, dead_code, clippy::all)]
+pub mod google {
+    pub mod protobuf {
+        pub mod compiler {
+            use ::tacky;
+            /// The version number of protocol compiler.
+            #[derive(Debug, Copy, Clone)]
+            pub struct Version {
+                pub major: tacky::Field<1, tacky::Optional<tacky::Int32>>,
+                pub minor: tacky::Field<2, tacky::Optional<tacky::Int32>>,
+                pub patch: tacky::Field<3, tacky::Optional<tacky::Int32>>,
+                /// A suffix for alpha, beta or rc release, e.g., "alpha-1", "rc2". It should
+                /// be empty for mainline stable releases.
+                pub suffix: tacky::Field<4, tacky::Optional<tacky::PbString>>,
+            }
+            impl tacky::MessageSchema for Version {}
+            impl tacky::DecodeMessage for Version {
+                type Fields<'a> = VersionFields<'a>;
+            }
+            impl Version {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> VersionFields<'_> {
+                    VersionFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum VersionField<'a> {
+                Major(i32),
+                Minor(i32),
+                Patch(i32),
+                /// A suffix for alpha, beta or rc release, e.g., "alpha-1", "rc2". It should
+                /// be empty for mainline stable releases.
+                Suffix(&'a str),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct VersionFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> VersionField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        VersionField::Major(_) => Some("major"),
+                        VersionField::Minor(_) => Some("minor"),
+                        VersionField::Patch(_) => Some("patch"),
+                        VersionField::Suffix(_) => Some("suffix"),
+                    }
+                }
+            }
+            impl<'a> VersionFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for VersionFields<'a> {
+                type Schema = Version;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for VersionFields<'a> {
+                type Item = ::core::result::Result<VersionField<'a>, tacky::DecodeError>;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "major",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(VersionField::Major(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "minor",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(VersionField::Minor(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "patch",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(VersionField::Patch(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "suffix",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(VersionField::Suffix(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct VersionOwned {
+                pub major: ::core::option::Option<i32>,
+                pub minor: ::core::option::Option<i32>,
+                pub patch: ::core::option::Option<i32>,
+                /// A suffix for alpha, beta or rc release, e.g., "alpha-1", "rc2". It should
+                /// be empty for mainline stable releases.
+                pub suffix: ::core::option::Option<::std::string::String>,
+            }
+            #[allow(deprecated)]
+            impl VersionOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(Version::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: VersionFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: VersionFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            VersionField::Major(v) => {
+                                self.major = Some(v);
+                            }
+                            VersionField::Minor(v) => {
+                                self.minor = Some(v);
+                            }
+                            VersionField::Patch(v) => {
+                                self.patch = Some(v);
+                            }
+                            VersionField::Suffix(v) => {
+                                self.suffix = Some(v.to_owned());
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = Version::schema();
+                    schema.major.write(buf, self.major.as_ref());
+                    schema.minor.write(buf, self.minor.as_ref());
+                    schema.patch.write(buf, self.patch.as_ref());
+                    schema.suffix.write(buf, self.suffix.as_ref());
+                }
+            }
+            /// An encoded CodeGeneratorRequest is written to the plugin's stdin.
+            #[derive(Debug, Copy, Clone)]
+            pub struct CodeGeneratorRequest {
+                /// The .proto files that were explicitly listed on the command-line.  The
+                /// code generator should generate code only for these files.  Each file's
+                /// descriptor will be included in proto_file, below.
+                pub file_to_generate: tacky::Field<1, tacky::Repeated<tacky::PbString>>,
+                /// The generator parameter passed on the command-line.
+                pub parameter: tacky::Field<2, tacky::Optional<tacky::PbString>>,
+                /// FileDescriptorProtos for all files in files_to_generate and everything
+                /// they import.  The files will appear in topological order, so each file
+                /// appears before any file that imports it.
+                ///
+                /// Note: the files listed in files_to_generate will include runtime-retention
+                /// options only, but all other files will include source-retention options.
+                /// The source_file_descriptors field below is available in case you need
+                /// source-retention options for files_to_generate.
+                ///
+                /// protoc guarantees that all proto_files will be written after
+                /// the fields above, even though this is not technically guaranteed by the
+                /// protobuf wire format.  This theoretically could allow a plugin to stream
+                /// in the FileDescriptorProtos and handle them one by one rather than read
+                /// the entire set into memory at once.  However, as of this writing, this
+                /// is not similarly optimized on protoc's end -- it will store all fields in
+                /// memory at once before sending them to the plugin.
+                ///
+                /// Type names of fields and extensions in the FileDescriptorProto are always
+                /// fully qualified.
+                pub proto_file: tacky::Field<15, tacky::Repeated<FileDescriptorProto>>,
+                /// File descriptors with all options, including source-retention options.
+                /// These descriptors are only provided for the files listed in
+                /// files_to_generate.
+                pub source_file_descriptors: tacky::Field<
+                    17,
+                    tacky::Repeated<FileDescriptorProto>,
+                >,
+                /// The version number of protocol compiler.
+                pub compiler_version: tacky::Field<3, tacky::Optional<Version>>,
+            }
+            impl tacky::MessageSchema for CodeGeneratorRequest {}
+            impl tacky::DecodeMessage for CodeGeneratorRequest {
+                type Fields<'a> = CodeGeneratorRequestFields<'a>;
+            }
+            impl CodeGeneratorRequest {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> CodeGeneratorRequestFields<'_> {
+                    CodeGeneratorRequestFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum CodeGeneratorRequestField<'a> {
+                /// The .proto files that were explicitly listed on the command-line.  The
+                /// code generator should generate code only for these files.  Each file's
+                /// descriptor will be included in proto_file, below.
+                FileToGenerate(&'a str),
+                /// The generator parameter passed on the command-line.
+                Parameter(&'a str),
+                /// FileDescriptorProtos for all files in files_to_generate and everything
+                /// they import.  The files will appear in topological order, so each file
+                /// appears before any file that imports it.
+                ///
+                /// Note: the files listed in files_to_generate will include runtime-retention
+                /// options only, but all other files will include source-retention options.
+                /// The source_file_descriptors field below is available in case you need
+                /// source-retention options for files_to_generate.
+                ///
+                /// protoc guarantees that all proto_files will be written after
+                /// the fields above, even though this is not technically guaranteed by the
+                /// protobuf wire format.  This theoretically could allow a plugin to stream
+                /// in the FileDescriptorProtos and handle them one by one rather than read
+                /// the entire set into memory at once.  However, as of this writing, this
+                /// is not similarly optimized on protoc's end -- it will store all fields in
+                /// memory at once before sending them to the plugin.
+                ///
+                /// Type names of fields and extensions in the FileDescriptorProto are always
+                /// fully qualified.
+                ProtoFile(FileDescriptorProtoFields<'a>),
+                /// File descriptors with all options, including source-retention options.
+                /// These descriptors are only provided for the files listed in
+                /// files_to_generate.
+                SourceFileDescriptors(FileDescriptorProtoFields<'a>),
+                /// The version number of protocol compiler.
+                CompilerVersion(VersionFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct CodeGeneratorRequestFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> CodeGeneratorRequestField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        CodeGeneratorRequestField::FileToGenerate(_) => {
+                            Some("fileToGenerate")
+                        }
+                        CodeGeneratorRequestField::Parameter(_) => Some("parameter"),
+                        CodeGeneratorRequestField::ProtoFile(_) => Some("protoFile"),
+                        CodeGeneratorRequestField::SourceFileDescriptors(_) => {
+                            Some("sourceFileDescriptors")
+                        }
+                        CodeGeneratorRequestField::CompilerVersion(_) => {
+                            Some("compilerVersion")
+                        }
+                    }
+                }
+            }
+            impl<'a> CodeGeneratorRequestFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for CodeGeneratorRequestFields<'a> {
+                type Schema = CodeGeneratorRequest;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for CodeGeneratorRequestFields<'a> {
+                type Item = ::core::result::Result<
+                    CodeGeneratorRequestField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "file_to_generate",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(CodeGeneratorRequestField::FileToGenerate(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "parameter",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(CodeGeneratorRequestField::Parameter(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            15u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "proto_file",
+                                    )?;
+                                    let data = FileDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(CodeGeneratorRequestField::ProtoFile(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            17u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "source_file_descriptors",
+                                    )?;
+                                    let data = FileDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(CodeGeneratorRequestField::SourceFileDescriptors(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "compiler_version",
+                                    )?;
+                                    let data = Version::decode(tacky::decode_len(buf)?);
+                                    Ok(CodeGeneratorRequestField::CompilerVersion(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct CodeGeneratorRequestOwned {
+                /// The .proto files that were explicitly listed on the command-line.  The
+                /// code generator should generate code only for these files.  Each file's
+                /// descriptor will be included in proto_file, below.
+                pub file_to_generate: ::std::vec::Vec<::std::string::String>,
+                /// The generator parameter passed on the command-line.
+                pub parameter: ::core::option::Option<::std::string::String>,
+                /// FileDescriptorProtos for all files in files_to_generate and everything
+                /// they import.  The files will appear in topological order, so each file
+                /// appears before any file that imports it.
+                ///
+                /// Note: the files listed in files_to_generate will include runtime-retention
+                /// options only, but all other files will include source-retention options.
+                /// The source_file_descriptors field below is available in case you need
+                /// source-retention options for files_to_generate.
+                ///
+                /// protoc guarantees that all proto_files will be written after
+                /// the fields above, even though this is not technically guaranteed by the
+                /// protobuf wire format.  This theoretically could allow a plugin to stream
+                /// in the FileDescriptorProtos and handle them one by one rather than read
+                /// the entire set into memory at once.  However, as of this writing, this
+                /// is not similarly optimized on protoc's end -- it will store all fields in
+                /// memory at once before sending them to the plugin.
+                ///
+                /// Type names of fields and extensions in the FileDescriptorProto are always
+                /// fully qualified.
+                pub proto_file: ::std::vec::Vec<FileDescriptorProtoOwned>,
+                /// File descriptors with all options, including source-retention options.
+                /// These descriptors are only provided for the files listed in
+                /// files_to_generate.
+                pub source_file_descriptors: ::std::vec::Vec<FileDescriptorProtoOwned>,
+                /// The version number of protocol compiler.
+                pub compiler_version: ::core::option::Option<
+                    ::std::boxed::Box<VersionOwned>,
+                >,
+            }
+            #[allow(deprecated)]
+            impl CodeGeneratorRequestOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(CodeGeneratorRequest::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: CodeGeneratorRequestFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: CodeGeneratorRequestFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            CodeGeneratorRequestField::FileToGenerate(v) => {
+                                self.file_to_generate.push(v.to_owned());
+                            }
+                            CodeGeneratorRequestField::Parameter(v) => {
+                                self.parameter = Some(v.to_owned());
+                            }
+                            CodeGeneratorRequestField::ProtoFile(v) => {
+                                self.proto_file
+                                    .push(FileDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            CodeGeneratorRequestField::SourceFileDescriptors(v) => {
+                                self.source_file_descriptors
+                                    .push(FileDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            CodeGeneratorRequestField::CompilerVersion(v) => {
+                                match &mut self.compiler_version {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.compiler_version = Some(
+                                            ::std::boxed::Box::new(VersionOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = CodeGeneratorRequest::schema();
+                    schema.file_to_generate.write(buf, &self.file_to_generate);
+                    schema.parameter.write(buf, self.parameter.as_ref());
+                    schema
+                        .proto_file
+                        .write_msgs(
+                            buf,
+                            &self.proto_file,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    schema
+                        .source_file_descriptors
+                        .write_msgs(
+                            buf,
+                            &self.source_file_descriptors,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    if let Some(m) = &self.compiler_version {
+                        schema
+                            .compiler_version
+                            .write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                }
+            }
+            /// The plugin writes an encoded CodeGeneratorResponse to stdout.
+            #[derive(Debug, Copy, Clone)]
+            pub struct CodeGeneratorResponse {
+                /// Error message.  If non-empty, code generation failed.  The plugin process
+                /// should exit with status code zero even if it reports an error in this way.
+                ///
+                /// This should be used to indicate errors in .proto files which prevent the
+                /// code generator from generating correct code.  Errors which indicate a
+                /// problem in protoc itself -- such as the input CodeGeneratorRequest being
+                /// unparseable -- should be reported by writing a message to stderr and
+                /// exiting with a non-zero status code.
+                pub error: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                /// A bitmask of supported features that the code generator supports.
+                /// This is a bitwise "or" of values from the Feature enum.
+                pub supported_features: tacky::Field<2, tacky::Optional<tacky::Uint64>>,
+                /// The minimum edition this plugin supports.  This will be treated as an
+                /// Edition enum, but we want to allow unknown values.  It should be specified
+                /// according the edition enum value, *not* the edition number.  Only takes
+                /// effect for plugins that have FEATURE_SUPPORTS_EDITIONS set.
+                pub minimum_edition: tacky::Field<3, tacky::Optional<tacky::Int32>>,
+                /// The maximum edition this plugin supports.  This will be treated as an
+                /// Edition enum, but we want to allow unknown values.  It should be specified
+                /// according the edition enum value, *not* the edition number.  Only takes
+                /// effect for plugins that have FEATURE_SUPPORTS_EDITIONS set.
+                pub maximum_edition: tacky::Field<4, tacky::Optional<tacky::Int32>>,
+                pub file: tacky::Field<
+                    15,
+                    tacky::Repeated<code_generator_response::File>,
+                >,
+            }
+            impl tacky::MessageSchema for CodeGeneratorResponse {}
+            impl tacky::DecodeMessage for CodeGeneratorResponse {
+                type Fields<'a> = CodeGeneratorResponseFields<'a>;
+            }
+            impl CodeGeneratorResponse {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> CodeGeneratorResponseFields<'_> {
+                    CodeGeneratorResponseFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum CodeGeneratorResponseField<'a> {
+                /// Error message.  If non-empty, code generation failed.  The plugin process
+                /// should exit with status code zero even if it reports an error in this way.
+                ///
+                /// This should be used to indicate errors in .proto files which prevent the
+                /// code generator from generating correct code.  Errors which indicate a
+                /// problem in protoc itself -- such as the input CodeGeneratorRequest being
+                /// unparseable -- should be reported by writing a message to stderr and
+                /// exiting with a non-zero status code.
+                Error(&'a str),
+                /// A bitmask of supported features that the code generator supports.
+                /// This is a bitwise "or" of values from the Feature enum.
+                SupportedFeatures(u64),
+                /// The minimum edition this plugin supports.  This will be treated as an
+                /// Edition enum, but we want to allow unknown values.  It should be specified
+                /// according the edition enum value, *not* the edition number.  Only takes
+                /// effect for plugins that have FEATURE_SUPPORTS_EDITIONS set.
+                MinimumEdition(i32),
+                /// The maximum edition this plugin supports.  This will be treated as an
+                /// Edition enum, but we want to allow unknown values.  It should be specified
+                /// according the edition enum value, *not* the edition number.  Only takes
+                /// effect for plugins that have FEATURE_SUPPORTS_EDITIONS set.
+                MaximumEdition(i32),
+                File(code_generator_response::FileFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct CodeGeneratorResponseFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> CodeGeneratorResponseField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        CodeGeneratorResponseField::Error(_) => Some("error"),
+                        CodeGeneratorResponseField::SupportedFeatures(_) => {
+                            Some("supportedFeatures")
+                        }
+                        CodeGeneratorResponseField::MinimumEdition(_) => {
+                            Some("minimumEdition")
+                        }
+                        CodeGeneratorResponseField::MaximumEdition(_) => {
+                            Some("maximumEdition")
+                        }
+                        CodeGeneratorResponseField::File(_) => Some("file"),
+                    }
+                }
+            }
+            impl<'a> CodeGeneratorResponseFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for CodeGeneratorResponseFields<'a> {
+                type Schema = CodeGeneratorResponse;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for CodeGeneratorResponseFields<'a> {
+                type Item = ::core::result::Result<
+                    CodeGeneratorResponseField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "error",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(CodeGeneratorResponseField::Error(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "supported_features",
+                                    )?;
+                                    let val = <tacky::Uint64 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(CodeGeneratorResponseField::SupportedFeatures(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "minimum_edition",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(CodeGeneratorResponseField::MinimumEdition(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "maximum_edition",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(CodeGeneratorResponseField::MaximumEdition(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            15u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "file",
+                                    )?;
+                                    let data = code_generator_response::File::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(CodeGeneratorResponseField::File(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct CodeGeneratorResponseOwned {
+                /// Error message.  If non-empty, code generation failed.  The plugin process
+                /// should exit with status code zero even if it reports an error in this way.
+                ///
+                /// This should be used to indicate errors in .proto files which prevent the
+                /// code generator from generating correct code.  Errors which indicate a
+                /// problem in protoc itself -- such as the input CodeGeneratorRequest being
+                /// unparseable -- should be reported by writing a message to stderr and
+                /// exiting with a non-zero status code.
+                pub error: ::core::option::Option<::std::string::String>,
+                /// A bitmask of supported features that the code generator supports.
+                /// This is a bitwise "or" of values from the Feature enum.
+                pub supported_features: ::core::option::Option<u64>,
+                /// The minimum edition this plugin supports.  This will be treated as an
+                /// Edition enum, but we want to allow unknown values.  It should be specified
+                /// according the edition enum value, *not* the edition number.  Only takes
+                /// effect for plugins that have FEATURE_SUPPORTS_EDITIONS set.
+                pub minimum_edition: ::core::option::Option<i32>,
+                /// The maximum edition this plugin supports.  This will be treated as an
+                /// Edition enum, but we want to allow unknown values.  It should be specified
+                /// according the edition enum value, *not* the edition number.  Only takes
+                /// effect for plugins that have FEATURE_SUPPORTS_EDITIONS set.
+                pub maximum_edition: ::core::option::Option<i32>,
+                pub file: ::std::vec::Vec<code_generator_response::FileOwned>,
+            }
+            #[allow(deprecated)]
+            impl CodeGeneratorResponseOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(CodeGeneratorResponse::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: CodeGeneratorResponseFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: CodeGeneratorResponseFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            CodeGeneratorResponseField::Error(v) => {
+                                self.error = Some(v.to_owned());
+                            }
+                            CodeGeneratorResponseField::SupportedFeatures(v) => {
+                                self.supported_features = Some(v);
+                            }
+                            CodeGeneratorResponseField::MinimumEdition(v) => {
+                                self.minimum_edition = Some(v);
+                            }
+                            CodeGeneratorResponseField::MaximumEdition(v) => {
+                                self.maximum_edition = Some(v);
+                            }
+                            CodeGeneratorResponseField::File(v) => {
+                                self.file
+                                    .push(code_generator_response::FileOwned::from_fields(v)?);
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = CodeGeneratorResponse::schema();
+                    schema.error.write(buf, self.error.as_ref());
+                    schema
+                        .supported_features
+                        .write(buf, self.supported_features.as_ref());
+                    schema.minimum_edition.write(buf, self.minimum_edition.as_ref());
+                    schema.maximum_edition.write(buf, self.maximum_edition.as_ref());
+                    schema
+                        .file
+                        .write_msgs(buf, &self.file, |buf, _, m| m.encode_dir(buf));
+                }
+            }
+            /// The protocol compiler can output a FileDescriptorSet containing the .proto
+            /// files it parses.
+            #[derive(Debug, Copy, Clone)]
+            pub struct FileDescriptorSet {
+                pub file: tacky::Field<1, tacky::Repeated<FileDescriptorProto>>,
+            }
+            impl tacky::MessageSchema for FileDescriptorSet {}
+            impl tacky::DecodeMessage for FileDescriptorSet {
+                type Fields<'a> = FileDescriptorSetFields<'a>;
+            }
+            impl FileDescriptorSet {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> FileDescriptorSetFields<'_> {
+                    FileDescriptorSetFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum FileDescriptorSetField<'a> {
+                File(FileDescriptorProtoFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct FileDescriptorSetFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> FileDescriptorSetField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        FileDescriptorSetField::File(_) => Some("file"),
+                    }
+                }
+            }
+            impl<'a> FileDescriptorSetFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for FileDescriptorSetFields<'a> {
+                type Schema = FileDescriptorSet;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for FileDescriptorSetFields<'a> {
+                type Item = ::core::result::Result<
+                    FileDescriptorSetField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "file",
+                                    )?;
+                                    let data = FileDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(FileDescriptorSetField::File(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct FileDescriptorSetOwned {
+                pub file: ::std::vec::Vec<FileDescriptorProtoOwned>,
+            }
+            #[allow(deprecated)]
+            impl FileDescriptorSetOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(FileDescriptorSet::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: FileDescriptorSetFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: FileDescriptorSetFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            FileDescriptorSetField::File(v) => {
+                                self.file.push(FileDescriptorProtoOwned::from_fields(v)?);
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = FileDescriptorSet::schema();
+                    schema
+                        .file
+                        .write_msgs(buf, &self.file, |buf, _, m| m.encode_dir(buf));
+                }
+            }
+            /// Describes a complete .proto file.
+            #[derive(Debug, Copy, Clone)]
+            pub struct FileDescriptorProto {
+                /// file name, relative to root of source tree
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                /// e.g. "foo", "foo.bar", etc.
+                pub package: tacky::Field<2, tacky::Optional<tacky::PbString>>,
+                /// Names of files imported by this file.
+                pub dependency: tacky::Field<3, tacky::Repeated<tacky::PbString>>,
+                /// Indexes of the public imported files in the dependency list above.
+                pub public_dependency: tacky::Field<10, tacky::Repeated<tacky::Int32>>,
+                /// Indexes of the weak imported files in the dependency list.
+                /// For Google-internal migration only. Do not use.
+                pub weak_dependency: tacky::Field<11, tacky::Repeated<tacky::Int32>>,
+                /// All top-level definitions in this file.
+                pub message_type: tacky::Field<4, tacky::Repeated<DescriptorProto>>,
+                pub enum_type: tacky::Field<5, tacky::Repeated<EnumDescriptorProto>>,
+                pub service: tacky::Field<6, tacky::Repeated<ServiceDescriptorProto>>,
+                pub extension: tacky::Field<7, tacky::Repeated<FieldDescriptorProto>>,
+                pub options: tacky::Field<8, tacky::Optional<FileOptions>>,
+                /// This field contains optional information about the original source code.
+                /// You may safely remove this entire field without harming runtime
+                /// functionality of the descriptors -- the information is needed only by
+                /// development tools.
+                pub source_code_info: tacky::Field<9, tacky::Optional<SourceCodeInfo>>,
+                /// The syntax of the proto file.
+                /// The supported values are "proto2" and "proto3".
+                pub syntax: tacky::Field<12, tacky::Optional<tacky::PbString>>,
+            }
+            impl tacky::MessageSchema for FileDescriptorProto {}
+            impl tacky::DecodeMessage for FileDescriptorProto {
+                type Fields<'a> = FileDescriptorProtoFields<'a>;
+            }
+            impl FileDescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> FileDescriptorProtoFields<'_> {
+                    FileDescriptorProtoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum FileDescriptorProtoField<'a> {
+                /// file name, relative to root of source tree
+                Name(&'a str),
+                /// e.g. "foo", "foo.bar", etc.
+                Package(&'a str),
+                /// Names of files imported by this file.
+                Dependency(&'a str),
+                /// Indexes of the public imported files in the dependency list above.
+                PublicDependency(i32),
+                /// Indexes of the weak imported files in the dependency list.
+                /// For Google-internal migration only. Do not use.
+                WeakDependency(i32),
+                /// All top-level definitions in this file.
+                MessageType(DescriptorProtoFields<'a>),
+                EnumType(EnumDescriptorProtoFields<'a>),
+                Service(ServiceDescriptorProtoFields<'a>),
+                Extension(FieldDescriptorProtoFields<'a>),
+                Options(FileOptionsFields<'a>),
+                /// This field contains optional information about the original source code.
+                /// You may safely remove this entire field without harming runtime
+                /// functionality of the descriptors -- the information is needed only by
+                /// development tools.
+                SourceCodeInfo(SourceCodeInfoFields<'a>),
+                /// The syntax of the proto file.
+                /// The supported values are "proto2" and "proto3".
+                Syntax(&'a str),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct FileDescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> FileDescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        FileDescriptorProtoField::Name(_) => Some("name"),
+                        FileDescriptorProtoField::Package(_) => Some("package"),
+                        FileDescriptorProtoField::Dependency(_) => Some("dependency"),
+                        FileDescriptorProtoField::PublicDependency(_) => {
+                            Some("publicDependency")
+                        }
+                        FileDescriptorProtoField::WeakDependency(_) => {
+                            Some("weakDependency")
+                        }
+                        FileDescriptorProtoField::MessageType(_) => Some("messageType"),
+                        FileDescriptorProtoField::EnumType(_) => Some("enumType"),
+                        FileDescriptorProtoField::Service(_) => Some("service"),
+                        FileDescriptorProtoField::Extension(_) => Some("extension"),
+                        FileDescriptorProtoField::Options(_) => Some("options"),
+                        FileDescriptorProtoField::SourceCodeInfo(_) => {
+                            Some("sourceCodeInfo")
+                        }
+                        FileDescriptorProtoField::Syntax(_) => Some("syntax"),
+                    }
+                }
+            }
+            impl<'a> FileDescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for FileDescriptorProtoFields<'a> {
+                type Schema = FileDescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for FileDescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    FileDescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileDescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "package",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileDescriptorProtoField::Package(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "dependency",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileDescriptorProtoField::Dependency(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            10u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "public_dependency",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileDescriptorProtoField::PublicDependency(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            11u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "weak_dependency",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileDescriptorProtoField::WeakDependency(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "message_type",
+                                    )?;
+                                    let data = DescriptorProto::decode(tacky::decode_len(buf)?);
+                                    Ok(FileDescriptorProtoField::MessageType(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            5u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "enum_type",
+                                    )?;
+                                    let data = EnumDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(FileDescriptorProtoField::EnumType(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            6u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "service",
+                                    )?;
+                                    let data = ServiceDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(FileDescriptorProtoField::Service(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            7u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "extension",
+                                    )?;
+                                    let data = FieldDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(FileDescriptorProtoField::Extension(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            8u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = FileOptions::decode(tacky::decode_len(buf)?);
+                                    Ok(FileDescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            9u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "source_code_info",
+                                    )?;
+                                    let data = SourceCodeInfo::decode(tacky::decode_len(buf)?);
+                                    Ok(FileDescriptorProtoField::SourceCodeInfo(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            12u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "syntax",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileDescriptorProtoField::Syntax(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct FileDescriptorProtoOwned {
+                /// file name, relative to root of source tree
+                pub name: ::core::option::Option<::std::string::String>,
+                /// e.g. "foo", "foo.bar", etc.
+                pub package: ::core::option::Option<::std::string::String>,
+                /// Names of files imported by this file.
+                pub dependency: ::std::vec::Vec<::std::string::String>,
+                /// Indexes of the public imported files in the dependency list above.
+                pub public_dependency: ::std::vec::Vec<i32>,
+                /// Indexes of the weak imported files in the dependency list.
+                /// For Google-internal migration only. Do not use.
+                pub weak_dependency: ::std::vec::Vec<i32>,
+                /// All top-level definitions in this file.
+                pub message_type: ::std::vec::Vec<DescriptorProtoOwned>,
+                pub enum_type: ::std::vec::Vec<EnumDescriptorProtoOwned>,
+                pub service: ::std::vec::Vec<ServiceDescriptorProtoOwned>,
+                pub extension: ::std::vec::Vec<FieldDescriptorProtoOwned>,
+                pub options: ::core::option::Option<::std::boxed::Box<FileOptionsOwned>>,
+                /// This field contains optional information about the original source code.
+                /// You may safely remove this entire field without harming runtime
+                /// functionality of the descriptors -- the information is needed only by
+                /// development tools.
+                pub source_code_info: ::core::option::Option<
+                    ::std::boxed::Box<SourceCodeInfoOwned>,
+                >,
+                /// The syntax of the proto file.
+                /// The supported values are "proto2" and "proto3".
+                pub syntax: ::core::option::Option<::std::string::String>,
+            }
+            #[allow(deprecated)]
+            impl FileDescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(FileDescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: FileDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: FileDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            FileDescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            FileDescriptorProtoField::Package(v) => {
+                                self.package = Some(v.to_owned());
+                            }
+                            FileDescriptorProtoField::Dependency(v) => {
+                                self.dependency.push(v.to_owned());
+                            }
+                            FileDescriptorProtoField::PublicDependency(v) => {
+                                self.public_dependency.push(v);
+                            }
+                            FileDescriptorProtoField::WeakDependency(v) => {
+                                self.weak_dependency.push(v);
+                            }
+                            FileDescriptorProtoField::MessageType(v) => {
+                                self.message_type
+                                    .push(DescriptorProtoOwned::from_fields(v)?);
+                            }
+                            FileDescriptorProtoField::EnumType(v) => {
+                                self.enum_type
+                                    .push(EnumDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            FileDescriptorProtoField::Service(v) => {
+                                self.service
+                                    .push(ServiceDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            FileDescriptorProtoField::Extension(v) => {
+                                self.extension
+                                    .push(FieldDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            FileDescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(FileOptionsOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                            FileDescriptorProtoField::SourceCodeInfo(v) => {
+                                match &mut self.source_code_info {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.source_code_info = Some(
+                                            ::std::boxed::Box::new(SourceCodeInfoOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                            FileDescriptorProtoField::Syntax(v) => {
+                                self.syntax = Some(v.to_owned());
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = FileDescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    schema.package.write(buf, self.package.as_ref());
+                    schema.dependency.write(buf, &self.dependency);
+                    schema.public_dependency.write(buf, &self.public_dependency);
+                    schema.weak_dependency.write(buf, &self.weak_dependency);
+                    schema
+                        .message_type
+                        .write_msgs(
+                            buf,
+                            &self.message_type,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    schema
+                        .enum_type
+                        .write_msgs(buf, &self.enum_type, |buf, _, m| m.encode_dir(buf));
+                    schema
+                        .service
+                        .write_msgs(buf, &self.service, |buf, _, m| m.encode_dir(buf));
+                    schema
+                        .extension
+                        .write_msgs(buf, &self.extension, |buf, _, m| m.encode_dir(buf));
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                    if let Some(m) = &self.source_code_info {
+                        schema
+                            .source_code_info
+                            .write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                    schema.syntax.write(buf, self.syntax.as_ref());
+                }
+            }
+            /// Describes a message type.
+            #[derive(Debug, Copy, Clone)]
+            pub struct DescriptorProto {
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                pub field: tacky::Field<2, tacky::Repeated<FieldDescriptorProto>>,
+                pub extension: tacky::Field<6, tacky::Repeated<FieldDescriptorProto>>,
+                pub nested_type: tacky::Field<3, tacky::Repeated<DescriptorProto>>,
+                pub enum_type: tacky::Field<4, tacky::Repeated<EnumDescriptorProto>>,
+                pub extension_range: tacky::Field<
+                    5,
+                    tacky::Repeated<descriptor_proto::ExtensionRange>,
+                >,
+                pub oneof_decl: tacky::Field<8, tacky::Repeated<OneofDescriptorProto>>,
+                pub options: tacky::Field<7, tacky::Optional<MessageOptions>>,
+                pub reserved_range: tacky::Field<
+                    9,
+                    tacky::Repeated<descriptor_proto::ReservedRange>,
+                >,
+                /// Reserved field names, which may not be used by fields in the same message.
+                /// A given name may only be reserved once.
+                pub reserved_name: tacky::Field<10, tacky::Repeated<tacky::PbString>>,
+            }
+            impl tacky::MessageSchema for DescriptorProto {}
+            impl tacky::DecodeMessage for DescriptorProto {
+                type Fields<'a> = DescriptorProtoFields<'a>;
+            }
+            impl DescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> DescriptorProtoFields<'_> {
+                    DescriptorProtoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum DescriptorProtoField<'a> {
+                Name(&'a str),
+                Field(FieldDescriptorProtoFields<'a>),
+                Extension(FieldDescriptorProtoFields<'a>),
+                NestedType(DescriptorProtoFields<'a>),
+                EnumType(EnumDescriptorProtoFields<'a>),
+                ExtensionRange(descriptor_proto::ExtensionRangeFields<'a>),
+                OneofDecl(OneofDescriptorProtoFields<'a>),
+                Options(MessageOptionsFields<'a>),
+                ReservedRange(descriptor_proto::ReservedRangeFields<'a>),
+                /// Reserved field names, which may not be used by fields in the same message.
+                /// A given name may only be reserved once.
+                ReservedName(&'a str),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct DescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> DescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        DescriptorProtoField::Name(_) => Some("name"),
+                        DescriptorProtoField::Field(_) => Some("field"),
+                        DescriptorProtoField::Extension(_) => Some("extension"),
+                        DescriptorProtoField::NestedType(_) => Some("nestedType"),
+                        DescriptorProtoField::EnumType(_) => Some("enumType"),
+                        DescriptorProtoField::ExtensionRange(_) => Some("extensionRange"),
+                        DescriptorProtoField::OneofDecl(_) => Some("oneofDecl"),
+                        DescriptorProtoField::Options(_) => Some("options"),
+                        DescriptorProtoField::ReservedRange(_) => Some("reservedRange"),
+                        DescriptorProtoField::ReservedName(_) => Some("reservedName"),
+                    }
+                }
+            }
+            impl<'a> DescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for DescriptorProtoFields<'a> {
+                type Schema = DescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for DescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    DescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(DescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "field",
+                                    )?;
+                                    let data = FieldDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(DescriptorProtoField::Field(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            6u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "extension",
+                                    )?;
+                                    let data = FieldDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(DescriptorProtoField::Extension(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "nested_type",
+                                    )?;
+                                    let data = DescriptorProto::decode(tacky::decode_len(buf)?);
+                                    Ok(DescriptorProtoField::NestedType(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "enum_type",
+                                    )?;
+                                    let data = EnumDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(DescriptorProtoField::EnumType(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            5u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "extension_range",
+                                    )?;
+                                    let data = descriptor_proto::ExtensionRange::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(DescriptorProtoField::ExtensionRange(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            8u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "oneof_decl",
+                                    )?;
+                                    let data = OneofDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(DescriptorProtoField::OneofDecl(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            7u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = MessageOptions::decode(tacky::decode_len(buf)?);
+                                    Ok(DescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            9u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "reserved_range",
+                                    )?;
+                                    let data = descriptor_proto::ReservedRange::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(DescriptorProtoField::ReservedRange(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            10u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "reserved_name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(DescriptorProtoField::ReservedName(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct DescriptorProtoOwned {
+                pub name: ::core::option::Option<::std::string::String>,
+                pub field: ::std::vec::Vec<FieldDescriptorProtoOwned>,
+                pub extension: ::std::vec::Vec<FieldDescriptorProtoOwned>,
+                pub nested_type: ::std::vec::Vec<DescriptorProtoOwned>,
+                pub enum_type: ::std::vec::Vec<EnumDescriptorProtoOwned>,
+                pub extension_range: ::std::vec::Vec<
+                    descriptor_proto::ExtensionRangeOwned,
+                >,
+                pub oneof_decl: ::std::vec::Vec<OneofDescriptorProtoOwned>,
+                pub options: ::core::option::Option<
+                    ::std::boxed::Box<MessageOptionsOwned>,
+                >,
+                pub reserved_range: ::std::vec::Vec<
+                    descriptor_proto::ReservedRangeOwned,
+                >,
+                /// Reserved field names, which may not be used by fields in the same message.
+                /// A given name may only be reserved once.
+                pub reserved_name: ::std::vec::Vec<::std::string::String>,
+            }
+            #[allow(deprecated)]
+            impl DescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(DescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: DescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: DescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            DescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            DescriptorProtoField::Field(v) => {
+                                self.field.push(FieldDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            DescriptorProtoField::Extension(v) => {
+                                self.extension
+                                    .push(FieldDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            DescriptorProtoField::NestedType(v) => {
+                                self.nested_type
+                                    .push(DescriptorProtoOwned::from_fields(v)?);
+                            }
+                            DescriptorProtoField::EnumType(v) => {
+                                self.enum_type
+                                    .push(EnumDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            DescriptorProtoField::ExtensionRange(v) => {
+                                self.extension_range
+                                    .push(
+                                        descriptor_proto::ExtensionRangeOwned::from_fields(v)?,
+                                    );
+                            }
+                            DescriptorProtoField::OneofDecl(v) => {
+                                self.oneof_decl
+                                    .push(OneofDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            DescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(MessageOptionsOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                            DescriptorProtoField::ReservedRange(v) => {
+                                self.reserved_range
+                                    .push(
+                                        descriptor_proto::ReservedRangeOwned::from_fields(v)?,
+                                    );
+                            }
+                            DescriptorProtoField::ReservedName(v) => {
+                                self.reserved_name.push(v.to_owned());
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = DescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    schema
+                        .field
+                        .write_msgs(buf, &self.field, |buf, _, m| m.encode_dir(buf));
+                    schema
+                        .extension
+                        .write_msgs(buf, &self.extension, |buf, _, m| m.encode_dir(buf));
+                    schema
+                        .nested_type
+                        .write_msgs(
+                            buf,
+                            &self.nested_type,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    schema
+                        .enum_type
+                        .write_msgs(buf, &self.enum_type, |buf, _, m| m.encode_dir(buf));
+                    schema
+                        .extension_range
+                        .write_msgs(
+                            buf,
+                            &self.extension_range,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    schema
+                        .oneof_decl
+                        .write_msgs(
+                            buf,
+                            &self.oneof_decl,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                    schema
+                        .reserved_range
+                        .write_msgs(
+                            buf,
+                            &self.reserved_range,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    schema.reserved_name.write(buf, &self.reserved_name);
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct ExtensionRangeOptions {
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for ExtensionRangeOptions {}
+            impl tacky::DecodeMessage for ExtensionRangeOptions {
+                type Fields<'a> = ExtensionRangeOptionsFields<'a>;
+            }
+            impl ExtensionRangeOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> ExtensionRangeOptionsFields<'_> {
+                    ExtensionRangeOptionsFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum ExtensionRangeOptionsField<'a> {
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct ExtensionRangeOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> ExtensionRangeOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        ExtensionRangeOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> ExtensionRangeOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for ExtensionRangeOptionsFields<'a> {
+                type Schema = ExtensionRangeOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for ExtensionRangeOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    ExtensionRangeOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(ExtensionRangeOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => {
+                                        return Some(Ok(ExtensionRangeOptionsField::Extension(ext)));
+                                    }
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct ExtensionRangeOptionsOwned {
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl ExtensionRangeOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(ExtensionRangeOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: ExtensionRangeOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: ExtensionRangeOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            ExtensionRangeOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            ExtensionRangeOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = ExtensionRangeOptions::schema();
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            /// Describes a field within a message.
+            #[derive(Debug, Copy, Clone)]
+            pub struct FieldDescriptorProto {
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                pub number: tacky::Field<3, tacky::Optional<tacky::Int32>>,
+                pub label: tacky::Field<
+                    4,
+                    tacky::Optional<tacky::PbEnum<field_descriptor_proto::Label>>,
+                >,
+                /// If type_name is set, this need not be set.  If both this and type_name
+                /// are set, this must be one of TYPE_ENUM, TYPE_MESSAGE or TYPE_GROUP.
+                pub r#type: tacky::Field<
+                    5,
+                    tacky::Optional<tacky::PbEnum<field_descriptor_proto::Type>>,
+                >,
+                /// For message and enum types, this is the name of the type.  If the name
+                /// starts with a '.', it is fully-qualified.  Otherwise, C++-like scoping
+                /// rules are used to find the type (i.e. first the nested types within this
+                /// message are searched, then within the parent, on up to the root
+                /// namespace).
+                pub type_name: tacky::Field<6, tacky::Optional<tacky::PbString>>,
+                /// For extensions, this is the name of the type being extended.  It is
+                /// resolved in the same manner as type_name.
+                pub extendee: tacky::Field<2, tacky::Optional<tacky::PbString>>,
+                /// For numeric types, contains the original text representation of the value.
+                /// For booleans, "true" or "false".
+                /// For strings, contains the default text contents (not escaped in any way).
+                /// For bytes, contains the C escaped value.  All bytes >= 128 are escaped.
+                pub default_value: tacky::Field<7, tacky::Optional<tacky::PbString>>,
+                /// If set, gives the index of a oneof in the containing type's oneof_decl
+                /// list.  This field is a member of that oneof.
+                pub oneof_index: tacky::Field<9, tacky::Optional<tacky::Int32>>,
+                /// JSON name of this field. The value is set by protocol compiler. If the
+                /// user has set a "json_name" option on this field, that option's value
+                /// will be used. Otherwise, it's deduced from the field's name by converting
+                /// it to camelCase.
+                pub json_name: tacky::Field<10, tacky::Optional<tacky::PbString>>,
+                pub options: tacky::Field<8, tacky::Optional<FieldOptions>>,
+                /// If true, this is a proto3 "optional". When a proto3 field is optional, it
+                /// tracks presence regardless of field type.
+                ///
+                /// When proto3_optional is true, this field must be belong to a oneof to
+                /// signal to old proto3 clients that presence is tracked for this field. This
+                /// oneof is known as a "synthetic" oneof, and this field must be its sole
+                /// member (each proto3 optional field gets its own synthetic oneof). Synthetic
+                /// oneofs exist in the descriptor only, and do not generate any API. Synthetic
+                /// oneofs must be ordered after all "real" oneofs.
+                ///
+                /// For message fields, proto3_optional doesn't create any semantic change,
+                /// since non-repeated message fields always track presence. However it still
+                /// indicates the semantic detail of whether the user wrote "optional" or not.
+                /// This can be useful for round-tripping the .proto file. For consistency we
+                /// give message fields a synthetic oneof also, even though it is not required
+                /// to track presence. This is especially important because the parser can't
+                /// tell if a field is a message or an enum, so it must always create a
+                /// synthetic oneof.
+                ///
+                /// Proto2 optional fields do not set this flag, because they already indicate
+                /// optional with `LABEL_OPTIONAL`.
+                pub proto3_optional: tacky::Field<17, tacky::Optional<tacky::Bool>>,
+            }
+            impl tacky::MessageSchema for FieldDescriptorProto {}
+            impl tacky::DecodeMessage for FieldDescriptorProto {
+                type Fields<'a> = FieldDescriptorProtoFields<'a>;
+            }
+            impl FieldDescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> FieldDescriptorProtoFields<'_> {
+                    FieldDescriptorProtoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum FieldDescriptorProtoField<'a> {
+                Name(&'a str),
+                Number(i32),
+                Label(field_descriptor_proto::Label),
+                /// If type_name is set, this need not be set.  If both this and type_name
+                /// are set, this must be one of TYPE_ENUM, TYPE_MESSAGE or TYPE_GROUP.
+                Type(field_descriptor_proto::Type),
+                /// For message and enum types, this is the name of the type.  If the name
+                /// starts with a '.', it is fully-qualified.  Otherwise, C++-like scoping
+                /// rules are used to find the type (i.e. first the nested types within this
+                /// message are searched, then within the parent, on up to the root
+                /// namespace).
+                TypeName(&'a str),
+                /// For extensions, this is the name of the type being extended.  It is
+                /// resolved in the same manner as type_name.
+                Extendee(&'a str),
+                /// For numeric types, contains the original text representation of the value.
+                /// For booleans, "true" or "false".
+                /// For strings, contains the default text contents (not escaped in any way).
+                /// For bytes, contains the C escaped value.  All bytes >= 128 are escaped.
+                DefaultValue(&'a str),
+                /// If set, gives the index of a oneof in the containing type's oneof_decl
+                /// list.  This field is a member of that oneof.
+                OneofIndex(i32),
+                /// JSON name of this field. The value is set by protocol compiler. If the
+                /// user has set a "json_name" option on this field, that option's value
+                /// will be used. Otherwise, it's deduced from the field's name by converting
+                /// it to camelCase.
+                JsonName(&'a str),
+                Options(FieldOptionsFields<'a>),
+                /// If true, this is a proto3 "optional". When a proto3 field is optional, it
+                /// tracks presence regardless of field type.
+                ///
+                /// When proto3_optional is true, this field must be belong to a oneof to
+                /// signal to old proto3 clients that presence is tracked for this field. This
+                /// oneof is known as a "synthetic" oneof, and this field must be its sole
+                /// member (each proto3 optional field gets its own synthetic oneof). Synthetic
+                /// oneofs exist in the descriptor only, and do not generate any API. Synthetic
+                /// oneofs must be ordered after all "real" oneofs.
+                ///
+                /// For message fields, proto3_optional doesn't create any semantic change,
+                /// since non-repeated message fields always track presence. However it still
+                /// indicates the semantic detail of whether the user wrote "optional" or not.
+                /// This can be useful for round-tripping the .proto file. For consistency we
+                /// give message fields a synthetic oneof also, even though it is not required
+                /// to track presence. This is especially important because the parser can't
+                /// tell if a field is a message or an enum, so it must always create a
+                /// synthetic oneof.
+                ///
+                /// Proto2 optional fields do not set this flag, because they already indicate
+                /// optional with `LABEL_OPTIONAL`.
+                Proto3Optional(bool),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct FieldDescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> FieldDescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        FieldDescriptorProtoField::Name(_) => Some("name"),
+                        FieldDescriptorProtoField::Number(_) => Some("number"),
+                        FieldDescriptorProtoField::Label(_) => Some("label"),
+                        FieldDescriptorProtoField::Type(_) => Some("type"),
+                        FieldDescriptorProtoField::TypeName(_) => Some("typeName"),
+                        FieldDescriptorProtoField::Extendee(_) => Some("extendee"),
+                        FieldDescriptorProtoField::DefaultValue(_) => {
+                            Some("defaultValue")
+                        }
+                        FieldDescriptorProtoField::OneofIndex(_) => Some("oneofIndex"),
+                        FieldDescriptorProtoField::JsonName(_) => Some("jsonName"),
+                        FieldDescriptorProtoField::Options(_) => Some("options"),
+                        FieldDescriptorProtoField::Proto3Optional(_) => {
+                            Some("proto3Optional")
+                        }
+                    }
+                }
+            }
+            impl<'a> FieldDescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for FieldDescriptorProtoFields<'a> {
+                type Schema = FieldDescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for FieldDescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    FieldDescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "number",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::Number(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                match (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "label",
+                                    )?;
+                                    let raw = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    let Some(val) = <field_descriptor_proto::Label as tacky::PbEnumType>::from_wire(
+                                        raw,
+                                    ) else {
+                                        return Ok(None);
+                                    };
+                                    Ok(Some(FieldDescriptorProtoField::Label(val)))
+                                })() {
+                                    Ok(Some(field)) => return Some(Ok(field)),
+                                    Ok(None) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            5u32 => {
+                                match (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "type",
+                                    )?;
+                                    let raw = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    let Some(val) = <field_descriptor_proto::Type as tacky::PbEnumType>::from_wire(
+                                        raw,
+                                    ) else {
+                                        return Ok(None);
+                                    };
+                                    Ok(Some(FieldDescriptorProtoField::Type(val)))
+                                })() {
+                                    Ok(Some(field)) => return Some(Ok(field)),
+                                    Ok(None) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            6u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "type_name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::TypeName(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "extendee",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::Extendee(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            7u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "default_value",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::DefaultValue(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            9u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "oneof_index",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::OneofIndex(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            10u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "json_name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::JsonName(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            8u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = FieldOptions::decode(tacky::decode_len(buf)?);
+                                    Ok(FieldDescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            17u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "proto3_optional",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldDescriptorProtoField::Proto3Optional(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct FieldDescriptorProtoOwned {
+                pub name: ::core::option::Option<::std::string::String>,
+                pub number: ::core::option::Option<i32>,
+                pub label: ::core::option::Option<field_descriptor_proto::Label>,
+                /// If type_name is set, this need not be set.  If both this and type_name
+                /// are set, this must be one of TYPE_ENUM, TYPE_MESSAGE or TYPE_GROUP.
+                pub r#type: ::core::option::Option<field_descriptor_proto::Type>,
+                /// For message and enum types, this is the name of the type.  If the name
+                /// starts with a '.', it is fully-qualified.  Otherwise, C++-like scoping
+                /// rules are used to find the type (i.e. first the nested types within this
+                /// message are searched, then within the parent, on up to the root
+                /// namespace).
+                pub type_name: ::core::option::Option<::std::string::String>,
+                /// For extensions, this is the name of the type being extended.  It is
+                /// resolved in the same manner as type_name.
+                pub extendee: ::core::option::Option<::std::string::String>,
+                /// For numeric types, contains the original text representation of the value.
+                /// For booleans, "true" or "false".
+                /// For strings, contains the default text contents (not escaped in any way).
+                /// For bytes, contains the C escaped value.  All bytes >= 128 are escaped.
+                pub default_value: ::core::option::Option<::std::string::String>,
+                /// If set, gives the index of a oneof in the containing type's oneof_decl
+                /// list.  This field is a member of that oneof.
+                pub oneof_index: ::core::option::Option<i32>,
+                /// JSON name of this field. The value is set by protocol compiler. If the
+                /// user has set a "json_name" option on this field, that option's value
+                /// will be used. Otherwise, it's deduced from the field's name by converting
+                /// it to camelCase.
+                pub json_name: ::core::option::Option<::std::string::String>,
+                pub options: ::core::option::Option<
+                    ::std::boxed::Box<FieldOptionsOwned>,
+                >,
+                /// If true, this is a proto3 "optional". When a proto3 field is optional, it
+                /// tracks presence regardless of field type.
+                ///
+                /// When proto3_optional is true, this field must be belong to a oneof to
+                /// signal to old proto3 clients that presence is tracked for this field. This
+                /// oneof is known as a "synthetic" oneof, and this field must be its sole
+                /// member (each proto3 optional field gets its own synthetic oneof). Synthetic
+                /// oneofs exist in the descriptor only, and do not generate any API. Synthetic
+                /// oneofs must be ordered after all "real" oneofs.
+                ///
+                /// For message fields, proto3_optional doesn't create any semantic change,
+                /// since non-repeated message fields always track presence. However it still
+                /// indicates the semantic detail of whether the user wrote "optional" or not.
+                /// This can be useful for round-tripping the .proto file. For consistency we
+                /// give message fields a synthetic oneof also, even though it is not required
+                /// to track presence. This is especially important because the parser can't
+                /// tell if a field is a message or an enum, so it must always create a
+                /// synthetic oneof.
+                ///
+                /// Proto2 optional fields do not set this flag, because they already indicate
+                /// optional with `LABEL_OPTIONAL`.
+                pub proto3_optional: ::core::option::Option<bool>,
+            }
+            #[allow(deprecated)]
+            impl FieldDescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(FieldDescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: FieldDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: FieldDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            FieldDescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            FieldDescriptorProtoField::Number(v) => {
+                                self.number = Some(v);
+                            }
+                            FieldDescriptorProtoField::Label(v) => {
+                                self.label = Some(v);
+                            }
+                            FieldDescriptorProtoField::Type(v) => {
+                                self.r#type = Some(v);
+                            }
+                            FieldDescriptorProtoField::TypeName(v) => {
+                                self.type_name = Some(v.to_owned());
+                            }
+                            FieldDescriptorProtoField::Extendee(v) => {
+                                self.extendee = Some(v.to_owned());
+                            }
+                            FieldDescriptorProtoField::DefaultValue(v) => {
+                                self.default_value = Some(v.to_owned());
+                            }
+                            FieldDescriptorProtoField::OneofIndex(v) => {
+                                self.oneof_index = Some(v);
+                            }
+                            FieldDescriptorProtoField::JsonName(v) => {
+                                self.json_name = Some(v.to_owned());
+                            }
+                            FieldDescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(FieldOptionsOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                            FieldDescriptorProtoField::Proto3Optional(v) => {
+                                self.proto3_optional = Some(v);
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = FieldDescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    schema.number.write(buf, self.number.as_ref());
+                    schema.label.write(buf, self.label.as_ref());
+                    schema.r#type.write(buf, self.r#type.as_ref());
+                    schema.type_name.write(buf, self.type_name.as_ref());
+                    schema.extendee.write(buf, self.extendee.as_ref());
+                    schema.default_value.write(buf, self.default_value.as_ref());
+                    schema.oneof_index.write(buf, self.oneof_index.as_ref());
+                    schema.json_name.write(buf, self.json_name.as_ref());
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                    schema.proto3_optional.write(buf, self.proto3_optional.as_ref());
+                }
+            }
+            /// Describes a oneof.
+            #[derive(Debug, Copy, Clone)]
+            pub struct OneofDescriptorProto {
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                pub options: tacky::Field<2, tacky::Optional<OneofOptions>>,
+            }
+            impl tacky::MessageSchema for OneofDescriptorProto {}
+            impl tacky::DecodeMessage for OneofDescriptorProto {
+                type Fields<'a> = OneofDescriptorProtoFields<'a>;
+            }
+            impl OneofDescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> OneofDescriptorProtoFields<'_> {
+                    OneofDescriptorProtoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum OneofDescriptorProtoField<'a> {
+                Name(&'a str),
+                Options(OneofOptionsFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct OneofDescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> OneofDescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        OneofDescriptorProtoField::Name(_) => Some("name"),
+                        OneofDescriptorProtoField::Options(_) => Some("options"),
+                    }
+                }
+            }
+            impl<'a> OneofDescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for OneofDescriptorProtoFields<'a> {
+                type Schema = OneofDescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for OneofDescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    OneofDescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(OneofDescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = OneofOptions::decode(tacky::decode_len(buf)?);
+                                    Ok(OneofDescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct OneofDescriptorProtoOwned {
+                pub name: ::core::option::Option<::std::string::String>,
+                pub options: ::core::option::Option<
+                    ::std::boxed::Box<OneofOptionsOwned>,
+                >,
+            }
+            #[allow(deprecated)]
+            impl OneofDescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(OneofDescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: OneofDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: OneofDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            OneofDescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            OneofDescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(OneofOptionsOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = OneofDescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                }
+            }
+            /// Describes an enum type.
+            #[derive(Debug, Copy, Clone)]
+            pub struct EnumDescriptorProto {
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                pub value: tacky::Field<2, tacky::Repeated<EnumValueDescriptorProto>>,
+                pub options: tacky::Field<3, tacky::Optional<EnumOptions>>,
+                /// Range of reserved numeric values. Reserved numeric values may not be used
+                /// by enum values in the same enum declaration. Reserved ranges may not
+                /// overlap.
+                pub reserved_range: tacky::Field<
+                    4,
+                    tacky::Repeated<enum_descriptor_proto::EnumReservedRange>,
+                >,
+                /// Reserved enum value names, which may not be reused. A given name may only
+                /// be reserved once.
+                pub reserved_name: tacky::Field<5, tacky::Repeated<tacky::PbString>>,
+            }
+            impl tacky::MessageSchema for EnumDescriptorProto {}
+            impl tacky::DecodeMessage for EnumDescriptorProto {
+                type Fields<'a> = EnumDescriptorProtoFields<'a>;
+            }
+            impl EnumDescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> EnumDescriptorProtoFields<'_> {
+                    EnumDescriptorProtoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum EnumDescriptorProtoField<'a> {
+                Name(&'a str),
+                Value(EnumValueDescriptorProtoFields<'a>),
+                Options(EnumOptionsFields<'a>),
+                /// Range of reserved numeric values. Reserved numeric values may not be used
+                /// by enum values in the same enum declaration. Reserved ranges may not
+                /// overlap.
+                ReservedRange(enum_descriptor_proto::EnumReservedRangeFields<'a>),
+                /// Reserved enum value names, which may not be reused. A given name may only
+                /// be reserved once.
+                ReservedName(&'a str),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct EnumDescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> EnumDescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        EnumDescriptorProtoField::Name(_) => Some("name"),
+                        EnumDescriptorProtoField::Value(_) => Some("value"),
+                        EnumDescriptorProtoField::Options(_) => Some("options"),
+                        EnumDescriptorProtoField::ReservedRange(_) => {
+                            Some("reservedRange")
+                        }
+                        EnumDescriptorProtoField::ReservedName(_) => Some("reservedName"),
+                    }
+                }
+            }
+            impl<'a> EnumDescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for EnumDescriptorProtoFields<'a> {
+                type Schema = EnumDescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for EnumDescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    EnumDescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(EnumDescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "value",
+                                    )?;
+                                    let data = EnumValueDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(EnumDescriptorProtoField::Value(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = EnumOptions::decode(tacky::decode_len(buf)?);
+                                    Ok(EnumDescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "reserved_range",
+                                    )?;
+                                    let data = enum_descriptor_proto::EnumReservedRange::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(EnumDescriptorProtoField::ReservedRange(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            5u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "reserved_name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(EnumDescriptorProtoField::ReservedName(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct EnumDescriptorProtoOwned {
+                pub name: ::core::option::Option<::std::string::String>,
+                pub value: ::std::vec::Vec<EnumValueDescriptorProtoOwned>,
+                pub options: ::core::option::Option<::std::boxed::Box<EnumOptionsOwned>>,
+                /// Range of reserved numeric values. Reserved numeric values may not be used
+                /// by enum values in the same enum declaration. Reserved ranges may not
+                /// overlap.
+                pub reserved_range: ::std::vec::Vec<
+                    enum_descriptor_proto::EnumReservedRangeOwned,
+                >,
+                /// Reserved enum value names, which may not be reused. A given name may only
+                /// be reserved once.
+                pub reserved_name: ::std::vec::Vec<::std::string::String>,
+            }
+            #[allow(deprecated)]
+            impl EnumDescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(EnumDescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: EnumDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: EnumDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            EnumDescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            EnumDescriptorProtoField::Value(v) => {
+                                self.value
+                                    .push(EnumValueDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            EnumDescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(EnumOptionsOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                            EnumDescriptorProtoField::ReservedRange(v) => {
+                                self.reserved_range
+                                    .push(
+                                        enum_descriptor_proto::EnumReservedRangeOwned::from_fields(
+                                            v,
+                                        )?,
+                                    );
+                            }
+                            EnumDescriptorProtoField::ReservedName(v) => {
+                                self.reserved_name.push(v.to_owned());
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = EnumDescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    schema
+                        .value
+                        .write_msgs(buf, &self.value, |buf, _, m| m.encode_dir(buf));
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                    schema
+                        .reserved_range
+                        .write_msgs(
+                            buf,
+                            &self.reserved_range,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                    schema.reserved_name.write(buf, &self.reserved_name);
+                }
+            }
+            /// Describes a value within an enum.
+            #[derive(Debug, Copy, Clone)]
+            pub struct EnumValueDescriptorProto {
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                pub number: tacky::Field<2, tacky::Optional<tacky::Int32>>,
+                pub options: tacky::Field<3, tacky::Optional<EnumValueOptions>>,
+            }
+            impl tacky::MessageSchema for EnumValueDescriptorProto {}
+            impl tacky::DecodeMessage for EnumValueDescriptorProto {
+                type Fields<'a> = EnumValueDescriptorProtoFields<'a>;
+            }
+            impl EnumValueDescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> EnumValueDescriptorProtoFields<'_> {
+                    EnumValueDescriptorProtoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum EnumValueDescriptorProtoField<'a> {
+                Name(&'a str),
+                Number(i32),
+                Options(EnumValueOptionsFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct EnumValueDescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> EnumValueDescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        EnumValueDescriptorProtoField::Name(_) => Some("name"),
+                        EnumValueDescriptorProtoField::Number(_) => Some("number"),
+                        EnumValueDescriptorProtoField::Options(_) => Some("options"),
+                    }
+                }
+            }
+            impl<'a> EnumValueDescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for EnumValueDescriptorProtoFields<'a> {
+                type Schema = EnumValueDescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for EnumValueDescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    EnumValueDescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(EnumValueDescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "number",
+                                    )?;
+                                    let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(EnumValueDescriptorProtoField::Number(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = EnumValueOptions::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(EnumValueDescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct EnumValueDescriptorProtoOwned {
+                pub name: ::core::option::Option<::std::string::String>,
+                pub number: ::core::option::Option<i32>,
+                pub options: ::core::option::Option<
+                    ::std::boxed::Box<EnumValueOptionsOwned>,
+                >,
+            }
+            #[allow(deprecated)]
+            impl EnumValueDescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(EnumValueDescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: EnumValueDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: EnumValueDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            EnumValueDescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            EnumValueDescriptorProtoField::Number(v) => {
+                                self.number = Some(v);
+                            }
+                            EnumValueDescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(
+                                                EnumValueOptionsOwned::from_fields(v)?,
+                                            ),
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = EnumValueDescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    schema.number.write(buf, self.number.as_ref());
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                }
+            }
+            /// Describes a service.
+            #[derive(Debug, Copy, Clone)]
+            pub struct ServiceDescriptorProto {
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                pub method: tacky::Field<2, tacky::Repeated<MethodDescriptorProto>>,
+                pub options: tacky::Field<3, tacky::Optional<ServiceOptions>>,
+            }
+            impl tacky::MessageSchema for ServiceDescriptorProto {}
+            impl tacky::DecodeMessage for ServiceDescriptorProto {
+                type Fields<'a> = ServiceDescriptorProtoFields<'a>;
+            }
+            impl ServiceDescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> ServiceDescriptorProtoFields<'_> {
+                    ServiceDescriptorProtoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum ServiceDescriptorProtoField<'a> {
+                Name(&'a str),
+                Method(MethodDescriptorProtoFields<'a>),
+                Options(ServiceOptionsFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct ServiceDescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> ServiceDescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        ServiceDescriptorProtoField::Name(_) => Some("name"),
+                        ServiceDescriptorProtoField::Method(_) => Some("method"),
+                        ServiceDescriptorProtoField::Options(_) => Some("options"),
+                    }
+                }
+            }
+            impl<'a> ServiceDescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for ServiceDescriptorProtoFields<'a> {
+                type Schema = ServiceDescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for ServiceDescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    ServiceDescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(ServiceDescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "method",
+                                    )?;
+                                    let data = MethodDescriptorProto::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(ServiceDescriptorProtoField::Method(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = ServiceOptions::decode(tacky::decode_len(buf)?);
+                                    Ok(ServiceDescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct ServiceDescriptorProtoOwned {
+                pub name: ::core::option::Option<::std::string::String>,
+                pub method: ::std::vec::Vec<MethodDescriptorProtoOwned>,
+                pub options: ::core::option::Option<
+                    ::std::boxed::Box<ServiceOptionsOwned>,
+                >,
+            }
+            #[allow(deprecated)]
+            impl ServiceDescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(ServiceDescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: ServiceDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: ServiceDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            ServiceDescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            ServiceDescriptorProtoField::Method(v) => {
+                                self.method
+                                    .push(MethodDescriptorProtoOwned::from_fields(v)?);
+                            }
+                            ServiceDescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(ServiceOptionsOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = ServiceDescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    schema
+                        .method
+                        .write_msgs(buf, &self.method, |buf, _, m| m.encode_dir(buf));
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                }
+            }
+            /// Describes a method of a service.
+            #[derive(Debug, Copy, Clone)]
+            pub struct MethodDescriptorProto {
+                pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                /// Input and output type names.  These are resolved in the same way as
+                /// FieldDescriptorProto.type_name, but must refer to a message type.
+                pub input_type: tacky::Field<2, tacky::Optional<tacky::PbString>>,
+                pub output_type: tacky::Field<3, tacky::Optional<tacky::PbString>>,
+                pub options: tacky::Field<4, tacky::Optional<MethodOptions>>,
+                /// Identifies if client streams multiple client messages
+                pub client_streaming: tacky::Field<5, tacky::Optional<tacky::Bool>>,
+                /// Identifies if server streams multiple server messages
+                pub server_streaming: tacky::Field<6, tacky::Optional<tacky::Bool>>,
+            }
+            impl tacky::MessageSchema for MethodDescriptorProto {}
+            impl tacky::DecodeMessage for MethodDescriptorProto {
+                type Fields<'a> = MethodDescriptorProtoFields<'a>;
+            }
+            impl MethodDescriptorProto {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> MethodDescriptorProtoFields<'_> {
+                    MethodDescriptorProtoFields::new(buf)
+                }
+            }
+            impl MethodDescriptorProto {
+                pub const DEFAULT_CLIENT_STREAMING: bool = false;
+                pub fn client_streaming_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_CLIENT_STREAMING,
+                    }
+                }
+                pub const DEFAULT_SERVER_STREAMING: bool = false;
+                pub fn server_streaming_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_SERVER_STREAMING,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum MethodDescriptorProtoField<'a> {
+                Name(&'a str),
+                /// Input and output type names.  These are resolved in the same way as
+                /// FieldDescriptorProto.type_name, but must refer to a message type.
+                InputType(&'a str),
+                OutputType(&'a str),
+                Options(MethodOptionsFields<'a>),
+                /// Identifies if client streams multiple client messages
+                ClientStreaming(bool),
+                /// Identifies if server streams multiple server messages
+                ServerStreaming(bool),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct MethodDescriptorProtoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> MethodDescriptorProtoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        MethodDescriptorProtoField::Name(_) => Some("name"),
+                        MethodDescriptorProtoField::InputType(_) => Some("inputType"),
+                        MethodDescriptorProtoField::OutputType(_) => Some("outputType"),
+                        MethodDescriptorProtoField::Options(_) => Some("options"),
+                        MethodDescriptorProtoField::ClientStreaming(_) => {
+                            Some("clientStreaming")
+                        }
+                        MethodDescriptorProtoField::ServerStreaming(_) => {
+                            Some("serverStreaming")
+                        }
+                    }
+                }
+            }
+            impl<'a> MethodDescriptorProtoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for MethodDescriptorProtoFields<'a> {
+                type Schema = MethodDescriptorProto;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for MethodDescriptorProtoFields<'a> {
+                type Item = ::core::result::Result<
+                    MethodDescriptorProtoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MethodDescriptorProtoField::Name(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "input_type",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MethodDescriptorProtoField::InputType(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "output_type",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MethodDescriptorProtoField::OutputType(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "options",
+                                    )?;
+                                    let data = MethodOptions::decode(tacky::decode_len(buf)?);
+                                    Ok(MethodDescriptorProtoField::Options(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            5u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "client_streaming",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MethodDescriptorProtoField::ClientStreaming(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            6u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "server_streaming",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MethodDescriptorProtoField::ServerStreaming(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct MethodDescriptorProtoOwned {
+                pub name: ::core::option::Option<::std::string::String>,
+                /// Input and output type names.  These are resolved in the same way as
+                /// FieldDescriptorProto.type_name, but must refer to a message type.
+                pub input_type: ::core::option::Option<::std::string::String>,
+                pub output_type: ::core::option::Option<::std::string::String>,
+                pub options: ::core::option::Option<
+                    ::std::boxed::Box<MethodOptionsOwned>,
+                >,
+                /// Identifies if client streams multiple client messages
+                pub client_streaming: ::core::option::Option<bool>,
+                /// Identifies if server streams multiple server messages
+                pub server_streaming: ::core::option::Option<bool>,
+            }
+            #[allow(deprecated)]
+            impl MethodDescriptorProtoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(MethodDescriptorProto::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: MethodDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: MethodDescriptorProtoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            MethodDescriptorProtoField::Name(v) => {
+                                self.name = Some(v.to_owned());
+                            }
+                            MethodDescriptorProtoField::InputType(v) => {
+                                self.input_type = Some(v.to_owned());
+                            }
+                            MethodDescriptorProtoField::OutputType(v) => {
+                                self.output_type = Some(v.to_owned());
+                            }
+                            MethodDescriptorProtoField::Options(v) => {
+                                match &mut self.options {
+                                    Some(m) => m.merge_fields(v)?,
+                                    None => {
+                                        self.options = Some(
+                                            ::std::boxed::Box::new(MethodOptionsOwned::from_fields(v)?),
+                                        );
+                                    }
+                                }
+                            }
+                            MethodDescriptorProtoField::ClientStreaming(v) => {
+                                self.client_streaming = Some(v);
+                            }
+                            MethodDescriptorProtoField::ServerStreaming(v) => {
+                                self.server_streaming = Some(v);
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = MethodDescriptorProto::schema();
+                    schema.name.write(buf, self.name.as_ref());
+                    schema.input_type.write(buf, self.input_type.as_ref());
+                    schema.output_type.write(buf, self.output_type.as_ref());
+                    if let Some(m) = &self.options {
+                        schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                    }
+                    schema.client_streaming.write(buf, self.client_streaming.as_ref());
+                    schema.server_streaming.write(buf, self.server_streaming.as_ref());
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct FileOptions {
+                /// Sets the Java package where classes generated from this .proto will be
+                /// placed.  By default, the proto package is used, but this is often
+                /// inappropriate because proto packages do not normally start with backwards
+                /// domain names.
+                pub java_package: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                /// Controls the name of the wrapper Java class generated for the .proto file.
+                /// That class will always contain the .proto file's getDescriptor() method as
+                /// well as any top-level extensions defined in the .proto file.
+                /// If java_multiple_files is disabled, then all the other classes from the
+                /// .proto file will be nested inside the single wrapper outer class.
+                pub java_outer_classname: tacky::Field<
+                    8,
+                    tacky::Optional<tacky::PbString>,
+                >,
+                /// If enabled, then the Java code generator will generate a separate .java
+                /// file for each top-level message, enum, and service defined in the .proto
+                /// file.  Thus, these types will *not* be nested inside the wrapper class
+                /// named by java_outer_classname.  However, the wrapper class will still be
+                /// generated to contain the file's getDescriptor() method as well as any
+                /// top-level extensions defined in the file.
+                pub java_multiple_files: tacky::Field<10, tacky::Optional<tacky::Bool>>,
+                /// This option does nothing.
+                #[deprecated]
+                pub java_generate_equals_and_hash: tacky::Field<
+                    20,
+                    tacky::Optional<tacky::Bool>,
+                >,
+                /// If set true, then the Java2 code generator will generate code that
+                /// throws an exception whenever an attempt is made to assign a non-UTF-8
+                /// byte sequence to a string field.
+                /// Message reflection will do the same.
+                /// However, an extension field still accepts non-UTF-8 byte sequences.
+                /// This option has no effect on when used with the lite runtime.
+                pub java_string_check_utf8: tacky::Field<
+                    27,
+                    tacky::Optional<tacky::Bool>,
+                >,
+                pub optimize_for: tacky::Field<
+                    9,
+                    tacky::Optional<tacky::PbEnum<file_options::OptimizeMode>>,
+                >,
+                /// Sets the Go package where structs generated from this .proto will be
+                /// placed. If omitted, the Go package will be derived from the following:
+                /// - The basename of the package import path, if provided.
+                /// - Otherwise, the package statement in the .proto file, if present.
+                /// - Otherwise, the basename of the .proto file, without extension.
+                pub go_package: tacky::Field<11, tacky::Optional<tacky::PbString>>,
+                /// Should generic services be generated in each language?  "Generic" services
+                /// are not specific to any particular RPC system.  They are generated by the
+                /// main code generators in each language (without additional plugins).
+                /// Generic services were the only kind of service generation supported by
+                /// early versions of google.protobuf.
+                ///
+                /// Generic services are now considered deprecated in favor of using plugins
+                /// that generate code specific to your particular RPC system.  Therefore,
+                /// these default to false.  Old code which depends on generic services should
+                /// explicitly set them to true.
+                pub cc_generic_services: tacky::Field<16, tacky::Optional<tacky::Bool>>,
+                pub java_generic_services: tacky::Field<
+                    17,
+                    tacky::Optional<tacky::Bool>,
+                >,
+                pub py_generic_services: tacky::Field<18, tacky::Optional<tacky::Bool>>,
+                pub php_generic_services: tacky::Field<42, tacky::Optional<tacky::Bool>>,
+                /// Is this file deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for everything in the file, or it will be completely ignored; in the very
+                /// least, this is a formalization for deprecating files.
+                pub deprecated: tacky::Field<23, tacky::Optional<tacky::Bool>>,
+                /// Enables the use of arenas for the proto messages in this file. This applies
+                /// only to generated classes for C++.
+                pub cc_enable_arenas: tacky::Field<31, tacky::Optional<tacky::Bool>>,
+                /// Sets the objective c class prefix which is prepended to all objective c
+                /// generated classes from this .proto. There is no default.
+                pub objc_class_prefix: tacky::Field<
+                    36,
+                    tacky::Optional<tacky::PbString>,
+                >,
+                /// Namespace for generated classes; defaults to the package.
+                pub csharp_namespace: tacky::Field<37, tacky::Optional<tacky::PbString>>,
+                /// By default Swift generators will take the proto package and CamelCase it
+                /// replacing '.' with underscore and use that to prefix the types/symbols
+                /// defined. When this options is provided, they will use this value instead
+                /// to prefix the types/symbols defined.
+                pub swift_prefix: tacky::Field<39, tacky::Optional<tacky::PbString>>,
+                /// Sets the php class prefix which is prepended to all php generated classes
+                /// from this .proto. Default is empty.
+                pub php_class_prefix: tacky::Field<40, tacky::Optional<tacky::PbString>>,
+                /// Use this option to change the namespace of php generated classes. Default
+                /// is empty. When this option is empty, the package name will be used for
+                /// determining the namespace.
+                pub php_namespace: tacky::Field<41, tacky::Optional<tacky::PbString>>,
+                /// Use this option to change the namespace of php generated metadata classes.
+                /// Default is empty. When this option is empty, the proto file name will be
+                /// used for determining the namespace.
+                pub php_metadata_namespace: tacky::Field<
+                    44,
+                    tacky::Optional<tacky::PbString>,
+                >,
+                /// Use this option to change the package of ruby generated classes. Default
+                /// is empty. When this option is not set, the package name will be used for
+                /// determining the ruby package.
+                pub ruby_package: tacky::Field<45, tacky::Optional<tacky::PbString>>,
+                /// The parser stores options it doesn't recognize here.
+                /// See the documentation for the "Options" section above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for FileOptions {}
+            impl tacky::DecodeMessage for FileOptions {
+                type Fields<'a> = FileOptionsFields<'a>;
+            }
+            impl FileOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> FileOptionsFields<'_> {
+                    FileOptionsFields::new(buf)
+                }
+            }
+            impl FileOptions {
+                pub const DEFAULT_JAVA_MULTIPLE_FILES: bool = false;
+                pub fn java_multiple_files_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_JAVA_MULTIPLE_FILES,
+                    }
+                }
+                pub const DEFAULT_JAVA_STRING_CHECK_UTF8: bool = false;
+                pub fn java_string_check_utf8_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_JAVA_STRING_CHECK_UTF8,
+                    }
+                }
+                pub const DEFAULT_OPTIMIZE_FOR: file_options::OptimizeMode = file_options::OptimizeMode::Speed;
+                pub fn optimize_for_or_default(
+                    value: ::core::option::Option<file_options::OptimizeMode>,
+                ) -> file_options::OptimizeMode {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_OPTIMIZE_FOR,
+                    }
+                }
+                pub const DEFAULT_CC_GENERIC_SERVICES: bool = false;
+                pub fn cc_generic_services_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_CC_GENERIC_SERVICES,
+                    }
+                }
+                pub const DEFAULT_JAVA_GENERIC_SERVICES: bool = false;
+                pub fn java_generic_services_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_JAVA_GENERIC_SERVICES,
+                    }
+                }
+                pub const DEFAULT_PY_GENERIC_SERVICES: bool = false;
+                pub fn py_generic_services_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_PY_GENERIC_SERVICES,
+                    }
+                }
+                pub const DEFAULT_PHP_GENERIC_SERVICES: bool = false;
+                pub fn php_generic_services_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_PHP_GENERIC_SERVICES,
+                    }
+                }
+                pub const DEFAULT_DEPRECATED: bool = false;
+                pub fn deprecated_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_DEPRECATED,
+                    }
+                }
+                pub const DEFAULT_CC_ENABLE_ARENAS: bool = true;
+                pub fn cc_enable_arenas_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_CC_ENABLE_ARENAS,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum FileOptionsField<'a> {
+                /// Sets the Java package where classes generated from this .proto will be
+                /// placed.  By default, the proto package is used, but this is often
+                /// inappropriate because proto packages do not normally start with backwards
+                /// domain names.
+                JavaPackage(&'a str),
+                /// Controls the name of the wrapper Java class generated for the .proto file.
+                /// That class will always contain the .proto file's getDescriptor() method as
+                /// well as any top-level extensions defined in the .proto file.
+                /// If java_multiple_files is disabled, then all the other classes from the
+                /// .proto file will be nested inside the single wrapper outer class.
+                JavaOuterClassname(&'a str),
+                /// If enabled, then the Java code generator will generate a separate .java
+                /// file for each top-level message, enum, and service defined in the .proto
+                /// file.  Thus, these types will *not* be nested inside the wrapper class
+                /// named by java_outer_classname.  However, the wrapper class will still be
+                /// generated to contain the file's getDescriptor() method as well as any
+                /// top-level extensions defined in the file.
+                JavaMultipleFiles(bool),
+                /// This option does nothing.
+                #[deprecated]
+                JavaGenerateEqualsAndHash(bool),
+                /// If set true, then the Java2 code generator will generate code that
+                /// throws an exception whenever an attempt is made to assign a non-UTF-8
+                /// byte sequence to a string field.
+                /// Message reflection will do the same.
+                /// However, an extension field still accepts non-UTF-8 byte sequences.
+                /// This option has no effect on when used with the lite runtime.
+                JavaStringCheckUtf8(bool),
+                OptimizeFor(file_options::OptimizeMode),
+                /// Sets the Go package where structs generated from this .proto will be
+                /// placed. If omitted, the Go package will be derived from the following:
+                /// - The basename of the package import path, if provided.
+                /// - Otherwise, the package statement in the .proto file, if present.
+                /// - Otherwise, the basename of the .proto file, without extension.
+                GoPackage(&'a str),
+                /// Should generic services be generated in each language?  "Generic" services
+                /// are not specific to any particular RPC system.  They are generated by the
+                /// main code generators in each language (without additional plugins).
+                /// Generic services were the only kind of service generation supported by
+                /// early versions of google.protobuf.
+                ///
+                /// Generic services are now considered deprecated in favor of using plugins
+                /// that generate code specific to your particular RPC system.  Therefore,
+                /// these default to false.  Old code which depends on generic services should
+                /// explicitly set them to true.
+                CcGenericServices(bool),
+                JavaGenericServices(bool),
+                PyGenericServices(bool),
+                PhpGenericServices(bool),
+                /// Is this file deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for everything in the file, or it will be completely ignored; in the very
+                /// least, this is a formalization for deprecating files.
+                Deprecated(bool),
+                /// Enables the use of arenas for the proto messages in this file. This applies
+                /// only to generated classes for C++.
+                CcEnableArenas(bool),
+                /// Sets the objective c class prefix which is prepended to all objective c
+                /// generated classes from this .proto. There is no default.
+                ObjcClassPrefix(&'a str),
+                /// Namespace for generated classes; defaults to the package.
+                CsharpNamespace(&'a str),
+                /// By default Swift generators will take the proto package and CamelCase it
+                /// replacing '.' with underscore and use that to prefix the types/symbols
+                /// defined. When this options is provided, they will use this value instead
+                /// to prefix the types/symbols defined.
+                SwiftPrefix(&'a str),
+                /// Sets the php class prefix which is prepended to all php generated classes
+                /// from this .proto. Default is empty.
+                PhpClassPrefix(&'a str),
+                /// Use this option to change the namespace of php generated classes. Default
+                /// is empty. When this option is empty, the package name will be used for
+                /// determining the namespace.
+                PhpNamespace(&'a str),
+                /// Use this option to change the namespace of php generated metadata classes.
+                /// Default is empty. When this option is empty, the proto file name will be
+                /// used for determining the namespace.
+                PhpMetadataNamespace(&'a str),
+                /// Use this option to change the package of ruby generated classes. Default
+                /// is empty. When this option is not set, the package name will be used for
+                /// determining the ruby package.
+                RubyPackage(&'a str),
+                /// The parser stores options it doesn't recognize here.
+                /// See the documentation for the "Options" section above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct FileOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> FileOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        FileOptionsField::JavaPackage(_) => Some("javaPackage"),
+                        FileOptionsField::JavaOuterClassname(_) => {
+                            Some("javaOuterClassname")
+                        }
+                        FileOptionsField::JavaMultipleFiles(_) => {
+                            Some("javaMultipleFiles")
+                        }
+                        FileOptionsField::JavaGenerateEqualsAndHash(_) => {
+                            Some("javaGenerateEqualsAndHash")
+                        }
+                        FileOptionsField::JavaStringCheckUtf8(_) => {
+                            Some("javaStringCheckUtf8")
+                        }
+                        FileOptionsField::OptimizeFor(_) => Some("optimizeFor"),
+                        FileOptionsField::GoPackage(_) => Some("goPackage"),
+                        FileOptionsField::CcGenericServices(_) => {
+                            Some("ccGenericServices")
+                        }
+                        FileOptionsField::JavaGenericServices(_) => {
+                            Some("javaGenericServices")
+                        }
+                        FileOptionsField::PyGenericServices(_) => {
+                            Some("pyGenericServices")
+                        }
+                        FileOptionsField::PhpGenericServices(_) => {
+                            Some("phpGenericServices")
+                        }
+                        FileOptionsField::Deprecated(_) => Some("deprecated"),
+                        FileOptionsField::CcEnableArenas(_) => Some("ccEnableArenas"),
+                        FileOptionsField::ObjcClassPrefix(_) => Some("objcClassPrefix"),
+                        FileOptionsField::CsharpNamespace(_) => Some("csharpNamespace"),
+                        FileOptionsField::SwiftPrefix(_) => Some("swiftPrefix"),
+                        FileOptionsField::PhpClassPrefix(_) => Some("phpClassPrefix"),
+                        FileOptionsField::PhpNamespace(_) => Some("phpNamespace"),
+                        FileOptionsField::PhpMetadataNamespace(_) => {
+                            Some("phpMetadataNamespace")
+                        }
+                        FileOptionsField::RubyPackage(_) => Some("rubyPackage"),
+                        FileOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> FileOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for FileOptionsFields<'a> {
+                type Schema = FileOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for FileOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    FileOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "java_package",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::JavaPackage(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            8u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "java_outer_classname",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::JavaOuterClassname(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            10u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "java_multiple_files",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::JavaMultipleFiles(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            20u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "java_generate_equals_and_hash",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::JavaGenerateEqualsAndHash(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            27u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "java_string_check_utf8",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::JavaStringCheckUtf8(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            9u32 => {
+                                match (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "optimize_for",
+                                    )?;
+                                    let raw = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    let Some(val) = <file_options::OptimizeMode as tacky::PbEnumType>::from_wire(
+                                        raw,
+                                    ) else {
+                                        return Ok(None);
+                                    };
+                                    Ok(Some(FileOptionsField::OptimizeFor(val)))
+                                })() {
+                                    Ok(Some(field)) => return Some(Ok(field)),
+                                    Ok(None) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            11u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "go_package",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::GoPackage(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            16u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "cc_generic_services",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::CcGenericServices(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            17u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "java_generic_services",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::JavaGenericServices(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            18u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "py_generic_services",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::PyGenericServices(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            42u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "php_generic_services",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::PhpGenericServices(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            23u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "deprecated",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::Deprecated(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            31u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "cc_enable_arenas",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::CcEnableArenas(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            36u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "objc_class_prefix",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::ObjcClassPrefix(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            37u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "csharp_namespace",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::CsharpNamespace(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            39u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "swift_prefix",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::SwiftPrefix(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            40u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "php_class_prefix",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::PhpClassPrefix(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            41u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "php_namespace",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::PhpNamespace(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            44u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "php_metadata_namespace",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::PhpMetadataNamespace(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            45u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "ruby_package",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FileOptionsField::RubyPackage(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(FileOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => return Some(Ok(FileOptionsField::Extension(ext))),
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct FileOptionsOwned {
+                /// Sets the Java package where classes generated from this .proto will be
+                /// placed.  By default, the proto package is used, but this is often
+                /// inappropriate because proto packages do not normally start with backwards
+                /// domain names.
+                pub java_package: ::core::option::Option<::std::string::String>,
+                /// Controls the name of the wrapper Java class generated for the .proto file.
+                /// That class will always contain the .proto file's getDescriptor() method as
+                /// well as any top-level extensions defined in the .proto file.
+                /// If java_multiple_files is disabled, then all the other classes from the
+                /// .proto file will be nested inside the single wrapper outer class.
+                pub java_outer_classname: ::core::option::Option<::std::string::String>,
+                /// If enabled, then the Java code generator will generate a separate .java
+                /// file for each top-level message, enum, and service defined in the .proto
+                /// file.  Thus, these types will *not* be nested inside the wrapper class
+                /// named by java_outer_classname.  However, the wrapper class will still be
+                /// generated to contain the file's getDescriptor() method as well as any
+                /// top-level extensions defined in the file.
+                pub java_multiple_files: ::core::option::Option<bool>,
+                /// This option does nothing.
+                #[deprecated]
+                pub java_generate_equals_and_hash: ::core::option::Option<bool>,
+                /// If set true, then the Java2 code generator will generate code that
+                /// throws an exception whenever an attempt is made to assign a non-UTF-8
+                /// byte sequence to a string field.
+                /// Message reflection will do the same.
+                /// However, an extension field still accepts non-UTF-8 byte sequences.
+                /// This option has no effect on when used with the lite runtime.
+                pub java_string_check_utf8: ::core::option::Option<bool>,
+                pub optimize_for: ::core::option::Option<file_options::OptimizeMode>,
+                /// Sets the Go package where structs generated from this .proto will be
+                /// placed. If omitted, the Go package will be derived from the following:
+                /// - The basename of the package import path, if provided.
+                /// - Otherwise, the package statement in the .proto file, if present.
+                /// - Otherwise, the basename of the .proto file, without extension.
+                pub go_package: ::core::option::Option<::std::string::String>,
+                /// Should generic services be generated in each language?  "Generic" services
+                /// are not specific to any particular RPC system.  They are generated by the
+                /// main code generators in each language (without additional plugins).
+                /// Generic services were the only kind of service generation supported by
+                /// early versions of google.protobuf.
+                ///
+                /// Generic services are now considered deprecated in favor of using plugins
+                /// that generate code specific to your particular RPC system.  Therefore,
+                /// these default to false.  Old code which depends on generic services should
+                /// explicitly set them to true.
+                pub cc_generic_services: ::core::option::Option<bool>,
+                pub java_generic_services: ::core::option::Option<bool>,
+                pub py_generic_services: ::core::option::Option<bool>,
+                pub php_generic_services: ::core::option::Option<bool>,
+                /// Is this file deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for everything in the file, or it will be completely ignored; in the very
+                /// least, this is a formalization for deprecating files.
+                pub deprecated: ::core::option::Option<bool>,
+                /// Enables the use of arenas for the proto messages in this file. This applies
+                /// only to generated classes for C++.
+                pub cc_enable_arenas: ::core::option::Option<bool>,
+                /// Sets the objective c class prefix which is prepended to all objective c
+                /// generated classes from this .proto. There is no default.
+                pub objc_class_prefix: ::core::option::Option<::std::string::String>,
+                /// Namespace for generated classes; defaults to the package.
+                pub csharp_namespace: ::core::option::Option<::std::string::String>,
+                /// By default Swift generators will take the proto package and CamelCase it
+                /// replacing '.' with underscore and use that to prefix the types/symbols
+                /// defined. When this options is provided, they will use this value instead
+                /// to prefix the types/symbols defined.
+                pub swift_prefix: ::core::option::Option<::std::string::String>,
+                /// Sets the php class prefix which is prepended to all php generated classes
+                /// from this .proto. Default is empty.
+                pub php_class_prefix: ::core::option::Option<::std::string::String>,
+                /// Use this option to change the namespace of php generated classes. Default
+                /// is empty. When this option is empty, the package name will be used for
+                /// determining the namespace.
+                pub php_namespace: ::core::option::Option<::std::string::String>,
+                /// Use this option to change the namespace of php generated metadata classes.
+                /// Default is empty. When this option is empty, the proto file name will be
+                /// used for determining the namespace.
+                pub php_metadata_namespace: ::core::option::Option<
+                    ::std::string::String,
+                >,
+                /// Use this option to change the package of ruby generated classes. Default
+                /// is empty. When this option is not set, the package name will be used for
+                /// determining the ruby package.
+                pub ruby_package: ::core::option::Option<::std::string::String>,
+                /// The parser stores options it doesn't recognize here.
+                /// See the documentation for the "Options" section above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl FileOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(FileOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: FileOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: FileOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            FileOptionsField::JavaPackage(v) => {
+                                self.java_package = Some(v.to_owned());
+                            }
+                            FileOptionsField::JavaOuterClassname(v) => {
+                                self.java_outer_classname = Some(v.to_owned());
+                            }
+                            FileOptionsField::JavaMultipleFiles(v) => {
+                                self.java_multiple_files = Some(v);
+                            }
+                            FileOptionsField::JavaGenerateEqualsAndHash(v) => {
+                                self.java_generate_equals_and_hash = Some(v);
+                            }
+                            FileOptionsField::JavaStringCheckUtf8(v) => {
+                                self.java_string_check_utf8 = Some(v);
+                            }
+                            FileOptionsField::OptimizeFor(v) => {
+                                self.optimize_for = Some(v);
+                            }
+                            FileOptionsField::GoPackage(v) => {
+                                self.go_package = Some(v.to_owned());
+                            }
+                            FileOptionsField::CcGenericServices(v) => {
+                                self.cc_generic_services = Some(v);
+                            }
+                            FileOptionsField::JavaGenericServices(v) => {
+                                self.java_generic_services = Some(v);
+                            }
+                            FileOptionsField::PyGenericServices(v) => {
+                                self.py_generic_services = Some(v);
+                            }
+                            FileOptionsField::PhpGenericServices(v) => {
+                                self.php_generic_services = Some(v);
+                            }
+                            FileOptionsField::Deprecated(v) => {
+                                self.deprecated = Some(v);
+                            }
+                            FileOptionsField::CcEnableArenas(v) => {
+                                self.cc_enable_arenas = Some(v);
+                            }
+                            FileOptionsField::ObjcClassPrefix(v) => {
+                                self.objc_class_prefix = Some(v.to_owned());
+                            }
+                            FileOptionsField::CsharpNamespace(v) => {
+                                self.csharp_namespace = Some(v.to_owned());
+                            }
+                            FileOptionsField::SwiftPrefix(v) => {
+                                self.swift_prefix = Some(v.to_owned());
+                            }
+                            FileOptionsField::PhpClassPrefix(v) => {
+                                self.php_class_prefix = Some(v.to_owned());
+                            }
+                            FileOptionsField::PhpNamespace(v) => {
+                                self.php_namespace = Some(v.to_owned());
+                            }
+                            FileOptionsField::PhpMetadataNamespace(v) => {
+                                self.php_metadata_namespace = Some(v.to_owned());
+                            }
+                            FileOptionsField::RubyPackage(v) => {
+                                self.ruby_package = Some(v.to_owned());
+                            }
+                            FileOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            FileOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = FileOptions::schema();
+                    schema.java_package.write(buf, self.java_package.as_ref());
+                    schema
+                        .java_outer_classname
+                        .write(buf, self.java_outer_classname.as_ref());
+                    schema
+                        .java_multiple_files
+                        .write(buf, self.java_multiple_files.as_ref());
+                    schema
+                        .java_generate_equals_and_hash
+                        .write(buf, self.java_generate_equals_and_hash.as_ref());
+                    schema
+                        .java_string_check_utf8
+                        .write(buf, self.java_string_check_utf8.as_ref());
+                    schema.optimize_for.write(buf, self.optimize_for.as_ref());
+                    schema.go_package.write(buf, self.go_package.as_ref());
+                    schema
+                        .cc_generic_services
+                        .write(buf, self.cc_generic_services.as_ref());
+                    schema
+                        .java_generic_services
+                        .write(buf, self.java_generic_services.as_ref());
+                    schema
+                        .py_generic_services
+                        .write(buf, self.py_generic_services.as_ref());
+                    schema
+                        .php_generic_services
+                        .write(buf, self.php_generic_services.as_ref());
+                    schema.deprecated.write(buf, self.deprecated.as_ref());
+                    schema.cc_enable_arenas.write(buf, self.cc_enable_arenas.as_ref());
+                    schema.objc_class_prefix.write(buf, self.objc_class_prefix.as_ref());
+                    schema.csharp_namespace.write(buf, self.csharp_namespace.as_ref());
+                    schema.swift_prefix.write(buf, self.swift_prefix.as_ref());
+                    schema.php_class_prefix.write(buf, self.php_class_prefix.as_ref());
+                    schema.php_namespace.write(buf, self.php_namespace.as_ref());
+                    schema
+                        .php_metadata_namespace
+                        .write(buf, self.php_metadata_namespace.as_ref());
+                    schema.ruby_package.write(buf, self.ruby_package.as_ref());
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct MessageOptions {
+                /// Set true to use the old proto1 MessageSet wire format for extensions.
+                /// This is provided for backwards-compatibility with the MessageSet wire
+                /// format.  You should not use this for any other reason:  It's less
+                /// efficient, has fewer features, and is more complicated.
+                ///
+                /// The message must be defined exactly as follows:
+                /// message Foo {
+                /// option message_set_wire_format = true;
+                /// extensions 4 to max;
+                /// }
+                /// Note that the message cannot have any defined fields; MessageSets only
+                /// have extensions.
+                ///
+                /// All extensions of your type must be singular messages; e.g. they cannot
+                /// be int32s, enums, or repeated messages.
+                ///
+                /// Because this is an option, the above two restrictions are not enforced by
+                /// the protocol compiler.
+                pub message_set_wire_format: tacky::Field<
+                    1,
+                    tacky::Optional<tacky::Bool>,
+                >,
+                /// Disables the generation of the standard "descriptor()" accessor, which can
+                /// conflict with a field of the same name.  This is meant to make migration
+                /// from proto1 easier; new code should avoid fields named "descriptor".
+                pub no_standard_descriptor_accessor: tacky::Field<
+                    2,
+                    tacky::Optional<tacky::Bool>,
+                >,
+                /// Is this message deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the message, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating messages.
+                pub deprecated: tacky::Field<3, tacky::Optional<tacky::Bool>>,
+                /// Whether the message is an automatically generated map entry type for the
+                /// maps field.
+                ///
+                /// For maps fields:
+                /// map<KeyType, ValueType> map_field = 1;
+                /// The parsed descriptor looks like:
+                /// message MapFieldEntry {
+                /// option map_entry = true;
+                /// optional KeyType key = 1;
+                /// optional ValueType value = 2;
+                /// }
+                /// repeated MapFieldEntry map_field = 1;
+                ///
+                /// Implementations may choose not to generate the map_entry=true message, but
+                /// use a native map in the target language to hold the keys and values.
+                /// The reflection APIs in such implementations still need to work as
+                /// if the field is a repeated message field.
+                ///
+                /// NOTE: Do not set the option in .proto files. Always use the maps syntax
+                /// instead. The option should only be implicitly set by the proto compiler
+                /// parser.
+                pub map_entry: tacky::Field<7, tacky::Optional<tacky::Bool>>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for MessageOptions {}
+            impl tacky::DecodeMessage for MessageOptions {
+                type Fields<'a> = MessageOptionsFields<'a>;
+            }
+            impl MessageOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> MessageOptionsFields<'_> {
+                    MessageOptionsFields::new(buf)
+                }
+            }
+            impl MessageOptions {
+                pub const DEFAULT_MESSAGE_SET_WIRE_FORMAT: bool = false;
+                pub fn message_set_wire_format_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_MESSAGE_SET_WIRE_FORMAT,
+                    }
+                }
+                pub const DEFAULT_NO_STANDARD_DESCRIPTOR_ACCESSOR: bool = false;
+                pub fn no_standard_descriptor_accessor_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_NO_STANDARD_DESCRIPTOR_ACCESSOR,
+                    }
+                }
+                pub const DEFAULT_DEPRECATED: bool = false;
+                pub fn deprecated_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_DEPRECATED,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum MessageOptionsField<'a> {
+                /// Set true to use the old proto1 MessageSet wire format for extensions.
+                /// This is provided for backwards-compatibility with the MessageSet wire
+                /// format.  You should not use this for any other reason:  It's less
+                /// efficient, has fewer features, and is more complicated.
+                ///
+                /// The message must be defined exactly as follows:
+                /// message Foo {
+                /// option message_set_wire_format = true;
+                /// extensions 4 to max;
+                /// }
+                /// Note that the message cannot have any defined fields; MessageSets only
+                /// have extensions.
+                ///
+                /// All extensions of your type must be singular messages; e.g. they cannot
+                /// be int32s, enums, or repeated messages.
+                ///
+                /// Because this is an option, the above two restrictions are not enforced by
+                /// the protocol compiler.
+                MessageSetWireFormat(bool),
+                /// Disables the generation of the standard "descriptor()" accessor, which can
+                /// conflict with a field of the same name.  This is meant to make migration
+                /// from proto1 easier; new code should avoid fields named "descriptor".
+                NoStandardDescriptorAccessor(bool),
+                /// Is this message deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the message, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating messages.
+                Deprecated(bool),
+                /// Whether the message is an automatically generated map entry type for the
+                /// maps field.
+                ///
+                /// For maps fields:
+                /// map<KeyType, ValueType> map_field = 1;
+                /// The parsed descriptor looks like:
+                /// message MapFieldEntry {
+                /// option map_entry = true;
+                /// optional KeyType key = 1;
+                /// optional ValueType value = 2;
+                /// }
+                /// repeated MapFieldEntry map_field = 1;
+                ///
+                /// Implementations may choose not to generate the map_entry=true message, but
+                /// use a native map in the target language to hold the keys and values.
+                /// The reflection APIs in such implementations still need to work as
+                /// if the field is a repeated message field.
+                ///
+                /// NOTE: Do not set the option in .proto files. Always use the maps syntax
+                /// instead. The option should only be implicitly set by the proto compiler
+                /// parser.
+                MapEntry(bool),
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct MessageOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> MessageOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        MessageOptionsField::MessageSetWireFormat(_) => {
+                            Some("messageSetWireFormat")
+                        }
+                        MessageOptionsField::NoStandardDescriptorAccessor(_) => {
+                            Some("noStandardDescriptorAccessor")
+                        }
+                        MessageOptionsField::Deprecated(_) => Some("deprecated"),
+                        MessageOptionsField::MapEntry(_) => Some("mapEntry"),
+                        MessageOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> MessageOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for MessageOptionsFields<'a> {
+                type Schema = MessageOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for MessageOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    MessageOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "message_set_wire_format",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MessageOptionsField::MessageSetWireFormat(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "no_standard_descriptor_accessor",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MessageOptionsField::NoStandardDescriptorAccessor(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "deprecated",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MessageOptionsField::Deprecated(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            7u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "map_entry",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MessageOptionsField::MapEntry(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(MessageOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => {
+                                        return Some(Ok(MessageOptionsField::Extension(ext)));
+                                    }
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct MessageOptionsOwned {
+                /// Set true to use the old proto1 MessageSet wire format for extensions.
+                /// This is provided for backwards-compatibility with the MessageSet wire
+                /// format.  You should not use this for any other reason:  It's less
+                /// efficient, has fewer features, and is more complicated.
+                ///
+                /// The message must be defined exactly as follows:
+                /// message Foo {
+                /// option message_set_wire_format = true;
+                /// extensions 4 to max;
+                /// }
+                /// Note that the message cannot have any defined fields; MessageSets only
+                /// have extensions.
+                ///
+                /// All extensions of your type must be singular messages; e.g. they cannot
+                /// be int32s, enums, or repeated messages.
+                ///
+                /// Because this is an option, the above two restrictions are not enforced by
+                /// the protocol compiler.
+                pub message_set_wire_format: ::core::option::Option<bool>,
+                /// Disables the generation of the standard "descriptor()" accessor, which can
+                /// conflict with a field of the same name.  This is meant to make migration
+                /// from proto1 easier; new code should avoid fields named "descriptor".
+                pub no_standard_descriptor_accessor: ::core::option::Option<bool>,
+                /// Is this message deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the message, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating messages.
+                pub deprecated: ::core::option::Option<bool>,
+                /// Whether the message is an automatically generated map entry type for the
+                /// maps field.
+                ///
+                /// For maps fields:
+                /// map<KeyType, ValueType> map_field = 1;
+                /// The parsed descriptor looks like:
+                /// message MapFieldEntry {
+                /// option map_entry = true;
+                /// optional KeyType key = 1;
+                /// optional ValueType value = 2;
+                /// }
+                /// repeated MapFieldEntry map_field = 1;
+                ///
+                /// Implementations may choose not to generate the map_entry=true message, but
+                /// use a native map in the target language to hold the keys and values.
+                /// The reflection APIs in such implementations still need to work as
+                /// if the field is a repeated message field.
+                ///
+                /// NOTE: Do not set the option in .proto files. Always use the maps syntax
+                /// instead. The option should only be implicitly set by the proto compiler
+                /// parser.
+                pub map_entry: ::core::option::Option<bool>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl MessageOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(MessageOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: MessageOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: MessageOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            MessageOptionsField::MessageSetWireFormat(v) => {
+                                self.message_set_wire_format = Some(v);
+                            }
+                            MessageOptionsField::NoStandardDescriptorAccessor(v) => {
+                                self.no_standard_descriptor_accessor = Some(v);
+                            }
+                            MessageOptionsField::Deprecated(v) => {
+                                self.deprecated = Some(v);
+                            }
+                            MessageOptionsField::MapEntry(v) => {
+                                self.map_entry = Some(v);
+                            }
+                            MessageOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            MessageOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = MessageOptions::schema();
+                    schema
+                        .message_set_wire_format
+                        .write(buf, self.message_set_wire_format.as_ref());
+                    schema
+                        .no_standard_descriptor_accessor
+                        .write(buf, self.no_standard_descriptor_accessor.as_ref());
+                    schema.deprecated.write(buf, self.deprecated.as_ref());
+                    schema.map_entry.write(buf, self.map_entry.as_ref());
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct FieldOptions {
+                /// The ctype option instructs the C++ code generator to use a different
+                /// representation of the field than it normally would.  See the specific
+                /// options below.  This option is not yet implemented in the open source
+                /// release -- sorry, we'll try to include it in a future version!
+                pub ctype: tacky::Field<
+                    1,
+                    tacky::Optional<tacky::PbEnum<field_options::CType>>,
+                >,
+                /// The packed option can be enabled for repeated primitive fields to enable
+                /// a more efficient representation on the wire. Rather than repeatedly
+                /// writing the tag and type for each element, the entire array is encoded as
+                /// a single length-delimited blob. In proto3, only explicit setting it to
+                /// false will avoid using packed encoding.
+                pub packed: tacky::Field<2, tacky::Optional<tacky::Bool>>,
+                /// The jstype option determines the JavaScript type used for values of the
+                /// field.  The option is permitted only for 64 bit integral and fixed types
+                /// (int64, uint64, sint64, fixed64, sfixed64).  A field with jstype JS_STRING
+                /// is represented as JavaScript string, which avoids loss of precision that
+                /// can happen when a large value is converted to a floating point JavaScript.
+                /// Specifying JS_NUMBER for the jstype causes the generated JavaScript code to
+                /// use the JavaScript "number" type.  The behavior of the default option
+                /// JS_NORMAL is implementation dependent.
+                ///
+                /// This option is an enum to permit additional types to be added, e.g.
+                /// goog.math.Integer.
+                pub jstype: tacky::Field<
+                    6,
+                    tacky::Optional<tacky::PbEnum<field_options::JSType>>,
+                >,
+                /// Should this field be parsed lazily?  Lazy applies only to message-type
+                /// fields.  It means that when the outer message is initially parsed, the
+                /// inner message's contents will not be parsed but instead stored in encoded
+                /// form.  The inner message will actually be parsed when it is first accessed.
+                ///
+                /// This is only a hint.  Implementations are free to choose whether to use
+                /// eager or lazy parsing regardless of the value of this option.  However,
+                /// setting this option true suggests that the protocol author believes that
+                /// using lazy parsing on this field is worth the additional bookkeeping
+                /// overhead typically needed to implement it.
+                ///
+                /// This option does not affect the public interface of any generated code;
+                /// all method signatures remain the same.  Furthermore, thread-safety of the
+                /// interface is not affected by this option; const methods remain safe to
+                /// call from multiple threads concurrently, while non-const methods continue
+                /// to require exclusive access.
+                ///
+                ///
+                /// Note that implementations may choose not to check required fields within
+                /// a lazy sub-message.  That is, calling IsInitialized() on the outer message
+                /// may return true even if the inner message has missing required fields.
+                /// This is necessary because otherwise the inner message would have to be
+                /// parsed in order to perform the check, defeating the purpose of lazy
+                /// parsing.  An implementation which chooses not to check required fields
+                /// must be consistent about it.  That is, for any particular sub-message, the
+                /// implementation must either *always* check its required fields, or *never*
+                /// check its required fields, regardless of whether or not the message has
+                /// been parsed.
+                ///
+                /// As of 2021, lazy does no correctness checks on the byte stream during
+                /// parsing.  This may lead to crashes if and when an invalid byte stream is
+                /// finally parsed upon access.
+                ///
+                /// TODO(b/211906113):  Enable validation on lazy fields.
+                pub lazy: tacky::Field<5, tacky::Optional<tacky::Bool>>,
+                /// unverified_lazy does no correctness checks on the byte stream. This should
+                /// only be used where lazy with verification is prohibitive for performance
+                /// reasons.
+                pub unverified_lazy: tacky::Field<15, tacky::Optional<tacky::Bool>>,
+                /// Is this field deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for accessors, or it will be completely ignored; in the very least, this
+                /// is a formalization for deprecating fields.
+                pub deprecated: tacky::Field<3, tacky::Optional<tacky::Bool>>,
+                /// For Google-internal migration only. Do not use.
+                pub weak: tacky::Field<10, tacky::Optional<tacky::Bool>>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for FieldOptions {}
+            impl tacky::DecodeMessage for FieldOptions {
+                type Fields<'a> = FieldOptionsFields<'a>;
+            }
+            impl FieldOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> FieldOptionsFields<'_> {
+                    FieldOptionsFields::new(buf)
+                }
+            }
+            impl FieldOptions {
+                pub const DEFAULT_CTYPE: field_options::CType = field_options::CType::String;
+                pub fn ctype_or_default(
+                    value: ::core::option::Option<field_options::CType>,
+                ) -> field_options::CType {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_CTYPE,
+                    }
+                }
+                pub const DEFAULT_JSTYPE: field_options::JSType = field_options::JSType::JsNormal;
+                pub fn jstype_or_default(
+                    value: ::core::option::Option<field_options::JSType>,
+                ) -> field_options::JSType {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_JSTYPE,
+                    }
+                }
+                pub const DEFAULT_LAZY: bool = false;
+                pub fn lazy_or_default(value: ::core::option::Option<bool>) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_LAZY,
+                    }
+                }
+                pub const DEFAULT_UNVERIFIED_LAZY: bool = false;
+                pub fn unverified_lazy_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_UNVERIFIED_LAZY,
+                    }
+                }
+                pub const DEFAULT_DEPRECATED: bool = false;
+                pub fn deprecated_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_DEPRECATED,
+                    }
+                }
+                pub const DEFAULT_WEAK: bool = false;
+                pub fn weak_or_default(value: ::core::option::Option<bool>) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_WEAK,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum FieldOptionsField<'a> {
+                /// The ctype option instructs the C++ code generator to use a different
+                /// representation of the field than it normally would.  See the specific
+                /// options below.  This option is not yet implemented in the open source
+                /// release -- sorry, we'll try to include it in a future version!
+                Ctype(field_options::CType),
+                /// The packed option can be enabled for repeated primitive fields to enable
+                /// a more efficient representation on the wire. Rather than repeatedly
+                /// writing the tag and type for each element, the entire array is encoded as
+                /// a single length-delimited blob. In proto3, only explicit setting it to
+                /// false will avoid using packed encoding.
+                Packed(bool),
+                /// The jstype option determines the JavaScript type used for values of the
+                /// field.  The option is permitted only for 64 bit integral and fixed types
+                /// (int64, uint64, sint64, fixed64, sfixed64).  A field with jstype JS_STRING
+                /// is represented as JavaScript string, which avoids loss of precision that
+                /// can happen when a large value is converted to a floating point JavaScript.
+                /// Specifying JS_NUMBER for the jstype causes the generated JavaScript code to
+                /// use the JavaScript "number" type.  The behavior of the default option
+                /// JS_NORMAL is implementation dependent.
+                ///
+                /// This option is an enum to permit additional types to be added, e.g.
+                /// goog.math.Integer.
+                Jstype(field_options::JSType),
+                /// Should this field be parsed lazily?  Lazy applies only to message-type
+                /// fields.  It means that when the outer message is initially parsed, the
+                /// inner message's contents will not be parsed but instead stored in encoded
+                /// form.  The inner message will actually be parsed when it is first accessed.
+                ///
+                /// This is only a hint.  Implementations are free to choose whether to use
+                /// eager or lazy parsing regardless of the value of this option.  However,
+                /// setting this option true suggests that the protocol author believes that
+                /// using lazy parsing on this field is worth the additional bookkeeping
+                /// overhead typically needed to implement it.
+                ///
+                /// This option does not affect the public interface of any generated code;
+                /// all method signatures remain the same.  Furthermore, thread-safety of the
+                /// interface is not affected by this option; const methods remain safe to
+                /// call from multiple threads concurrently, while non-const methods continue
+                /// to require exclusive access.
+                ///
+                ///
+                /// Note that implementations may choose not to check required fields within
+                /// a lazy sub-message.  That is, calling IsInitialized() on the outer message
+                /// may return true even if the inner message has missing required fields.
+                /// This is necessary because otherwise the inner message would have to be
+                /// parsed in order to perform the check, defeating the purpose of lazy
+                /// parsing.  An implementation which chooses not to check required fields
+                /// must be consistent about it.  That is, for any particular sub-message, the
+                /// implementation must either *always* check its required fields, or *never*
+                /// check its required fields, regardless of whether or not the message has
+                /// been parsed.
+                ///
+                /// As of 2021, lazy does no correctness checks on the byte stream during
+                /// parsing.  This may lead to crashes if and when an invalid byte stream is
+                /// finally parsed upon access.
+                ///
+                /// TODO(b/211906113):  Enable validation on lazy fields.
+                Lazy(bool),
+                /// unverified_lazy does no correctness checks on the byte stream. This should
+                /// only be used where lazy with verification is prohibitive for performance
+                /// reasons.
+                UnverifiedLazy(bool),
+                /// Is this field deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for accessors, or it will be completely ignored; in the very least, this
+                /// is a formalization for deprecating fields.
+                Deprecated(bool),
+                /// For Google-internal migration only. Do not use.
+                Weak(bool),
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct FieldOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> FieldOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        FieldOptionsField::Ctype(_) => Some("ctype"),
+                        FieldOptionsField::Packed(_) => Some("packed"),
+                        FieldOptionsField::Jstype(_) => Some("jstype"),
+                        FieldOptionsField::Lazy(_) => Some("lazy"),
+                        FieldOptionsField::UnverifiedLazy(_) => Some("unverifiedLazy"),
+                        FieldOptionsField::Deprecated(_) => Some("deprecated"),
+                        FieldOptionsField::Weak(_) => Some("weak"),
+                        FieldOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> FieldOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for FieldOptionsFields<'a> {
+                type Schema = FieldOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for FieldOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    FieldOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                match (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "ctype",
+                                    )?;
+                                    let raw = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    let Some(val) = <field_options::CType as tacky::PbEnumType>::from_wire(
+                                        raw,
+                                    ) else {
+                                        return Ok(None);
+                                    };
+                                    Ok(Some(FieldOptionsField::Ctype(val)))
+                                })() {
+                                    Ok(Some(field)) => return Some(Ok(field)),
+                                    Ok(None) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "packed",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldOptionsField::Packed(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            6u32 => {
+                                match (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "jstype",
+                                    )?;
+                                    let raw = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    let Some(val) = <field_options::JSType as tacky::PbEnumType>::from_wire(
+                                        raw,
+                                    ) else {
+                                        return Ok(None);
+                                    };
+                                    Ok(Some(FieldOptionsField::Jstype(val)))
+                                })() {
+                                    Ok(Some(field)) => return Some(Ok(field)),
+                                    Ok(None) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            5u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "lazy",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldOptionsField::Lazy(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            15u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "unverified_lazy",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldOptionsField::UnverifiedLazy(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "deprecated",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldOptionsField::Deprecated(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            10u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "weak",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(FieldOptionsField::Weak(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(FieldOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => {
+                                        return Some(Ok(FieldOptionsField::Extension(ext)));
+                                    }
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct FieldOptionsOwned {
+                /// The ctype option instructs the C++ code generator to use a different
+                /// representation of the field than it normally would.  See the specific
+                /// options below.  This option is not yet implemented in the open source
+                /// release -- sorry, we'll try to include it in a future version!
+                pub ctype: ::core::option::Option<field_options::CType>,
+                /// The packed option can be enabled for repeated primitive fields to enable
+                /// a more efficient representation on the wire. Rather than repeatedly
+                /// writing the tag and type for each element, the entire array is encoded as
+                /// a single length-delimited blob. In proto3, only explicit setting it to
+                /// false will avoid using packed encoding.
+                pub packed: ::core::option::Option<bool>,
+                /// The jstype option determines the JavaScript type used for values of the
+                /// field.  The option is permitted only for 64 bit integral and fixed types
+                /// (int64, uint64, sint64, fixed64, sfixed64).  A field with jstype JS_STRING
+                /// is represented as JavaScript string, which avoids loss of precision that
+                /// can happen when a large value is converted to a floating point JavaScript.
+                /// Specifying JS_NUMBER for the jstype causes the generated JavaScript code to
+                /// use the JavaScript "number" type.  The behavior of the default option
+                /// JS_NORMAL is implementation dependent.
+                ///
+                /// This option is an enum to permit additional types to be added, e.g.
+                /// goog.math.Integer.
+                pub jstype: ::core::option::Option<field_options::JSType>,
+                /// Should this field be parsed lazily?  Lazy applies only to message-type
+                /// fields.  It means that when the outer message is initially parsed, the
+                /// inner message's contents will not be parsed but instead stored in encoded
+                /// form.  The inner message will actually be parsed when it is first accessed.
+                ///
+                /// This is only a hint.  Implementations are free to choose whether to use
+                /// eager or lazy parsing regardless of the value of this option.  However,
+                /// setting this option true suggests that the protocol author believes that
+                /// using lazy parsing on this field is worth the additional bookkeeping
+                /// overhead typically needed to implement it.
+                ///
+                /// This option does not affect the public interface of any generated code;
+                /// all method signatures remain the same.  Furthermore, thread-safety of the
+                /// interface is not affected by this option; const methods remain safe to
+                /// call from multiple threads concurrently, while non-const methods continue
+                /// to require exclusive access.
+                ///
+                ///
+                /// Note that implementations may choose not to check required fields within
+                /// a lazy sub-message.  That is, calling IsInitialized() on the outer message
+                /// may return true even if the inner message has missing required fields.
+                /// This is necessary because otherwise the inner message would have to be
+                /// parsed in order to perform the check, defeating the purpose of lazy
+                /// parsing.  An implementation which chooses not to check required fields
+                /// must be consistent about it.  That is, for any particular sub-message, the
+                /// implementation must either *always* check its required fields, or *never*
+                /// check its required fields, regardless of whether or not the message has
+                /// been parsed.
+                ///
+                /// As of 2021, lazy does no correctness checks on the byte stream during
+                /// parsing.  This may lead to crashes if and when an invalid byte stream is
+                /// finally parsed upon access.
+                ///
+                /// TODO(b/211906113):  Enable validation on lazy fields.
+                pub lazy: ::core::option::Option<bool>,
+                /// unverified_lazy does no correctness checks on the byte stream. This should
+                /// only be used where lazy with verification is prohibitive for performance
+                /// reasons.
+                pub unverified_lazy: ::core::option::Option<bool>,
+                /// Is this field deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for accessors, or it will be completely ignored; in the very least, this
+                /// is a formalization for deprecating fields.
+                pub deprecated: ::core::option::Option<bool>,
+                /// For Google-internal migration only. Do not use.
+                pub weak: ::core::option::Option<bool>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl FieldOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(FieldOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: FieldOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: FieldOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            FieldOptionsField::Ctype(v) => {
+                                self.ctype = Some(v);
+                            }
+                            FieldOptionsField::Packed(v) => {
+                                self.packed = Some(v);
+                            }
+                            FieldOptionsField::Jstype(v) => {
+                                self.jstype = Some(v);
+                            }
+                            FieldOptionsField::Lazy(v) => {
+                                self.lazy = Some(v);
+                            }
+                            FieldOptionsField::UnverifiedLazy(v) => {
+                                self.unverified_lazy = Some(v);
+                            }
+                            FieldOptionsField::Deprecated(v) => {
+                                self.deprecated = Some(v);
+                            }
+                            FieldOptionsField::Weak(v) => {
+                                self.weak = Some(v);
+                            }
+                            FieldOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            FieldOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = FieldOptions::schema();
+                    schema.ctype.write(buf, self.ctype.as_ref());
+                    schema.packed.write(buf, self.packed.as_ref());
+                    schema.jstype.write(buf, self.jstype.as_ref());
+                    schema.lazy.write(buf, self.lazy.as_ref());
+                    schema.unverified_lazy.write(buf, self.unverified_lazy.as_ref());
+                    schema.deprecated.write(buf, self.deprecated.as_ref());
+                    schema.weak.write(buf, self.weak.as_ref());
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct OneofOptions {
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for OneofOptions {}
+            impl tacky::DecodeMessage for OneofOptions {
+                type Fields<'a> = OneofOptionsFields<'a>;
+            }
+            impl OneofOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> OneofOptionsFields<'_> {
+                    OneofOptionsFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum OneofOptionsField<'a> {
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct OneofOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> OneofOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        OneofOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> OneofOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for OneofOptionsFields<'a> {
+                type Schema = OneofOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for OneofOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    OneofOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(OneofOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => {
+                                        return Some(Ok(OneofOptionsField::Extension(ext)));
+                                    }
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct OneofOptionsOwned {
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl OneofOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(OneofOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: OneofOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: OneofOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            OneofOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            OneofOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = OneofOptions::schema();
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct EnumOptions {
+                /// Set this option to true to allow mapping different tag names to the same
+                /// value.
+                pub allow_alias: tacky::Field<2, tacky::Optional<tacky::Bool>>,
+                /// Is this enum deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the enum, or it will be completely ignored; in the very least, this
+                /// is a formalization for deprecating enums.
+                pub deprecated: tacky::Field<3, tacky::Optional<tacky::Bool>>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for EnumOptions {}
+            impl tacky::DecodeMessage for EnumOptions {
+                type Fields<'a> = EnumOptionsFields<'a>;
+            }
+            impl EnumOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> EnumOptionsFields<'_> {
+                    EnumOptionsFields::new(buf)
+                }
+            }
+            impl EnumOptions {
+                pub const DEFAULT_DEPRECATED: bool = false;
+                pub fn deprecated_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_DEPRECATED,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum EnumOptionsField<'a> {
+                /// Set this option to true to allow mapping different tag names to the same
+                /// value.
+                AllowAlias(bool),
+                /// Is this enum deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the enum, or it will be completely ignored; in the very least, this
+                /// is a formalization for deprecating enums.
+                Deprecated(bool),
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct EnumOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> EnumOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        EnumOptionsField::AllowAlias(_) => Some("allowAlias"),
+                        EnumOptionsField::Deprecated(_) => Some("deprecated"),
+                        EnumOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> EnumOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for EnumOptionsFields<'a> {
+                type Schema = EnumOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for EnumOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    EnumOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "allow_alias",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(EnumOptionsField::AllowAlias(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "deprecated",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(EnumOptionsField::Deprecated(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(EnumOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => return Some(Ok(EnumOptionsField::Extension(ext))),
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct EnumOptionsOwned {
+                /// Set this option to true to allow mapping different tag names to the same
+                /// value.
+                pub allow_alias: ::core::option::Option<bool>,
+                /// Is this enum deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the enum, or it will be completely ignored; in the very least, this
+                /// is a formalization for deprecating enums.
+                pub deprecated: ::core::option::Option<bool>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl EnumOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(EnumOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: EnumOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: EnumOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            EnumOptionsField::AllowAlias(v) => {
+                                self.allow_alias = Some(v);
+                            }
+                            EnumOptionsField::Deprecated(v) => {
+                                self.deprecated = Some(v);
+                            }
+                            EnumOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            EnumOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = EnumOptions::schema();
+                    schema.allow_alias.write(buf, self.allow_alias.as_ref());
+                    schema.deprecated.write(buf, self.deprecated.as_ref());
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct EnumValueOptions {
+                /// Is this enum value deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the enum value, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating enum values.
+                pub deprecated: tacky::Field<1, tacky::Optional<tacky::Bool>>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for EnumValueOptions {}
+            impl tacky::DecodeMessage for EnumValueOptions {
+                type Fields<'a> = EnumValueOptionsFields<'a>;
+            }
+            impl EnumValueOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> EnumValueOptionsFields<'_> {
+                    EnumValueOptionsFields::new(buf)
+                }
+            }
+            impl EnumValueOptions {
+                pub const DEFAULT_DEPRECATED: bool = false;
+                pub fn deprecated_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_DEPRECATED,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum EnumValueOptionsField<'a> {
+                /// Is this enum value deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the enum value, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating enum values.
+                Deprecated(bool),
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct EnumValueOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> EnumValueOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        EnumValueOptionsField::Deprecated(_) => Some("deprecated"),
+                        EnumValueOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> EnumValueOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for EnumValueOptionsFields<'a> {
+                type Schema = EnumValueOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for EnumValueOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    EnumValueOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "deprecated",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(EnumValueOptionsField::Deprecated(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(EnumValueOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => {
+                                        return Some(Ok(EnumValueOptionsField::Extension(ext)));
+                                    }
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct EnumValueOptionsOwned {
+                /// Is this enum value deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the enum value, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating enum values.
+                pub deprecated: ::core::option::Option<bool>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl EnumValueOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(EnumValueOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: EnumValueOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: EnumValueOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            EnumValueOptionsField::Deprecated(v) => {
+                                self.deprecated = Some(v);
+                            }
+                            EnumValueOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            EnumValueOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = EnumValueOptions::schema();
+                    schema.deprecated.write(buf, self.deprecated.as_ref());
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct ServiceOptions {
+                /// Is this service deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the service, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating services.
+                pub deprecated: tacky::Field<33, tacky::Optional<tacky::Bool>>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for ServiceOptions {}
+            impl tacky::DecodeMessage for ServiceOptions {
+                type Fields<'a> = ServiceOptionsFields<'a>;
+            }
+            impl ServiceOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> ServiceOptionsFields<'_> {
+                    ServiceOptionsFields::new(buf)
+                }
+            }
+            impl ServiceOptions {
+                pub const DEFAULT_DEPRECATED: bool = false;
+                pub fn deprecated_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_DEPRECATED,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum ServiceOptionsField<'a> {
+                /// Is this service deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the service, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating services.
+                Deprecated(bool),
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct ServiceOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> ServiceOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        ServiceOptionsField::Deprecated(_) => Some("deprecated"),
+                        ServiceOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> ServiceOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for ServiceOptionsFields<'a> {
+                type Schema = ServiceOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for ServiceOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    ServiceOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            33u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "deprecated",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(ServiceOptionsField::Deprecated(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(ServiceOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => {
+                                        return Some(Ok(ServiceOptionsField::Extension(ext)));
+                                    }
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct ServiceOptionsOwned {
+                /// Is this service deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the service, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating services.
+                pub deprecated: ::core::option::Option<bool>,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl ServiceOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(ServiceOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: ServiceOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: ServiceOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            ServiceOptionsField::Deprecated(v) => {
+                                self.deprecated = Some(v);
+                            }
+                            ServiceOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            ServiceOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = ServiceOptions::schema();
+                    schema.deprecated.write(buf, self.deprecated.as_ref());
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            #[derive(Debug, Copy, Clone)]
+            pub struct MethodOptions {
+                /// Is this method deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the method, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating methods.
+                pub deprecated: tacky::Field<33, tacky::Optional<tacky::Bool>>,
+                pub idempotency_level: tacky::Field<
+                    34,
+                    tacky::Optional<tacky::PbEnum<method_options::IdempotencyLevel>>,
+                >,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: tacky::Field<
+                    999,
+                    tacky::Repeated<UninterpretedOption>,
+                >,
+            }
+            impl tacky::MessageSchema for MethodOptions {}
+            impl tacky::DecodeMessage for MethodOptions {
+                type Fields<'a> = MethodOptionsFields<'a>;
+            }
+            impl MethodOptions {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> MethodOptionsFields<'_> {
+                    MethodOptionsFields::new(buf)
+                }
+            }
+            impl MethodOptions {
+                pub const DEFAULT_DEPRECATED: bool = false;
+                pub fn deprecated_or_default(
+                    value: ::core::option::Option<bool>,
+                ) -> bool {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_DEPRECATED,
+                    }
+                }
+                pub const DEFAULT_IDEMPOTENCY_LEVEL: method_options::IdempotencyLevel = method_options::IdempotencyLevel::IdempotencyUnknown;
+                pub fn idempotency_level_or_default(
+                    value: ::core::option::Option<method_options::IdempotencyLevel>,
+                ) -> method_options::IdempotencyLevel {
+                    match value {
+                        Some(v) => v,
+                        None => Self::DEFAULT_IDEMPOTENCY_LEVEL,
+                    }
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum MethodOptionsField<'a> {
+                /// Is this method deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the method, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating methods.
+                Deprecated(bool),
+                IdempotencyLevel(method_options::IdempotencyLevel),
+                /// The parser stores options it doesn't recognize here. See above.
+                UninterpretedOption(UninterpretedOptionFields<'a>),
+                Extension(tacky::ExtensionField<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct MethodOptionsFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> MethodOptionsField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        MethodOptionsField::Deprecated(_) => Some("deprecated"),
+                        MethodOptionsField::IdempotencyLevel(_) => {
+                            Some("idempotencyLevel")
+                        }
+                        MethodOptionsField::UninterpretedOption(_) => {
+                            Some("uninterpretedOption")
+                        }
+                        _ => None,
+                    }
+                }
+            }
+            impl<'a> MethodOptionsFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for MethodOptionsFields<'a> {
+                type Schema = MethodOptions;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for MethodOptionsFields<'a> {
+                type Item = ::core::result::Result<
+                    MethodOptionsField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            33u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "deprecated",
+                                    )?;
+                                    let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(MethodOptionsField::Deprecated(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            34u32 => {
+                                match (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "idempotency_level",
+                                    )?;
+                                    let raw = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    let Some(val) = <method_options::IdempotencyLevel as tacky::PbEnumType>::from_wire(
+                                        raw,
+                                    ) else {
+                                        return Ok(None);
+                                    };
+                                    Ok(Some(MethodOptionsField::IdempotencyLevel(val)))
+                                })() {
+                                    Ok(Some(field)) => return Some(Ok(field)),
+                                    Ok(None) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            999u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "uninterpreted_option",
+                                    )?;
+                                    let data = UninterpretedOption::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(MethodOptionsField::UninterpretedOption(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            1000u32..=536870911u32 => {
+                                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                                    Ok(ext) => {
+                                        return Some(Ok(MethodOptionsField::Extension(ext)));
+                                    }
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct MethodOptionsOwned {
+                /// Is this method deprecated?
+                /// Depending on the target platform, this can emit Deprecated annotations
+                /// for the method, or it will be completely ignored; in the very least,
+                /// this is a formalization for deprecating methods.
+                pub deprecated: ::core::option::Option<bool>,
+                pub idempotency_level: ::core::option::Option<
+                    method_options::IdempotencyLevel,
+                >,
+                /// The parser stores options it doesn't recognize here. See above.
+                pub uninterpreted_option: ::std::vec::Vec<UninterpretedOptionOwned>,
+            }
+            #[allow(deprecated)]
+            impl MethodOptionsOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(MethodOptions::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: MethodOptionsFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: MethodOptionsFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            MethodOptionsField::Deprecated(v) => {
+                                self.deprecated = Some(v);
+                            }
+                            MethodOptionsField::IdempotencyLevel(v) => {
+                                self.idempotency_level = Some(v);
+                            }
+                            MethodOptionsField::UninterpretedOption(v) => {
+                                self.uninterpreted_option
+                                    .push(UninterpretedOptionOwned::from_fields(v)?);
+                            }
+                            MethodOptionsField::Extension(_) => {}
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = MethodOptions::schema();
+                    schema.deprecated.write(buf, self.deprecated.as_ref());
+                    schema.idempotency_level.write(buf, self.idempotency_level.as_ref());
+                    schema
+                        .uninterpreted_option
+                        .write_msgs(
+                            buf,
+                            &self.uninterpreted_option,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            /// A message representing a option the parser does not recognize. This only
+            /// appears in options protos created by the compiler::Parser class.
+            /// DescriptorPool resolves these when building Descriptor objects. Therefore,
+            /// options protos in descriptor objects (e.g. returned by Descriptor::options(),
+            /// or produced by Descriptor::CopyTo()) will never have UninterpretedOptions
+            /// in them.
+            #[derive(Debug, Copy, Clone)]
+            pub struct UninterpretedOption {
+                pub name: tacky::Field<
+                    2,
+                    tacky::Repeated<uninterpreted_option::NamePart>,
+                >,
+                /// The value of the uninterpreted option, in whatever type the tokenizer
+                /// identified it as during parsing. Exactly one of these should be set.
+                pub identifier_value: tacky::Field<3, tacky::Optional<tacky::PbString>>,
+                pub positive_int_value: tacky::Field<4, tacky::Optional<tacky::Uint64>>,
+                pub negative_int_value: tacky::Field<5, tacky::Optional<tacky::Int64>>,
+                pub double_value: tacky::Field<6, tacky::Optional<tacky::Double>>,
+                pub string_value: tacky::Field<7, tacky::Optional<tacky::PbBytes>>,
+                pub aggregate_value: tacky::Field<8, tacky::Optional<tacky::PbString>>,
+            }
+            impl tacky::MessageSchema for UninterpretedOption {}
+            impl tacky::DecodeMessage for UninterpretedOption {
+                type Fields<'a> = UninterpretedOptionFields<'a>;
+            }
+            impl UninterpretedOption {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> UninterpretedOptionFields<'_> {
+                    UninterpretedOptionFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum UninterpretedOptionField<'a> {
+                Name(uninterpreted_option::NamePartFields<'a>),
+                /// The value of the uninterpreted option, in whatever type the tokenizer
+                /// identified it as during parsing. Exactly one of these should be set.
+                IdentifierValue(&'a str),
+                PositiveIntValue(u64),
+                NegativeIntValue(i64),
+                DoubleValue(f64),
+                StringValue(&'a [u8]),
+                AggregateValue(&'a str),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct UninterpretedOptionFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> UninterpretedOptionField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        UninterpretedOptionField::Name(_) => Some("name"),
+                        UninterpretedOptionField::IdentifierValue(_) => {
+                            Some("identifierValue")
+                        }
+                        UninterpretedOptionField::PositiveIntValue(_) => {
+                            Some("positiveIntValue")
+                        }
+                        UninterpretedOptionField::NegativeIntValue(_) => {
+                            Some("negativeIntValue")
+                        }
+                        UninterpretedOptionField::DoubleValue(_) => Some("doubleValue"),
+                        UninterpretedOptionField::StringValue(_) => Some("stringValue"),
+                        UninterpretedOptionField::AggregateValue(_) => {
+                            Some("aggregateValue")
+                        }
+                    }
+                }
+            }
+            impl<'a> UninterpretedOptionFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for UninterpretedOptionFields<'a> {
+                type Schema = UninterpretedOption;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for UninterpretedOptionFields<'a> {
+                type Item = ::core::result::Result<
+                    UninterpretedOptionField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            2u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "name",
+                                    )?;
+                                    let data = uninterpreted_option::NamePart::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(UninterpretedOptionField::Name(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            3u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "identifier_value",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(UninterpretedOptionField::IdentifierValue(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            4u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "positive_int_value",
+                                    )?;
+                                    let val = <tacky::Uint64 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(UninterpretedOptionField::PositiveIntValue(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            5u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::VARINT,
+                                        "negative_int_value",
+                                    )?;
+                                    let val = <tacky::Int64 as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(UninterpretedOptionField::NegativeIntValue(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            6u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::I64,
+                                        "double_value",
+                                    )?;
+                                    let val = <tacky::Double as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(UninterpretedOptionField::DoubleValue(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            7u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "string_value",
+                                    )?;
+                                    let val = <tacky::PbBytes as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(UninterpretedOptionField::StringValue(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            8u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "aggregate_value",
+                                    )?;
+                                    let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                        buf,
+                                    )?;
+                                    Ok(UninterpretedOptionField::AggregateValue(val))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct UninterpretedOptionOwned {
+                pub name: ::std::vec::Vec<uninterpreted_option::NamePartOwned>,
+                /// The value of the uninterpreted option, in whatever type the tokenizer
+                /// identified it as during parsing. Exactly one of these should be set.
+                pub identifier_value: ::core::option::Option<::std::string::String>,
+                pub positive_int_value: ::core::option::Option<u64>,
+                pub negative_int_value: ::core::option::Option<i64>,
+                pub double_value: ::core::option::Option<f64>,
+                pub string_value: ::core::option::Option<::std::vec::Vec<u8>>,
+                pub aggregate_value: ::core::option::Option<::std::string::String>,
+            }
+            #[allow(deprecated)]
+            impl UninterpretedOptionOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(UninterpretedOption::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: UninterpretedOptionFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: UninterpretedOptionFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            UninterpretedOptionField::Name(v) => {
+                                self.name
+                                    .push(uninterpreted_option::NamePartOwned::from_fields(v)?);
+                            }
+                            UninterpretedOptionField::IdentifierValue(v) => {
+                                self.identifier_value = Some(v.to_owned());
+                            }
+                            UninterpretedOptionField::PositiveIntValue(v) => {
+                                self.positive_int_value = Some(v);
+                            }
+                            UninterpretedOptionField::NegativeIntValue(v) => {
+                                self.negative_int_value = Some(v);
+                            }
+                            UninterpretedOptionField::DoubleValue(v) => {
+                                self.double_value = Some(v);
+                            }
+                            UninterpretedOptionField::StringValue(v) => {
+                                self.string_value = Some(v.to_vec());
+                            }
+                            UninterpretedOptionField::AggregateValue(v) => {
+                                self.aggregate_value = Some(v.to_owned());
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = UninterpretedOption::schema();
+                    schema
+                        .name
+                        .write_msgs(buf, &self.name, |buf, _, m| m.encode_dir(buf));
+                    schema.identifier_value.write(buf, self.identifier_value.as_ref());
+                    schema
+                        .positive_int_value
+                        .write(buf, self.positive_int_value.as_ref());
+                    schema
+                        .negative_int_value
+                        .write(buf, self.negative_int_value.as_ref());
+                    schema.double_value.write(buf, self.double_value.as_ref());
+                    schema.string_value.write(buf, self.string_value.as_ref());
+                    schema.aggregate_value.write(buf, self.aggregate_value.as_ref());
+                }
+            }
+            /// Encapsulates information about the original source file from which a
+            /// FileDescriptorProto was generated.
+            #[derive(Debug, Copy, Clone)]
+            pub struct SourceCodeInfo {
+                /// A Location identifies a piece of source code in a .proto file which
+                /// corresponds to a particular definition.  This information is intended
+                /// to be useful to IDEs, code indexers, documentation generators, and similar
+                /// tools.
+                ///
+                /// For example, say we have a file like:
+                /// message Foo {
+                /// optional string foo = 1;
+                /// }
+                /// Let's look at just the field definition:
+                /// optional string foo = 1;
+                /// ^       ^^     ^^  ^  ^^^
+                /// a       bc     de  f  ghi
+                /// We have the following locations:
+                /// span   path               represents
+                /// \[a,i)  \[ 4, 0, 2, 0 \]     The whole field definition.
+                /// \[a,b)  \[ 4, 0, 2, 0, 4 \]  The label (optional).
+                /// \[c,d)  \[ 4, 0, 2, 0, 5 \]  The type (string).
+                /// \[e,f)  \[ 4, 0, 2, 0, 1 \]  The name (foo).
+                /// \[g,h)  \[ 4, 0, 2, 0, 3 \]  The number (1).
+                ///
+                /// Notes:
+                /// - A location may refer to a repeated field itself (i.e. not to any
+                /// particular index within it).  This is used whenever a set of elements are
+                /// logically enclosed in a single code segment.  For example, an entire
+                /// extend block (possibly containing multiple extension definitions) will
+                /// have an outer location whose path refers to the "extensions" repeated
+                /// field without an index.
+                /// - Multiple locations may have the same path.  This happens when a single
+                /// logical declaration is spread out across multiple places.  The most
+                /// obvious example is the "extend" block again -- there may be multiple
+                /// extend blocks in the same scope, each of which will have the same path.
+                /// - A location's span is not always a subset of its parent's span.  For
+                /// example, the "extendee" of an extension declaration appears at the
+                /// beginning of the "extend" block and is shared by all extensions within
+                /// the block.
+                /// - Just because a location's span is a subset of some other location's span
+                /// does not mean that it is a descendant.  For example, a "group" defines
+                /// both a type and a field in a single declaration.  Thus, the locations
+                /// corresponding to the type and field and their components will overlap.
+                /// - Code which tries to interpret locations should probably be designed to
+                /// ignore those that it doesn't understand, as more types of locations could
+                /// be recorded in the future.
+                pub location: tacky::Field<
+                    1,
+                    tacky::Repeated<source_code_info::Location>,
+                >,
+            }
+            impl tacky::MessageSchema for SourceCodeInfo {}
+            impl tacky::DecodeMessage for SourceCodeInfo {
+                type Fields<'a> = SourceCodeInfoFields<'a>;
+            }
+            impl SourceCodeInfo {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> SourceCodeInfoFields<'_> {
+                    SourceCodeInfoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum SourceCodeInfoField<'a> {
+                /// A Location identifies a piece of source code in a .proto file which
+                /// corresponds to a particular definition.  This information is intended
+                /// to be useful to IDEs, code indexers, documentation generators, and similar
+                /// tools.
+                ///
+                /// For example, say we have a file like:
+                /// message Foo {
+                /// optional string foo = 1;
+                /// }
+                /// Let's look at just the field definition:
+                /// optional string foo = 1;
+                /// ^       ^^     ^^  ^  ^^^
+                /// a       bc     de  f  ghi
+                /// We have the following locations:
+                /// span   path               represents
+                /// \[a,i)  \[ 4, 0, 2, 0 \]     The whole field definition.
+                /// \[a,b)  \[ 4, 0, 2, 0, 4 \]  The label (optional).
+                /// \[c,d)  \[ 4, 0, 2, 0, 5 \]  The type (string).
+                /// \[e,f)  \[ 4, 0, 2, 0, 1 \]  The name (foo).
+                /// \[g,h)  \[ 4, 0, 2, 0, 3 \]  The number (1).
+                ///
+                /// Notes:
+                /// - A location may refer to a repeated field itself (i.e. not to any
+                /// particular index within it).  This is used whenever a set of elements are
+                /// logically enclosed in a single code segment.  For example, an entire
+                /// extend block (possibly containing multiple extension definitions) will
+                /// have an outer location whose path refers to the "extensions" repeated
+                /// field without an index.
+                /// - Multiple locations may have the same path.  This happens when a single
+                /// logical declaration is spread out across multiple places.  The most
+                /// obvious example is the "extend" block again -- there may be multiple
+                /// extend blocks in the same scope, each of which will have the same path.
+                /// - A location's span is not always a subset of its parent's span.  For
+                /// example, the "extendee" of an extension declaration appears at the
+                /// beginning of the "extend" block and is shared by all extensions within
+                /// the block.
+                /// - Just because a location's span is a subset of some other location's span
+                /// does not mean that it is a descendant.  For example, a "group" defines
+                /// both a type and a field in a single declaration.  Thus, the locations
+                /// corresponding to the type and field and their components will overlap.
+                /// - Code which tries to interpret locations should probably be designed to
+                /// ignore those that it doesn't understand, as more types of locations could
+                /// be recorded in the future.
+                Location(source_code_info::LocationFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct SourceCodeInfoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> SourceCodeInfoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        SourceCodeInfoField::Location(_) => Some("location"),
+                    }
+                }
+            }
+            impl<'a> SourceCodeInfoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for SourceCodeInfoFields<'a> {
+                type Schema = SourceCodeInfo;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for SourceCodeInfoFields<'a> {
+                type Item = ::core::result::Result<
+                    SourceCodeInfoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "location",
+                                    )?;
+                                    let data = source_code_info::Location::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(SourceCodeInfoField::Location(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct SourceCodeInfoOwned {
+                /// A Location identifies a piece of source code in a .proto file which
+                /// corresponds to a particular definition.  This information is intended
+                /// to be useful to IDEs, code indexers, documentation generators, and similar
+                /// tools.
+                ///
+                /// For example, say we have a file like:
+                /// message Foo {
+                /// optional string foo = 1;
+                /// }
+                /// Let's look at just the field definition:
+                /// optional string foo = 1;
+                /// ^       ^^     ^^  ^  ^^^
+                /// a       bc     de  f  ghi
+                /// We have the following locations:
+                /// span   path               represents
+                /// \[a,i)  \[ 4, 0, 2, 0 \]     The whole field definition.
+                /// \[a,b)  \[ 4, 0, 2, 0, 4 \]  The label (optional).
+                /// \[c,d)  \[ 4, 0, 2, 0, 5 \]  The type (string).
+                /// \[e,f)  \[ 4, 0, 2, 0, 1 \]  The name (foo).
+                /// \[g,h)  \[ 4, 0, 2, 0, 3 \]  The number (1).
+                ///
+                /// Notes:
+                /// - A location may refer to a repeated field itself (i.e. not to any
+                /// particular index within it).  This is used whenever a set of elements are
+                /// logically enclosed in a single code segment.  For example, an entire
+                /// extend block (possibly containing multiple extension definitions) will
+                /// have an outer location whose path refers to the "extensions" repeated
+                /// field without an index.
+                /// - Multiple locations may have the same path.  This happens when a single
+                /// logical declaration is spread out across multiple places.  The most
+                /// obvious example is the "extend" block again -- there may be multiple
+                /// extend blocks in the same scope, each of which will have the same path.
+                /// - A location's span is not always a subset of its parent's span.  For
+                /// example, the "extendee" of an extension declaration appears at the
+                /// beginning of the "extend" block and is shared by all extensions within
+                /// the block.
+                /// - Just because a location's span is a subset of some other location's span
+                /// does not mean that it is a descendant.  For example, a "group" defines
+                /// both a type and a field in a single declaration.  Thus, the locations
+                /// corresponding to the type and field and their components will overlap.
+                /// - Code which tries to interpret locations should probably be designed to
+                /// ignore those that it doesn't understand, as more types of locations could
+                /// be recorded in the future.
+                pub location: ::std::vec::Vec<source_code_info::LocationOwned>,
+            }
+            #[allow(deprecated)]
+            impl SourceCodeInfoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(SourceCodeInfo::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: SourceCodeInfoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: SourceCodeInfoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            SourceCodeInfoField::Location(v) => {
+                                self.location
+                                    .push(source_code_info::LocationOwned::from_fields(v)?);
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = SourceCodeInfo::schema();
+                    schema
+                        .location
+                        .write_msgs(buf, &self.location, |buf, _, m| m.encode_dir(buf));
+                }
+            }
+            /// Describes the relationship between generated code and its original source
+            /// file. A GeneratedCodeInfo message is associated with only one generated
+            /// source file, but may contain references to different source .proto files.
+            #[derive(Debug, Copy, Clone)]
+            pub struct GeneratedCodeInfo {
+                /// An Annotation connects some span of text in generated code to an element
+                /// of its generating .proto file.
+                pub annotation: tacky::Field<
+                    1,
+                    tacky::Repeated<generated_code_info::Annotation>,
+                >,
+            }
+            impl tacky::MessageSchema for GeneratedCodeInfo {}
+            impl tacky::DecodeMessage for GeneratedCodeInfo {
+                type Fields<'a> = GeneratedCodeInfoFields<'a>;
+            }
+            impl GeneratedCodeInfo {
+                pub fn schema() -> Self {
+                    <Self as tacky::MessageSchema>::schema()
+                }
+                pub fn decode(buf: &[u8]) -> GeneratedCodeInfoFields<'_> {
+                    GeneratedCodeInfoFields::new(buf)
+                }
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum GeneratedCodeInfoField<'a> {
+                /// An Annotation connects some span of text in generated code to an element
+                /// of its generating .proto file.
+                Annotation(generated_code_info::AnnotationFields<'a>),
+            }
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub struct GeneratedCodeInfoFields<'a> {
+                buf: &'a [u8],
+            }
+            #[allow(deprecated)]
+            impl<'a> GeneratedCodeInfoField<'a> {
+                /// The field's name in JSON; `None` for an extension.
+                pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        GeneratedCodeInfoField::Annotation(_) => Some("annotation"),
+                    }
+                }
+            }
+            impl<'a> GeneratedCodeInfoFields<'a> {
+                pub fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+            }
+            impl<'a> tacky::MessageFields<'a> for GeneratedCodeInfoFields<'a> {
+                type Schema = GeneratedCodeInfo;
+                fn new(buf: &'a [u8]) -> Self {
+                    Self { buf }
+                }
+                fn as_bytes(&self) -> &'a [u8] {
+                    self.buf
+                }
+            }
+            #[allow(deprecated)]
+            impl<'a> ::core::iter::Iterator for GeneratedCodeInfoFields<'a> {
+                type Item = ::core::result::Result<
+                    GeneratedCodeInfoField<'a>,
+                    tacky::DecodeError,
+                >;
+                /// Yields one field per call, and **stops at the first error**: a malformed
+                /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                /// re-reading the same bytes would fail identically forever. Every error path
+                /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                /// `for` loop over hostile input terminates.
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    loop {
+                        if self.buf.is_empty() {
+                            return None;
+                        }
+                        let buf = &mut self.buf;
+                        let (tag, wire_type) = match tacky::decode_key(buf) {
+                            Ok(t) => t,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        };
+                        match tag {
+                            1u32 => {
+                                let decoded = (|| {
+                                    tacky::check_wire_type(
+                                        wire_type,
+                                        tacky::WireType::LEN,
+                                        "annotation",
+                                    )?;
+                                    let data = generated_code_info::Annotation::decode(
+                                        tacky::decode_len(buf)?,
+                                    );
+                                    Ok(GeneratedCodeInfoField::Annotation(data))
+                                })();
+                                if decoded.is_err() {
+                                    self.buf = &[];
+                                }
+                                return Some(decoded);
+                            }
+                            _ => {
+                                match tacky::skip_field(wire_type, buf) {
+                                    Ok(()) => continue,
+                                    Err(e) => {
+                                        self.buf = &[];
+                                        return Some(Err(e));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            #[derive(Debug, Clone, PartialEq, Default)]
+            pub struct GeneratedCodeInfoOwned {
+                /// An Annotation connects some span of text in generated code to an element
+                /// of its generating .proto file.
+                pub annotation: ::std::vec::Vec<generated_code_info::AnnotationOwned>,
+            }
+            #[allow(deprecated)]
+            impl GeneratedCodeInfoOwned {
+                /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                /// or, for a message, are merged into one; a missing required field is an error.
+                pub fn from_bytes(
+                    buf: &[u8],
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    Self::from_fields(GeneratedCodeInfo::decode(buf))
+                }
+                pub fn from_fields(
+                    fields: GeneratedCodeInfoFields<'_>,
+                ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                    let mut out = Self::default();
+                    out.merge_fields(fields)?;
+                    Ok(out)
+                }
+                /// Decodes a message into this one, as a later occurrence of it would be.
+                pub fn merge_fields(
+                    &mut self,
+                    fields: GeneratedCodeInfoFields<'_>,
+                ) -> ::core::result::Result<(), tacky::DecodeError> {
+                    for field in fields {
+                        match field? {
+                            GeneratedCodeInfoField::Annotation(v) => {
+                                self.annotation
+                                    .push(
+                                        generated_code_info::AnnotationOwned::from_fields(v)?,
+                                    );
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                    self.encode_dir(tacky::AnyDir::from_mut(buf));
+                }
+                /// Repeated fields need their direction known where they are written, and `encode`
+                /// is generic over it, so the writes go through the direction-erased view. Public
+                /// for the messages holding this one, which may be generated in other modules.
+                #[doc(hidden)]
+                pub fn encode_dir<B: tacky::WriteBuf>(
+                    &self,
+                    buf: &mut tacky::AnyDir<B>,
+                ) {
+                    let schema = GeneratedCodeInfo::schema();
+                    schema
+                        .annotation
+                        .write_msgs(
+                            buf,
+                            &self.annotation,
+                            |buf, _, m| m.encode_dir(buf),
+                        );
+                }
+            }
+            pub mod code_generator_response {
+                use ::tacky;
+                /// Represents a single generated file.
+                #[derive(Debug, Copy, Clone)]
+                pub struct File {
+                    /// The file name, relative to the output directory.  The name must not
+                    /// contain "." or ".." components and must be relative, not be absolute (so,
+                    /// the file cannot lie outside the output directory).  "/" must be used as
+                    /// the path separator, not "\".
+                    ///
+                    /// If the name is omitted, the content will be appended to the previous
+                    /// file.  This allows the generator to break large files into small chunks,
+                    /// and allows the generated text to be streamed back to protoc so that large
+                    /// files need not reside completely in memory at one time.  Note that as of
+                    /// this writing protoc does not optimize for this -- it will read the entire
+                    /// CodeGeneratorResponse before writing files to disk.
+                    pub name: tacky::Field<1, tacky::Optional<tacky::PbString>>,
+                    /// If non-empty, indicates that the named file should already exist, and the
+                    /// content here is to be inserted into that file at a defined insertion
+                    /// point.  This feature allows a code generator to extend the output
+                    /// produced by another code generator.  The original generator may provide
+                    /// insertion points by placing special annotations in the file that look
+                    /// like:
+                    /// @@protoc_insertion_point(NAME)
+                    /// The annotation can have arbitrary text before and after it on the line,
+                    /// which allows it to be placed in a comment.  NAME should be replaced with
+                    /// an identifier naming the point -- this is what other generators will use
+                    /// as the insertion_point.  Code inserted at this point will be placed
+                    /// immediately above the line containing the insertion point (thus multiple
+                    /// insertions to the same point will come out in the order they were added).
+                    /// The double-@ is intended to make it unlikely that the generated code
+                    /// could contain things that look like insertion points by accident.
+                    ///
+                    /// For example, the C++ code generator places the following line in the
+                    /// .pb.h files that it generates:
+                    /// // @@protoc_insertion_point(namespace_scope)
+                    /// This line appears within the scope of the file's package namespace, but
+                    /// outside of any particular class.  Another plugin can then specify the
+                    /// insertion_point "namespace_scope" to generate additional classes or
+                    /// other declarations that should be placed in this scope.
+                    ///
+                    /// Note that if the line containing the insertion point begins with
+                    /// whitespace, the same whitespace will be added to every line of the
+                    /// inserted text.  This is useful for languages like Python, where
+                    /// indentation matters.  In these languages, the insertion point comment
+                    /// should be indented the same amount as any inserted code will need to be
+                    /// in order to work correctly in that context.
+                    ///
+                    /// The code generator that generates the initial file and the one which
+                    /// inserts into it must both run as part of a single invocation of protoc.
+                    /// Code generators are executed in the order in which they appear on the
+                    /// command line.
+                    ///
+                    /// If |insertion_point| is present, |name| must also be present.
+                    pub insertion_point: tacky::Field<
+                        2,
+                        tacky::Optional<tacky::PbString>,
+                    >,
+                    /// The file contents.
+                    pub content: tacky::Field<15, tacky::Optional<tacky::PbString>>,
+                    /// Information describing the file content being inserted. If an insertion
+                    /// point is used, this information will be appropriately offset and inserted
+                    /// into the code generation metadata for the generated files.
+                    pub generated_code_info: tacky::Field<
+                        16,
+                        tacky::Optional<super::GeneratedCodeInfo>,
+                    >,
+                }
+                impl tacky::MessageSchema for File {}
+                impl tacky::DecodeMessage for File {
+                    type Fields<'a> = FileFields<'a>;
+                }
+                impl File {
+                    pub fn schema() -> Self {
+                        <Self as tacky::MessageSchema>::schema()
+                    }
+                    pub fn decode(buf: &[u8]) -> FileFields<'_> {
+                        FileFields::new(buf)
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub enum FileField<'a> {
+                    /// The file name, relative to the output directory.  The name must not
+                    /// contain "." or ".." components and must be relative, not be absolute (so,
+                    /// the file cannot lie outside the output directory).  "/" must be used as
+                    /// the path separator, not "\".
+                    ///
+                    /// If the name is omitted, the content will be appended to the previous
+                    /// file.  This allows the generator to break large files into small chunks,
+                    /// and allows the generated text to be streamed back to protoc so that large
+                    /// files need not reside completely in memory at one time.  Note that as of
+                    /// this writing protoc does not optimize for this -- it will read the entire
+                    /// CodeGeneratorResponse before writing files to disk.
+                    Name(&'a str),
+                    /// If non-empty, indicates that the named file should already exist, and the
+                    /// content here is to be inserted into that file at a defined insertion
+                    /// point.  This feature allows a code generator to extend the output
+                    /// produced by another code generator.  The original generator may provide
+                    /// insertion points by placing special annotations in the file that look
+                    /// like:
+                    /// @@protoc_insertion_point(NAME)
+                    /// The annotation can have arbitrary text before and after it on the line,
+                    /// which allows it to be placed in a comment.  NAME should be replaced with
+                    /// an identifier naming the point -- this is what other generators will use
+                    /// as the insertion_point.  Code inserted at this point will be placed
+                    /// immediately above the line containing the insertion point (thus multiple
+                    /// insertions to the same point will come out in the order they were added).
+                    /// The double-@ is intended to make it unlikely that the generated code
+                    /// could contain things that look like insertion points by accident.
+                    ///
+                    /// For example, the C++ code generator places the following line in the
+                    /// .pb.h files that it generates:
+                    /// // @@protoc_insertion_point(namespace_scope)
+                    /// This line appears within the scope of the file's package namespace, but
+                    /// outside of any particular class.  Another plugin can then specify the
+                    /// insertion_point "namespace_scope" to generate additional classes or
+                    /// other declarations that should be placed in this scope.
+                    ///
+                    /// Note that if the line containing the insertion point begins with
+                    /// whitespace, the same whitespace will be added to every line of the
+                    /// inserted text.  This is useful for languages like Python, where
+                    /// indentation matters.  In these languages, the insertion point comment
+                    /// should be indented the same amount as any inserted code will need to be
+                    /// in order to work correctly in that context.
+                    ///
+                    /// The code generator that generates the initial file and the one which
+                    /// inserts into it must both run as part of a single invocation of protoc.
+                    /// Code generators are executed in the order in which they appear on the
+                    /// command line.
+                    ///
+                    /// If |insertion_point| is present, |name| must also be present.
+                    InsertionPoint(&'a str),
+                    /// The file contents.
+                    Content(&'a str),
+                    /// Information describing the file content being inserted. If an insertion
+                    /// point is used, this information will be appropriately offset and inserted
+                    /// into the code generation metadata for the generated files.
+                    GeneratedCodeInfo(super::GeneratedCodeInfoFields<'a>),
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub struct FileFields<'a> {
+                    buf: &'a [u8],
+                }
+                #[allow(deprecated)]
+                impl<'a> FileField<'a> {
+                    /// The field's name in JSON; `None` for an extension.
+                    pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                        match *self {
+                            FileField::Name(_) => Some("name"),
+                            FileField::InsertionPoint(_) => Some("insertionPoint"),
+                            FileField::Content(_) => Some("content"),
+                            FileField::GeneratedCodeInfo(_) => Some("generatedCodeInfo"),
+                        }
+                    }
+                }
+                impl<'a> FileFields<'a> {
+                    pub fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                }
+                impl<'a> tacky::MessageFields<'a> for FileFields<'a> {
+                    type Schema = File;
+                    fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                    fn as_bytes(&self) -> &'a [u8] {
+                        self.buf
+                    }
+                }
+                #[allow(deprecated)]
+                impl<'a> ::core::iter::Iterator for FileFields<'a> {
+                    type Item = ::core::result::Result<
+                        FileField<'a>,
+                        tacky::DecodeError,
+                    >;
+                    /// Yields one field per call, and **stops at the first error**: a malformed
+                    /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                    /// re-reading the same bytes would fail identically forever. Every error path
+                    /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                    /// `for` loop over hostile input terminates.
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        loop {
+                            if self.buf.is_empty() {
+                                return None;
+                            }
+                            let buf = &mut self.buf;
+                            let (tag, wire_type) = match tacky::decode_key(buf) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            };
+                            match tag {
+                                1u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "name",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(FileField::Name(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                2u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "insertion_point",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(FileField::InsertionPoint(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                15u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "content",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(FileField::Content(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                16u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "generated_code_info",
+                                        )?;
+                                        let data = super::GeneratedCodeInfo::decode(
+                                            tacky::decode_len(buf)?,
+                                        );
+                                        Ok(FileField::GeneratedCodeInfo(data))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                _ => {
+                                    match tacky::skip_field(wire_type, buf) {
+                                        Ok(()) => continue,
+                                        Err(e) => {
+                                            self.buf = &[];
+                                            return Some(Err(e));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq, Default)]
+                pub struct FileOwned {
+                    /// The file name, relative to the output directory.  The name must not
+                    /// contain "." or ".." components and must be relative, not be absolute (so,
+                    /// the file cannot lie outside the output directory).  "/" must be used as
+                    /// the path separator, not "\".
+                    ///
+                    /// If the name is omitted, the content will be appended to the previous
+                    /// file.  This allows the generator to break large files into small chunks,
+                    /// and allows the generated text to be streamed back to protoc so that large
+                    /// files need not reside completely in memory at one time.  Note that as of
+                    /// this writing protoc does not optimize for this -- it will read the entire
+                    /// CodeGeneratorResponse before writing files to disk.
+                    pub name: ::core::option::Option<::std::string::String>,
+                    /// If non-empty, indicates that the named file should already exist, and the
+                    /// content here is to be inserted into that file at a defined insertion
+                    /// point.  This feature allows a code generator to extend the output
+                    /// produced by another code generator.  The original generator may provide
+                    /// insertion points by placing special annotations in the file that look
+                    /// like:
+                    /// @@protoc_insertion_point(NAME)
+                    /// The annotation can have arbitrary text before and after it on the line,
+                    /// which allows it to be placed in a comment.  NAME should be replaced with
+                    /// an identifier naming the point -- this is what other generators will use
+                    /// as the insertion_point.  Code inserted at this point will be placed
+                    /// immediately above the line containing the insertion point (thus multiple
+                    /// insertions to the same point will come out in the order they were added).
+                    /// The double-@ is intended to make it unlikely that the generated code
+                    /// could contain things that look like insertion points by accident.
+                    ///
+                    /// For example, the C++ code generator places the following line in the
+                    /// .pb.h files that it generates:
+                    /// // @@protoc_insertion_point(namespace_scope)
+                    /// This line appears within the scope of the file's package namespace, but
+                    /// outside of any particular class.  Another plugin can then specify the
+                    /// insertion_point "namespace_scope" to generate additional classes or
+                    /// other declarations that should be placed in this scope.
+                    ///
+                    /// Note that if the line containing the insertion point begins with
+                    /// whitespace, the same whitespace will be added to every line of the
+                    /// inserted text.  This is useful for languages like Python, where
+                    /// indentation matters.  In these languages, the insertion point comment
+                    /// should be indented the same amount as any inserted code will need to be
+                    /// in order to work correctly in that context.
+                    ///
+                    /// The code generator that generates the initial file and the one which
+                    /// inserts into it must both run as part of a single invocation of protoc.
+                    /// Code generators are executed in the order in which they appear on the
+                    /// command line.
+                    ///
+                    /// If |insertion_point| is present, |name| must also be present.
+                    pub insertion_point: ::core::option::Option<::std::string::String>,
+                    /// The file contents.
+                    pub content: ::core::option::Option<::std::string::String>,
+                    /// Information describing the file content being inserted. If an insertion
+                    /// point is used, this information will be appropriately offset and inserted
+                    /// into the code generation metadata for the generated files.
+                    pub generated_code_info: ::core::option::Option<
+                        ::std::boxed::Box<super::GeneratedCodeInfoOwned>,
+                    >,
+                }
+                #[allow(deprecated)]
+                impl FileOwned {
+                    /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                    /// or, for a message, are merged into one; a missing required field is an error.
+                    pub fn from_bytes(
+                        buf: &[u8],
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        Self::from_fields(File::decode(buf))
+                    }
+                    pub fn from_fields(
+                        fields: FileFields<'_>,
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        let mut out = Self::default();
+                        out.merge_fields(fields)?;
+                        Ok(out)
+                    }
+                    /// Decodes a message into this one, as a later occurrence of it would be.
+                    pub fn merge_fields(
+                        &mut self,
+                        fields: FileFields<'_>,
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        for field in fields {
+                            match field? {
+                                FileField::Name(v) => {
+                                    self.name = Some(v.to_owned());
+                                }
+                                FileField::InsertionPoint(v) => {
+                                    self.insertion_point = Some(v.to_owned());
+                                }
+                                FileField::Content(v) => {
+                                    self.content = Some(v.to_owned());
+                                }
+                                FileField::GeneratedCodeInfo(v) => {
+                                    match &mut self.generated_code_info {
+                                        Some(m) => m.merge_fields(v)?,
+                                        None => {
+                                            self.generated_code_info = Some(
+                                                ::std::boxed::Box::new(
+                                                    super::GeneratedCodeInfoOwned::from_fields(v)?,
+                                                ),
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                        self.encode_dir(tacky::AnyDir::from_mut(buf));
+                    }
+                    /// Repeated fields need their direction known where they are written, and `encode`
+                    /// is generic over it, so the writes go through the direction-erased view. Public
+                    /// for the messages holding this one, which may be generated in other modules.
+                    #[doc(hidden)]
+                    pub fn encode_dir<B: tacky::WriteBuf>(
+                        &self,
+                        buf: &mut tacky::AnyDir<B>,
+                    ) {
+                        let schema = File::schema();
+                        schema.name.write(buf, self.name.as_ref());
+                        schema.insertion_point.write(buf, self.insertion_point.as_ref());
+                        schema.content.write(buf, self.content.as_ref());
+                        if let Some(m) = &self.generated_code_info {
+                            schema
+                                .generated_code_info
+                                .write_msg(buf, |buf, _| m.encode_dir(buf));
+                        }
+                    }
+                }
+                /// Sync with code_generator.h.
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+                pub enum Feature {
+                    #[default]
+                    FeatureNone,
+                    FeatureProto3Optional,
+                    FeatureSupportsEditions,
+                }
+                impl Feature {
+                    /// Every value declared, aliases aside, in declaration order.
+                    pub const VALUES: &'static [Feature] = &[
+                        Feature::FeatureNone,
+                        Feature::FeatureProto3Optional,
+                        Feature::FeatureSupportsEditions,
+                    ];
+                    /// The value's name as declared, or `None` for a number the enum doesn't declare.
+                    pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                        match self {
+                            Feature::FeatureNone => Some("FEATURE_NONE"),
+                            Feature::FeatureProto3Optional => {
+                                Some("FEATURE_PROTO3_OPTIONAL")
+                            }
+                            Feature::FeatureSupportsEditions => {
+                                Some("FEATURE_SUPPORTS_EDITIONS")
+                            }
+                        }
+                    }
+                    /// The value declared as `name`, aliases included.
+                    pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            "FEATURE_NONE" => Some(Feature::FeatureNone),
+                            "FEATURE_PROTO3_OPTIONAL" => {
+                                Some(Feature::FeatureProto3Optional)
+                            }
+                            "FEATURE_SUPPORTS_EDITIONS" => {
+                                Some(Feature::FeatureSupportsEditions)
+                            }
+                            _ => None,
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<i32> for Feature {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(
+                        value: i32,
+                    ) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            0i32 => Ok(Feature::FeatureNone),
+                            1i32 => Ok(Feature::FeatureProto3Optional),
+                            2i32 => Ok(Feature::FeatureSupportsEditions),
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for Feature {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+                impl ::core::convert::From<Feature> for i32 {
+                    fn from(value: Feature) -> i32 {
+                        match value {
+                            Feature::FeatureNone => 0i32,
+                            Feature::FeatureProto3Optional => 1i32,
+                            Feature::FeatureSupportsEditions => 2i32,
+                        }
+                    }
+                }
+            }
+            pub mod descriptor_proto {
+                use ::tacky;
+                #[derive(Debug, Copy, Clone)]
+                pub struct ExtensionRange {
+                    /// Inclusive.
+                    pub start: tacky::Field<1, tacky::Optional<tacky::Int32>>,
+                    /// Exclusive.
+                    pub end: tacky::Field<2, tacky::Optional<tacky::Int32>>,
+                    pub options: tacky::Field<
+                        3,
+                        tacky::Optional<super::ExtensionRangeOptions>,
+                    >,
+                }
+                impl tacky::MessageSchema for ExtensionRange {}
+                impl tacky::DecodeMessage for ExtensionRange {
+                    type Fields<'a> = ExtensionRangeFields<'a>;
+                }
+                impl ExtensionRange {
+                    pub fn schema() -> Self {
+                        <Self as tacky::MessageSchema>::schema()
+                    }
+                    pub fn decode(buf: &[u8]) -> ExtensionRangeFields<'_> {
+                        ExtensionRangeFields::new(buf)
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub enum ExtensionRangeField<'a> {
+                    /// Inclusive.
+                    Start(i32),
+                    /// Exclusive.
+                    End(i32),
+                    Options(super::ExtensionRangeOptionsFields<'a>),
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub struct ExtensionRangeFields<'a> {
+                    buf: &'a [u8],
+                }
+                #[allow(deprecated)]
+                impl<'a> ExtensionRangeField<'a> {
+                    /// The field's name in JSON; `None` for an extension.
+                    pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                        match *self {
+                            ExtensionRangeField::Start(_) => Some("start"),
+                            ExtensionRangeField::End(_) => Some("end"),
+                            ExtensionRangeField::Options(_) => Some("options"),
+                        }
+                    }
+                }
+                impl<'a> ExtensionRangeFields<'a> {
+                    pub fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                }
+                impl<'a> tacky::MessageFields<'a> for ExtensionRangeFields<'a> {
+                    type Schema = ExtensionRange;
+                    fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                    fn as_bytes(&self) -> &'a [u8] {
+                        self.buf
+                    }
+                }
+                #[allow(deprecated)]
+                impl<'a> ::core::iter::Iterator for ExtensionRangeFields<'a> {
+                    type Item = ::core::result::Result<
+                        ExtensionRangeField<'a>,
+                        tacky::DecodeError,
+                    >;
+                    /// Yields one field per call, and **stops at the first error**: a malformed
+                    /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                    /// re-reading the same bytes would fail identically forever. Every error path
+                    /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                    /// `for` loop over hostile input terminates.
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        loop {
+                            if self.buf.is_empty() {
+                                return None;
+                            }
+                            let buf = &mut self.buf;
+                            let (tag, wire_type) = match tacky::decode_key(buf) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            };
+                            match tag {
+                                1u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "start",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(ExtensionRangeField::Start(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                2u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "end",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(ExtensionRangeField::End(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                3u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "options",
+                                        )?;
+                                        let data = super::ExtensionRangeOptions::decode(
+                                            tacky::decode_len(buf)?,
+                                        );
+                                        Ok(ExtensionRangeField::Options(data))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                _ => {
+                                    match tacky::skip_field(wire_type, buf) {
+                                        Ok(()) => continue,
+                                        Err(e) => {
+                                            self.buf = &[];
+                                            return Some(Err(e));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq, Default)]
+                pub struct ExtensionRangeOwned {
+                    /// Inclusive.
+                    pub start: ::core::option::Option<i32>,
+                    /// Exclusive.
+                    pub end: ::core::option::Option<i32>,
+                    pub options: ::core::option::Option<
+                        ::std::boxed::Box<super::ExtensionRangeOptionsOwned>,
+                    >,
+                }
+                #[allow(deprecated)]
+                impl ExtensionRangeOwned {
+                    /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                    /// or, for a message, are merged into one; a missing required field is an error.
+                    pub fn from_bytes(
+                        buf: &[u8],
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        Self::from_fields(ExtensionRange::decode(buf))
+                    }
+                    pub fn from_fields(
+                        fields: ExtensionRangeFields<'_>,
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        let mut out = Self::default();
+                        out.merge_fields(fields)?;
+                        Ok(out)
+                    }
+                    /// Decodes a message into this one, as a later occurrence of it would be.
+                    pub fn merge_fields(
+                        &mut self,
+                        fields: ExtensionRangeFields<'_>,
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        for field in fields {
+                            match field? {
+                                ExtensionRangeField::Start(v) => {
+                                    self.start = Some(v);
+                                }
+                                ExtensionRangeField::End(v) => {
+                                    self.end = Some(v);
+                                }
+                                ExtensionRangeField::Options(v) => {
+                                    match &mut self.options {
+                                        Some(m) => m.merge_fields(v)?,
+                                        None => {
+                                            self.options = Some(
+                                                ::std::boxed::Box::new(
+                                                    super::ExtensionRangeOptionsOwned::from_fields(v)?,
+                                                ),
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                        self.encode_dir(tacky::AnyDir::from_mut(buf));
+                    }
+                    /// Repeated fields need their direction known where they are written, and `encode`
+                    /// is generic over it, so the writes go through the direction-erased view. Public
+                    /// for the messages holding this one, which may be generated in other modules.
+                    #[doc(hidden)]
+                    pub fn encode_dir<B: tacky::WriteBuf>(
+                        &self,
+                        buf: &mut tacky::AnyDir<B>,
+                    ) {
+                        let schema = ExtensionRange::schema();
+                        schema.start.write(buf, self.start.as_ref());
+                        schema.end.write(buf, self.end.as_ref());
+                        if let Some(m) = &self.options {
+                            schema.options.write_msg(buf, |buf, _| m.encode_dir(buf));
+                        }
+                    }
+                }
+                /// Range of reserved tag numbers. Reserved tag numbers may not be used by
+                /// fields or extension ranges in the same message. Reserved ranges may
+                /// not overlap.
+                #[derive(Debug, Copy, Clone)]
+                pub struct ReservedRange {
+                    /// Inclusive.
+                    pub start: tacky::Field<1, tacky::Optional<tacky::Int32>>,
+                    /// Exclusive.
+                    pub end: tacky::Field<2, tacky::Optional<tacky::Int32>>,
+                }
+                impl tacky::MessageSchema for ReservedRange {}
+                impl tacky::DecodeMessage for ReservedRange {
+                    type Fields<'a> = ReservedRangeFields<'a>;
+                }
+                impl ReservedRange {
+                    pub fn schema() -> Self {
+                        <Self as tacky::MessageSchema>::schema()
+                    }
+                    pub fn decode(buf: &[u8]) -> ReservedRangeFields<'_> {
+                        ReservedRangeFields::new(buf)
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub enum ReservedRangeField {
+                    /// Inclusive.
+                    Start(i32),
+                    /// Exclusive.
+                    End(i32),
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub struct ReservedRangeFields<'a> {
+                    buf: &'a [u8],
+                }
+                #[allow(deprecated)]
+                impl ReservedRangeField {
+                    /// The field's name in JSON; `None` for an extension.
+                    pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                        match *self {
+                            ReservedRangeField::Start(_) => Some("start"),
+                            ReservedRangeField::End(_) => Some("end"),
+                        }
+                    }
+                }
+                impl<'a> ReservedRangeFields<'a> {
+                    pub fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                }
+                impl<'a> tacky::MessageFields<'a> for ReservedRangeFields<'a> {
+                    type Schema = ReservedRange;
+                    fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                    fn as_bytes(&self) -> &'a [u8] {
+                        self.buf
+                    }
+                }
+                #[allow(deprecated)]
+                impl<'a> ::core::iter::Iterator for ReservedRangeFields<'a> {
+                    type Item = ::core::result::Result<
+                        ReservedRangeField,
+                        tacky::DecodeError,
+                    >;
+                    /// Yields one field per call, and **stops at the first error**: a malformed
+                    /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                    /// re-reading the same bytes would fail identically forever. Every error path
+                    /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                    /// `for` loop over hostile input terminates.
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        loop {
+                            if self.buf.is_empty() {
+                                return None;
+                            }
+                            let buf = &mut self.buf;
+                            let (tag, wire_type) = match tacky::decode_key(buf) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            };
+                            match tag {
+                                1u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "start",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(ReservedRangeField::Start(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                2u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "end",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(ReservedRangeField::End(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                _ => {
+                                    match tacky::skip_field(wire_type, buf) {
+                                        Ok(()) => continue,
+                                        Err(e) => {
+                                            self.buf = &[];
+                                            return Some(Err(e));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq, Default)]
+                pub struct ReservedRangeOwned {
+                    /// Inclusive.
+                    pub start: ::core::option::Option<i32>,
+                    /// Exclusive.
+                    pub end: ::core::option::Option<i32>,
+                }
+                #[allow(deprecated)]
+                impl ReservedRangeOwned {
+                    /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                    /// or, for a message, are merged into one; a missing required field is an error.
+                    pub fn from_bytes(
+                        buf: &[u8],
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        Self::from_fields(ReservedRange::decode(buf))
+                    }
+                    pub fn from_fields(
+                        fields: ReservedRangeFields<'_>,
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        let mut out = Self::default();
+                        out.merge_fields(fields)?;
+                        Ok(out)
+                    }
+                    /// Decodes a message into this one, as a later occurrence of it would be.
+                    pub fn merge_fields(
+                        &mut self,
+                        fields: ReservedRangeFields<'_>,
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        for field in fields {
+                            match field? {
+                                ReservedRangeField::Start(v) => {
+                                    self.start = Some(v);
+                                }
+                                ReservedRangeField::End(v) => {
+                                    self.end = Some(v);
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                        self.encode_dir(tacky::AnyDir::from_mut(buf));
+                    }
+                    /// Repeated fields need their direction known where they are written, and `encode`
+                    /// is generic over it, so the writes go through the direction-erased view. Public
+                    /// for the messages holding this one, which may be generated in other modules.
+                    #[doc(hidden)]
+                    pub fn encode_dir<B: tacky::WriteBuf>(
+                        &self,
+                        buf: &mut tacky::AnyDir<B>,
+                    ) {
+                        let schema = ReservedRange::schema();
+                        schema.start.write(buf, self.start.as_ref());
+                        schema.end.write(buf, self.end.as_ref());
+                    }
+                }
+            }
+            pub mod enum_descriptor_proto {
+                use ::tacky;
+                /// Range of reserved numeric values. Reserved values may not be used by
+                /// entries in the same enum. Reserved ranges may not overlap.
+                ///
+                /// Note that this is distinct from DescriptorProto.ReservedRange in that it
+                /// is inclusive such that it can appropriately represent the entire int32
+                /// domain.
+                #[derive(Debug, Copy, Clone)]
+                pub struct EnumReservedRange {
+                    /// Inclusive.
+                    pub start: tacky::Field<1, tacky::Optional<tacky::Int32>>,
+                    /// Inclusive.
+                    pub end: tacky::Field<2, tacky::Optional<tacky::Int32>>,
+                }
+                impl tacky::MessageSchema for EnumReservedRange {}
+                impl tacky::DecodeMessage for EnumReservedRange {
+                    type Fields<'a> = EnumReservedRangeFields<'a>;
+                }
+                impl EnumReservedRange {
+                    pub fn schema() -> Self {
+                        <Self as tacky::MessageSchema>::schema()
+                    }
+                    pub fn decode(buf: &[u8]) -> EnumReservedRangeFields<'_> {
+                        EnumReservedRangeFields::new(buf)
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub enum EnumReservedRangeField {
+                    /// Inclusive.
+                    Start(i32),
+                    /// Inclusive.
+                    End(i32),
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub struct EnumReservedRangeFields<'a> {
+                    buf: &'a [u8],
+                }
+                #[allow(deprecated)]
+                impl EnumReservedRangeField {
+                    /// The field's name in JSON; `None` for an extension.
+                    pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                        match *self {
+                            EnumReservedRangeField::Start(_) => Some("start"),
+                            EnumReservedRangeField::End(_) => Some("end"),
+                        }
+                    }
+                }
+                impl<'a> EnumReservedRangeFields<'a> {
+                    pub fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                }
+                impl<'a> tacky::MessageFields<'a> for EnumReservedRangeFields<'a> {
+                    type Schema = EnumReservedRange;
+                    fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                    fn as_bytes(&self) -> &'a [u8] {
+                        self.buf
+                    }
+                }
+                #[allow(deprecated)]
+                impl<'a> ::core::iter::Iterator for EnumReservedRangeFields<'a> {
+                    type Item = ::core::result::Result<
+                        EnumReservedRangeField,
+                        tacky::DecodeError,
+                    >;
+                    /// Yields one field per call, and **stops at the first error**: a malformed
+                    /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                    /// re-reading the same bytes would fail identically forever. Every error path
+                    /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                    /// `for` loop over hostile input terminates.
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        loop {
+                            if self.buf.is_empty() {
+                                return None;
+                            }
+                            let buf = &mut self.buf;
+                            let (tag, wire_type) = match tacky::decode_key(buf) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            };
+                            match tag {
+                                1u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "start",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(EnumReservedRangeField::Start(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                2u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "end",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(EnumReservedRangeField::End(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                _ => {
+                                    match tacky::skip_field(wire_type, buf) {
+                                        Ok(()) => continue,
+                                        Err(e) => {
+                                            self.buf = &[];
+                                            return Some(Err(e));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq, Default)]
+                pub struct EnumReservedRangeOwned {
+                    /// Inclusive.
+                    pub start: ::core::option::Option<i32>,
+                    /// Inclusive.
+                    pub end: ::core::option::Option<i32>,
+                }
+                #[allow(deprecated)]
+                impl EnumReservedRangeOwned {
+                    /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                    /// or, for a message, are merged into one; a missing required field is an error.
+                    pub fn from_bytes(
+                        buf: &[u8],
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        Self::from_fields(EnumReservedRange::decode(buf))
+                    }
+                    pub fn from_fields(
+                        fields: EnumReservedRangeFields<'_>,
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        let mut out = Self::default();
+                        out.merge_fields(fields)?;
+                        Ok(out)
+                    }
+                    /// Decodes a message into this one, as a later occurrence of it would be.
+                    pub fn merge_fields(
+                        &mut self,
+                        fields: EnumReservedRangeFields<'_>,
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        for field in fields {
+                            match field? {
+                                EnumReservedRangeField::Start(v) => {
+                                    self.start = Some(v);
+                                }
+                                EnumReservedRangeField::End(v) => {
+                                    self.end = Some(v);
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                        self.encode_dir(tacky::AnyDir::from_mut(buf));
+                    }
+                    /// Repeated fields need their direction known where they are written, and `encode`
+                    /// is generic over it, so the writes go through the direction-erased view. Public
+                    /// for the messages holding this one, which may be generated in other modules.
+                    #[doc(hidden)]
+                    pub fn encode_dir<B: tacky::WriteBuf>(
+                        &self,
+                        buf: &mut tacky::AnyDir<B>,
+                    ) {
+                        let schema = EnumReservedRange::schema();
+                        schema.start.write(buf, self.start.as_ref());
+                        schema.end.write(buf, self.end.as_ref());
+                    }
+                }
+            }
+            pub mod field_descriptor_proto {
+                use ::tacky;
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+                pub enum Type {
+                    #[default]
+                    /// 0 is reserved for errors.
+                    /// Order is weird for historical reasons.
+                    TypeDouble,
+                    TypeFloat,
+                    /// Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT64 if
+                    /// negative values are likely.
+                    TypeInt64,
+                    TypeUint64,
+                    /// Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT32 if
+                    /// negative values are likely.
+                    TypeInt32,
+                    TypeFixed64,
+                    TypeFixed32,
+                    TypeBool,
+                    TypeString,
+                    /// Tag-delimited aggregate.
+                    /// Group type is deprecated and not supported in proto3. However, Proto3
+                    /// implementations should still be able to parse the group wire format and
+                    /// treat group fields as unknown fields.
+                    TypeGroup,
+                    /// Length-delimited aggregate.
+                    TypeMessage,
+                    /// New in version 2.
+                    TypeBytes,
+                    TypeUint32,
+                    TypeEnum,
+                    TypeSfixed32,
+                    TypeSfixed64,
+                    /// Uses ZigZag encoding.
+                    TypeSint32,
+                    /// Uses ZigZag encoding.
+                    TypeSint64,
+                }
+                impl Type {
+                    /// Every value declared, aliases aside, in declaration order.
+                    pub const VALUES: &'static [Type] = &[
+                        Type::TypeDouble,
+                        Type::TypeFloat,
+                        Type::TypeInt64,
+                        Type::TypeUint64,
+                        Type::TypeInt32,
+                        Type::TypeFixed64,
+                        Type::TypeFixed32,
+                        Type::TypeBool,
+                        Type::TypeString,
+                        Type::TypeGroup,
+                        Type::TypeMessage,
+                        Type::TypeBytes,
+                        Type::TypeUint32,
+                        Type::TypeEnum,
+                        Type::TypeSfixed32,
+                        Type::TypeSfixed64,
+                        Type::TypeSint32,
+                        Type::TypeSint64,
+                    ];
+                    /// The value's name as declared, or `None` for a number the enum doesn't declare.
+                    pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                        match self {
+                            Type::TypeDouble => Some("TYPE_DOUBLE"),
+                            Type::TypeFloat => Some("TYPE_FLOAT"),
+                            Type::TypeInt64 => Some("TYPE_INT64"),
+                            Type::TypeUint64 => Some("TYPE_UINT64"),
+                            Type::TypeInt32 => Some("TYPE_INT32"),
+                            Type::TypeFixed64 => Some("TYPE_FIXED64"),
+                            Type::TypeFixed32 => Some("TYPE_FIXED32"),
+                            Type::TypeBool => Some("TYPE_BOOL"),
+                            Type::TypeString => Some("TYPE_STRING"),
+                            Type::TypeGroup => Some("TYPE_GROUP"),
+                            Type::TypeMessage => Some("TYPE_MESSAGE"),
+                            Type::TypeBytes => Some("TYPE_BYTES"),
+                            Type::TypeUint32 => Some("TYPE_UINT32"),
+                            Type::TypeEnum => Some("TYPE_ENUM"),
+                            Type::TypeSfixed32 => Some("TYPE_SFIXED32"),
+                            Type::TypeSfixed64 => Some("TYPE_SFIXED64"),
+                            Type::TypeSint32 => Some("TYPE_SINT32"),
+                            Type::TypeSint64 => Some("TYPE_SINT64"),
+                        }
+                    }
+                    /// The value declared as `name`, aliases included.
+                    pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            "TYPE_DOUBLE" => Some(Type::TypeDouble),
+                            "TYPE_FLOAT" => Some(Type::TypeFloat),
+                            "TYPE_INT64" => Some(Type::TypeInt64),
+                            "TYPE_UINT64" => Some(Type::TypeUint64),
+                            "TYPE_INT32" => Some(Type::TypeInt32),
+                            "TYPE_FIXED64" => Some(Type::TypeFixed64),
+                            "TYPE_FIXED32" => Some(Type::TypeFixed32),
+                            "TYPE_BOOL" => Some(Type::TypeBool),
+                            "TYPE_STRING" => Some(Type::TypeString),
+                            "TYPE_GROUP" => Some(Type::TypeGroup),
+                            "TYPE_MESSAGE" => Some(Type::TypeMessage),
+                            "TYPE_BYTES" => Some(Type::TypeBytes),
+                            "TYPE_UINT32" => Some(Type::TypeUint32),
+                            "TYPE_ENUM" => Some(Type::TypeEnum),
+                            "TYPE_SFIXED32" => Some(Type::TypeSfixed32),
+                            "TYPE_SFIXED64" => Some(Type::TypeSfixed64),
+                            "TYPE_SINT32" => Some(Type::TypeSint32),
+                            "TYPE_SINT64" => Some(Type::TypeSint64),
+                            _ => None,
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<i32> for Type {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(
+                        value: i32,
+                    ) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            1i32 => Ok(Type::TypeDouble),
+                            2i32 => Ok(Type::TypeFloat),
+                            3i32 => Ok(Type::TypeInt64),
+                            4i32 => Ok(Type::TypeUint64),
+                            5i32 => Ok(Type::TypeInt32),
+                            6i32 => Ok(Type::TypeFixed64),
+                            7i32 => Ok(Type::TypeFixed32),
+                            8i32 => Ok(Type::TypeBool),
+                            9i32 => Ok(Type::TypeString),
+                            10i32 => Ok(Type::TypeGroup),
+                            11i32 => Ok(Type::TypeMessage),
+                            12i32 => Ok(Type::TypeBytes),
+                            13i32 => Ok(Type::TypeUint32),
+                            14i32 => Ok(Type::TypeEnum),
+                            15i32 => Ok(Type::TypeSfixed32),
+                            16i32 => Ok(Type::TypeSfixed64),
+                            17i32 => Ok(Type::TypeSint32),
+                            18i32 => Ok(Type::TypeSint64),
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for Type {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+                impl ::core::convert::From<Type> for i32 {
+                    fn from(value: Type) -> i32 {
+                        match value {
+                            Type::TypeDouble => 1i32,
+                            Type::TypeFloat => 2i32,
+                            Type::TypeInt64 => 3i32,
+                            Type::TypeUint64 => 4i32,
+                            Type::TypeInt32 => 5i32,
+                            Type::TypeFixed64 => 6i32,
+                            Type::TypeFixed32 => 7i32,
+                            Type::TypeBool => 8i32,
+                            Type::TypeString => 9i32,
+                            Type::TypeGroup => 10i32,
+                            Type::TypeMessage => 11i32,
+                            Type::TypeBytes => 12i32,
+                            Type::TypeUint32 => 13i32,
+                            Type::TypeEnum => 14i32,
+                            Type::TypeSfixed32 => 15i32,
+                            Type::TypeSfixed64 => 16i32,
+                            Type::TypeSint32 => 17i32,
+                            Type::TypeSint64 => 18i32,
+                        }
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+                pub enum Label {
+                    #[default]
+                    /// 0 is reserved for errors
+                    LabelOptional,
+                    LabelRequired,
+                    LabelRepeated,
+                }
+                impl Label {
+                    /// Every value declared, aliases aside, in declaration order.
+                    pub const VALUES: &'static [Label] = &[
+                        Label::LabelOptional,
+                        Label::LabelRequired,
+                        Label::LabelRepeated,
+                    ];
+                    /// The value's name as declared, or `None` for a number the enum doesn't declare.
+                    pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                        match self {
+                            Label::LabelOptional => Some("LABEL_OPTIONAL"),
+                            Label::LabelRequired => Some("LABEL_REQUIRED"),
+                            Label::LabelRepeated => Some("LABEL_REPEATED"),
+                        }
+                    }
+                    /// The value declared as `name`, aliases included.
+                    pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            "LABEL_OPTIONAL" => Some(Label::LabelOptional),
+                            "LABEL_REQUIRED" => Some(Label::LabelRequired),
+                            "LABEL_REPEATED" => Some(Label::LabelRepeated),
+                            _ => None,
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<i32> for Label {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(
+                        value: i32,
+                    ) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            1i32 => Ok(Label::LabelOptional),
+                            2i32 => Ok(Label::LabelRequired),
+                            3i32 => Ok(Label::LabelRepeated),
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for Label {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+                impl ::core::convert::From<Label> for i32 {
+                    fn from(value: Label) -> i32 {
+                        match value {
+                            Label::LabelOptional => 1i32,
+                            Label::LabelRequired => 2i32,
+                            Label::LabelRepeated => 3i32,
+                        }
+                    }
+                }
+            }
+            pub mod field_options {
+                use ::tacky;
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+                pub enum CType {
+                    #[default]
+                    /// Default mode.
+                    String,
+                    Cord,
+                    StringPiece,
+                }
+                impl CType {
+                    /// Every value declared, aliases aside, in declaration order.
+                    pub const VALUES: &'static [CType] = &[
+                        CType::String,
+                        CType::Cord,
+                        CType::StringPiece,
+                    ];
+                    /// The value's name as declared, or `None` for a number the enum doesn't declare.
+                    pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                        match self {
+                            CType::String => Some("STRING"),
+                            CType::Cord => Some("CORD"),
+                            CType::StringPiece => Some("STRING_PIECE"),
+                        }
+                    }
+                    /// The value declared as `name`, aliases included.
+                    pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            "STRING" => Some(CType::String),
+                            "CORD" => Some(CType::Cord),
+                            "STRING_PIECE" => Some(CType::StringPiece),
+                            _ => None,
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<i32> for CType {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(
+                        value: i32,
+                    ) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            0i32 => Ok(CType::String),
+                            1i32 => Ok(CType::Cord),
+                            2i32 => Ok(CType::StringPiece),
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for CType {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+                impl ::core::convert::From<CType> for i32 {
+                    fn from(value: CType) -> i32 {
+                        match value {
+                            CType::String => 0i32,
+                            CType::Cord => 1i32,
+                            CType::StringPiece => 2i32,
+                        }
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+                pub enum JSType {
+                    #[default]
+                    /// Use the default type.
+                    JsNormal,
+                    /// Use JavaScript strings.
+                    JsString,
+                    /// Use JavaScript numbers.
+                    JsNumber,
+                }
+                impl JSType {
+                    /// Every value declared, aliases aside, in declaration order.
+                    pub const VALUES: &'static [JSType] = &[
+                        JSType::JsNormal,
+                        JSType::JsString,
+                        JSType::JsNumber,
+                    ];
+                    /// The value's name as declared, or `None` for a number the enum doesn't declare.
+                    pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                        match self {
+                            JSType::JsNormal => Some("JS_NORMAL"),
+                            JSType::JsString => Some("JS_STRING"),
+                            JSType::JsNumber => Some("JS_NUMBER"),
+                        }
+                    }
+                    /// The value declared as `name`, aliases included.
+                    pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            "JS_NORMAL" => Some(JSType::JsNormal),
+                            "JS_STRING" => Some(JSType::JsString),
+                            "JS_NUMBER" => Some(JSType::JsNumber),
+                            _ => None,
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<i32> for JSType {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(
+                        value: i32,
+                    ) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            0i32 => Ok(JSType::JsNormal),
+                            1i32 => Ok(JSType::JsString),
+                            2i32 => Ok(JSType::JsNumber),
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for JSType {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+                impl ::core::convert::From<JSType> for i32 {
+                    fn from(value: JSType) -> i32 {
+                        match value {
+                            JSType::JsNormal => 0i32,
+                            JSType::JsString => 1i32,
+                            JSType::JsNumber => 2i32,
+                        }
+                    }
+                }
+            }
+            pub mod file_options {
+                use ::tacky;
+                /// Generated classes can be optimized for speed or code size.
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+                pub enum OptimizeMode {
+                    #[default]
+                    /// Generate complete code for parsing, serialization,
+                    Speed,
+                    /// etc.
+                    ///
+                    /// Use ReflectionOps to implement these methods.
+                    CodeSize,
+                    /// Generate code using MessageLite and the lite runtime.
+                    LiteRuntime,
+                }
+                impl OptimizeMode {
+                    /// Every value declared, aliases aside, in declaration order.
+                    pub const VALUES: &'static [OptimizeMode] = &[
+                        OptimizeMode::Speed,
+                        OptimizeMode::CodeSize,
+                        OptimizeMode::LiteRuntime,
+                    ];
+                    /// The value's name as declared, or `None` for a number the enum doesn't declare.
+                    pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                        match self {
+                            OptimizeMode::Speed => Some("SPEED"),
+                            OptimizeMode::CodeSize => Some("CODE_SIZE"),
+                            OptimizeMode::LiteRuntime => Some("LITE_RUNTIME"),
+                        }
+                    }
+                    /// The value declared as `name`, aliases included.
+                    pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            "SPEED" => Some(OptimizeMode::Speed),
+                            "CODE_SIZE" => Some(OptimizeMode::CodeSize),
+                            "LITE_RUNTIME" => Some(OptimizeMode::LiteRuntime),
+                            _ => None,
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<i32> for OptimizeMode {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(
+                        value: i32,
+                    ) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            1i32 => Ok(OptimizeMode::Speed),
+                            2i32 => Ok(OptimizeMode::CodeSize),
+                            3i32 => Ok(OptimizeMode::LiteRuntime),
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for OptimizeMode {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+                impl ::core::convert::From<OptimizeMode> for i32 {
+                    fn from(value: OptimizeMode) -> i32 {
+                        match value {
+                            OptimizeMode::Speed => 1i32,
+                            OptimizeMode::CodeSize => 2i32,
+                            OptimizeMode::LiteRuntime => 3i32,
+                        }
+                    }
+                }
+            }
+            pub mod generated_code_info {
+                use ::tacky;
+                #[derive(Debug, Copy, Clone)]
+                pub struct Annotation {
+                    /// Identifies the element in the original source .proto file. This field
+                    /// is formatted the same as SourceCodeInfo.Location.path.
+                    pub path: tacky::Field<1, tacky::Packed<tacky::Int32>>,
+                    /// Identifies the filesystem path to the original source .proto.
+                    pub source_file: tacky::Field<2, tacky::Optional<tacky::PbString>>,
+                    /// Identifies the starting offset in bytes in the generated code
+                    /// that relates to the identified object.
+                    pub begin: tacky::Field<3, tacky::Optional<tacky::Int32>>,
+                    /// Identifies the ending offset in bytes in the generated code that
+                    /// relates to the identified offset. The end offset should be one past
+                    /// the last relevant byte (so the length of the text = end - begin).
+                    pub end: tacky::Field<4, tacky::Optional<tacky::Int32>>,
+                }
+                impl tacky::MessageSchema for Annotation {}
+                impl tacky::DecodeMessage for Annotation {
+                    type Fields<'a> = AnnotationFields<'a>;
+                }
+                impl Annotation {
+                    pub fn schema() -> Self {
+                        <Self as tacky::MessageSchema>::schema()
+                    }
+                    pub fn decode(buf: &[u8]) -> AnnotationFields<'_> {
+                        AnnotationFields::new(buf)
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub enum AnnotationField<'a> {
+                    /// Identifies the element in the original source .proto file. This field
+                    /// is formatted the same as SourceCodeInfo.Location.path.
+                    Path(tacky::packed::PackedIter<'a, tacky::Int32>),
+                    /// Identifies the filesystem path to the original source .proto.
+                    SourceFile(&'a str),
+                    /// Identifies the starting offset in bytes in the generated code
+                    /// that relates to the identified object.
+                    Begin(i32),
+                    /// Identifies the ending offset in bytes in the generated code that
+                    /// relates to the identified offset. The end offset should be one past
+                    /// the last relevant byte (so the length of the text = end - begin).
+                    End(i32),
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub struct AnnotationFields<'a> {
+                    buf: &'a [u8],
+                }
+                #[allow(deprecated)]
+                impl<'a> AnnotationField<'a> {
+                    /// The field's name in JSON; `None` for an extension.
+                    pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                        match *self {
+                            AnnotationField::Path(_) => Some("path"),
+                            AnnotationField::SourceFile(_) => Some("sourceFile"),
+                            AnnotationField::Begin(_) => Some("begin"),
+                            AnnotationField::End(_) => Some("end"),
+                        }
+                    }
+                }
+                impl<'a> AnnotationFields<'a> {
+                    pub fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                }
+                impl<'a> tacky::MessageFields<'a> for AnnotationFields<'a> {
+                    type Schema = Annotation;
+                    fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                    fn as_bytes(&self) -> &'a [u8] {
+                        self.buf
+                    }
+                }
+                #[allow(deprecated)]
+                impl<'a> ::core::iter::Iterator for AnnotationFields<'a> {
+                    type Item = ::core::result::Result<
+                        AnnotationField<'a>,
+                        tacky::DecodeError,
+                    >;
+                    /// Yields one field per call, and **stops at the first error**: a malformed
+                    /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                    /// re-reading the same bytes would fail identically forever. Every error path
+                    /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                    /// `for` loop over hostile input terminates.
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        loop {
+                            if self.buf.is_empty() {
+                                return None;
+                            }
+                            let buf = &mut self.buf;
+                            let (tag, wire_type) = match tacky::decode_key(buf) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            };
+                            match tag {
+                                1u32 => {
+                                    let decoded = (|| {
+                                        if wire_type == tacky::WireType::LEN {
+                                            let data = tacky::decode_len(buf)?;
+                                            Ok(
+                                                AnnotationField::Path(
+                                                    tacky::packed::PackedIter::<tacky::Int32>::new(data),
+                                                ),
+                                            )
+                                        } else {
+                                            tacky::check_wire_type(
+                                                wire_type,
+                                                tacky::WireType::VARINT,
+                                                "path",
+                                            )?;
+                                            let start = *buf;
+                                            <tacky::Int32 as tacky::ProtobufScalar>::read_known(buf)?;
+                                            let data = &start[..start.len() - buf.len()];
+                                            Ok(
+                                                AnnotationField::Path(
+                                                    tacky::packed::PackedIter::<tacky::Int32>::new(data),
+                                                ),
+                                            )
+                                        }
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                2u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "source_file",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(AnnotationField::SourceFile(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                3u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "begin",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(AnnotationField::Begin(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                4u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "end",
+                                        )?;
+                                        let val = <tacky::Int32 as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(AnnotationField::End(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                _ => {
+                                    match tacky::skip_field(wire_type, buf) {
+                                        Ok(()) => continue,
+                                        Err(e) => {
+                                            self.buf = &[];
+                                            return Some(Err(e));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq, Default)]
+                pub struct AnnotationOwned {
+                    /// Identifies the element in the original source .proto file. This field
+                    /// is formatted the same as SourceCodeInfo.Location.path.
+                    pub path: ::std::vec::Vec<i32>,
+                    /// Identifies the filesystem path to the original source .proto.
+                    pub source_file: ::core::option::Option<::std::string::String>,
+                    /// Identifies the starting offset in bytes in the generated code
+                    /// that relates to the identified object.
+                    pub begin: ::core::option::Option<i32>,
+                    /// Identifies the ending offset in bytes in the generated code that
+                    /// relates to the identified offset. The end offset should be one past
+                    /// the last relevant byte (so the length of the text = end - begin).
+                    pub end: ::core::option::Option<i32>,
+                }
+                #[allow(deprecated)]
+                impl AnnotationOwned {
+                    /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                    /// or, for a message, are merged into one; a missing required field is an error.
+                    pub fn from_bytes(
+                        buf: &[u8],
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        Self::from_fields(Annotation::decode(buf))
+                    }
+                    pub fn from_fields(
+                        fields: AnnotationFields<'_>,
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        let mut out = Self::default();
+                        out.merge_fields(fields)?;
+                        Ok(out)
+                    }
+                    /// Decodes a message into this one, as a later occurrence of it would be.
+                    pub fn merge_fields(
+                        &mut self,
+                        fields: AnnotationFields<'_>,
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        for field in fields {
+                            match field? {
+                                AnnotationField::Path(v) => {
+                                    for v in v {
+                                        self.path.push(v?);
+                                    }
+                                }
+                                AnnotationField::SourceFile(v) => {
+                                    self.source_file = Some(v.to_owned());
+                                }
+                                AnnotationField::Begin(v) => {
+                                    self.begin = Some(v);
+                                }
+                                AnnotationField::End(v) => {
+                                    self.end = Some(v);
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                        self.encode_dir(tacky::AnyDir::from_mut(buf));
+                    }
+                    /// Repeated fields need their direction known where they are written, and `encode`
+                    /// is generic over it, so the writes go through the direction-erased view. Public
+                    /// for the messages holding this one, which may be generated in other modules.
+                    #[doc(hidden)]
+                    pub fn encode_dir<B: tacky::WriteBuf>(
+                        &self,
+                        buf: &mut tacky::AnyDir<B>,
+                    ) {
+                        let schema = Annotation::schema();
+                        schema.path.write(buf, &self.path);
+                        schema.source_file.write(buf, self.source_file.as_ref());
+                        schema.begin.write(buf, self.begin.as_ref());
+                        schema.end.write(buf, self.end.as_ref());
+                    }
+                }
+            }
+            pub mod method_options {
+                use ::tacky;
+                /// Is this method side-effect-free (or safe in HTTP parlance), or idempotent,
+                /// or neither? HTTP based RPC implementation may choose GET verb for safe
+                /// methods, and PUT verb for idempotent methods instead of the default POST.
+                #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+                pub enum IdempotencyLevel {
+                    #[default]
+                    IdempotencyUnknown,
+                    /// implies idempotent
+                    NoSideEffects,
+                    /// idempotent, but may have side effects
+                    Idempotent,
+                }
+                impl IdempotencyLevel {
+                    /// Every value declared, aliases aside, in declaration order.
+                    pub const VALUES: &'static [IdempotencyLevel] = &[
+                        IdempotencyLevel::IdempotencyUnknown,
+                        IdempotencyLevel::NoSideEffects,
+                        IdempotencyLevel::Idempotent,
+                    ];
+                    /// The value's name as declared, or `None` for a number the enum doesn't declare.
+                    pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                        match self {
+                            IdempotencyLevel::IdempotencyUnknown => {
+                                Some("IDEMPOTENCY_UNKNOWN")
+                            }
+                            IdempotencyLevel::NoSideEffects => Some("NO_SIDE_EFFECTS"),
+                            IdempotencyLevel::Idempotent => Some("IDEMPOTENT"),
+                        }
+                    }
+                    /// The value declared as `name`, aliases included.
+                    pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                        match name {
+                            "IDEMPOTENCY_UNKNOWN" => {
+                                Some(IdempotencyLevel::IdempotencyUnknown)
+                            }
+                            "NO_SIDE_EFFECTS" => Some(IdempotencyLevel::NoSideEffects),
+                            "IDEMPOTENT" => Some(IdempotencyLevel::Idempotent),
+                            _ => None,
+                        }
+                    }
+                }
+                impl ::core::convert::TryFrom<i32> for IdempotencyLevel {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(
+                        value: i32,
+                    ) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            0i32 => Ok(IdempotencyLevel::IdempotencyUnknown),
+                            1i32 => Ok(IdempotencyLevel::NoSideEffects),
+                            2i32 => Ok(IdempotencyLevel::Idempotent),
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for IdempotencyLevel {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+                impl ::core::convert::From<IdempotencyLevel> for i32 {
+                    fn from(value: IdempotencyLevel) -> i32 {
+                        match value {
+                            IdempotencyLevel::IdempotencyUnknown => 0i32,
+                            IdempotencyLevel::NoSideEffects => 1i32,
+                            IdempotencyLevel::Idempotent => 2i32,
+                        }
+                    }
+                }
+            }
+            pub mod source_code_info {
+                use ::tacky;
+                #[derive(Debug, Copy, Clone)]
+                pub struct Location {
+                    /// Identifies which part of the FileDescriptorProto was defined at this
+                    /// location.
+                    ///
+                    /// Each element is a field number or an index.  They form a path from
+                    /// the root FileDescriptorProto to the place where the definition occurs.
+                    /// For example, this path:
+                    /// \[ 4, 3, 2, 7, 1 \]
+                    /// refers to:
+                    /// file.message_type(3)  // 4, 3
+                    /// .field(7)         // 2, 7
+                    /// .name()           // 1
+                    /// This is because FileDescriptorProto.message_type has field number 4:
+                    /// repeated DescriptorProto message_type = 4;
+                    /// and DescriptorProto.field has field number 2:
+                    /// repeated FieldDescriptorProto field = 2;
+                    /// and FieldDescriptorProto.name has field number 1:
+                    /// optional string name = 1;
+                    ///
+                    /// Thus, the above path gives the location of a field name.  If we removed
+                    /// the last element:
+                    /// \[ 4, 3, 2, 7 \]
+                    /// this path refers to the whole field declaration (from the beginning
+                    /// of the label to the terminating semicolon).
+                    pub path: tacky::Field<1, tacky::Packed<tacky::Int32>>,
+                    /// Always has exactly three or four elements: start line, start column,
+                    /// end line (optional, otherwise assumed same as start line), end column.
+                    /// These are packed into a single field for efficiency.  Note that line
+                    /// and column numbers are zero-based -- typically you will want to add
+                    /// 1 to each before displaying to a user.
+                    pub span: tacky::Field<2, tacky::Packed<tacky::Int32>>,
+                    /// If this SourceCodeInfo represents a complete declaration, these are any
+                    /// comments appearing before and after the declaration which appear to be
+                    /// attached to the declaration.
+                    ///
+                    /// A series of line comments appearing on consecutive lines, with no other
+                    /// tokens appearing on those lines, will be treated as a single comment.
+                    ///
+                    /// leading_detached_comments will keep paragraphs of comments that appear
+                    /// before (but not connected to) the current element. Each paragraph,
+                    /// separated by empty lines, will be one comment element in the repeated
+                    /// field.
+                    ///
+                    /// Only the comment content is provided; comment markers (e.g. //) are
+                    /// stripped out.  For block comments, leading whitespace and an asterisk
+                    /// will be stripped from the beginning of each line other than the first.
+                    /// Newlines are included in the output.
+                    ///
+                    /// Examples:
+                    ///
+                    /// optional int32 foo = 1;  // Comment attached to foo.
+                    /// // Comment attached to bar.
+                    /// optional int32 bar = 2;
+                    ///
+                    /// optional string baz = 3;
+                    /// // Comment attached to baz.
+                    /// // Another line attached to baz.
+                    ///
+                    /// // Comment attached to qux.
+                    /// //
+                    /// // Another line attached to qux.
+                    /// optional double qux = 4;
+                    ///
+                    /// // Detached comment for corge. This is not leading or trailing comments
+                    /// // to qux or corge because there are blank lines separating it from
+                    /// // both.
+                    ///
+                    /// // Detached comment for corge paragraph 2.
+                    ///
+                    /// optional string corge = 5;
+                    /// /* Block comment attached
+                    /// * to corge.  Leading asterisks
+                    /// * will be removed. */
+                    /// /* Block comment attached to
+                    /// * grault. */
+                    /// optional int32 grault = 6;
+                    ///
+                    /// // ignored detached comments.
+                    pub leading_comments: tacky::Field<
+                        3,
+                        tacky::Optional<tacky::PbString>,
+                    >,
+                    pub trailing_comments: tacky::Field<
+                        4,
+                        tacky::Optional<tacky::PbString>,
+                    >,
+                    pub leading_detached_comments: tacky::Field<
+                        6,
+                        tacky::Repeated<tacky::PbString>,
+                    >,
+                }
+                impl tacky::MessageSchema for Location {}
+                impl tacky::DecodeMessage for Location {
+                    type Fields<'a> = LocationFields<'a>;
+                }
+                impl Location {
+                    pub fn schema() -> Self {
+                        <Self as tacky::MessageSchema>::schema()
+                    }
+                    pub fn decode(buf: &[u8]) -> LocationFields<'_> {
+                        LocationFields::new(buf)
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub enum LocationField<'a> {
+                    /// Identifies which part of the FileDescriptorProto was defined at this
+                    /// location.
+                    ///
+                    /// Each element is a field number or an index.  They form a path from
+                    /// the root FileDescriptorProto to the place where the definition occurs.
+                    /// For example, this path:
+                    /// \[ 4, 3, 2, 7, 1 \]
+                    /// refers to:
+                    /// file.message_type(3)  // 4, 3
+                    /// .field(7)         // 2, 7
+                    /// .name()           // 1
+                    /// This is because FileDescriptorProto.message_type has field number 4:
+                    /// repeated DescriptorProto message_type = 4;
+                    /// and DescriptorProto.field has field number 2:
+                    /// repeated FieldDescriptorProto field = 2;
+                    /// and FieldDescriptorProto.name has field number 1:
+                    /// optional string name = 1;
+                    ///
+                    /// Thus, the above path gives the location of a field name.  If we removed
+                    /// the last element:
+                    /// \[ 4, 3, 2, 7 \]
+                    /// this path refers to the whole field declaration (from the beginning
+                    /// of the label to the terminating semicolon).
+                    Path(tacky::packed::PackedIter<'a, tacky::Int32>),
+                    /// Always has exactly three or four elements: start line, start column,
+                    /// end line (optional, otherwise assumed same as start line), end column.
+                    /// These are packed into a single field for efficiency.  Note that line
+                    /// and column numbers are zero-based -- typically you will want to add
+                    /// 1 to each before displaying to a user.
+                    Span(tacky::packed::PackedIter<'a, tacky::Int32>),
+                    /// If this SourceCodeInfo represents a complete declaration, these are any
+                    /// comments appearing before and after the declaration which appear to be
+                    /// attached to the declaration.
+                    ///
+                    /// A series of line comments appearing on consecutive lines, with no other
+                    /// tokens appearing on those lines, will be treated as a single comment.
+                    ///
+                    /// leading_detached_comments will keep paragraphs of comments that appear
+                    /// before (but not connected to) the current element. Each paragraph,
+                    /// separated by empty lines, will be one comment element in the repeated
+                    /// field.
+                    ///
+                    /// Only the comment content is provided; comment markers (e.g. //) are
+                    /// stripped out.  For block comments, leading whitespace and an asterisk
+                    /// will be stripped from the beginning of each line other than the first.
+                    /// Newlines are included in the output.
+                    ///
+                    /// Examples:
+                    ///
+                    /// optional int32 foo = 1;  // Comment attached to foo.
+                    /// // Comment attached to bar.
+                    /// optional int32 bar = 2;
+                    ///
+                    /// optional string baz = 3;
+                    /// // Comment attached to baz.
+                    /// // Another line attached to baz.
+                    ///
+                    /// // Comment attached to qux.
+                    /// //
+                    /// // Another line attached to qux.
+                    /// optional double qux = 4;
+                    ///
+                    /// // Detached comment for corge. This is not leading or trailing comments
+                    /// // to qux or corge because there are blank lines separating it from
+                    /// // both.
+                    ///
+                    /// // Detached comment for corge paragraph 2.
+                    ///
+                    /// optional string corge = 5;
+                    /// /* Block comment attached
+                    /// * to corge.  Leading asterisks
+                    /// * will be removed. */
+                    /// /* Block comment attached to
+                    /// * grault. */
+                    /// optional int32 grault = 6;
+                    ///
+                    /// // ignored detached comments.
+                    LeadingComments(&'a str),
+                    TrailingComments(&'a str),
+                    LeadingDetachedComments(&'a str),
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub struct LocationFields<'a> {
+                    buf: &'a [u8],
+                }
+                #[allow(deprecated)]
+                impl<'a> LocationField<'a> {
+                    /// The field's name in JSON; `None` for an extension.
+                    pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                        match *self {
+                            LocationField::Path(_) => Some("path"),
+                            LocationField::Span(_) => Some("span"),
+                            LocationField::LeadingComments(_) => Some("leadingComments"),
+                            LocationField::TrailingComments(_) => {
+                                Some("trailingComments")
+                            }
+                            LocationField::LeadingDetachedComments(_) => {
+                                Some("leadingDetachedComments")
+                            }
+                        }
+                    }
+                }
+                impl<'a> LocationFields<'a> {
+                    pub fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                }
+                impl<'a> tacky::MessageFields<'a> for LocationFields<'a> {
+                    type Schema = Location;
+                    fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                    fn as_bytes(&self) -> &'a [u8] {
+                        self.buf
+                    }
+                }
+                #[allow(deprecated)]
+                impl<'a> ::core::iter::Iterator for LocationFields<'a> {
+                    type Item = ::core::result::Result<
+                        LocationField<'a>,
+                        tacky::DecodeError,
+                    >;
+                    /// Yields one field per call, and **stops at the first error**: a malformed
+                    /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                    /// re-reading the same bytes would fail identically forever. Every error path
+                    /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                    /// `for` loop over hostile input terminates.
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        loop {
+                            if self.buf.is_empty() {
+                                return None;
+                            }
+                            let buf = &mut self.buf;
+                            let (tag, wire_type) = match tacky::decode_key(buf) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            };
+                            match tag {
+                                1u32 => {
+                                    let decoded = (|| {
+                                        if wire_type == tacky::WireType::LEN {
+                                            let data = tacky::decode_len(buf)?;
+                                            Ok(
+                                                LocationField::Path(
+                                                    tacky::packed::PackedIter::<tacky::Int32>::new(data),
+                                                ),
+                                            )
+                                        } else {
+                                            tacky::check_wire_type(
+                                                wire_type,
+                                                tacky::WireType::VARINT,
+                                                "path",
+                                            )?;
+                                            let start = *buf;
+                                            <tacky::Int32 as tacky::ProtobufScalar>::read_known(buf)?;
+                                            let data = &start[..start.len() - buf.len()];
+                                            Ok(
+                                                LocationField::Path(
+                                                    tacky::packed::PackedIter::<tacky::Int32>::new(data),
+                                                ),
+                                            )
+                                        }
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                2u32 => {
+                                    let decoded = (|| {
+                                        if wire_type == tacky::WireType::LEN {
+                                            let data = tacky::decode_len(buf)?;
+                                            Ok(
+                                                LocationField::Span(
+                                                    tacky::packed::PackedIter::<tacky::Int32>::new(data),
+                                                ),
+                                            )
+                                        } else {
+                                            tacky::check_wire_type(
+                                                wire_type,
+                                                tacky::WireType::VARINT,
+                                                "span",
+                                            )?;
+                                            let start = *buf;
+                                            <tacky::Int32 as tacky::ProtobufScalar>::read_known(buf)?;
+                                            let data = &start[..start.len() - buf.len()];
+                                            Ok(
+                                                LocationField::Span(
+                                                    tacky::packed::PackedIter::<tacky::Int32>::new(data),
+                                                ),
+                                            )
+                                        }
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                3u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "leading_comments",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(LocationField::LeadingComments(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                4u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "trailing_comments",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(LocationField::TrailingComments(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                6u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "leading_detached_comments",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(LocationField::LeadingDetachedComments(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                _ => {
+                                    match tacky::skip_field(wire_type, buf) {
+                                        Ok(()) => continue,
+                                        Err(e) => {
+                                            self.buf = &[];
+                                            return Some(Err(e));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq, Default)]
+                pub struct LocationOwned {
+                    /// Identifies which part of the FileDescriptorProto was defined at this
+                    /// location.
+                    ///
+                    /// Each element is a field number or an index.  They form a path from
+                    /// the root FileDescriptorProto to the place where the definition occurs.
+                    /// For example, this path:
+                    /// \[ 4, 3, 2, 7, 1 \]
+                    /// refers to:
+                    /// file.message_type(3)  // 4, 3
+                    /// .field(7)         // 2, 7
+                    /// .name()           // 1
+                    /// This is because FileDescriptorProto.message_type has field number 4:
+                    /// repeated DescriptorProto message_type = 4;
+                    /// and DescriptorProto.field has field number 2:
+                    /// repeated FieldDescriptorProto field = 2;
+                    /// and FieldDescriptorProto.name has field number 1:
+                    /// optional string name = 1;
+                    ///
+                    /// Thus, the above path gives the location of a field name.  If we removed
+                    /// the last element:
+                    /// \[ 4, 3, 2, 7 \]
+                    /// this path refers to the whole field declaration (from the beginning
+                    /// of the label to the terminating semicolon).
+                    pub path: ::std::vec::Vec<i32>,
+                    /// Always has exactly three or four elements: start line, start column,
+                    /// end line (optional, otherwise assumed same as start line), end column.
+                    /// These are packed into a single field for efficiency.  Note that line
+                    /// and column numbers are zero-based -- typically you will want to add
+                    /// 1 to each before displaying to a user.
+                    pub span: ::std::vec::Vec<i32>,
+                    /// If this SourceCodeInfo represents a complete declaration, these are any
+                    /// comments appearing before and after the declaration which appear to be
+                    /// attached to the declaration.
+                    ///
+                    /// A series of line comments appearing on consecutive lines, with no other
+                    /// tokens appearing on those lines, will be treated as a single comment.
+                    ///
+                    /// leading_detached_comments will keep paragraphs of comments that appear
+                    /// before (but not connected to) the current element. Each paragraph,
+                    /// separated by empty lines, will be one comment element in the repeated
+                    /// field.
+                    ///
+                    /// Only the comment content is provided; comment markers (e.g. //) are
+                    /// stripped out.  For block comments, leading whitespace and an asterisk
+                    /// will be stripped from the beginning of each line other than the first.
+                    /// Newlines are included in the output.
+                    ///
+                    /// Examples:
+                    ///
+                    /// optional int32 foo = 1;  // Comment attached to foo.
+                    /// // Comment attached to bar.
+                    /// optional int32 bar = 2;
+                    ///
+                    /// optional string baz = 3;
+                    /// // Comment attached to baz.
+                    /// // Another line attached to baz.
+                    ///
+                    /// // Comment attached to qux.
+                    /// //
+                    /// // Another line attached to qux.
+                    /// optional double qux = 4;
+                    ///
+                    /// // Detached comment for corge. This is not leading or trailing comments
+                    /// // to qux or corge because there are blank lines separating it from
+                    /// // both.
+                    ///
+                    /// // Detached comment for corge paragraph 2.
+                    ///
+                    /// optional string corge = 5;
+                    /// /* Block comment attached
+                    /// * to corge.  Leading asterisks
+                    /// * will be removed. */
+                    /// /* Block comment attached to
+                    /// * grault. */
+                    /// optional int32 grault = 6;
+                    ///
+                    /// // ignored detached comments.
+                    pub leading_comments: ::core::option::Option<::std::string::String>,
+                    pub trailing_comments: ::core::option::Option<::std::string::String>,
+                    pub leading_detached_comments: ::std::vec::Vec<
+                        ::std::string::String,
+                    >,
+                }
+                #[allow(deprecated)]
+                impl LocationOwned {
+                    /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                    /// or, for a message, are merged into one; a missing required field is an error.
+                    pub fn from_bytes(
+                        buf: &[u8],
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        Self::from_fields(Location::decode(buf))
+                    }
+                    pub fn from_fields(
+                        fields: LocationFields<'_>,
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        let mut out = Self::default();
+                        out.merge_fields(fields)?;
+                        Ok(out)
+                    }
+                    /// Decodes a message into this one, as a later occurrence of it would be.
+                    pub fn merge_fields(
+                        &mut self,
+                        fields: LocationFields<'_>,
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        for field in fields {
+                            match field? {
+                                LocationField::Path(v) => {
+                                    for v in v {
+                                        self.path.push(v?);
+                                    }
+                                }
+                                LocationField::Span(v) => {
+                                    for v in v {
+                                        self.span.push(v?);
+                                    }
+                                }
+                                LocationField::LeadingComments(v) => {
+                                    self.leading_comments = Some(v.to_owned());
+                                }
+                                LocationField::TrailingComments(v) => {
+                                    self.trailing_comments = Some(v.to_owned());
+                                }
+                                LocationField::LeadingDetachedComments(v) => {
+                                    self.leading_detached_comments.push(v.to_owned());
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                        self.encode_dir(tacky::AnyDir::from_mut(buf));
+                    }
+                    /// Repeated fields need their direction known where they are written, and `encode`
+                    /// is generic over it, so the writes go through the direction-erased view. Public
+                    /// for the messages holding this one, which may be generated in other modules.
+                    #[doc(hidden)]
+                    pub fn encode_dir<B: tacky::WriteBuf>(
+                        &self,
+                        buf: &mut tacky::AnyDir<B>,
+                    ) {
+                        let schema = Location::schema();
+                        schema.path.write(buf, &self.path);
+                        schema.span.write(buf, &self.span);
+                        schema
+                            .leading_comments
+                            .write(buf, self.leading_comments.as_ref());
+                        schema
+                            .trailing_comments
+                            .write(buf, self.trailing_comments.as_ref());
+                        schema
+                            .leading_detached_comments
+                            .write(buf, &self.leading_detached_comments);
+                    }
+                }
+            }
+            pub mod uninterpreted_option {
+                use ::tacky;
+                /// The name of the uninterpreted option.  Each string represents a segment in
+                /// a dot-separated name.  is_extension is true iff a segment represents an
+                /// extension (denoted with parentheses in options specs in .proto files).
+                /// E.g.,{ \["foo", false\], \["bar.baz", true\], \["qux", false\] } represents
+                /// "foo.(bar.baz).qux".
+                #[derive(Debug, Copy, Clone)]
+                pub struct NamePart {
+                    pub name_part: tacky::Field<1, tacky::Required<tacky::PbString>>,
+                    pub is_extension: tacky::Field<2, tacky::Required<tacky::Bool>>,
+                }
+                impl tacky::MessageSchema for NamePart {}
+                impl tacky::DecodeMessage for NamePart {
+                    type Fields<'a> = NamePartFields<'a>;
+                }
+                impl NamePart {
+                    pub fn schema() -> Self {
+                        <Self as tacky::MessageSchema>::schema()
+                    }
+                    pub fn decode(buf: &[u8]) -> NamePartFields<'_> {
+                        NamePartFields::new(buf)
+                    }
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub enum NamePartField<'a> {
+                    NamePart(&'a str),
+                    IsExtension(bool),
+                }
+                #[derive(Debug, Copy, Clone, PartialEq)]
+                pub struct NamePartFields<'a> {
+                    buf: &'a [u8],
+                }
+                #[allow(deprecated)]
+                impl<'a> NamePartField<'a> {
+                    /// The field's name in JSON; `None` for an extension.
+                    pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                        match *self {
+                            NamePartField::NamePart(_) => Some("namePart"),
+                            NamePartField::IsExtension(_) => Some("isExtension"),
+                        }
+                    }
+                }
+                impl<'a> NamePartFields<'a> {
+                    pub fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                }
+                impl<'a> tacky::MessageFields<'a> for NamePartFields<'a> {
+                    type Schema = NamePart;
+                    fn new(buf: &'a [u8]) -> Self {
+                        Self { buf }
+                    }
+                    fn as_bytes(&self) -> &'a [u8] {
+                        self.buf
+                    }
+                }
+                #[allow(deprecated)]
+                impl<'a> ::core::iter::Iterator for NamePartFields<'a> {
+                    type Item = ::core::result::Result<
+                        NamePartField<'a>,
+                        tacky::DecodeError,
+                    >;
+                    /// Yields one field per call, and **stops at the first error**: a malformed
+                    /// field leaves the cursor mid-value, so there is nothing to resync to, and
+                    /// re-reading the same bytes would fail identically forever. Every error path
+                    /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+                    /// `for` loop over hostile input terminates.
+                    fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                        loop {
+                            if self.buf.is_empty() {
+                                return None;
+                            }
+                            let buf = &mut self.buf;
+                            let (tag, wire_type) = match tacky::decode_key(buf) {
+                                Ok(t) => t,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            };
+                            match tag {
+                                1u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::LEN,
+                                            "name_part",
+                                        )?;
+                                        let val = <tacky::PbString as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(NamePartField::NamePart(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                2u32 => {
+                                    let decoded = (|| {
+                                        tacky::check_wire_type(
+                                            wire_type,
+                                            tacky::WireType::VARINT,
+                                            "is_extension",
+                                        )?;
+                                        let val = <tacky::Bool as tacky::ProtobufScalar>::read(
+                                            buf,
+                                        )?;
+                                        Ok(NamePartField::IsExtension(val))
+                                    })();
+                                    if decoded.is_err() {
+                                        self.buf = &[];
+                                    }
+                                    return Some(decoded);
+                                }
+                                _ => {
+                                    match tacky::skip_field(wire_type, buf) {
+                                        Ok(()) => continue,
+                                        Err(e) => {
+                                            self.buf = &[];
+                                            return Some(Err(e));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq, Default)]
+                pub struct NamePartOwned {
+                    pub name_part: ::std::string::String,
+                    pub is_extension: bool,
+                }
+                #[allow(deprecated)]
+                impl NamePartOwned {
+                    /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+                    /// or, for a message, are merged into one; a missing required field is an error.
+                    pub fn from_bytes(
+                        buf: &[u8],
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        Self::from_fields(NamePart::decode(buf))
+                    }
+                    /// Fails with `MissingField` if a required field isn't there.
+                    pub fn from_fields(
+                        fields: NamePartFields<'_>,
+                    ) -> ::core::result::Result<Self, tacky::DecodeError> {
+                        let mut out = Self::default();
+                        let mut seen = [false; 2usize];
+                        out.merge_seen(fields, &mut seen)?;
+                        if !seen[0usize] {
+                            return Err(tacky::DecodeError::MissingField("name_part"));
+                        }
+                        if !seen[1usize] {
+                            return Err(tacky::DecodeError::MissingField("is_extension"));
+                        }
+                        Ok(out)
+                    }
+                    /// Decodes a message into this one, as a later occurrence of it would be. The
+                    /// required fields needn't all be there, since this one may have them already.
+                    pub fn merge_fields(
+                        &mut self,
+                        fields: NamePartFields<'_>,
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        self.merge_seen(fields, &mut [false; 2usize])
+                    }
+                    fn merge_seen(
+                        &mut self,
+                        fields: NamePartFields<'_>,
+                        seen: &mut [bool; 2usize],
+                    ) -> ::core::result::Result<(), tacky::DecodeError> {
+                        for field in fields {
+                            match field? {
+                                NamePartField::NamePart(v) => {
+                                    seen[0usize] = true;
+                                    self.name_part = v.to_owned();
+                                }
+                                NamePartField::IsExtension(v) => {
+                                    seen[1usize] = true;
+                                    self.is_extension = v;
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                        self.encode_dir(tacky::AnyDir::from_mut(buf));
+                    }
+                    /// Repeated fields need their direction known where they are written, and `encode`
+                    /// is generic over it, so the writes go through the direction-erased view. Public
+                    /// for the messages holding this one, which may be generated in other modules.
+                    #[doc(hidden)]
+                    pub fn encode_dir<B: tacky::WriteBuf>(
+                        &self,
+                        buf: &mut tacky::AnyDir<B>,
+                    ) {
+                        let schema = NamePart::schema();
+                        schema.name_part.write(buf, &self.name_part);
+                        schema.is_extension.write(buf, &self.is_extension);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,645 @@
+//! `.fds` input: a serialized `FileDescriptorSet`, as `protoc --descriptor_set_out` writes
+//! it. Decoded with tacky's own generated descriptor types, then mapped onto the same
+//! [`Field`]/[`PbType`]/[`Label`] model the `.proto` path builds out of pb-rs, so both
+//! inputs go through one codegen.
+//!
+//! protoc has already resolved every name and applied every syntax rule, which leaves
+//! little to do here beyond undoing the map-entry encoding. Files declaring an edition
+//! get the edition 2023 defaults: the vendored descriptor predates `features`.
+//!
+//! Docs come from the files' `SourceCodeInfo`, which protoc only writes with
+//! `--include_source_info`; a set without it generates the same code, undocumented, and
+//! style lints point at the file alone.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::descriptor::google::protobuf::compiler::{
+    field_descriptor_proto::{Label as FieldLabel, Type as FieldType},
+    DescriptorProtoOwned, EnumDescriptorProtoOwned, FieldDescriptorProtoOwned,
+    FileDescriptorProtoOwned, FileDescriptorSetOwned,
+};
+use tacky_codegen::error::{Error, ErrorKind};
+use tacky_codegen::lint::{Level, Style, StyleLints, Violation};
+use tacky_codegen::{
+    check_names, doc_comment, enum_items_for, extension_items, in_package, json_name,
+    message_items_for, runtime_prelude, service_items, type_path, write_formatted, EnumValue,
+    Expansion, Field, Label, ModuleTree, OneOfGroup, PackageOptions, PbType, Scalar, Service,
+    ServiceMethod, Span,
+};
+
+/// Like [`write_proto`](crate::write_proto), from the descriptor set at `fds` rather than
+/// `.proto` source. `file` is the name the set knows the file by, as it was passed to protoc
+/// (e.g. `"simple_message.proto"`); like the `.proto` path, the output covers its imports
+/// too, so the set needs them (`protoc --include_imports`). Panics on anything wrong with
+/// it, as `write_proto` does.
+pub fn write_fds(fds: &str, file: &str, output: &str) {
+    let tokens = std::fs::read(fds)
+        .map_err(|e| Error::from(ErrorKind::Read(e.into())))
+        .and_then(|bytes| fds_tokens(&bytes, file, &PackageOptions::default()))
+        .unwrap_or_else(|e| panic!("{fds}: {e}"));
+    write_formatted(tokens.tokens, output);
+}
+
+pub(crate) fn fds_tokens(
+    bytes: &[u8],
+    file: &str,
+    options: &PackageOptions,
+) -> Result<Expansion, Error> {
+    let set = FileDescriptorSetOwned::from_bytes(bytes)
+        .map_err(|e| ErrorKind::Descriptor(format!("decoding the descriptor set: {e}")))?;
+    file_tokens(&set.file, file, options)
+}
+
+/// The code for `file`, one of `set`, which has to hold everything it imports, generated
+/// with `options`. Options set by path apply; `option_attributes` and `extern_paths` don't,
+/// and style lints only check `file` itself, as the rest is generated on its own.
+pub(crate) fn file_tokens(
+    set: &[FileDescriptorProtoOwned],
+    file: &str,
+    options: &PackageOptions,
+) -> Result<Expansion, Error> {
+    let files: HashMap<&str, &FileDescriptorProtoOwned> =
+        set.iter().map(|f| (name(&f.name), f)).collect();
+    let target = *files
+        .get(file)
+        .ok_or_else(|| ErrorKind::Descriptor(format!("{file} is not in the descriptor set")))?;
+
+    // The target, then everything it imports, depth first: the order pb-rs merges them in.
+    let mut order = Vec::new();
+    imports(target, &files, &mut order)?;
+    let warnings = lint(target, &options.style_lints)?;
+
+    let mut types = Types::default();
+    for f in set {
+        let package = match f.package.as_deref() {
+            Some(p) if !p.is_empty() => format!(".{p}"),
+            _ => String::new(),
+        };
+        types.add(&package, &[], &f.message_type, &f.enum_type);
+    }
+
+    // Messages first, then enums, as the `.proto` path orders them.
+    let mut tree = ModuleTree::default();
+    for f in &order {
+        let info = FileInfo::of(f);
+        types.messages(&f.message_type, &[], &[4], &info, options, &mut tree)?;
+    }
+    for f in &order {
+        let info = FileInfo::of(f);
+        for (i, e) in f.enum_type.iter().enumerate() {
+            tree.push(&[], info.enum_items(e, &[], &[5, i as i32], options)?);
+        }
+        info.nested_enums(&f.message_type, &[], &[4], options, &mut tree)?;
+    }
+
+    // Only the target's own extensions; pb-rs does not carry imported ones over either.
+    let info = FileInfo::of(target);
+    let mut by_extendee: Vec<(String, Vec<Field>)> = Vec::new();
+    let top_level = target
+        .extension
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (vec![7, i as i32], e));
+    let nested = all_messages(&target.message_type, vec![4]).flat_map(|(path, m)| {
+        m.extension
+            .iter()
+            .enumerate()
+            .map(move |(i, e)| ([&path[..], &[6, i as i32]].concat(), e))
+    });
+    for (path, ext) in top_level.chain(nested) {
+        let extendee = types.message_name(ext.extendee.as_deref().unwrap_or_default())?;
+        let mut field = types.field(ext, &[], &path, &info, false)?;
+        // Extensions always track presence, proto3 included.
+        if matches!(field.label, Label::Plain) {
+            field.label = Label::Optional;
+        }
+        match by_extendee.iter_mut().find(|(name, _)| *name == extendee) {
+            Some((_, existing)) => existing.push(field),
+            None => by_extendee.push((extendee, vec![field])),
+        }
+    }
+
+    // Again only the target's.
+    for (i, s) in target.service.iter().enumerate() {
+        let path = [6, i as i32];
+        let service_name = name(&s.name);
+        let full_name = match target.package.as_deref() {
+            Some(p) if !p.is_empty() => format!("{p}.{service_name}"),
+            _ => service_name.to_string(),
+        };
+        let service = service_items(&Service {
+            name: service_name.to_string(),
+            full_name,
+            doc: info.doc(&path),
+            methods: s
+                .method
+                .iter()
+                .enumerate()
+                .map(|(j, m)| {
+                    Ok(ServiceMethod {
+                        name: name(&m.name).to_string(),
+                        doc: info.doc(&[&path[..], &[2, j as i32]].concat()),
+                        input: types.message_name(m.input_type.as_deref().unwrap_or_default())?,
+                        output: types.message_name(m.output_type.as_deref().unwrap_or_default())?,
+                        client_streaming: m.client_streaming.unwrap_or_default(),
+                        server_streaming: m.server_streaming.unwrap_or_default(),
+                        span: None,
+                    })
+                })
+                .collect::<Result<_, Error>>()?,
+        })?;
+        tree.push(&[], service);
+    }
+    tree.push(&[], extension_items(&by_extendee)?);
+
+    let module = match target.package.as_deref() {
+        Some(p) if !p.is_empty() => p.to_string(),
+        // A package-less file is named after itself, as pb-rs does it.
+        _ => std::path::Path::new(file)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(file)
+            .replace(|c: char| !c.is_alphanumeric(), "_"),
+    };
+    let prelude = runtime_prelude(options.runtime_path.as_deref())?;
+    let inner = tree.into_tokens(&prelude)?;
+    let tokens = in_package(&module, quote!(#prelude #inner));
+    check_names(&tokens)?;
+    Ok(Expansion {
+        tokens,
+        files: order.iter().map(|f| PathBuf::from(name(&f.name))).collect(),
+        warnings,
+    })
+}
+
+/// The style lints over `file`'s own declarations, at the levels in `style`.
+fn lint(
+    file: &FileDescriptorProtoOwned,
+    style: &[(Style, Level)],
+) -> Result<Vec<Violation>, Error> {
+    let info = FileInfo::of(file);
+    let mut lints = StyleLints::new(style);
+    let enums = |lints: &mut StyleLints, enums: &[EnumDescriptorProtoOwned], path: &[i32]| {
+        for (i, e) in enums.iter().enumerate() {
+            let path = [path, &[i as i32]].concat();
+            lints.type_name("enum", name(&e.name), &info.span(&path));
+            for (j, v) in e.value.iter().enumerate() {
+                let span = info.span(&[&path[..], &[2, j as i32]].concat());
+                lints.enum_value_name(name(&v.name), &span);
+            }
+        }
+    };
+    for (path, m) in all_messages(&file.message_type, vec![4]) {
+        if is_map_entry(m) {
+            continue;
+        }
+        lints.type_name("message", name(&m.name), &info.span(&path));
+        for (i, f) in m.field.iter().enumerate() {
+            let span = info.span(&[&path[..], &[2, i as i32]].concat());
+            lints.field_name(name(&f.name), &span);
+        }
+        enums(&mut lints, &m.enum_type, &[&path[..], &[4]].concat());
+    }
+    enums(&mut lints, &file.enum_type, &[5]);
+    for (i, s) in file.service.iter().enumerate() {
+        lints.type_name("service", name(&s.name), &info.span(&[6, i as i32]));
+    }
+    lints.finish()
+}
+
+fn imports<'a>(
+    file: &'a FileDescriptorProtoOwned,
+    files: &HashMap<&str, &'a FileDescriptorProtoOwned>,
+    order: &mut Vec<&'a FileDescriptorProtoOwned>,
+) -> Result<(), Error> {
+    if order.iter().any(|o| std::ptr::eq(*o, file)) {
+        return Ok(());
+    }
+    order.push(file);
+    for dep in &file.dependency {
+        let dep = files.get(dep.as_str()).ok_or_else(|| {
+            ErrorKind::Descriptor(format!(
+                "{dep}, imported by {}, is not in the descriptor set; \
+                 pass --include_imports to protoc",
+                name(&file.name)
+            ))
+        })?;
+        imports(dep, files, order)?;
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Syntax {
+    Proto2,
+    Proto3,
+    Editions,
+}
+
+impl Syntax {
+    fn of(file: &FileDescriptorProtoOwned) -> Self {
+        match file.syntax.as_deref() {
+            Some("proto3") => Syntax::Proto3,
+            Some("editions") => Syntax::Editions,
+            _ => Syntax::Proto2,
+        }
+    }
+}
+
+/// What generating a declaration needs to know of the file it is in.
+struct FileInfo {
+    file: PathBuf,
+    syntax: Syntax,
+    /// The package, as full names start with it: `.pkg`, or nothing.
+    package: String,
+    /// The docs for each commented declaration, by its `SourceCodeInfo` path: `[4, 0, 2, 1]`
+    /// is the second field of the first message.
+    docs: HashMap<Vec<i32>, String>,
+    /// Where each declaration starts, 0-based, by the same paths.
+    starts: HashMap<Vec<i32>, (i32, i32)>,
+}
+
+impl FileInfo {
+    fn of(file: &FileDescriptorProtoOwned) -> Self {
+        let locations = || file.source_code_info.iter().flat_map(|i| &i.location);
+        let starts = locations()
+            .filter_map(|l| match l.span[..] {
+                [line, column, ..] => Some((l.path.clone(), (line, column))),
+                _ => None,
+            })
+            .collect();
+        let docs = locations()
+            .filter_map(|l| {
+                let leading = l.leading_comments.as_deref();
+                let doc = doc_comment(leading, l.trailing_comments.as_deref())?;
+                Some((l.path.clone(), doc))
+            })
+            .collect();
+        let package = match file.package.as_deref() {
+            Some(p) if !p.is_empty() => format!(".{p}"),
+            _ => String::new(),
+        };
+        FileInfo {
+            file: PathBuf::from(name(&file.name)),
+            syntax: Syntax::of(file),
+            package,
+            docs,
+            starts,
+        }
+    }
+
+    fn doc(&self, path: &[i32]) -> Option<String> {
+        self.docs.get(path).cloned()
+    }
+
+    /// Where the declaration at `path` is: the file alone, without source info.
+    fn span(&self, path: &[i32]) -> Span {
+        let mut span = Span::default();
+        span.file = self.file.clone();
+        if let Some((line, column)) = self.starts.get(path) {
+            span.line = *line as usize + 1;
+            span.column = *column as usize + 1;
+        }
+        span
+    }
+
+    /// The full name of `name`, nested in the messages `parents`: `.pkg.Outer.Inner`.
+    fn full_name(&self, parents: &[&str], name: &str) -> String {
+        let mut full_name = self.package.clone();
+        for part in parents.iter().chain([&name]) {
+            full_name.push('.');
+            full_name.push_str(part);
+        }
+        full_name
+    }
+
+    /// The enum `e`, at `path` and nested in `parents`.
+    fn enum_items(
+        &self,
+        e: &EnumDescriptorProtoOwned,
+        parents: &[&str],
+        path: &[i32],
+        options: &PackageOptions,
+    ) -> Result<TokenStream, Error> {
+        let values: Vec<EnumValue> = e
+            .value
+            .iter()
+            .enumerate()
+            .map(|(i, v)| EnumValue {
+                name: name(&v.name).to_string(),
+                number: v.number.unwrap_or_default(),
+                doc: self.doc(&[path, &[2, i as i32]].concat()),
+            })
+            .collect();
+        let closed = self.syntax == Syntax::Proto2;
+        let full_name = self.full_name(parents, name(&e.name));
+        enum_items_for(
+            options,
+            &full_name,
+            self.doc(path).as_deref(),
+            &values,
+            closed,
+        )
+    }
+
+    /// Every enum nested in `messages`, at `path`, themselves nested in `parents`, at any
+    /// depth.
+    fn nested_enums(
+        &self,
+        messages: &[DescriptorProtoOwned],
+        parents: &[&str],
+        path: &[i32],
+        options: &PackageOptions,
+        tree: &mut ModuleTree,
+    ) -> Result<(), Error> {
+        for (i, m) in messages.iter().enumerate() {
+            let parents = [parents, &[name(&m.name)]].concat();
+            let path = [path, &[i as i32]].concat();
+            for (j, e) in m.enum_type.iter().enumerate() {
+                let enum_path = [&path[..], &[4, j as i32]].concat();
+                tree.push(&parents, self.enum_items(e, &parents, &enum_path, options)?);
+            }
+            let nested = [&path[..], &[3]].concat();
+            self.nested_enums(&m.nested_type, &parents, &nested, options, tree)?;
+        }
+        Ok(())
+    }
+}
+
+/// What a fully-qualified type name (`.pkg.Outer.Inner`) refers to, with the names of the
+/// messages it is nested in, then its own (`["Outer", "Inner"]`).
+enum Named<'a> {
+    Message(Vec<&'a str>, &'a DescriptorProtoOwned),
+    Enum(Vec<&'a str>, Vec<i32>),
+}
+
+#[derive(Default)]
+struct Types<'a> {
+    by_name: HashMap<String, Named<'a>>,
+}
+
+impl<'a> Types<'a> {
+    fn add(
+        &mut self,
+        scope: &str,
+        parents: &[&'a str],
+        messages: &'a [DescriptorProtoOwned],
+        enums: &'a [EnumDescriptorProtoOwned],
+    ) {
+        for e in enums {
+            let values = e
+                .value
+                .iter()
+                .map(|v| v.number.unwrap_or_default())
+                .collect();
+            self.by_name.insert(
+                format!("{scope}.{}", name(&e.name)),
+                Named::Enum([parents, &[name(&e.name)]].concat(), values),
+            );
+        }
+        for m in messages {
+            let scope = format!("{scope}.{}", name(&m.name));
+            let path = [parents, &[name(&m.name)]].concat();
+            self.add(&scope, &path, &m.nested_type, &m.enum_type);
+            self.by_name.insert(scope, Named::Message(path, m));
+        }
+    }
+
+    fn get(&self, type_name: &str) -> Result<&Named<'a>, Error> {
+        self.by_name
+            .get(type_name)
+            .ok_or_else(|| ErrorKind::UnresolvedType(type_name.to_string()).into())
+    }
+
+    /// How top-level code names the message `type_name`.
+    fn message_name(&self, type_name: &str) -> Result<String, Error> {
+        match self.get(type_name)? {
+            Named::Message(path, _) => Ok(type_path(&[], path)),
+            Named::Enum(..) => Err(ErrorKind::Descriptor(format!(
+                "{type_name} is an enum, where a message is needed"
+            ))
+            .into()),
+        }
+    }
+
+    /// Generates `messages`, at `path` in `file` and nested in `parents`, and, depth first,
+    /// every message nested in them, skipping the synthetic entry messages of map fields.
+    fn messages(
+        &self,
+        messages: &[DescriptorProtoOwned],
+        parents: &[&str],
+        path: &[i32],
+        file: &FileInfo,
+        options: &PackageOptions,
+        tree: &mut ModuleTree,
+    ) -> Result<(), Error> {
+        for (i, m) in messages.iter().enumerate() {
+            if is_map_entry(m) {
+                continue;
+            }
+            let path = [path, &[i as i32]].concat();
+            tree.push(parents, self.message(m, parents, &path, file, options)?);
+            let parents = [parents, &[name(&m.name)]].concat();
+            let nested = [&path[..], &[3]].concat();
+            self.messages(&m.nested_type, &parents, &nested, file, options, tree)?;
+        }
+        Ok(())
+    }
+
+    fn message(
+        &self,
+        m: &DescriptorProtoOwned,
+        parents: &[&str],
+        path: &[i32],
+        file: &FileInfo,
+        options: &PackageOptions,
+    ) -> Result<TokenStream, Error> {
+        // A proto3 `optional` field sits alone in a synthetic oneof; it is an ordinary
+        // field with presence here, as it is in pb-rs.
+        let oneof_of = |f: &FieldDescriptorProtoOwned| {
+            f.oneof_index
+                .filter(|_| !f.proto3_optional.unwrap_or_default())
+        };
+        let field_path = |i: usize| [path, &[2, i as i32]].concat();
+        let regular_fields: Vec<Field> = m
+            .field
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| oneof_of(f).is_none())
+            .map(|(i, f)| self.field(f, parents, &field_path(i), file, false))
+            .collect::<Result<_, Error>>()?;
+        let mut oneof_groups: Vec<OneOfGroup> = m
+            .oneof_decl
+            .iter()
+            .enumerate()
+            .map(|(i, o)| {
+                Ok(OneOfGroup {
+                    name: name(&o.name).to_string(),
+                    fields: m
+                        .field
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, f)| oneof_of(f) == Some(i as i32))
+                        .map(|(j, f)| self.field(f, parents, &field_path(j), file, true))
+                        .collect::<Result<_, Error>>()?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+        oneof_groups.retain(|o| !o.fields.is_empty());
+        // Descriptor ends are exclusive, ours inclusive.
+        let extensions: Vec<(u32, u32)> = m
+            .extension_range
+            .iter()
+            .map(|r| {
+                (
+                    r.start.unwrap_or_default() as u32,
+                    r.end.unwrap_or_default() as u32 - 1,
+                )
+            })
+            .collect();
+        message_items_for(
+            options,
+            &file.full_name(parents, name(&m.name)),
+            file.doc(path).as_deref(),
+            &regular_fields,
+            &oneof_groups,
+            &extensions,
+        )
+    }
+
+    /// `f`, at `path` in `file`, of a message nested in `parents`.
+    fn field(
+        &self,
+        f: &FieldDescriptorProtoOwned,
+        parents: &[&str],
+        path: &[i32],
+        file: &FileInfo,
+        in_oneof: bool,
+    ) -> Result<Field, Error> {
+        let syntax = file.syntax;
+        let ty = self.field_type(f, parents)?;
+        let packed = match f.options.as_ref().and_then(|o| o.packed) {
+            Some(packed) => packed,
+            None => syntax != Syntax::Proto2,
+        };
+        let label = match f.label.unwrap_or_default() {
+            _ if matches!(ty, PbType::Map(..) | PbType::SimpleMap(..)) => Label::Plain,
+            _ if in_oneof && syntax == Syntax::Proto3 => Label::Plain,
+            _ if in_oneof => Label::Optional,
+            FieldLabel::LabelRequired => Label::Required,
+            FieldLabel::LabelRepeated if packed && ty.is_packable_scalar() => Label::Packed,
+            FieldLabel::LabelRepeated => Label::Repeated,
+            _ if syntax == Syntax::Proto3 && !f.proto3_optional.unwrap_or_default() => Label::Plain,
+            _ => Label::Optional,
+        };
+        // protoc stores string defaults as the raw text and everything else as written;
+        // [`Field::default`] is the source spelling, escapes included.
+        let default = f.default_value.as_ref().map(|d| match ty {
+            PbType::Scalar(Scalar::String) => d.replace('\\', "\\\\").replace('"', "\\\""),
+            _ => d.clone(),
+        });
+        Ok(Field {
+            name: name(&f.name).to_string(),
+            number: f.number.unwrap_or_default(),
+            ty,
+            label,
+            default,
+            doc: file.doc(path),
+            deprecated: f.options.as_ref().and_then(|o| o.deprecated) == Some(true),
+            json_name: f
+                .json_name
+                .clone()
+                .unwrap_or_else(|| json_name(name(&f.name))),
+            span: None,
+        })
+    }
+
+    fn field_type(&self, f: &FieldDescriptorProtoOwned, parents: &[&str]) -> Result<PbType, Error> {
+        let scalar = match f.r#type {
+            Some(FieldType::TypeInt32) => Scalar::Int32,
+            Some(FieldType::TypeInt64) => Scalar::Int64,
+            Some(FieldType::TypeUint32) => Scalar::Uint32,
+            Some(FieldType::TypeUint64) => Scalar::Uint64,
+            Some(FieldType::TypeSint32) => Scalar::Sint32,
+            Some(FieldType::TypeSint64) => Scalar::Sint64,
+            Some(FieldType::TypeBool) => Scalar::Bool,
+            Some(FieldType::TypeFixed32) => Scalar::Fixed32,
+            Some(FieldType::TypeFixed64) => Scalar::Fixed64,
+            Some(FieldType::TypeSfixed32) => Scalar::Sfixed32,
+            Some(FieldType::TypeSfixed64) => Scalar::Sfixed64,
+            Some(FieldType::TypeFloat) => Scalar::Float,
+            Some(FieldType::TypeDouble) => Scalar::Double,
+            Some(FieldType::TypeString) => Scalar::String,
+            Some(FieldType::TypeBytes) => Scalar::Bytes,
+            Some(FieldType::TypeGroup) => {
+                return Err(ErrorKind::Unsupported(format!(
+                    "`{}` is a group, which is proto2's delimited message encoding",
+                    name(&f.name)
+                ))
+                .into())
+            }
+            // Message, enum, or left for the name to tell.
+            _ => return self.named_type(f, parents),
+        };
+        Ok(PbType::Scalar(scalar))
+    }
+
+    fn named_type(&self, f: &FieldDescriptorProtoOwned, parents: &[&str]) -> Result<PbType, Error> {
+        Ok(
+            match self.get(f.type_name.as_deref().unwrap_or_default())? {
+                Named::Enum(path, values) => {
+                    PbType::Enum((type_path(parents, path), values.clone()))
+                }
+                Named::Message(_, entry) if is_map_entry(entry) => {
+                    let entry_field = |n| entry.field.iter().find(|f| f.number == Some(n));
+                    let (Some(k), Some(v)) = (entry_field(1), entry_field(2)) else {
+                        return Err(ErrorKind::InvalidMap(format!(
+                            "`{}`'s entry needs a key and a value",
+                            name(&f.name)
+                        ))
+                        .into());
+                    };
+                    match (self.field_type(k, parents)?, self.field_type(v, parents)?) {
+                        (PbType::Scalar(k), PbType::Scalar(v)) => PbType::SimpleMap(k, v),
+                        (PbType::Scalar(k), v) => PbType::Map(k, Box::new(v)),
+                        (k, _) => {
+                            return Err(ErrorKind::InvalidMap(format!(
+                            "`{}` has `{k}` keys: only integral and string types can be map keys",
+                            name(&f.name)
+                        ))
+                            .into())
+                        }
+                    }
+                }
+                Named::Message(path, _) => PbType::Message(type_path(parents, path)),
+            },
+        )
+    }
+}
+
+fn name(name: &Option<String>) -> &str {
+    name.as_deref().unwrap_or_default()
+}
+
+fn is_map_entry(m: &DescriptorProtoOwned) -> bool {
+    m.options
+        .as_ref()
+        .and_then(|o| o.map_entry)
+        .unwrap_or_default()
+}
+
+/// `messages`, at `path`, and everything nested in them, each with its own path.
+fn all_messages(
+    messages: &[DescriptorProtoOwned],
+    path: Vec<i32>,
+) -> Box<dyn Iterator<Item = (Vec<i32>, &DescriptorProtoOwned)> + '_> {
+    Box::new(messages.iter().enumerate().flat_map(move |(i, m)| {
+        let path = [&path[..], &[i as i32]].concat();
+        let nested = all_messages(&m.nested_type, [&path[..], &[3]].concat());
+        std::iter::once((path, m)).chain(nested)
+    }))
+}
@@ -1,5 +1,11 @@
-#![allow(unused, dead_code)]
-mod field_enum;
-mod field_type;
-mod parser;
-pub use parser::{write_proto, write_proto_with_includes};
+//! Build-script entry points: [`write_proto`] and friends for `.proto` source, and
+//! [`write_fds`] for descriptor sets, or [`Config`] for anything they don't cover.
+//! [`plugin`] is the same codegen behind protoc's plugin protocol, for `protoc-gen-tacky`.
+mod config;
+#[rustfmt::skip] // as generated; testing checks it against the generator
+mod descriptor;
+mod fds;
+pub mod plugin;
+pub use config::{Config, Error};
+pub use fds::write_fds;
+pub use tacky_codegen::*;
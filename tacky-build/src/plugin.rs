@@ -0,0 +1,160 @@
+//! The protoc plugin protocol, for `protoc-gen-tacky`: a `CodeGeneratorRequest` in, a
+//! `CodeGeneratorResponse` out, with one `.rs` file per proto file protoc was asked to
+//! generate, named after it (`api/v1/user.proto` becomes `api/v1/user.rs`). Each is what
+//! [`write_fds`](crate::write_fds) would write for that file.
+//!
+//! The parameter is a comma-separated list, as `--tacky_opt=owned,random` or buf's
+//! `opt: [owned, random]` passes it, setting the same [`PackageOptions`] `Config` does:
+//! - `owned`: also generate `<Message>Owned` structs, as `write_proto_with_owned` does.
+//! - `random`: also generate `<Message>Owned::random`, as `Config::random` does.
+//! - `runtime=<path>`: reach the runtime through `<path>` rather than `::tacky`.
+//! - `type_attribute=<path>=<attribute>`, and likewise `field_attribute` and
+//!   `enum_attribute`: put `<attribute>` on what matches the proto path `<path>`, such as
+//!   `.api.User`. Commas inside brackets, as in `#[derive(Hash, Eq)]`, don't split.
+//! - `string_decoding=<path>=strict|unchecked|lazy`: how matching `string` fields decode.
+//! - `style_lint=field_name|enum_value_name|type_name=allow|warn|deny`: check the files
+//!   to generate against the proto style guide. Warnings go to stderr, which protoc
+//!   passes on.
+//!
+//! Attributes made from proto options and extern paths take code, and are left to
+//! `Config`.
+
+use crate::descriptor::google::protobuf::compiler::{
+    code_generator_response::{Feature, FileOwned},
+    CodeGeneratorRequestOwned, CodeGeneratorResponseOwned,
+};
+use crate::fds::file_tokens;
+use tacky_codegen::error::ErrorKind;
+use tacky_codegen::lint::{Level, Style};
+use tacky_codegen::{format_tokens, PackageOptions, StringDecoding};
+
+/// The codegen options a plugin parameter sets.
+pub fn parse_parameter(parameter: &str) -> Result<PackageOptions, String> {
+    let mut options = PackageOptions::default();
+    for p in split(parameter).map(str::trim).filter(|p| !p.is_empty()) {
+        let (key, value) = match p.split_once('=') {
+            Some((key, value)) => (key, Some(value)),
+            None => (p, None),
+        };
+        match (key, value) {
+            ("owned", None) => options.owned = true,
+            ("random", None) => options.random = true,
+            ("runtime", Some(path)) => options.runtime_path = Some(path.to_string()),
+            ("type_attribute", Some(v)) => options.type_attributes.push(by_path(p, v)?),
+            ("field_attribute", Some(v)) => options.field_attributes.push(by_path(p, v)?),
+            ("enum_attribute", Some(v)) => options.enum_attributes.push(by_path(p, v)?),
+            ("string_decoding", Some(v)) => {
+                let (path, decoding) = by_path(p, v)?;
+                let decoding = match decoding.as_str() {
+                    "strict" => StringDecoding::Strict,
+                    "unchecked" => StringDecoding::Unchecked,
+                    "lazy" => StringDecoding::Lazy,
+                    _ => return Err(invalid(p, "`strict`, `unchecked` or `lazy`")),
+                };
+                options.string_decoding.push((path, decoding));
+            }
+            ("style_lint", Some(v)) => {
+                let lint = match v.split_once('=') {
+                    Some(("field_name", level)) => (Style::FieldName, level),
+                    Some(("enum_value_name", level)) => (Style::EnumValueName, level),
+                    Some(("type_name", level)) => (Style::TypeName, level),
+                    _ => return Err(invalid(p, "`field_name`, `enum_value_name` or `type_name`")),
+                };
+                let level = match lint.1 {
+                    "allow" => Level::Allow,
+                    "warn" => Level::Warn,
+                    "deny" => Level::Deny,
+                    _ => return Err(invalid(p, "`allow`, `warn` or `deny`")),
+                };
+                options.style_lints.push((lint.0, level));
+            }
+            _ => {
+                return Err(format!(
+                    "unknown parameter {p:?}; expected `owned`, `random`, `runtime=`, \
+                     `type_attribute=`, `field_attribute=`, `enum_attribute=`, \
+                     `string_decoding=` or `style_lint=`"
+                ))
+            }
+        }
+    }
+    Ok(options)
+}
+
+/// `parameter` split at its commas, but those inside brackets or quotes, which are part of
+/// an attribute.
+fn split(parameter: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0i32;
+    let mut quoted = false;
+    parameter.split(move |c| {
+        match c {
+            '"' => quoted = !quoted,
+            '(' | '[' | '{' if !quoted => depth += 1,
+            ')' | ']' | '}' if !quoted => depth -= 1,
+            _ => {}
+        }
+        c == ',' && depth == 0 && !quoted
+    })
+}
+
+/// The `<path>=<value>` of `parameter`.
+fn by_path(parameter: &str, value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((path, value)) => Ok((path.to_string(), value.to_string())),
+        None => Err(invalid(parameter, "`<path>=<value>`")),
+    }
+}
+
+fn invalid(parameter: &str, expected: &str) -> String {
+    format!("invalid parameter {parameter:?}; expected {expected}")
+}
+
+/// Handles one request: the bytes protoc writes to the plugin's stdin, to the bytes it
+/// reads back from its stdout. Anything wrong with the input, from an unknown parameter
+/// to a proto tacky can't generate, goes in the response's `error`, as the protocol has
+/// it, rather than failing the plugin.
+pub fn run(request: &[u8]) -> Vec<u8> {
+    let mut response = match respond(request) {
+        Ok(file) => CodeGeneratorResponseOwned {
+            file,
+            ..Default::default()
+        },
+        Err(error) => CodeGeneratorResponseOwned {
+            error: Some(error),
+            ..Default::default()
+        },
+    };
+    let proto3_optional = i32::from(Feature::FeatureProto3Optional);
+    response.supported_features = Some(proto3_optional as u64);
+    let mut out = Vec::new();
+    response.encode(&mut out);
+    out
+}
+
+fn respond(request: &[u8]) -> Result<Vec<FileOwned>, String> {
+    let request = CodeGeneratorRequestOwned::from_bytes(request)
+        .map_err(|e| format!("decoding CodeGeneratorRequest: {e}"))?;
+    let options = parse_parameter(request.parameter.as_deref().unwrap_or_default())?;
+    request
+        .file_to_generate
+        .iter()
+        .map(|file| {
+            // Lint errors say where they are themselves.
+            let expansion =
+                file_tokens(&request.proto_file, file, &options).map_err(|e| match e.kind() {
+                    ErrorKind::Lint(_) => e.to_string(),
+                    _ => format!("{file}: {e}"),
+                })?;
+            for warning in &expansion.warnings {
+                eprintln!("warning: {warning}");
+            }
+            Ok(FileOwned {
+                name: Some(format!(
+                    "{}.rs",
+                    file.strip_suffix(".proto").unwrap_or(file)
+                )),
+                content: Some(format_tokens(expansion.tokens)),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,473 @@
+//! Whether a new version of a schema still reads what the old one wrote, and the other way
+//! round: [`check`] compares the two, as `buf breaking` does, for a build to fail on an
+//! edit that would break the wire.
+//!
+//! Fields are matched by number, messages and enums by their fully-qualified name. Only
+//! what is declared in the files given is compared, not what they import, so a schema is
+//! best read whole, as [`read_schema`] does for a directory.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use pb_rs::types::{
+    find_option, Enumerator, Field, FieldType, FileDescriptor, Frequency, Message, OptionValue,
+    Span,
+};
+
+use crate::error::{self, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Change {
+    /// A field number now has a type whose encoding the other side can't read.
+    FieldType,
+    /// A field that was repeated and now isn't, or the other way round; or a proto2 field
+    /// that became required, or stopped being.
+    FieldLabel,
+    /// A repeated field that is now packed, which readers of the old schema reject.
+    Packed,
+    /// A field moved into, out of or between oneofs, changing what it is exclusive with.
+    Oneof,
+    /// A field gone, without its number reserved against reuse.
+    FieldDeleted,
+    /// An enum number gone, without being reserved against reuse.
+    EnumValueDeleted,
+    /// A message or enum now in another package, under the same name.
+    Moved,
+}
+
+/// One breaking change, and where: in the new schema, or the old one for what was deleted.
+#[derive(Debug, Clone)]
+pub struct Breaking {
+    pub change: Change,
+    pub message: String,
+    pub span: Span,
+}
+
+impl fmt::Display for Breaking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        error::write_at(f, &self.span, &self.message)
+    }
+}
+
+/// Reads one version of a schema: `files`, relative to `root` and importing from it, or every
+/// `.proto` under `root` when there are none.
+pub fn read_schema(root: &Path, files: &[&Path]) -> Result<Vec<FileDescriptor>, Error> {
+    let mut found = Vec::new();
+    if files.is_empty() {
+        find_protos(root, &mut found).map_err(pb_rs::errors::Error::from)?;
+        found.sort();
+    } else {
+        found.extend(files.iter().map(|file| root.join(file)));
+    }
+    // pb-rs takes a relative search path as relative to the importing file.
+    let search_path = [std::fs::canonicalize(root).map_err(pb_rs::errors::Error::from)?];
+    found
+        .iter()
+        .map(|file| Ok(FileDescriptor::read_proto(file, &search_path)?))
+        .collect()
+}
+
+fn find_protos(dir: &Path, found: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_protos(&path, found)?;
+        } else if path.extension().is_some_and(|e| e == "proto") {
+            found.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Everything in `new` that breaks compatibility with `old`: types that moved, then what
+/// changed in each, in the order `old` declares it.
+pub fn check(old: &[FileDescriptor], new: &[FileDescriptor]) -> Vec<Breaking> {
+    let (old, new) = (Schema::new(old), Schema::new(new));
+    let mut found = Vec::new();
+
+    // A type that is gone from its package, where one of the same name has appeared in
+    // another, moved there. Its nested types went with it.
+    let mut moved: HashMap<(&str, &str), &str> = HashMap::new();
+    for decl in old.messages.iter().filter(|d| !d.name.contains('.')) {
+        if new.message(decl.package, &decl.name).is_some() {
+            continue;
+        }
+        let to = new
+            .messages
+            .iter()
+            .find(|d| d.name == decl.name && old.message(d.package, &d.name).is_none());
+        if let Some(to) = to {
+            moved.insert((decl.package, &decl.name), to.package);
+            found.push(Breaking {
+                change: Change::Moved,
+                message: format!(
+                    "message `{}` moved from package `{}` to `{}`",
+                    decl.name, decl.package, to.package
+                ),
+                span: to.item.span.clone(),
+            });
+        }
+    }
+    for decl in old.enums.iter().filter(|d| !d.name.contains('.')) {
+        if new.enumeration(decl.package, &decl.name).is_some() {
+            continue;
+        }
+        let to = new
+            .enums
+            .iter()
+            .find(|d| d.name == decl.name && old.enumeration(d.package, &d.name).is_none());
+        if let Some(to) = to {
+            moved.insert((decl.package, &decl.name), to.package);
+            found.push(Breaking {
+                change: Change::Moved,
+                message: format!(
+                    "enum `{}` moved from package `{}` to `{}`",
+                    decl.name, decl.package, to.package
+                ),
+                span: to.item.span.clone(),
+            });
+        }
+    }
+    // The package `package`'s `name` is in, in the new schema.
+    let package_of = |package: &str, name: &str| -> String {
+        let top = name.split('.').next().unwrap_or(name);
+        moved
+            .get(&(package, top))
+            .map_or(package, |to| *to)
+            .to_string()
+    };
+
+    for decl in &old.messages {
+        let package = package_of(decl.package, &decl.name);
+        if let Some(to) = new.message(&package, &decl.name) {
+            message(decl, to, &package_of, &mut found);
+        }
+    }
+    for decl in &old.enums {
+        let package = package_of(decl.package, &decl.name);
+        if let Some(to) = new.enumeration(&package, &decl.name) {
+            enumeration(decl, to, &mut found);
+        }
+    }
+    found
+}
+
+/// A message or enum, and where it is declared.
+struct Decl<'a, T> {
+    package: &'a str,
+    /// Its name in the package, with the messages it is nested in: `Outer.Inner`.
+    name: String,
+    item: &'a T,
+    desc: &'a FileDescriptor,
+}
+
+/// Every message and enum the files declare.
+struct Schema<'a> {
+    messages: Vec<Decl<'a, Message>>,
+    enums: Vec<Decl<'a, Enumerator>>,
+}
+
+impl<'a> Schema<'a> {
+    fn new(descs: &'a [FileDescriptor]) -> Self {
+        let mut schema = Schema {
+            messages: Vec::new(),
+            enums: Vec::new(),
+        };
+        for desc in descs {
+            for m in desc.messages.iter().filter(|m| !m.imported) {
+                schema.add_message(desc, "", m);
+            }
+            for e in desc.enums.iter().filter(|e| !e.imported) {
+                schema.add_enum(desc, "", e);
+            }
+        }
+        schema
+    }
+
+    fn add_message(&mut self, desc: &'a FileDescriptor, parent: &str, m: &'a Message) {
+        let name = nested(parent, &m.name);
+        // The same file given twice.
+        if self.message(&desc.package, &name).is_some() {
+            return;
+        }
+        self.messages.push(Decl {
+            package: &desc.package,
+            name: name.clone(),
+            item: m,
+            desc,
+        });
+        for nested in &m.messages {
+            self.add_message(desc, &name, nested);
+        }
+        for e in &m.enums {
+            self.add_enum(desc, &name, e);
+        }
+    }
+
+    fn add_enum(&mut self, desc: &'a FileDescriptor, parent: &str, e: &'a Enumerator) {
+        let name = nested(parent, &e.name);
+        if self.enumeration(&desc.package, &name).is_none() {
+            self.enums.push(Decl {
+                package: &desc.package,
+                name,
+                item: e,
+                desc,
+            });
+        }
+    }
+
+    fn message(&self, package: &str, name: &str) -> Option<&Decl<'a, Message>> {
+        self.messages
+            .iter()
+            .find(|d| d.package == package && d.name == name)
+    }
+
+    fn enumeration(&self, package: &str, name: &str) -> Option<&Decl<'a, Enumerator>> {
+        self.enums
+            .iter()
+            .find(|d| d.package == package && d.name == name)
+    }
+}
+
+fn nested(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{parent}.{name}")
+    }
+}
+
+/// How a field's values are encoded, as far as compatibility goes: types with the same
+/// encoding can stand in for each other, as `int32` and `uint64` or `string` and `bytes` can.
+#[derive(Debug, PartialEq)]
+enum Encoding {
+    Varint,
+    ZigZag,
+    Fixed32,
+    Fixed64,
+    Float,
+    Double,
+    Bytes,
+    /// A message, by package and name.
+    Message(String, String),
+    Map(Box<Encoding>, Box<Encoding>),
+}
+
+fn encoding(typ: &FieldType, desc: &FileDescriptor) -> Encoding {
+    match typ {
+        FieldType::Int32
+        | FieldType::Int64
+        | FieldType::Uint32
+        | FieldType::Uint64
+        | FieldType::Bool
+        | FieldType::Enum(_) => Encoding::Varint,
+        FieldType::Sint32 | FieldType::Sint64 => Encoding::ZigZag,
+        FieldType::Fixed32 | FieldType::Sfixed32 => Encoding::Fixed32,
+        FieldType::Fixed64 | FieldType::Sfixed64 => Encoding::Fixed64,
+        FieldType::Float => Encoding::Float,
+        FieldType::Double => Encoding::Double,
+        FieldType::String | FieldType::Bytes => Encoding::Bytes,
+        FieldType::Message(_) => {
+            let (package, name) = message_name(typ, desc);
+            Encoding::Message(package, name)
+        }
+        FieldType::Map(key, value) => Encoding::Map(
+            Box::new(encoding(key, desc)),
+            Box::new(encoding(value, desc)),
+        ),
+        FieldType::MessageOrEnum(name) => unreachable!("{name} not resolved"),
+    }
+}
+
+/// The package and name of the message `typ` is.
+fn message_name(typ: &FieldType, desc: &FileDescriptor) -> (String, String) {
+    let FieldType::Message(index) = typ else {
+        unreachable!("not a message");
+    };
+    let m = index.get_message(desc);
+    let name = index.path(desc).join(".");
+    // The package a message is given is its parent's full name, for a nested one.
+    let full = nested(&m.package, &m.name);
+    let package = full[..full.len() - name.len()].trim_end_matches('.');
+    (package.to_string(), name)
+}
+
+/// How `typ` is written in a proto file, near enough.
+fn type_name(typ: &FieldType, desc: &FileDescriptor) -> String {
+    match typ {
+        FieldType::Message(_) => {
+            let (package, name) = message_name(typ, desc);
+            nested(&package, &name)
+        }
+        FieldType::Enum(index) => index.path(desc).join("."),
+        FieldType::Map(key, value) => {
+            format!("map<{}, {}>", type_name(key, desc), type_name(value, desc))
+        }
+        typ => typ.proto_type().to_string(),
+    }
+}
+
+fn is_repeated(f: &Field) -> bool {
+    matches!(f.frequency, Some(Frequency::Repeated | Frequency::Packed))
+        || matches!(f.typ, FieldType::Map(..))
+}
+
+fn is_packed(f: &Field) -> bool {
+    let packable = f.typ.is_primitive() || matches!(f.typ, FieldType::Enum(_));
+    let unpacked = find_option(&f.options, "packed").and_then(OptionValue::as_bool) == Some(false);
+    matches!(f.frequency, Some(Frequency::Packed)) && packable && !unpacked
+}
+
+/// The oneof `number` is in, if it is.
+fn oneof_of(m: &Message, number: i32) -> Option<&str> {
+    m.oneofs
+        .iter()
+        .find(|o| o.fields.iter().any(|f| f.number == number))
+        .map(|o| o.name.as_str())
+}
+
+fn message(
+    old: &Decl<Message>,
+    new: &Decl<Message>,
+    package_of: &dyn Fn(&str, &str) -> String,
+    found: &mut Vec<Breaking>,
+) {
+    let (old_msg, new_msg) = (old.item, new.item);
+    let mut fields: Vec<&Field> = old_msg.all_fields().collect();
+    // Oneof fields come last from `all_fields`, wherever they are declared.
+    fields.sort_by_key(|f| (f.span.line, f.span.column));
+    for f in fields {
+        let Some(to) = new_msg.all_fields().find(|to| to.number == f.number) else {
+            if !new_msg
+                .reserved_nums
+                .iter()
+                .flatten()
+                .any(|n| *n == f.number)
+            {
+                found.push(Breaking {
+                    change: Change::FieldDeleted,
+                    message: format!(
+                        "field `{}` = {} of `{}` was deleted without reserving {}",
+                        f.name, f.number, old.name, f.number
+                    ),
+                    span: f.span.clone(),
+                });
+            }
+            continue;
+        };
+        let field = format!("field `{}` = {}", to.name, to.number);
+        let mut report = |change, message| {
+            found.push(Breaking {
+                change,
+                message,
+                span: to.span.clone(),
+            })
+        };
+
+        let mut was = encoding(&f.typ, old.desc);
+        translate(&mut was, package_of);
+        if was != encoding(&to.typ, new.desc) {
+            report(
+                Change::FieldType,
+                format!(
+                    "{field} changed type from `{}` to `{}`",
+                    type_name(&f.typ, old.desc),
+                    type_name(&to.typ, new.desc)
+                ),
+            );
+        }
+
+        let required = |f: &Field| matches!(f.frequency, Some(Frequency::Required));
+        if is_repeated(f) != is_repeated(to) {
+            let now = if is_repeated(to) {
+                "repeated"
+            } else {
+                "singular"
+            };
+            report(Change::FieldLabel, format!("{field} is now {now}"));
+        } else if required(f) != required(to) {
+            let now = if required(to) {
+                "required"
+            } else {
+                "no longer required"
+            };
+            report(Change::FieldLabel, format!("{field} is now {now}"));
+        } else if is_repeated(f) && !is_packed(f) && is_packed(to) {
+            // Packed fields decode either way, as protobuf has it; tacky's unpacked ones
+            // only take one value per tag.
+            report(
+                Change::Packed,
+                format!("{field} is now packed, which readers of the old schema reject"),
+            );
+        }
+
+        let (from, into) = (oneof_of(old_msg, f.number), oneof_of(new_msg, to.number));
+        match (from, into) {
+            (None, None) => {}
+            (Some(from), Some(into)) if from == into => {}
+            // A field on its own can join a new oneof: it is exclusive only with fields old
+            // writers don't know.
+            (None, Some(into)) => {
+                let others = new_msg
+                    .oneofs
+                    .iter()
+                    .filter(|o| o.name == into)
+                    .flat_map(|o| &o.fields)
+                    .filter(|o| o.number != to.number)
+                    .find(|o| old_msg.all_fields().any(|f| f.number == o.number));
+                if let Some(other) = others {
+                    report(
+                        Change::Oneof,
+                        format!(
+                            "{field} moved into oneof `{into}`, with `{}`, which it wasn't exclusive with",
+                            other.name
+                        ),
+                    );
+                }
+            }
+            (Some(from), None) => report(
+                Change::Oneof,
+                format!("{field} moved out of oneof `{from}`"),
+            ),
+            (Some(from), Some(into)) => report(
+                Change::Oneof,
+                format!("{field} moved from oneof `{from}` to `{into}`"),
+            ),
+        }
+    }
+}
+
+/// Where the messages in `encoding` are in the new schema.
+fn translate(encoding: &mut Encoding, package_of: &dyn Fn(&str, &str) -> String) {
+    match encoding {
+        Encoding::Message(package, name) => *package = package_of(package, name),
+        Encoding::Map(key, value) => {
+            translate(key, package_of);
+            translate(value, package_of);
+        }
+        _ => {}
+    }
+}
+
+fn enumeration(old: &Decl<Enumerator>, new: &Decl<Enumerator>, found: &mut Vec<Breaking>) {
+    let (old_enum, new_enum) = (old.item, new.item);
+    for (i, (name, number)) in old_enum.fields.iter().enumerate() {
+        let kept = new_enum.fields.iter().any(|(_, n)| n == number);
+        let reserved = new_enum.reserved_nums.iter().flatten().any(|n| n == number);
+        if !kept && !reserved {
+            found.push(Breaking {
+                change: Change::EnumValueDeleted,
+                message: format!(
+                    "value `{name}` = {number} of `{}` was deleted without reserving {number}",
+                    old.name
+                ),
+                span: old_enum
+                    .value_spans
+                    .get(i)
+                    .unwrap_or(&old_enum.span)
+                    .clone(),
+            });
+        }
+    }
+}
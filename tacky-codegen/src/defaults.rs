@@ -0,0 +1,187 @@
+//! Proto2 `[default = ...]` values. Tacky never materializes a message, so a declared
+//! default has nowhere to live but the schema: each one becomes a typed `DEFAULT_<FIELD>`
+//! constant, plus a `<field>_or_default` helper for the decode side, which only ever sees
+//! fields that were actually on the wire.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::error::{Error, ErrorKind};
+use crate::names::variant_ident;
+use crate::parser::{parse_ty, Field, PbType, Scalar};
+
+/// The constants and helpers for `fields`' defaults; an error for the first default that
+/// isn't a value of its field's type.
+pub fn default_consts(name: &str, fields: &[Field]) -> Result<TokenStream, Error> {
+    let mut items = Vec::new();
+    for f in fields {
+        let Some(default) = f.default.as_deref() else {
+            continue;
+        };
+        let (ty, value) = default_value(f, default)?;
+        let const_ident = format_ident!("DEFAULT_{}", heck::AsShoutySnakeCase(&f.name).to_string());
+        let helper_ident = format_ident!("{}_or_default", f.name);
+        items.push(quote! {
+            pub const #const_ident: #ty = #value;
+            pub fn #helper_ident(value: ::core::option::Option<#ty>) -> #ty {
+                match value {
+                    Some(v) => v,
+                    None => Self::#const_ident,
+                }
+            }
+        });
+    }
+    if items.is_empty() {
+        return Ok(quote!());
+    }
+    let name_ident = format_ident!("{name}");
+    Ok(quote! {
+        impl #name_ident {
+            #(#items)*
+        }
+    })
+}
+
+/// The constant's type and value, the type being what the field decodes to.
+fn default_value(field: &Field, default: &str) -> Result<(TokenStream, TokenStream), Error> {
+    let invalid = || {
+        let kind = ErrorKind::InvalidDefault(format!(
+            "`{default}` for field `{}` of type {}",
+            field.name, field.ty
+        ));
+        Error::new(kind, field.span.clone())
+    };
+    match &field.ty {
+        PbType::Enum((name, _)) => {
+            let enum_ident = parse_ty(name);
+            let variant = variant_ident(default);
+            Ok((quote!(#enum_ident), quote!(#enum_ident::#variant)))
+        }
+        PbType::Scalar(s) => match s {
+            Scalar::Int32 | Scalar::Sint32 | Scalar::Sfixed32 => {
+                int_value::<i32>(default).ok_or_else(invalid)
+            }
+            Scalar::Int64 | Scalar::Sint64 | Scalar::Sfixed64 => {
+                int_value::<i64>(default).ok_or_else(invalid)
+            }
+            Scalar::Uint32 | Scalar::Fixed32 => int_value::<u32>(default).ok_or_else(invalid),
+            Scalar::Uint64 | Scalar::Fixed64 => int_value::<u64>(default).ok_or_else(invalid),
+            Scalar::Float => float_value(quote!(f32), default).ok_or_else(invalid),
+            Scalar::Double => float_value(quote!(f64), default).ok_or_else(invalid),
+            Scalar::Bool => match default {
+                "true" => Ok((quote!(bool), quote!(true))),
+                "false" => Ok((quote!(bool), quote!(false))),
+                _ => Err(invalid()),
+            },
+            Scalar::String | Scalar::LazyString => {
+                let s = String::from_utf8(unescape(default)).map_err(|_| invalid())?;
+                let lit = Literal::string(&s);
+                Ok((quote!(&str), quote!(#lit)))
+            }
+            Scalar::Bytes => {
+                let lit = Literal::byte_string(&unescape(default));
+                Ok((quote!(&[u8]), quote!(#lit)))
+            }
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// Decimal, `0x` hex or leading-zero octal, optionally negative, as protoc accepts them.
+fn int_value<T>(s: &str) -> Option<(TokenStream, TokenStream)>
+where
+    T: TryFrom<i128>,
+{
+    let (neg, digits) = match s.strip_prefix('-') {
+        Some(d) => (true, d),
+        None => (false, s),
+    };
+    let magnitude = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i128::from_str_radix(hex, 16).ok()?
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i128::from_str_radix(&digits[1..], 8).ok()?
+    } else {
+        digits.parse::<i128>().ok()?
+    };
+    let value = if neg { -magnitude } else { magnitude };
+    T::try_from(value).ok()?;
+
+    let ty = format_ident!("{}", core::any::type_name::<T>());
+    let lit = Literal::u128_unsuffixed(magnitude as u128);
+    let value = if neg { quote!(-#lit) } else { quote!(#lit) };
+    Some((quote!(#ty), value))
+}
+
+fn float_value(ty: TokenStream, s: &str) -> Option<(TokenStream, TokenStream)> {
+    let value = match s {
+        "inf" => quote!(#ty::INFINITY),
+        "-inf" => quote!(#ty::NEG_INFINITY),
+        "nan" => quote!(#ty::NAN),
+        _ => {
+            let v: f64 = s.parse().ok()?;
+            let lit = Literal::f64_unsuffixed(v.abs());
+            if v.is_sign_negative() {
+                quote!(-#lit)
+            } else {
+                quote!(#lit)
+            }
+        }
+    };
+    Some((ty, value))
+}
+
+/// Resolves the C-style escapes protoc allows in string and bytes defaults. `\x` and octal
+/// escapes name raw bytes, so for a `string` the result still has to be checked for UTF-8.
+fn unescape(s: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut utf8 = [0; 4];
+            out.extend_from_slice(c.encode_utf8(&mut utf8).as_bytes());
+            continue;
+        }
+        let Some(e) = chars.next() else {
+            out.push(b'\\');
+            break;
+        };
+        // Up to `max` more digits, accumulated onto `v`.
+        let mut digits = |radix: u32, max: usize, mut v: u32| {
+            for _ in 0..max {
+                match chars.peek().and_then(|d| d.to_digit(radix)) {
+                    Some(d) => {
+                        v = v * radix + d;
+                        chars.next();
+                    }
+                    None => break,
+                }
+            }
+            v
+        };
+        match e {
+            'a' => out.push(0x07),
+            'b' => out.push(0x08),
+            'f' => out.push(0x0c),
+            'n' => out.push(b'\n'),
+            'r' => out.push(b'\r'),
+            't' => out.push(b'\t'),
+            'v' => out.push(0x0b),
+            'x' | 'X' => out.push(digits(16, 2, 0) as u8),
+            'u' | 'U' => {
+                let code = digits(16, if e == 'u' { 4 } else { 8 }, 0);
+                let c = char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+                let mut utf8 = [0; 4];
+                out.extend_from_slice(c.encode_utf8(&mut utf8).as_bytes());
+            }
+            '0'..='7' => out.push(digits(8, 2, e.to_digit(8).unwrap()) as u8),
+            other => {
+                let mut utf8 = [0; 4];
+                out.extend_from_slice(other.encode_utf8(&mut utf8).as_bytes());
+            }
+        }
+    }
+    out
+}
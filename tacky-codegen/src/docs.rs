@@ -0,0 +1,60 @@
+//! Proto comments as rustdoc, on whatever is generated for the commented declaration.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The docs for a declaration with these comments, as protoc or pb-rs found them: the
+/// leading comment, then the trailing one as a paragraph of its own.
+pub fn doc_comment(leading: Option<&str>, trailing: Option<&str>) -> Option<String> {
+    match (leading, trailing) {
+        (Some(leading), Some(trailing)) => Some(format!("{}\n\n{trailing}", leading.trim_end())),
+        (Some(doc), None) | (None, Some(doc)) => Some(doc.to_string()),
+        (None, None) => None,
+    }
+}
+
+/// A `#[doc]` per line of `doc`.
+///
+/// Proto comments aren't written for rustdoc, and what markdown makes of them would run as
+/// doctests: lines lose their indentation, so none is an indented code block, and fenced
+/// blocks are marked `text`. Brackets are escaped rather than read as links.
+pub(crate) fn doc_attrs(doc: Option<&str>) -> TokenStream {
+    let Some(doc) = doc else {
+        return quote!();
+    };
+    let mut lines: Vec<&str> = doc.lines().map(str::trim).collect();
+    // A `/**` comment's opening star.
+    if lines.first() == Some(&"*") {
+        lines.remove(0);
+    }
+    let first = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let last = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(0, |i| i + 1);
+    let mut fenced = false;
+    let attrs = lines[first..last.max(first)].iter().map(|line| {
+        let line = if let Some(info) = line.strip_prefix("```") {
+            fenced = !fenced;
+            if fenced && info.trim() != "text" {
+                "```text".to_string()
+            } else {
+                line.to_string()
+            }
+        } else if fenced {
+            line.to_string()
+        } else {
+            line.replace('[', "\\[").replace(']', "\\]")
+        };
+        let line = if line.is_empty() {
+            line
+        } else {
+            format!(" {line}")
+        };
+        quote!(#[doc = #line])
+    });
+    quote!(#(#attrs)*)
+}
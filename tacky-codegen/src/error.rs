@@ -0,0 +1,169 @@
+//! What goes wrong generating code from `.proto` source, and where.
+
+use pb_rs::types::Span;
+use std::fmt;
+
+use crate::lint::Violation;
+
+/// A proto file tacky can't generate code for. Errors with a place in a file display as
+/// `foo.proto:12:5: unknown type Bar`, then the line in question with the column marked.
+#[derive(Debug)]
+pub struct Error(Box<Inner>);
+
+#[derive(Debug)]
+struct Inner {
+    kind: ErrorKind,
+    span: Option<Span>,
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// The source isn't valid proto, or uses syntax pb-rs doesn't parse.
+    Parse(String),
+    /// A field's type names no message or enum in scope.
+    UnresolvedType(String),
+    /// A map field whose key can't be a map key.
+    InvalidMap(String),
+    /// A `[default = ...]` that isn't a value of the field's type.
+    InvalidDefault(String),
+    /// An enum protoc would reject, such as one reusing a number without `allow_alias`.
+    InvalidEnum(String),
+    /// Valid proto that tacky doesn't generate code for (yet).
+    Unsupported(String),
+    /// Distinct proto names that come out as the same Rust name, such as fields `foo_bar`
+    /// and `fooBar`, or a name that would shadow one generated code relies on.
+    Collision(String),
+    /// An attribute from `PackageOptions` that doesn't parse as one, for the path it was
+    /// given for, or the declaration whose option it was made from.
+    InvalidAttribute { path: String, attribute: String },
+    /// A Rust path from `PackageOptions`, such as the runtime's, that doesn't parse as one.
+    InvalidPath(String),
+    /// A descriptor set missing a file or type it refers to, or that doesn't decode.
+    Descriptor(String),
+    /// A file that couldn't be read or imported, and the like.
+    Read(pb_rs::errors::Error),
+    /// What the [lint pass](crate::lint) denies, all of it: each has its own place.
+    Lint(Vec<Violation>),
+}
+
+impl Error {
+    pub(crate) fn at(kind: ErrorKind, span: &Span) -> Self {
+        Error::new(kind, Some(span.clone()))
+    }
+
+    pub(crate) fn new(kind: ErrorKind, span: Option<Span>) -> Self {
+        Error(Box::new(Inner { kind, span }))
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.0.kind
+    }
+
+    /// Where in which file, when it is down to one declaration.
+    pub fn span(&self) -> Option<&Span> {
+        self.0.span.as_ref()
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Error::new(kind, None)
+    }
+}
+
+impl From<pb_rs::errors::Error> for Error {
+    fn from(e: pb_rs::errors::Error) -> Self {
+        use pb_rs::errors::Error as PbError;
+        match e {
+            PbError::Located(span, e) => {
+                let kind = match *e {
+                    PbError::MessageOrEnumNotFound(name) => ErrorKind::UnresolvedType(name),
+                    // Both come down to the parser giving up at the start of a declaration
+                    // it couldn't make sense of, which the span already points at.
+                    PbError::Nom(_) | PbError::TrailingGarbage(_) => {
+                        ErrorKind::Parse("could not parse this declaration".to_string())
+                    }
+                    e => ErrorKind::Read(e),
+                };
+                Error::new(kind, Some(span))
+            }
+            PbError::MessageOrEnumNotFound(name) => ErrorKind::UnresolvedType(name).into(),
+            e => ErrorKind::Read(e).into(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Parse(message) => write!(f, "parse error: {message}"),
+            ErrorKind::UnresolvedType(name) => write!(f, "unknown type {name}"),
+            ErrorKind::InvalidMap(message) => write!(f, "invalid map: {message}"),
+            ErrorKind::InvalidDefault(message) => write!(f, "invalid default: {message}"),
+            ErrorKind::InvalidEnum(message) => write!(f, "invalid enum: {message}"),
+            ErrorKind::Unsupported(message) => write!(f, "unsupported: {message}"),
+            ErrorKind::Collision(message) => write!(f, "name collision: {message}"),
+            ErrorKind::InvalidAttribute { path, attribute } => {
+                write!(f, "invalid attribute `{attribute}` for `{path}`")
+            }
+            ErrorKind::InvalidPath(path) => write!(f, "invalid Rust path `{path}`"),
+            ErrorKind::Descriptor(message) => write!(f, "invalid descriptor set: {message}"),
+            ErrorKind::Read(e) => write!(f, "{e}"),
+            ErrorKind::Lint(violations) => match &violations[..] {
+                [one] => write!(f, "{}", one.message),
+                _ => write!(f, "{} problems", violations.len()),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let ErrorKind::Lint(violations) = self.kind() {
+            for (i, violation) in violations.iter().enumerate() {
+                if i > 0 {
+                    f.write_str("\n\n")?;
+                }
+                write!(f, "{violation}")?;
+            }
+            return Ok(());
+        }
+        match self.span() {
+            Some(span) => write_at(f, span, self.kind()),
+            None => write!(f, "{}", self.kind()),
+        }
+    }
+}
+
+/// `message` at `span`: the place, then the line in question with the column marked.
+pub(crate) fn write_at(
+    f: &mut fmt::Formatter<'_>,
+    span: &Span,
+    message: &dyn fmt::Display,
+) -> fmt::Result {
+    // Declarations from a descriptor set without source info are only known by file, and
+    // those with it have no source to quote.
+    if span.line == 0 {
+        return write!(f, "{}: {message}", span.file.display());
+    }
+    if span.line_text.is_empty() {
+        return write!(f, "{span}: {message}");
+    }
+    let line = span.line.to_string();
+    let gutter = " ".repeat(line.len());
+    let caret = " ".repeat(span.column.saturating_sub(1));
+    write!(
+        f,
+        "{span}: {message}\n{gutter} |\n{line} | {}\n{gutter} | {caret}^",
+        span.line_text
+    )
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self.kind() {
+            ErrorKind::Read(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use core::panic;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::docs::doc_attrs;
+use crate::names::variant_ident;
+use crate::parser::{parse_ty, Field, Label, PbType, Scalar};
+
+/// Whether a field's variant borrows from the input buffer (needs lifetime 'a).
+fn field_borrows(field: &Field) -> bool {
+    match field.label {
+        Label::Packed => true, // packed → &'a [u8]
+        _ => matches!(
+            &field.ty,
+            PbType::Scalar(Scalar::String)
+                | PbType::Scalar(Scalar::Bytes)
+                | PbType::Scalar(Scalar::LazyString)
+                | PbType::Message(_)
+                | PbType::Map(_, _)
+                | PbType::SimpleMap(_, _)
+        ),
+    }
+}
+
+/// The Rust type carried by this field's enum variant.
+fn variant_type(field: &Field) -> TokenStream {
+    match field.label {
+        Label::Packed => packed_variant_type(field),
+        _ => match &field.ty {
+            PbType::Scalar(s) => scalar_variant_type(s),
+            PbType::Enum((name, _)) => {
+                let ident = parse_ty(name);
+                quote!(#ident)
+            }
+            PbType::Message(msg_name) => {
+                let m = parse_ty(&format!("{}Fields<'a>", msg_name));
+                quote!(#m)
+            }
+            PbType::Map(k, m) => {
+                let PbType::Message(msg_name) = &**m else {
+                    unreachable!("maps with enum values are rejected when resolving types")
+                };
+                let k = scalar_variant_type(k);
+                let m = parse_ty(&format!("{}Fields<'a>", msg_name));
+                quote! {
+                    (#k, ::core::option::Option<#m>)
+                }
+            }
+            PbType::SimpleMap(k, v) => {
+                let k = scalar_variant_type(k);
+                let v = scalar_variant_type(v);
+                quote! {
+                    (#k, ::core::option::Option<#v>)
+                }
+            }
+        },
+    }
+}
+
+fn packed_variant_type(field: &Field) -> TokenStream {
+    match &field.ty {
+        PbType::Scalar(s) => {
+            let ty = s.tacky_path();
+            quote!(tacky::packed::PackedIter::<'a, #ty>)
+        }
+        PbType::Enum((name, _)) => {
+            let ident = parse_ty(name);
+            quote!(tacky::packed::PackedIter::<'a, tacky::PbEnum<#ident>>)
+        }
+        _ => panic!("Only scalar and enum fields can be packed"),
+    }
+}
+
+pub fn scalar_variant_type(s: &Scalar) -> TokenStream {
+    match s {
+        Scalar::Int32 => quote!(i32),
+        Scalar::Sint32 => quote!(i32),
+        Scalar::Int64 => quote!(i64),
+        Scalar::Sint64 => quote!(i64),
+        Scalar::Uint32 => quote!(u32),
+        Scalar::Uint64 => quote!(u64),
+        Scalar::Bool => quote!(bool),
+        Scalar::Fixed32 => quote!(u32),
+        Scalar::Sfixed32 => quote!(i32),
+        Scalar::Float => quote!(f32),
+        Scalar::Fixed64 => quote!(u64),
+        Scalar::Sfixed64 => quote!(i64),
+        Scalar::Double => quote!(f64),
+        Scalar::String => quote!(&'a str),
+        Scalar::Bytes => quote!(&'a [u8]),
+        Scalar::LazyString => quote!(tacky::PbStr<'a>),
+    }
+}
+
+/// The WireType constant for this field.
+fn wire_type_token(field: &Field) -> TokenStream {
+    match field.label {
+        Label::Packed => quote!(tacky::WireType::LEN),
+        _ => match &field.ty {
+            PbType::Scalar(s) => scalar_wire_type_token(s),
+            PbType::Enum(_) => quote!(tacky::WireType::VARINT),
+            PbType::Message(_) => quote!(tacky::WireType::LEN),
+            PbType::Map(_, _) | PbType::SimpleMap(_, _) => quote!(tacky::WireType::LEN),
+        },
+    }
+}
+
+fn scalar_wire_type_token(s: &Scalar) -> TokenStream {
+    match s {
+        Scalar::Int32
+        | Scalar::Sint32
+        | Scalar::Int64
+        | Scalar::Sint64
+        | Scalar::Uint32
+        | Scalar::Uint64
+        | Scalar::Bool => quote!(tacky::WireType::VARINT),
+        Scalar::Fixed32 | Scalar::Sfixed32 | Scalar::Float => quote!(tacky::WireType::I32),
+        Scalar::Fixed64 | Scalar::Sfixed64 | Scalar::Double => quote!(tacky::WireType::I64),
+        Scalar::String | Scalar::Bytes | Scalar::LazyString => quote!(tacky::WireType::LEN),
+    }
+}
+
+/// The decode expression for a field, assuming wire type has already been checked.
+fn decode_expr(field: &Field) -> TokenStream {
+    match field.label {
+        Label::Packed => {
+            // All packed fields just return raw bytes for user to iterate
+            quote! {
+                let data = tacky::decode_len(buf)?;
+            }
+        }
+        _ => match &field.ty {
+            PbType::Scalar(s) => scalar_decode_expr(s),
+            PbType::Enum((name, _)) => {
+                let ident = parse_ty(name);
+                quote! {
+                    let raw = <tacky::Int32 as tacky::ProtobufScalar>::read(buf)?;
+                    let Some(val) = <#ident as tacky::PbEnumType>::from_wire(raw) else {
+                        return Ok(None);
+                    };
+                }
+            }
+            PbType::Message(nested) => {
+                let msg_name = parse_ty(nested);
+                quote! {
+                    let data = #msg_name::decode(tacky::decode_len(buf)?);
+                }
+            }
+            PbType::Map(k, m) => {
+                let PbType::Message(msg_name) = &**m else {
+                    panic!("Map value type must be a message");
+                };
+                let k = k.tacky_path();
+                let v = parse_ty(msg_name);
+
+                quote! {
+                    let data = tacky::PbMap::<#k, #v>::read_msg(buf, #v::decode)?;
+                }
+            }
+            PbType::SimpleMap(k, v) => {
+                let k = k.tacky_path();
+                let v = v.tacky_path();
+
+                quote! {
+                    let data = tacky::PbMap::<#k, #v>::read(buf)?;
+                }
+            }
+        },
+    }
+}
+
+fn scalar_decode_expr(s: &Scalar) -> TokenStream {
+    let ty = s.tacky_path();
+    quote! {
+        let val = <#ty as tacky::ProtobufScalar>::read(buf)?;
+    }
+}
+
+/// The value expression to wrap in `Some(Self::Variant(...))`.
+fn packed_value_expr(field: &Field) -> TokenStream {
+    match &field.ty {
+        PbType::Scalar(s) => {
+            let ty = s.tacky_path();
+            quote!(tacky::packed::PackedIter::<#ty>::new(data))
+        }
+        PbType::Enum((name, _)) => {
+            let ident = parse_ty(name);
+            quote!(tacky::packed::PackedIter::<tacky::PbEnum<#ident>>::new(data))
+        }
+        _ => panic!("Only scalar and enum fields can be packed"),
+    }
+}
+
+/// Returns (wire_type_token, scalar_type_token) for a packed field's underlying scalar.
+fn packed_scalar_info(field: &Field) -> (TokenStream, TokenStream) {
+    match &field.ty {
+        PbType::Scalar(s) => (scalar_wire_type_token(s), s.tacky_path()),
+        PbType::Enum((name, _)) => {
+            let ident = parse_ty(name);
+            (
+                quote!(tacky::WireType::VARINT),
+                quote!(tacky::PbEnum<#ident>),
+            )
+        }
+        _ => panic!("Only scalar and enum fields can be packed"),
+    }
+}
+
+/// PackedIter construction for the unpacked-single-element fallback path.
+/// Uses `data` as the slice name (set by caller to the consumed bytes).
+fn packed_unpacked_value_expr(field: &Field) -> TokenStream {
+    match &field.ty {
+        PbType::Scalar(s) => {
+            let ty = s.tacky_path();
+            quote!(tacky::packed::PackedIter::<#ty>::new(data))
+        }
+        PbType::Enum((name, _)) => {
+            let ident = parse_ty(name);
+            quote!(tacky::packed::PackedIter::<tacky::PbEnum<#ident>>::new(data))
+        }
+        _ => panic!("Only scalar and enum fields can be packed"),
+    }
+}
+
+fn variant_value_expr(field: &Field) -> TokenStream {
+    match field.label {
+        Label::Packed => packed_value_expr(field),
+        _ => match &field.ty {
+            PbType::Scalar(Scalar::String) => quote!(val),
+            PbType::Scalar(Scalar::Bytes) => quote!(val),
+            PbType::Scalar(_) | PbType::Enum(_) => quote!(val),
+            PbType::Message(_) | PbType::Map(_, _) | PbType::SimpleMap(_, _) => quote!(data),
+        },
+    }
+}
+
+/// Whether decoding `f` can find a value to skip rather than yield: a number its closed
+/// enum doesn't declare. Its [`decode_result`] is then a `Result<Option<_>>`.
+fn skippable(f: &Field) -> bool {
+    !matches!(f.label, Label::Packed) && matches!(f.ty, PbType::Enum(_))
+}
+
+/// The expression decoding one field's value into `enum_name`'s variant for it, as a
+/// `Result`, or a `Result<Option>` if it is [`skippable`]. Expects `buf` (positioned after
+/// the key) and `wire_type` in scope.
+fn decode_result(enum_name: &proc_macro2::Ident, f: &Field) -> TokenStream {
+    let variant_name = variant_ident(&f.name);
+    let field_name_str = &f.name;
+
+    if matches!(f.label, Label::Packed) {
+        // Packed fields must accept both LEN (packed) and the scalar's
+        // native wire type (unpacked) per the protobuf spec.
+        let packed_value = packed_value_expr(f);
+        let (scalar_wt, scalar_ty) = packed_scalar_info(f);
+        let unpacked_value = packed_unpacked_value_expr(f);
+
+        quote! {
+            (|| {
+                if wire_type == tacky::WireType::LEN {
+                    let data = tacky::decode_len(buf)?;
+                    Ok(#enum_name::#variant_name(#packed_value))
+                } else {
+                    tacky::check_wire_type(wire_type, #scalar_wt, #field_name_str)?;
+                    let start = *buf;
+                    <#scalar_ty as tacky::ProtobufScalar>::read_known(buf)?;
+                    let data = &start[..start.len() - buf.len()];
+                    Ok(#enum_name::#variant_name(#unpacked_value))
+                }
+            })()
+        }
+    } else {
+        let wt = wire_type_token(f);
+        let decode = decode_expr(f);
+        let value = variant_value_expr(f);
+        let variant = if skippable(f) {
+            quote!(Some(#enum_name::#variant_name(#value)))
+        } else {
+            quote!(#enum_name::#variant_name(#value))
+        };
+
+        quote! {
+            (|| {
+                tacky::check_wire_type(wire_type, #wt, #field_name_str)?;
+                #decode
+                Ok(#variant)
+            })()
+        }
+    }
+}
+
+fn variant_defs(fields: &[Field]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .map(|f| {
+            let variant_name = variant_ident(&f.name);
+            let doc = doc_attrs(f.doc.as_deref());
+            let deprecated = f.deprecation();
+            let ty = variant_type(f);
+            quote! { #doc #deprecated #variant_name(#ty) }
+        })
+        .collect()
+}
+
+/// `extensions` are the message's extension ranges, ends inclusive: fields numbered inside
+/// any of them are yielded as an `Extension` variant rather than skipped.
+pub fn field_enum(name: &str, fields: &[Field], extensions: &[(u32, u32)]) -> TokenStream {
+    let enum_name = format_ident!("{name}Field");
+
+    let needs_lifetime = !extensions.is_empty() || fields.iter().any(field_borrows);
+
+    // Generate variant definitions
+    let mut variants = variant_defs(fields);
+
+    // Generate match arms for decode
+    let mut match_arms: Vec<TokenStream> = fields
+        .iter()
+        .map(|f| {
+            let tag = f.number as u32;
+            let decoded = decode_result(&enum_name, f);
+            if skippable(f) {
+                return quote! {
+                    #tag => {
+                        match #decoded {
+                            Ok(Some(field)) => return Some(Ok(field)),
+                            Ok(None) => continue,
+                            Err(e) => {
+                                self.buf = &[];
+                                return Some(Err(e));
+                            }
+                        }
+                    }
+                };
+            }
+            quote! {
+                #tag => {
+                    let decoded = #decoded;
+                    if decoded.is_err() {
+                        self.buf = &[];
+                    }
+                    return Some(decoded);
+                }
+            }
+        })
+        .collect();
+
+    if !extensions.is_empty() {
+        let ranges = extensions.iter().map(|(from, to)| quote!(#from..=#to));
+        variants.push(quote! { Extension(tacky::ExtensionField<'a>) });
+        match_arms.push(quote! {
+            #(#ranges)|* => {
+                match tacky::ExtensionField::read(tag, wire_type, buf) {
+                    Ok(ext) => return Some(Ok(#enum_name::Extension(ext))),
+                    Err(e) => {
+                        self.buf = &[];
+                        return Some(Err(e));
+                    }
+                }
+            }
+        });
+    }
+
+    let fields_iterator_name = format_ident!("{name}Fields");
+    let schema_name = format_ident!("{name}");
+
+    let json_arms = fields.iter().map(|f| {
+        let variant_name = variant_ident(&f.name);
+        let json_name = &f.json_name;
+        quote!(#enum_name::#variant_name(_) => Some(#json_name),)
+    });
+    let json_rest = (!extensions.is_empty()).then(|| quote!(_ => None,));
+
+    let (lt_token, lt_name) = if needs_lifetime {
+        (quote! {<'a>}, (quote! {'a}))
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    quote! {
+        #[derive(Debug, Copy,Clone, PartialEq)]
+        pub enum #enum_name #lt_token {
+            #(#variants,)*
+        }
+        #[derive(Debug, Copy,Clone, PartialEq)]
+        pub struct #fields_iterator_name<'a> {
+            buf: &'a [u8],
+        }
+
+        #[allow(deprecated)]
+        impl #lt_token #enum_name #lt_token {
+            /// The field's name in JSON; `None` for an extension.
+            pub fn json_name(&self) -> ::core::option::Option<&'static str> {
+                match *self {
+                    #(#json_arms)*
+                    #json_rest
+                }
+            }
+        }
+
+        impl<'a> #fields_iterator_name<'a> {
+            pub fn new(buf: &'a [u8]) -> Self {
+                Self { buf }
+            }
+        }
+        impl<'a> tacky::MessageFields<'a> for #fields_iterator_name<'a> {
+            type Schema = #schema_name;
+
+            fn new(buf: &'a [u8]) -> Self {
+                Self { buf }
+            }
+
+            fn as_bytes(&self) -> &'a [u8] {
+                self.buf
+            }
+        }
+        #[allow(deprecated)]
+        impl<'a> ::core::iter::Iterator for #fields_iterator_name<'a> {
+            type Item = ::core::result::Result<#enum_name #lt_token, tacky::DecodeError>;
+
+            /// Yields one field per call, and **stops at the first error**: a malformed
+            /// field leaves the cursor mid-value, so there is nothing to resync to, and
+            /// re-reading the same bytes would fail identically forever. Every error path
+            /// therefore empties the cursor first, so the `Err` is followed by `None` and a
+            /// `for` loop over hostile input terminates.
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                loop {
+                    if self.buf.is_empty() {
+                        return None;
+                    }
+                    let buf = &mut self.buf;
+                    let (tag, wire_type) = match tacky::decode_key(buf) {
+                        Ok(t) => t,
+                        Err(e) => {
+                            self.buf = &[];
+                            return Some(Err(e));
+                        }
+                    };
+                    match tag {
+                        #(#match_arms)*
+                        _ => {
+                            match tacky::skip_field(wire_type, buf) {
+                                Ok(()) => continue,
+                                Err(e) => {
+                                    self.buf = &[];
+                                    return Some(Err(e));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The registry for the extensions one file declares on `extendee`: an enum with a variant
+/// per extension and a `decode` that maps an [`ExtensionField`](tacky::ExtensionField) to
+/// it, or to `None` when the number belongs to an extension declared elsewhere. It is
+/// named after the extendee, wherever that lives.
+///
+/// A repeated scalar extension's variant holds a packed iterator whether it was declared
+/// packed or not: writers may pack it either way, so one `ExtensionField` can carry many
+/// elements or just one.
+pub fn extension_enum(extendee: &str, fields: &[Field]) -> TokenStream {
+    let extendee = extendee.rsplit("::").next().unwrap_or(extendee);
+    let enum_name = format_ident!("{extendee}Extension");
+    let fields: Vec<Field> = fields
+        .iter()
+        .map(|f| match f.label {
+            Label::Repeated if f.ty.is_packable_scalar() => Field {
+                label: Label::Packed,
+                ..f.clone()
+            },
+            _ => f.clone(),
+        })
+        .collect();
+    let fields = &fields[..];
+    let variants = variant_defs(fields);
+    let arms = fields.iter().map(|f| {
+        let tag = f.number as u32;
+        let decoded = decode_result(&enum_name, f);
+        if skippable(f) {
+            quote!(#tag => #decoded.transpose(),)
+        } else {
+            quote!(#tag => Some(#decoded),)
+        }
+    });
+
+    let (lt_token, ext_lt) = if fields.iter().any(field_borrows) {
+        (quote!(<'a>), quote!('a))
+    } else {
+        (quote!(), quote!('_))
+    };
+
+    quote! {
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub enum #enum_name #lt_token {
+            #(#variants,)*
+        }
+
+        #[allow(deprecated)]
+        impl #lt_token #enum_name #lt_token {
+            pub fn decode(
+                ext: tacky::ExtensionField<#ext_lt>,
+            ) -> ::core::option::Option<::core::result::Result<Self, tacky::DecodeError>> {
+                let wire_type = ext.wire_type;
+                let mut value = ext.value;
+                let buf = &mut value;
+                match ext.number {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::docs::doc_attrs;
+use crate::names::ident;
+use crate::parser::{parse_ty, Field, Label, PbType, Scalar};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn field_type(field: &Field) -> TokenStream {
+    let name_ident = ident(&field.name);
+    let doc = doc_attrs(field.doc.as_deref());
+    let deprecated = field.deprecation();
+    let ty = schema_type(field);
+    quote!(#doc #deprecated pub #name_ident: #ty)
+}
+
+/// The `Field<N, Label<T>>` schema type for a field, shared by struct members and
+/// extension constants.
+pub fn schema_type(field: &Field) -> TokenStream {
+    let Field {
+        number, ty, label, ..
+    } = field;
+
+    let number_lit = proc_macro2::Literal::u32_unsuffixed(*number as u32);
+
+    let wrap_label = |ty: TokenStream| match label {
+        Label::Required => quote!(tacky::Field<#number_lit, tacky::Required<#ty>>),
+        Label::Optional => quote!(tacky::Field<#number_lit, tacky::Optional<#ty>>),
+        Label::Repeated => quote!(tacky::Field<#number_lit, tacky::Repeated<#ty>>),
+        Label::Packed => quote!(tacky::Field<#number_lit, tacky::Packed<#ty>>),
+        Label::Plain => quote!(tacky::Field<#number_lit, tacky::Plain<#ty>>),
+    };
+
+    match ty {
+        PbType::Scalar(p) => wrap_label(p.tacky_path()),
+        PbType::SimpleMap(k, v) => {
+            let (k, v) = (k.tacky_path(), v.tacky_path());
+            quote!(tacky::Field<#number_lit, tacky::PbMap<#k, #v>>)
+        }
+        PbType::Message(m) => {
+            let m = parse_ty(m);
+            wrap_label(quote!(#m))
+        }
+        PbType::Enum((name, _fields)) => {
+            let name = parse_ty(name);
+            wrap_label(quote!(tacky::PbEnum<#name>))
+        }
+        PbType::Map(k, v) => {
+            let k = k.tacky_path();
+            let v = match &**v {
+                PbType::Scalar(s) => s.tacky_path(),
+                PbType::Enum((name, _)) | PbType::Message(name) => {
+                    let name = parse_ty(name);
+                    quote!(#name)
+                }
+                _ => unreachable!("map values are checked by `check_field`"),
+            };
+            quote!(tacky::Field<#number_lit, tacky::PbMap<#k, #v>>)
+        }
+    }
+}
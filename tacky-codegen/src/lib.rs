@@ -0,0 +1,32 @@
+//! tacky's code generator. Build scripts use it through `tacky-build`, which re-exports all
+//! of it; `tacky::include_proto!` runs it at compile time.
+//!
+//! Input is parsed into the [`Field`] model, which is what generation works from:
+//! [`message_items`], [`enum_items`], [`extension_items`] and [`service_items`] are there
+//! for inputs other than `.proto` source, with [`ModuleTree`] and [`type_path`] for
+//! where nested messages and enums go and how to name them from elsewhere, and
+//! [`doc_comment`] for turning proto comments into their docs.
+#![allow(unused, dead_code)]
+pub mod breaking;
+mod defaults;
+mod docs;
+pub mod error;
+mod field_enum;
+mod field_type;
+pub mod lint;
+mod names;
+mod owned;
+mod parser;
+mod random;
+mod service;
+pub use docs::doc_comment;
+pub use names::check_names;
+pub use parser::{
+    enum_items, enum_items_for, expand_packages, expand_proto_file, expand_proto_source,
+    extension_items, format_tokens, in_package, message_items, message_items_for, runtime_prelude,
+    type_path, write_formatted, write_proto, write_proto_with_includes, write_proto_with_owned,
+    write_protos, write_protos_with_extern, EnumValue, Expansion, Field, Label, ModuleTree,
+    OneOfGroup, OptionAttribute, OptionAttributeFn, PackageOptions, PbType, Scalar, StringDecoding,
+};
+pub use pb_rs::types::{json_name, OptionValue, Span};
+pub use service::{service_items, Service, ServiceMethod};
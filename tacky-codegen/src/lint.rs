@@ -0,0 +1,329 @@
+//! Checks over parsed protos, run before any code is generated so that a file with several
+//! problems reports them all at once, each where it is.
+//!
+//! What protoc rejects is always an error: a field conflicting with the message's
+//! `reserved` names or numbers, a number in protobuf's own 19000–19999 range or outside
+//! 1 to 2^29-1, two fields sharing a number (oneofs included), a proto3 enum not starting
+//! at zero, and map keys that can't be. Style lints, off unless given a [`Level`], hold
+//! names to the proto style guide.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+
+use pb_rs::types::{Enumerator, Extensions, Field, FieldType, FileDescriptor, Message, Span};
+
+use crate::error::{self, Error, ErrorKind};
+
+/// Protobuf's own numbers, which no field may use.
+const IMPLEMENTATION_RESERVED: std::ops::RangeInclusive<i32> = 19000..=19999;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A field with a name or number the message reserves.
+    ReservedConflict,
+    /// A field numbered in 19000–19999.
+    ImplementationReserved,
+    /// A field numbered below 1 or above 2^29-1.
+    NumberOutOfRange,
+    /// Two fields of a message with the same number.
+    DuplicateNumber,
+    /// A proto3 enum whose first value isn't zero.
+    EnumZero,
+    /// A map keyed by something other than an integral type, `bool` or `string`.
+    MapKey,
+    Style(Style),
+}
+
+/// Names that break the proto style guide, but not protoc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// Fields not in `snake_case`.
+    FieldName,
+    /// Enum values not in `UPPER_SNAKE_CASE`.
+    EnumValueName,
+    /// Messages, enums and services not in `UpperCamelCase`.
+    TypeName,
+}
+
+/// What a style lint does when it finds something.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Level {
+    #[default]
+    Allow,
+    Warn,
+    Deny,
+}
+
+/// One thing [`check`] found, and where.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub lint: Lint,
+    /// [`Level::Deny`] for everything but style lints, which have the level they were
+    /// given.
+    pub level: Level,
+    pub message: String,
+    pub span: Span,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        error::write_at(f, &self.span, &self.message)
+    }
+}
+
+/// Everything wrong with `descs`, the files and what they import, in the order it is
+/// declared. Style lints are checked at the levels in `style`, the last for each winning.
+pub fn check(descs: &[FileDescriptor], style: &[(Style, Level)]) -> Vec<Violation> {
+    let mut lints = Lints {
+        style,
+        found: Vec::new(),
+        seen: HashSet::new(),
+    };
+    for desc in descs {
+        for m in &desc.messages {
+            lints.message(m);
+        }
+        for e in &desc.enums {
+            lints.enumeration(e);
+        }
+        for f in desc.message_extends.iter().flat_map(|e| &e.fields) {
+            lints.number(f);
+        }
+        for s in &desc.rpc_services {
+            lints.type_name("service", &s.service_name, &s.span);
+        }
+    }
+    // Oneof fields were checked after the rest, wherever they are declared.
+    let mut files: Vec<PathBuf> = Vec::new();
+    for v in &lints.found {
+        if !files.contains(&v.span.file) {
+            files.push(v.span.file.clone());
+        }
+    }
+    let mut found = lints.found;
+    found.sort_by_key(|v| {
+        let file = files.iter().position(|f| *f == v.span.file);
+        (file, v.span.line, v.span.column)
+    });
+    found
+}
+
+/// Fails with every denied violation in `descs`, or returns the warnings.
+pub(crate) fn enforce(
+    descs: &[FileDescriptor],
+    style: &[(Style, Level)],
+) -> Result<Vec<Violation>, Error> {
+    partition(check(descs, style))
+}
+
+fn partition(found: Vec<Violation>) -> Result<Vec<Violation>, Error> {
+    let (denied, warnings): (Vec<_>, Vec<_>) =
+        found.into_iter().partition(|v| v.level == Level::Deny);
+    if denied.is_empty() {
+        Ok(warnings)
+    } else {
+        Err(ErrorKind::Lint(denied).into())
+    }
+}
+
+/// The style lints alone, for names that come from somewhere other than parsed `.proto`
+/// source, such as a descriptor set, which protoc has checked everything else in.
+pub struct StyleLints<'a>(Lints<'a>);
+
+impl<'a> StyleLints<'a> {
+    /// Checks at the levels in `style`, the last for each winning, as [`check`] does.
+    pub fn new(style: &'a [(Style, Level)]) -> Self {
+        StyleLints(Lints {
+            style,
+            found: Vec::new(),
+            seen: HashSet::new(),
+        })
+    }
+
+    /// A message, enum or service name: `kind` is which.
+    pub fn type_name(&mut self, kind: &str, name: &str, span: &Span) {
+        self.0.type_name(kind, name, span);
+    }
+
+    pub fn field_name(&mut self, name: &str, span: &Span) {
+        self.0.field_name(name, span);
+    }
+
+    pub fn enum_value_name(&mut self, name: &str, span: &Span) {
+        self.0.enum_value_name(name, span);
+    }
+
+    /// Fails with every denied name, or returns the warnings, in the order they were
+    /// checked.
+    pub fn finish(self) -> Result<Vec<Violation>, Error> {
+        partition(self.0.found)
+    }
+}
+
+struct Lints<'a> {
+    style: &'a [(Style, Level)],
+    found: Vec<Violation>,
+    /// Imports come back with every file importing them: by place and message.
+    seen: HashSet<(PathBuf, usize, usize, String)>,
+}
+
+impl Lints<'_> {
+    fn report(&mut self, lint: Lint, span: &Span, message: String) {
+        let level = match lint {
+            Lint::Style(style) => self
+                .style
+                .iter()
+                .rev()
+                .find(|(s, _)| *s == style)
+                .map_or(Level::Allow, |(_, level)| *level),
+            _ => Level::Deny,
+        };
+        let place = (span.file.clone(), span.line, span.column, message.clone());
+        if level != Level::Allow && self.seen.insert(place) {
+            self.found.push(Violation {
+                lint,
+                level,
+                message,
+                span: span.clone(),
+            });
+        }
+    }
+
+    fn message(&mut self, m: &Message) {
+        self.type_name("message", &m.name, &m.span);
+        let mut numbers: Vec<&Field> = Vec::new();
+        for f in m.all_fields() {
+            self.number(f);
+            if m.reserved_names.iter().flatten().any(|n| *n == f.name) {
+                let message = format!("field `{}` uses a name `{}` reserves", f.name, m.name);
+                self.report(Lint::ReservedConflict, &f.span, message);
+            }
+            if m.reserved_nums.iter().flatten().any(|n| *n == f.number) {
+                let message = format!(
+                    "field `{}` uses number {}, which `{}` reserves",
+                    f.name, f.number, m.name
+                );
+                self.report(Lint::ReservedConflict, &f.span, message);
+            }
+            if let Some(first) = numbers.iter().find(|first| first.number == f.number) {
+                let message = format!(
+                    "field `{}` has number {}, as `{}` does",
+                    f.name, f.number, first.name
+                );
+                self.report(Lint::DuplicateNumber, &f.span, message);
+            }
+            numbers.push(f);
+            if let FieldType::Map(key, _) = &f.typ {
+                let invalid = match &**key {
+                    FieldType::Float => Some("float"),
+                    FieldType::Double => Some("double"),
+                    FieldType::Bytes => Some("bytes"),
+                    FieldType::Message(_) => Some("message"),
+                    FieldType::Enum(_) => Some("enum"),
+                    _ => None,
+                };
+                if let Some(key) = invalid {
+                    let message = format!(
+                        "map `{}` has {key} keys: only integral types, `bool` and `string` can be map keys",
+                        f.name
+                    );
+                    self.report(Lint::MapKey, &f.span, message);
+                }
+            }
+            self.field_name(&f.name, &f.span);
+        }
+        for nested in &m.messages {
+            self.message(nested);
+        }
+        for e in &m.enums {
+            self.enumeration(e);
+        }
+    }
+
+    /// The number checks every field goes through, extensions included.
+    fn number(&mut self, f: &Field) {
+        if IMPLEMENTATION_RESERVED.contains(&f.number) {
+            let message = format!(
+                "field `{}` uses number {}: 19000 to 19999 are reserved for protobuf",
+                f.name, f.number
+            );
+            self.report(Lint::ImplementationReserved, &f.span, message);
+        } else if !(1..=Extensions::max()).contains(&f.number) {
+            let message = format!(
+                "field `{}` has number {}: field numbers go from 1 to {}",
+                f.name,
+                f.number,
+                Extensions::max()
+            );
+            self.report(Lint::NumberOutOfRange, &f.span, message);
+        }
+    }
+
+    fn enumeration(&mut self, e: &Enumerator) {
+        self.type_name("enum", &e.name, &e.span);
+        // Proto2 enums are closed; the rest have to default to something they declare.
+        if !e.closed {
+            if let Some((name, number)) = e.fields.first().filter(|(_, n)| *n != 0) {
+                let message = format!(
+                    "the first value of `{}` is `{name}` = {number}: open enums start at 0",
+                    e.name
+                );
+                let span = e.value_spans.first().unwrap_or(&e.span);
+                self.report(Lint::EnumZero, span, message);
+            }
+        }
+        for (i, (name, _)) in e.fields.iter().enumerate() {
+            self.enum_value_name(name, e.value_spans.get(i).unwrap_or(&e.span));
+        }
+    }
+
+    fn field_name(&mut self, name: &str, span: &Span) {
+        if !is_snake_case(name) {
+            let message = format!(
+                "field `{name}` should be snake_case: `{}`",
+                heck::AsSnakeCase(name)
+            );
+            self.report(Lint::Style(Style::FieldName), span, message);
+        }
+    }
+
+    fn enum_value_name(&mut self, name: &str, span: &Span) {
+        if !is_upper_snake_case(name) {
+            let message = format!(
+                "enum value `{name}` should be UPPER_SNAKE_CASE: `{}`",
+                heck::AsShoutySnakeCase(name)
+            );
+            self.report(Lint::Style(Style::EnumValueName), span, message);
+        }
+    }
+
+    fn type_name(&mut self, kind: &str, name: &str, span: &Span) {
+        // Acronyms can stay in capitals: `HTTPRequest` is fine.
+        if !name.starts_with(|c: char| c.is_ascii_uppercase()) || name.contains('_') {
+            let message = format!(
+                "{kind} `{name}` should be UpperCamelCase: `{}`",
+                heck::AsUpperCamelCase(name)
+            );
+            self.report(Lint::Style(Style::TypeName), span, message);
+        }
+    }
+}
+
+/// Lowercase words, digits allowed, joined by single underscores.
+fn is_snake_case(name: &str) -> bool {
+    is_snake(name, |c| c.is_ascii_lowercase())
+}
+
+fn is_upper_snake_case(name: &str) -> bool {
+    is_snake(name, |c| c.is_ascii_uppercase())
+}
+
+fn is_snake(name: &str, letter: impl Fn(char) -> bool) -> bool {
+    name.starts_with(&letter)
+        && !name.ends_with('_')
+        && !name.contains("__")
+        && name
+            .chars()
+            .all(|c| letter(c) || c.is_ascii_digit() || c == '_')
+}
@@ -0,0 +1,153 @@
+//! Rust names for proto ones, and the check that they come out distinct.
+//!
+//! Every identifier generated from a proto name goes through [`escape`]: proto allows
+//! `type` or `self` as a field, message or package name, Rust does not. Nested messages
+//! and enums live in a module named after the message they are nested in, snake-cased, as
+//! prost lays them out (`Outer.Inner` is `outer::Inner`).
+//!
+//! Escaping keeps each name valid, not distinct: `foo_bar` and `fooBar` both make a
+//! `FooBar` variant, and a top-level `OuterField` message meets `Outer`'s own `OuterField`
+//! companion. [`check_names`] runs over the finished code and turns any name defined twice
+//! into an error, rather than code that doesn't compile.
+
+use std::collections::{HashMap, HashSet};
+
+use proc_macro2::{Ident, TokenStream};
+use quote::format_ident;
+use syn::ext::IdentExt;
+
+use crate::error::{Error, ErrorKind};
+
+/// `name` as it can be spelled in Rust: keywords as raw identifiers (`r#type`), and the
+/// few that can't be raw with a trailing underscore (`self_`).
+pub fn escape(name: &str) -> String {
+    match name {
+        "self" | "super" | "crate" | "Self" | "_" => format!("{name}_"),
+        _ if syn::parse_str::<Ident>(name).is_err() => format!("r#{name}"),
+        _ => name.to_string(),
+    }
+}
+
+/// `name`, [escaped](escape), as an identifier.
+pub fn ident(name: &str) -> Ident {
+    format_ident!("{}", escape(name))
+}
+
+/// The enum variant for a field or enum value named `name`: `foo_bar` is `FooBar`.
+pub fn variant_ident(name: &str) -> Ident {
+    ident(&heck::AsUpperCamelCase(name).to_string())
+}
+
+/// The module the messages and enums nested in `message` go in.
+pub(crate) fn nest_module(message: &str) -> String {
+    heck::AsSnakeCase(message).to_string()
+}
+
+/// What generated code names without a path: the runtime, which every module it generates
+/// imports as `tacky`. Everything else it uses goes by a path from there or from `::core`
+/// and `::std`, so a message may well be called `Field`, `Option` or `Method`.
+const RESERVED: &[&str] = &["tacky"];
+
+/// Fails on the first name `tokens`, generated code, defines twice in one place: two items
+/// in a module, two variants of an enum, two fields of a struct, two members of a trait or
+/// two inherent members of a type. Also on an item shadowing a [`RESERVED`] name in a module that imports the
+/// runtime.
+pub fn check_names(tokens: &TokenStream) -> Result<(), Error> {
+    let file: syn::File = syn::parse2(tokens.clone()).expect("generated code parses");
+    check_module(&[], &file.items)
+}
+
+fn check_module(path: &[String], items: &[syn::Item]) -> Result<(), Error> {
+    let here = |name: &str| {
+        let mut path = path.to_vec();
+        path.push(name.to_string());
+        path.join("::")
+    };
+    let twice = |name: &str, place: String| -> Result<(), Error> {
+        Err(ErrorKind::Collision(format!("`{name}` is generated twice in `{place}`")).into())
+    };
+    let prelude = items.iter().any(|i| matches!(i, syn::Item::Use(_)));
+
+    let mut names = HashSet::new();
+    let mut members: HashMap<String, HashSet<String>> = HashMap::new();
+    for item in items {
+        let ident = match item {
+            syn::Item::Struct(s) => {
+                let mut fields = HashSet::new();
+                for name in s.fields.iter().filter_map(|f| f.ident.as_ref()) {
+                    let name = name.unraw().to_string();
+                    if !fields.insert(name.clone()) {
+                        return twice(&name, here(&s.ident.unraw().to_string()));
+                    }
+                }
+                &s.ident
+            }
+            syn::Item::Enum(e) => {
+                let mut variants = HashSet::new();
+                for v in &e.variants {
+                    let name = v.ident.unraw().to_string();
+                    if !variants.insert(name.clone()) {
+                        return twice(&name, here(&e.ident.unraw().to_string()));
+                    }
+                }
+                &e.ident
+            }
+            syn::Item::Impl(i) if i.trait_.is_none() => {
+                let ty = &i.self_ty;
+                let ty = quote::quote!(#ty).to_string().replace(' ', "");
+                let seen = members.entry(ty.clone()).or_default();
+                for member in &i.items {
+                    let name = match member {
+                        syn::ImplItem::Const(c) => &c.ident,
+                        syn::ImplItem::Fn(f) => &f.sig.ident,
+                        _ => continue,
+                    };
+                    let name = name.unraw().to_string();
+                    if !seen.insert(name.clone()) {
+                        return twice(&name, here(&ty));
+                    }
+                }
+                continue;
+            }
+            syn::Item::Mod(m) => {
+                if let Some((_, inner)) = &m.content {
+                    let mut inner_path = path.to_vec();
+                    inner_path.push(m.ident.unraw().to_string());
+                    check_module(&inner_path, inner)?;
+                }
+                &m.ident
+            }
+            syn::Item::Trait(t) => {
+                let mut trait_members = HashSet::new();
+                for member in &t.items {
+                    let name = match member {
+                        syn::TraitItem::Const(c) => &c.ident,
+                        syn::TraitItem::Fn(f) => &f.sig.ident,
+                        syn::TraitItem::Type(ty) => &ty.ident,
+                        _ => continue,
+                    };
+                    let name = name.unraw().to_string();
+                    if !trait_members.insert(name.clone()) {
+                        return twice(&name, here(&t.ident.unraw().to_string()));
+                    }
+                }
+                &t.ident
+            }
+            syn::Item::Const(c) => &c.ident,
+            syn::Item::Fn(f) => &f.sig.ident,
+            _ => continue,
+        };
+        let name = ident.unraw().to_string();
+        if prelude && RESERVED.contains(&name.as_str()) {
+            return Err(ErrorKind::Collision(format!(
+                "`{name}` in `{}` shadows the `{name}` the generated code there uses",
+                path.join("::")
+            ))
+            .into());
+        }
+        if !names.insert(name.clone()) {
+            return twice(&name, path.join("::"));
+        }
+    }
+    Ok(())
+}
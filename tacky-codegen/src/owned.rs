@@ -0,0 +1,396 @@
+//! Opt-in owned structs, for the code paths that really do want a message in memory: tests,
+//! tooling, caches. `<Message>Owned` is built on the same generated pieces as everything
+//! else — `from_bytes` drains the `Fields` iterator, `encode` calls the schema's writers —
+//! so it adds no second decoder or encoder to keep in sync.
+//!
+//! Field mapping: `optional` becomes `Option<T>`, `required` and proto3 implicit-presence
+//! scalars a bare `T`, `repeated` a `Vec<T>` and maps a `HashMap`. Singular message fields
+//! are `Option<Box<_>>`, which is what lets a message contain itself. A oneof is an
+//! `Option` of a generated `<Message><Oneof>Owned` enum. Unknown fields and extensions are
+//! dropped.
+//!
+//! Decoding follows the wire format's merge rules: a singular scalar keeps its last
+//! occurrence, a singular message merges every occurrence into one, and a message missing
+//! a `required` field fails with `DecodeError::MissingField`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::docs::doc_attrs;
+use crate::field_enum::scalar_variant_type;
+use crate::names::ident;
+use crate::parser::{parse_ty, Field, Label, OneOfGroup, PbType, Scalar};
+
+pub fn owned_struct(
+    name: &str,
+    fields: &[Field],
+    oneofs: &[OneOfGroup],
+    has_extensions: bool,
+) -> TokenStream {
+    let owned_ident = format_ident!("{name}Owned");
+    let schema_ident = format_ident!("{name}");
+    let field_enum = format_ident!("{name}Field");
+    let fields_iter = format_ident!("{name}Fields");
+
+    let field_defs = fields.iter().map(|f| {
+        let ident = ident(&f.name);
+        let doc = doc_attrs(f.doc.as_deref());
+        let deprecated = f.deprecation();
+        let ty = owned_type(f);
+        quote!(#doc #deprecated pub #ident: #ty)
+    });
+    let oneof_defs = oneofs.iter().map(|o| {
+        let ident = ident(&o.name);
+        let enum_ident = oneof_ident(name, o);
+        quote!(pub #ident: ::core::option::Option<#enum_ident>)
+    });
+
+    let required: Vec<&Field> = fields
+        .iter()
+        .filter(|f| matches!(f.label, Label::Required))
+        .collect();
+    let mut decode_arms: Vec<TokenStream> = fields
+        .iter()
+        .map(|f| {
+            let variant = variant_ident(f);
+            let store = decode_store(f);
+            let seen = required
+                .iter()
+                .position(|r| r.number == f.number)
+                .map(|i| quote!(seen[#i] = true;));
+            quote!(#field_enum::#variant(v) => { #seen #store })
+        })
+        .collect();
+    decode_arms.extend(oneofs.iter().flat_map(|o| {
+        let enum_ident = oneof_ident(name, o);
+        let group = ident(&o.name);
+        let field_enum = &field_enum;
+        o.fields.iter().map(move |f| {
+            let variant = variant_ident(f);
+            match &f.ty {
+                // Merged into the one already there, as a singular message field is.
+                PbType::Message(m) => {
+                    let owned = parse_ty(&format!("{m}Owned"));
+                    quote! {
+                        #field_enum::#variant(v) => match &mut self.#group {
+                            Some(#enum_ident::#variant(m)) => m.merge_fields(v)?,
+                            _ => self.#group = Some(#enum_ident::#variant(::std::boxed::Box::new(#owned::from_fields(v)?))),
+                        },
+                    }
+                }
+                ty => {
+                    let value = match ty {
+                        PbType::Scalar(s) => to_owned(s, quote!(v)),
+                        _ => quote!(v),
+                    };
+                    quote!(#field_enum::#variant(v) => self.#group = Some(#enum_ident::#variant(#value)),)
+                }
+            }
+        })
+    }));
+    if has_extensions {
+        decode_arms.push(quote!(#field_enum::Extension(_) => {}));
+    }
+
+    let decode_loop = quote! {
+        for field in fields {
+            match field? {
+                #(#decode_arms)*
+            }
+        }
+        Ok(())
+    };
+    let decodes = if required.is_empty() {
+        quote! {
+            pub fn from_fields(fields: #fields_iter<'_>) -> ::core::result::Result<Self, tacky::DecodeError> {
+                let mut out = Self::default();
+                out.merge_fields(fields)?;
+                Ok(out)
+            }
+
+            /// Decodes a message into this one, as a later occurrence of it would be.
+            pub fn merge_fields(&mut self, fields: #fields_iter<'_>) -> ::core::result::Result<(), tacky::DecodeError> {
+                #decode_loop
+            }
+        }
+    } else {
+        let count = required.len();
+        let checks = required.iter().enumerate().map(|(i, f)| {
+            let name = &f.name;
+            quote!(if !seen[#i] { return Err(tacky::DecodeError::MissingField(#name)); })
+        });
+        quote! {
+            /// Fails with `MissingField` if a required field isn't there.
+            pub fn from_fields(fields: #fields_iter<'_>) -> ::core::result::Result<Self, tacky::DecodeError> {
+                let mut out = Self::default();
+                let mut seen = [false; #count];
+                out.merge_seen(fields, &mut seen)?;
+                #(#checks)*
+                Ok(out)
+            }
+
+            /// Decodes a message into this one, as a later occurrence of it would be. The
+            /// required fields needn't all be there, since this one may have them already.
+            pub fn merge_fields(&mut self, fields: #fields_iter<'_>) -> ::core::result::Result<(), tacky::DecodeError> {
+                self.merge_seen(fields, &mut [false; #count])
+            }
+
+            fn merge_seen(&mut self, fields: #fields_iter<'_>, seen: &mut [bool; #count]) -> ::core::result::Result<(), tacky::DecodeError> {
+                #decode_loop
+            }
+        }
+    };
+
+    let encodes = fields.iter().map(encode_stmt);
+    let oneof_encodes = oneofs.iter().map(|o| {
+        let enum_ident = oneof_ident(name, o);
+        let group = ident(&o.name);
+        let arms = o.fields.iter().map(|f| {
+            let variant = variant_ident(f);
+            match &f.ty {
+                PbType::Message(_) => {
+                    let method = format_ident!("write_{}_msg", f.name);
+                    quote! {
+                        Some(#enum_ident::#variant(m)) => {
+                            schema.#group.#method(buf, |buf, _| m.encode_dir(buf));
+                        }
+                    }
+                }
+                _ => {
+                    let method = format_ident!("write_{}", f.name);
+                    quote! {
+                        Some(#enum_ident::#variant(v)) => {
+                            schema.#group.#method(buf, v);
+                        }
+                    }
+                }
+            }
+        });
+        quote! {
+            match &self.#group {
+                #(#arms)*
+                None => {}
+            }
+        }
+    });
+
+    let oneof_enums = oneofs.iter().map(|o| {
+        let enum_ident = oneof_ident(name, o);
+        let variants = o.fields.iter().map(|f| {
+            let variant = variant_ident(f);
+            let ty = match &f.ty {
+                PbType::Message(m) => {
+                    let owned = parse_ty(&format!("{m}Owned"));
+                    quote!(::std::boxed::Box<#owned>)
+                }
+                ty => element_type(ty),
+            };
+            quote!(#variant(#ty))
+        });
+        quote! {
+            #[derive(Debug, Clone, PartialEq)]
+            pub enum #enum_ident {
+                #(#variants,)*
+            }
+        }
+    });
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Default)]
+        pub struct #owned_ident {
+            #(#field_defs,)*
+            #(#oneof_defs,)*
+        }
+
+        #[allow(deprecated)]
+        impl #owned_ident {
+            /// Decodes a whole message. Repeated occurrences of a singular field keep the last,
+            /// or, for a message, are merged into one; a missing required field is an error.
+            pub fn from_bytes(buf: &[u8]) -> ::core::result::Result<Self, tacky::DecodeError> {
+                Self::from_fields(#schema_ident::decode(buf))
+            }
+
+            #decodes
+
+            pub fn encode(&self, buf: &mut impl tacky::WriteBuf) {
+                self.encode_dir(tacky::AnyDir::from_mut(buf));
+            }
+
+            /// Repeated fields need their direction known where they are written, and `encode`
+            /// is generic over it, so the writes go through the direction-erased view. Public
+            /// for the messages holding this one, which may be generated in other modules.
+            #[doc(hidden)]
+            pub fn encode_dir<B: tacky::WriteBuf>(&self, buf: &mut tacky::AnyDir<B>) {
+                let schema = #schema_ident::schema();
+                #(#encodes)*
+                #(#oneof_encodes)*
+            }
+        }
+
+        #(#oneof_enums)*
+    }
+}
+
+pub(crate) fn variant_ident(f: &Field) -> proc_macro2::Ident {
+    crate::names::variant_ident(&f.name)
+}
+
+pub(crate) fn oneof_ident(msg: &str, o: &OneOfGroup) -> proc_macro2::Ident {
+    format_ident!(
+        "{}{}Owned",
+        msg,
+        heck::AsUpperCamelCase(&o.name).to_string()
+    )
+}
+
+/// The owned counterpart of a scalar's decoded type.
+fn owned_scalar(s: &Scalar) -> TokenStream {
+    match s {
+        Scalar::String => quote!(::std::string::String),
+        // Unchecked, it can only be kept as bytes.
+        Scalar::Bytes | Scalar::LazyString => quote!(::std::vec::Vec<u8>),
+        s => scalar_variant_type(s),
+    }
+}
+
+/// Converts a decoded scalar, which borrows for strings and bytes, to its owned type.
+fn to_owned(s: &Scalar, v: TokenStream) -> TokenStream {
+    match s {
+        Scalar::String => quote!(#v.to_owned()),
+        Scalar::Bytes => quote!(#v.to_vec()),
+        Scalar::LazyString => quote!(#v.as_bytes().to_vec()),
+        _ => v,
+    }
+}
+
+/// One element's type: what a `Vec` holds, or an `Option` wraps. Messages are unboxed.
+pub(crate) fn element_type(ty: &PbType) -> TokenStream {
+    match ty {
+        PbType::Scalar(s) => owned_scalar(s),
+        PbType::Enum((name, _)) => {
+            let ident = parse_ty(name);
+            quote!(#ident)
+        }
+        PbType::Message(m) => {
+            let ident = parse_ty(&format!("{m}Owned"));
+            quote!(#ident)
+        }
+        PbType::SimpleMap(_, _) | PbType::Map(_, _) => {
+            unreachable!("maps have no element type, and oneofs no maps")
+        }
+    }
+}
+
+pub(crate) fn map_value(v: &PbType) -> &str {
+    match v {
+        PbType::Message(m) => m,
+        _ => unreachable!("map values are checked by `check_field`"),
+    }
+}
+
+fn owned_type(f: &Field) -> TokenStream {
+    match (&f.ty, &f.label) {
+        (PbType::SimpleMap(k, v), _) => {
+            let k = owned_scalar(k);
+            let v = owned_scalar(v);
+            quote!(::std::collections::HashMap<#k, #v>)
+        }
+        (PbType::Map(k, v), _) => {
+            let k = owned_scalar(k);
+            let v = parse_ty(&format!("{}Owned", map_value(v)));
+            quote!(::std::collections::HashMap<#k, #v>)
+        }
+        (ty, Label::Repeated | Label::Packed) => {
+            let elem = element_type(ty);
+            quote!(::std::vec::Vec<#elem>)
+        }
+        (PbType::Message(_), _) => {
+            let elem = element_type(&f.ty);
+            quote!(::core::option::Option<::std::boxed::Box<#elem>>)
+        }
+        (ty, Label::Optional) => {
+            let elem = element_type(ty);
+            quote!(::core::option::Option<#elem>)
+        }
+        (ty, Label::Required | Label::Plain) => element_type(ty),
+    }
+}
+
+/// Stores the decoded variant payload `v` into `self`.
+fn decode_store(f: &Field) -> TokenStream {
+    let ident = ident(&f.name);
+    match (&f.ty, &f.label) {
+        (PbType::SimpleMap(k, v), _) => {
+            let key = to_owned(k, quote!(k));
+            let value = to_owned(v, quote!(v));
+            quote! {
+                let (k, v) = v;
+                self.#ident.insert(#key, v.map(|v| #value).unwrap_or_default());
+            }
+        }
+        (PbType::Map(k, v), _) => {
+            let key = to_owned(k, quote!(k));
+            let owned = parse_ty(&format!("{}Owned", map_value(v)));
+            quote! {
+                let (k, v) = v;
+                let v = match v {
+                    Some(v) => #owned::from_fields(v)?,
+                    None => ::core::default::Default::default(),
+                };
+                self.#ident.insert(#key, v);
+            }
+        }
+        (_, Label::Packed) => quote! {
+            for v in v {
+                self.#ident.push(v?);
+            }
+        },
+        (PbType::Message(m), label) => {
+            let owned = parse_ty(&format!("{m}Owned"));
+            if matches!(label, Label::Repeated) {
+                quote!(self.#ident.push(#owned::from_fields(v)?);)
+            } else {
+                // The first occurrence is a message of its own, required fields and all;
+                // later ones merge into it.
+                quote! {
+                    match &mut self.#ident {
+                        Some(m) => m.merge_fields(v)?,
+                        None => self.#ident = Some(::std::boxed::Box::new(#owned::from_fields(v)?)),
+                    }
+                }
+            }
+        }
+        (ty, label) => {
+            let value = match ty {
+                PbType::Scalar(s) => to_owned(s, quote!(v)),
+                _ => quote!(v),
+            };
+            match label {
+                Label::Repeated => quote!(self.#ident.push(#value);),
+                Label::Optional => quote!(self.#ident = Some(#value);),
+                _ => quote!(self.#ident = #value;),
+            }
+        }
+    }
+}
+
+fn encode_stmt(f: &Field) -> TokenStream {
+    let ident = ident(&f.name);
+    match (&f.ty, &f.label) {
+        (PbType::Map(_, _), _) => quote! {
+            for (k, v) in &self.#ident {
+                schema.#ident.write_msg(buf, k, |buf, _| v.encode_dir(buf));
+            }
+        },
+        (PbType::Message(_), Label::Repeated) => quote! {
+            schema.#ident.write_msgs(buf, &self.#ident, |buf, _, m| m.encode_dir(buf));
+        },
+        (PbType::Message(_), _) => quote! {
+            if let Some(m) = &self.#ident {
+                schema.#ident.write_msg(buf, |buf, _| m.encode_dir(buf));
+            }
+        },
+        (_, Label::Optional) => quote!(schema.#ident.write(buf, self.#ident.as_ref());),
+        _ => quote!(schema.#ident.write(buf, &self.#ident);),
+    }
+}
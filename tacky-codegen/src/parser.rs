@@ -0,0 +1,1764 @@
+//! Currently wraps/uses pb-rs from quick-protobuf as the underlying parser, as i dont want any protoc system deps (a la prost)
+//! and dont i dont to write my own (yet).
+
+use crate::service::{service_items, Service, ServiceMethod};
+use crate::{
+    docs::{doc_attrs, doc_comment},
+    error::{Error, ErrorKind},
+    field_enum::{extension_enum, field_enum},
+    field_type::{field_type, schema_type},
+    lint::{self, Level, Style, Violation},
+    names::{check_names, escape, ident, nest_module, variant_ident},
+};
+use pb_rs::types::{
+    Enumerator, Extend, FieldType, FileDescriptor, Message, MessageEncoding, OptionValue,
+    ProtoOption, RpcService, Span, Utf8Validation,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Recursively collect all messages (including nested), each with the names of the
+/// messages it is nested in.
+fn collect_all_messages<'a>(
+    messages: &'a [Message],
+    parents: &[&'a str],
+) -> Vec<(&'a Message, Vec<&'a str>)> {
+    let mut result = Vec::new();
+    for m in messages {
+        result.push((m, parents.to_vec()));
+        let mut nested = parents.to_vec();
+        nested.push(&m.name);
+        result.extend(collect_all_messages(&m.messages, &nested));
+    }
+    result
+}
+
+/// Recursively collect all enums (top-level and nested inside messages), each with the
+/// names of the messages it is nested in.
+fn collect_all_enums<'a>(
+    messages: &'a [Message],
+    enums: &'a [Enumerator],
+    parents: &[&'a str],
+) -> Vec<(&'a Enumerator, Vec<&'a str>)> {
+    let mut result = Vec::new();
+    for e in enums {
+        result.push((e, parents.to_vec()));
+    }
+    for m in messages {
+        let mut nested = parents.to_vec();
+        nested.push(&m.name);
+        result.extend(collect_all_enums(&m.messages, &m.enums, &nested));
+    }
+    result
+}
+
+pub fn parse_ty(s: &str) -> syn::Type {
+    syn::parse_str(s).unwrap_or_else(|_| panic!("failed to parse type: {}", s))
+}
+
+/// The dotted module path, within their package's, of items nested in `parents`.
+fn nest(parents: &[&str]) -> String {
+    let modules: Vec<String> = parents.iter().map(|p| nest_module(p)).collect();
+    modules.join(".")
+}
+
+/// The path from one module to another, both as dotted parts, then `name`: up to the
+/// nearest common ancestor and back down.
+fn relative_path(from: &[&str], to: &[&str], name: &str) -> String {
+    let common = from.iter().zip(to).take_while(|(a, b)| a == b).count();
+    let mut path = vec!["super".to_string(); from.len() - common];
+    path.extend(to[common..].iter().map(|m| escape(m)));
+    path.push(escape(name));
+    path.join("::")
+}
+
+/// How the code generated for a message nested in `parents` refers to the message or enum
+/// at `path` (the messages it is nested in, then its own name), both in the same module:
+/// `Inner` from `Outer`'s fields is `outer::Inner`, and `Outer` from `Inner`'s is
+/// `super::Outer`.
+pub fn type_path(parents: &[&str], path: &[&str]) -> String {
+    let (name, to) = path.split_last().expect("a type has a name");
+    let (from, to) = (nest(parents), nest(to));
+    relative_path(&dotted(&from), &dotted(&to), name)
+}
+
+/// The parts of a dotted path, none for an empty one.
+fn dotted(path: &str) -> Vec<&str> {
+    path.split('.').filter(|p| !p.is_empty()).collect()
+}
+
+fn read_proto_file(file: &str, includes: &[&str]) -> Result<FileDescriptor, Error> {
+    Ok(FileDescriptor::read_proto(
+        Path::new(file),
+        &search_path(includes),
+    )?)
+}
+
+#[derive(Debug, Clone)]
+pub enum Scalar {
+    Int32,
+    Sint32,
+    Int64,
+    Sint64,
+    Uint32,
+    Uint64,
+    Bool,
+    Fixed32,
+    Sfixed32,
+    Float,
+    Fixed64,
+    Sfixed64,
+    Double,
+    String,
+    Bytes,
+    /// A `string` checked for UTF-8 only when it is read as text: see
+    /// [`StringDecoding::Lazy`].
+    LazyString,
+}
+
+impl Scalar {
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Scalar::Int32 => "int32",
+            Scalar::Sint32 => "sint32",
+            Scalar::Int64 => "int64",
+            Scalar::Sint64 => "sint64",
+            Scalar::Uint32 => "uint32",
+            Scalar::Uint64 => "uint64",
+            Scalar::Bool => "bool",
+            Scalar::Fixed32 => "fixed32",
+            Scalar::Sfixed32 => "sfixed32",
+            Scalar::Float => "float",
+            Scalar::Fixed64 => "fixed64",
+            Scalar::Sfixed64 => "sfixed64",
+            Scalar::Double => "double",
+            Scalar::String | Scalar::LazyString => "string",
+            Scalar::Bytes => "bytes",
+        }
+    }
+
+    pub const fn tacky_type(&self) -> &str {
+        match self {
+            Scalar::Int32 => "Int32",
+            Scalar::Sint32 => "Sint32",
+            Scalar::Int64 => "Int64",
+            Scalar::Sint64 => "Sint64",
+            Scalar::Uint32 => "Uint32",
+            Scalar::Uint64 => "Uint64",
+            Scalar::Bool => "Bool",
+            Scalar::Fixed32 => "Fixed32",
+            Scalar::Sfixed32 => "Sfixed32",
+            Scalar::Float => "Float",
+            Scalar::Fixed64 => "Fixed64",
+            Scalar::Sfixed64 => "Sfixed64",
+            Scalar::Double => "Double",
+            Scalar::String => "PbString",
+            Scalar::Bytes => "PbBytes",
+            Scalar::LazyString => "PbLazyString",
+        }
+    }
+
+    /// The runtime's type for this scalar, by its path.
+    pub fn tacky_path(&self) -> TokenStream {
+        let ident = format_ident!("{}", self.tacky_type());
+        quote!(tacky::#ident)
+    }
+
+    pub const fn wire_type(&self) -> u32 {
+        match self {
+            Scalar::Int32
+            | Scalar::Sint32
+            | Scalar::Int64
+            | Scalar::Sint64
+            | Scalar::Uint32
+            | Scalar::Uint64
+            | Scalar::Bool => 0,
+            Scalar::Fixed32 | Scalar::Sfixed32 | Scalar::Float => 5,
+            Scalar::Fixed64 | Scalar::Sfixed64 | Scalar::Double => 1,
+            Scalar::String | Scalar::Bytes | Scalar::LazyString => 2,
+        }
+    }
+}
+impl std::fmt::Display for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum PbType {
+    Scalar(Scalar),
+    Enum((String, Vec<i32>)), // name and allowed values
+    Message(String),          //name
+    SimpleMap(Scalar, Scalar),
+    Map(Scalar, Box<PbType>),
+}
+
+/// Where generated code refers to other types from.
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    /// The package module being written into, when types from different packages land in
+    /// different modules. `None` when everything a file needs is generated into one.
+    module: Option<&'a str>,
+    /// The module within that, dotted, for the code of a nested message.
+    nest: &'a str,
+    /// Packages another crate has generated, as `(".proto.package", "::rust::path")`.
+    extern_paths: &'a [(String, String)],
+}
+
+impl<'a> Scope<'a> {
+    /// One package module for everything, as for a single file and its imports.
+    const FLAT: Scope<'static> = Scope {
+        module: None,
+        nest: "",
+        extern_paths: &[],
+    };
+
+    /// The same, from inside `module`.
+    fn within<'b>(self, module: &'b str) -> Scope<'b>
+    where
+        'a: 'b,
+    {
+        Scope {
+            module: Some(module),
+            nest: self.nest,
+            extern_paths: self.extern_paths,
+        }
+    }
+
+    /// The same, from `nest`, the module within the package's that a nested message's
+    /// code goes in.
+    fn nested<'b>(self, nest: &'b str) -> Scope<'b>
+    where
+        'a: 'b,
+    {
+        Scope { nest, ..self }
+    }
+
+    /// Where another crate generated `module`, if it falls under one of `extern_paths`. The
+    /// longest match wins, so a subpackage can be mapped apart from its parent.
+    fn extern_module(&self, module: &str) -> Option<String> {
+        let package = format!(".{module}");
+        self.extern_paths
+            .iter()
+            .filter_map(|(proto, rust)| {
+                let rest = package.strip_prefix(proto)?;
+                if !rest.is_empty() && !rest.starts_with('.') {
+                    return None;
+                }
+                let path = rest.split('.').filter(|p| !p.is_empty()).map(escape);
+                Some((proto.len(), std::iter::once(rust.clone()).chain(path)))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, path)| path.collect::<Vec<_>>().join("::"))
+    }
+
+    /// How code here names the type at `path` (the messages it is nested in, then its own
+    /// name) generated into `module`: as-is from the same module, by its path from the
+    /// nearest common ancestor from another one (`super::super::common::v1::AnyValue`), and
+    /// absolutely if another crate has it.
+    fn name(&self, module: &str, path: &[&str]) -> String {
+        let (name, parents) = path.split_last().expect("a type has a name");
+        let nest = nest(parents);
+        if let Some(module) = self.extern_module(module) {
+            return format!("{module}::{}", relative_path(&[], &dotted(&nest), name));
+        }
+        let (mut from, mut to) = (Vec::new(), Vec::new());
+        if let Some(scope) = self.module {
+            from.extend(dotted(scope));
+            to.extend(dotted(module));
+        }
+        from.extend(dotted(self.nest));
+        to.extend(dotted(&nest));
+        relative_path(&from, &to, name)
+    }
+}
+
+fn resolve_type(
+    value: FieldType,
+    desc: &FileDescriptor,
+    scope: Scope,
+) -> Result<PbType, ErrorKind> {
+    let ty = match value {
+        FieldType::Int32 => PbType::Scalar(Scalar::Int32),
+        FieldType::Int64 => PbType::Scalar(Scalar::Int64),
+        FieldType::Uint32 => PbType::Scalar(Scalar::Uint32),
+        FieldType::Uint64 => PbType::Scalar(Scalar::Uint64),
+        FieldType::Sint32 => PbType::Scalar(Scalar::Sint32),
+        FieldType::Sint64 => PbType::Scalar(Scalar::Sint64),
+        FieldType::Bool => PbType::Scalar(Scalar::Bool),
+        FieldType::Fixed64 => PbType::Scalar(Scalar::Fixed64),
+        FieldType::Sfixed64 => PbType::Scalar(Scalar::Sfixed64),
+        FieldType::Double => PbType::Scalar(Scalar::Double),
+        FieldType::String => PbType::Scalar(Scalar::String),
+        FieldType::Bytes => PbType::Scalar(Scalar::Bytes),
+        FieldType::Fixed32 => PbType::Scalar(Scalar::Fixed32),
+        FieldType::Sfixed32 => PbType::Scalar(Scalar::Sfixed32),
+        FieldType::Float => PbType::Scalar(Scalar::Float),
+        FieldType::Map(k, v) => {
+            let kt: PbType = resolve_type(*k, desc, scope)?;
+            let vt: PbType = resolve_type(*v, desc, scope)?;
+            match (kt, vt) {
+                (PbType::Scalar(k), _)
+                    if matches!(k, Scalar::Float | Scalar::Double | Scalar::Bytes) =>
+                {
+                    return Err(invalid_map_key(k.as_str()))
+                }
+                (PbType::Scalar(k), PbType::Scalar(v)) => PbType::SimpleMap(k, v),
+                (PbType::Scalar(_), PbType::Enum(_)) => {
+                    return Err(ErrorKind::Unsupported(
+                        "map fields with enum values".to_string(),
+                    ))
+                }
+                (PbType::Scalar(k), v) => PbType::Map(k, Box::new(v)),
+                (k, _) => return Err(invalid_map_key(&k.to_string())),
+            }
+        }
+        FieldType::Message(m) => {
+            let name = scope.name(m.module(desc), &m.path(desc));
+            PbType::Message(name)
+        }
+        FieldType::Enum(e) => {
+            let name = scope.name(e.module(desc), &e.path(desc));
+            let enum_data = e.get_enum(desc);
+            let values = enum_data.fields.iter().map(|(_, v)| *v).collect();
+            PbType::Enum((name, values))
+        }
+        FieldType::MessageOrEnum(s) => unreachable!(),
+    };
+    Ok(ty)
+}
+
+fn invalid_map_key(key: &str) -> ErrorKind {
+    ErrorKind::InvalidMap(format!(
+        "`{key}` keys: only integral and string types can be map keys"
+    ))
+}
+
+impl PbType {
+    pub const fn wire_type(&self) -> u32 {
+        match self {
+            PbType::Scalar(s) => s.wire_type(),
+            PbType::Enum(_) => 0, //varint
+            PbType::Message(_) | PbType::Map(_, _) | PbType::SimpleMap(_, _) => 2,
+        }
+    }
+
+    /// Whether this type is a scalar that supports packed encoding (everything except string/bytes).
+    pub fn is_packable_scalar(&self) -> bool {
+        match self {
+            PbType::Scalar(Scalar::String | Scalar::Bytes | Scalar::LazyString) => false,
+            PbType::Scalar(_) | PbType::Enum(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for PbType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PbType::Scalar(s) => f.write_str(s.as_str()),
+            PbType::Enum(e) => f.write_str(&e.0),
+            PbType::Message(m) => f.write_str(m),
+            PbType::Map(k, v) => write!(f, "map<{},{}>", k.as_str(), v),
+            PbType::SimpleMap(k, v) => write!(f, "map<{},{}>", k.as_str(), v.as_str()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum Label {
+    Required, //proto2 required fields, N/A to proto3
+    Optional, //Proto2 and Proto3 fields with "optional" label
+    Repeated, //"repeated" fields in both proto2/3
+    Packed,   // packable fields with packed = true in proto2 or by default in proto3
+    Plain,    //field with no label in proto3, not written to the wire if equals to default
+}
+
+#[derive(Clone)]
+pub struct Field {
+    pub name: String,
+    pub number: i32,
+    pub ty: PbType,
+    pub label: Label,
+    /// The proto2 `[default = ...]` value as written in the source, quotes stripped.
+    pub default: Option<String>,
+    /// The field's comments, for its docs: see [`doc_comment`](crate::doc_comment).
+    pub doc: Option<String>,
+    /// `[deprecated = true]`: what is generated for the field is `#[deprecated]`.
+    pub deprecated: bool,
+    /// The field's name in JSON: its `json_name` option, or protoc's lowerCamelCase of its
+    /// name.
+    pub json_name: String,
+    /// Where the field is declared, for errors about it: `None` when it came from a
+    /// descriptor set.
+    pub span: Option<Span>,
+}
+
+impl Field {
+    /// `#[deprecated]`, if the field is.
+    pub(crate) fn deprecation(&self) -> TokenStream {
+        if self.deprecated {
+            quote!(#[deprecated])
+        } else {
+            quote!()
+        }
+    }
+}
+
+pub struct OneOfGroup {
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+fn convert_field(
+    field: &pb_rs::types::Field,
+    desc: &FileDescriptor,
+    scope: Scope,
+) -> Result<Field, Error> {
+    let pb_rs::types::Field {
+        name,
+        frequency,
+        typ,
+        number,
+        default,
+        deprecated,
+        span,
+        comments,
+        features,
+        ..
+    } = field;
+    let mut ty = resolve_type(typ.clone(), desc, scope).map_err(|kind| Error::at(kind, span))?;
+    match ty {
+        // Text nobody checked is bytes, as far as Rust is concerned.
+        PbType::Scalar(Scalar::String) if features.utf8_validation == Utf8Validation::None => {
+            ty = PbType::Scalar(Scalar::Bytes)
+        }
+        PbType::Message(_) if features.message_encoding == MessageEncoding::Delimited => {
+            let kind = ErrorKind::Unsupported(
+                "delimited message encoding, which is proto2's groups".to_string(),
+            );
+            return Err(Error::at(kind, span));
+        }
+        _ => {}
+    }
+    let mut label: Label = frequency.map(|f| f.into()).unwrap_or(Label::Plain);
+
+    // pb-rs's scan_syntax fails on files with leading comments, misdetecting
+    // proto3 as proto2. This causes repeated scalar fields to get Repeated
+    // instead of Packed. Fix it here using the correctly-parsed syntax.
+    if matches!(desc.syntax, pb_rs::types::Syntax::Proto3)
+        && matches!(label, Label::Repeated)
+        && ty.is_packable_scalar()
+        && pb_rs::types::find_option(&field.options, "packed").and_then(OptionValue::as_bool)
+            != Some(false)
+    {
+        label = Label::Packed;
+    }
+
+    Ok(Field {
+        name: name.clone(),
+        number: *number,
+        ty,
+        label,
+        default: default.clone(),
+        doc: doc(comments),
+        deprecated: *deprecated,
+        json_name: field.json_name(),
+        span: Some(span.clone()),
+    })
+}
+
+fn doc(comments: &pb_rs::types::Comments) -> Option<String> {
+    doc_comment(comments.leading.as_deref(), comments.trailing.as_deref())
+}
+impl From<pb_rs::types::Frequency> for Label {
+    fn from(value: pb_rs::types::Frequency) -> Self {
+        match value {
+            pb_rs::types::Frequency::Optional => Label::Optional,
+            pb_rs::types::Frequency::Repeated => Label::Repeated,
+            pb_rs::types::Frequency::Required => Label::Required,
+            pb_rs::types::Frequency::Packed => Label::Packed,
+            pb_rs::types::Frequency::Plain => Label::Plain,
+        }
+    }
+}
+
+fn write_message(
+    m: &Message,
+    desc: &FileDescriptor,
+    scope: Scope,
+    owned: bool,
+    attrs: &Attributes,
+) -> Result<TokenStream, Error> {
+    // Distinct in proto, the same variant of the `Field` enum.
+    let mut variants: Vec<(String, &str)> = Vec::new();
+    for f in m.all_fields() {
+        let variant = heck::AsUpperCamelCase(&f.name).to_string();
+        if let Some((_, first)) = variants.iter().find(|(v, _)| *v == variant) {
+            return Err(Error::at(
+                ErrorKind::Collision(format!(
+                    "fields `{first}` and `{}` are both the `{variant}` variant of `{}Field`",
+                    f.name, m.name
+                )),
+                &f.span,
+            ));
+        }
+        variants.push((variant, &f.name));
+    }
+
+    let convert = |f| {
+        let mut field = convert_field(f, desc, scope)?;
+        attrs.decode_string(&mut field);
+        Ok::<_, Error>(field)
+    };
+
+    // Regular (non-oneof) fields
+    let regular_fields: Vec<Field> = m.fields.iter().map(convert).collect::<Result<_, _>>()?;
+
+    // Oneof groups
+    let oneof_groups: Vec<OneOfGroup> = m
+        .oneofs
+        .iter()
+        .map(|o| {
+            Ok(OneOfGroup {
+                name: o.name.clone(),
+                fields: o.fields.iter().map(convert).collect::<Result<_, Error>>()?,
+            })
+        })
+        .collect::<Result<_, Error>>()?;
+
+    let extensions: Vec<(u32, u32)> = (m.extensions.iter())
+        .map(|e| (e.from as u32, e.to as u32))
+        .collect();
+    message_items_with(
+        &m.name,
+        doc(&m.comments).as_deref(),
+        &regular_fields,
+        &oneof_groups,
+        &extensions,
+        owned,
+        attrs,
+    )
+}
+
+/// Everything generated for one message, from fields already mapped onto [`Field`]:
+/// `regular_fields` outside any oneof, then the oneofs'. `extensions` are the extension
+/// ranges, ends inclusive. `name` is the message's own, unescaped; whatever it is nested in
+/// is up to the module the items go in. `doc` goes on the schema struct. Fails on a field
+/// default that isn't a value of the field's type, and on fields no message can have: see
+/// [`check_field`].
+pub fn message_items(
+    name: &str,
+    doc: Option<&str>,
+    regular_fields: &[Field],
+    oneof_groups: &[OneOfGroup],
+    extensions: &[(u32, u32)],
+    owned: bool,
+) -> Result<TokenStream, Error> {
+    message_items_with(
+        name,
+        doc,
+        regular_fields,
+        oneof_groups,
+        extensions,
+        owned,
+        &Attributes::default(),
+    )
+}
+
+/// [`message_items`], with what `options` sets for the message by path: `full_name` is its
+/// fully-qualified proto name (`.pkg.Outer.Inner`). Attributes made from proto options are
+/// left out, as a [`Field`] doesn't carry its options.
+pub fn message_items_for(
+    options: &PackageOptions,
+    full_name: &str,
+    doc: Option<&str>,
+    regular_fields: &[Field],
+    oneof_groups: &[OneOfGroup],
+    extensions: &[(u32, u32)],
+) -> Result<TokenStream, Error> {
+    let oneof_fields = oneof_groups.iter().flat_map(|o| &o.fields);
+    let fields: Vec<&str> = (regular_fields.iter().chain(oneof_fields))
+        .map(|f| f.name.as_str())
+        .collect();
+    let oneofs: Vec<&str> = oneof_groups.iter().map(|o| o.name.as_str()).collect();
+    let attrs = Attributes::message_by_path(options, full_name, &fields, &oneofs)?;
+    let decoded = |fields: &[Field]| -> Vec<Field> {
+        let mut fields = fields.to_vec();
+        for f in &mut fields {
+            attrs.decode_string(f);
+        }
+        fields
+    };
+    let oneof_groups: Vec<OneOfGroup> = oneof_groups
+        .iter()
+        .map(|o| OneOfGroup {
+            name: o.name.clone(),
+            fields: decoded(&o.fields),
+        })
+        .collect();
+    message_items_with(
+        full_name.rsplit('.').next().unwrap_or(full_name),
+        doc,
+        &decoded(regular_fields),
+        &oneof_groups,
+        extensions,
+        options.owned || options.random,
+        &attrs,
+    )
+}
+
+fn message_items_with(
+    name: &str,
+    doc: Option<&str>,
+    regular_fields: &[Field],
+    oneof_groups: &[OneOfGroup],
+    extensions: &[(u32, u32)],
+    owned: bool,
+    attrs: &Attributes,
+) -> Result<TokenStream, Error> {
+    for f in regular_fields {
+        check_field(f, false)?;
+    }
+    for f in oneof_groups.iter().flat_map(|o| &o.fields) {
+        check_field(f, true)?;
+    }
+
+    // All fields flattened (for the decode enum)
+    let all_fields: Vec<Field> = regular_fields
+        .iter()
+        .chain(oneof_groups.iter().flat_map(|o| &o.fields))
+        .cloned()
+        .collect();
+
+    // Companions are named by appending to this, so `r#type` makes `r#typeFields`.
+    let name = &escape(name);
+    let struct_schema = message_schema(name, doc, regular_fields, oneof_groups, attrs);
+    let field_enum = field_enum(name, &all_fields, extensions);
+    let defaults = crate::defaults::default_consts(name, &all_fields)?;
+    let owned = owned.then(|| {
+        crate::owned::owned_struct(name, regular_fields, oneof_groups, !extensions.is_empty())
+    });
+    let random = (owned.is_some() && attrs.random)
+        .then(|| crate::random::random_impl(name, regular_fields, oneof_groups));
+    let oneof_impls: Vec<TokenStream> = oneof_groups.iter().map(|g| write_oneof(name, g)).collect();
+
+    Ok(quote! {
+        #struct_schema
+        #defaults
+        #field_enum
+        #(#oneof_impls)*
+        #owned
+        #random
+    })
+}
+
+/// What neither `.proto` source nor a descriptor set protoc accepted can declare, but a
+/// [`Field`] put together some other way can: a map keyed by what can't be a key, valued by
+/// another map, or in a oneof, and a repeated field in a oneof. Also maps with enum values,
+/// which are valid but not generated yet.
+fn check_field(f: &Field, in_oneof: bool) -> Result<(), Error> {
+    let kind = match &f.ty {
+        PbType::SimpleMap(k, _) | PbType::Map(k, _)
+            if matches!(k, Scalar::Float | Scalar::Double | Scalar::Bytes) =>
+        {
+            Some(invalid_map_key(k.as_str()))
+        }
+        PbType::SimpleMap(..) | PbType::Map(..) if in_oneof => Some(ErrorKind::InvalidMap(
+            format!("`{}` is in a oneof, which can't hold maps", f.name),
+        )),
+        PbType::Map(_, v) => match &**v {
+            PbType::Message(_) => None,
+            PbType::Enum(_) => Some(ErrorKind::Unsupported(
+                "map fields with enum values".to_string(),
+            )),
+            PbType::Scalar(_) => Some(ErrorKind::InvalidMap(format!(
+                "`{v}` values: a map of scalars is a `PbType::SimpleMap`"
+            ))),
+            PbType::SimpleMap(..) | PbType::Map(..) => Some(ErrorKind::InvalidMap(format!(
+                "`{v}` values: a map's values can't be maps"
+            ))),
+        },
+        _ if in_oneof && matches!(f.label, Label::Repeated | Label::Packed) => {
+            Some(ErrorKind::Unsupported(format!(
+                "`{}` is repeated and in a oneof, which proto doesn't allow",
+                f.name
+            )))
+        }
+        _ => None,
+    };
+    match kind {
+        Some(kind) => Err(Error::new(kind, f.span.clone())),
+        None => Ok(()),
+    }
+}
+
+/// Adds `extends` to `by_extendee`, grouped by the message they extend: several `extend`
+/// blocks may target the same message, and each message gets one registry.
+fn group_extends(
+    extends: &[Extend],
+    desc: &FileDescriptor,
+    scope: Scope,
+    by_extendee: &mut Vec<(String, Vec<Field>)>,
+) -> Result<(), Error> {
+    for e in extends {
+        let extendee = e.extendee.as_ref().expect("extendee resolved by pb-rs");
+        let module = extendee.module(desc);
+        let path = extendee.path(desc);
+        // The extension constants are inherent consts on the extendee's schema.
+        if scope.extern_module(module).is_some() {
+            let kind = ErrorKind::Unsupported(format!(
+                "extending `{module}.{}`, which is generated by another crate",
+                path.join(".")
+            ));
+            return Err(match e.fields.first() {
+                Some(f) => Error::at(kind, &f.span),
+                None => kind.into(),
+            });
+        }
+        let extendee = scope.name(module, &path);
+        let fields = e
+            .fields
+            .iter()
+            .map(|f| {
+                let mut field = convert_field(f, desc, scope)?;
+                // Extensions always track presence, proto3 included.
+                if matches!(field.label, Label::Plain) {
+                    field.label = Label::Optional;
+                }
+                Ok(field)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        match by_extendee.iter_mut().find(|(name, _)| *name == extendee) {
+            Some((_, existing)) => existing.extend(fields),
+            None => by_extendee.push((extendee, fields)),
+        }
+    }
+    Ok(())
+}
+
+/// The extension constants and registry per extended message, given its name and the
+/// extension fields declared on it. Fails on fields no message can have: see
+/// [`check_field`].
+pub fn extension_items(by_extendee: &[(String, Vec<Field>)]) -> Result<TokenStream, Error> {
+    for f in by_extendee.iter().flat_map(|(_, fields)| fields) {
+        check_field(f, false)?;
+    }
+    let items = by_extendee.iter().map(|(extendee, fields)| {
+        let extendee_ty = parse_ty(extendee);
+        let consts = fields.iter().map(|f| {
+            let const_ident = format_ident!("{}", heck::AsShoutySnakeCase(&f.name).to_string());
+            let doc = doc_attrs(f.doc.as_deref());
+            let deprecated = f.deprecation();
+            let ty = schema_type(f);
+            quote!(#doc #deprecated pub const #const_ident: #ty = tacky::Field::new();)
+        });
+        let registry = extension_enum(extendee, fields);
+        quote! {
+            impl #extendee_ty {
+                #(#consts)*
+            }
+            #registry
+        }
+    });
+    Ok(quote!(#(#items)*))
+}
+
+fn write_service(
+    s: &RpcService,
+    desc: &FileDescriptor,
+    scope: Scope,
+) -> Result<TokenStream, Error> {
+    let resolved = |index: &Option<pb_rs::types::MessageIndex>| {
+        let index = index.as_ref().expect("rpc types resolved by pb-rs");
+        scope.name(index.module(desc), &index.path(desc))
+    };
+    let full_name = proto_name(&desc.package, &s.service_name);
+    service_items(&Service {
+        name: s.service_name.clone(),
+        full_name: full_name[1..].to_string(),
+        doc: doc(&s.comments),
+        methods: s
+            .functions
+            .iter()
+            .map(|f| ServiceMethod {
+                name: f.name.clone(),
+                doc: doc(&f.comments),
+                input: resolved(&f.input),
+                output: resolved(&f.output),
+                client_streaming: f.client_streaming,
+                server_streaming: f.server_streaming,
+                span: Some(f.span.clone()),
+            })
+            .collect(),
+    })
+}
+
+fn write_enum(e: &Enumerator, attrs: &Attributes) -> Result<TokenStream, Error> {
+    let mut variants: Vec<(String, &str)> = Vec::new();
+    for (name, _) in &e.fields {
+        let variant = heck::AsUpperCamelCase(name).to_string();
+        if let Some((_, first)) = variants.iter().find(|(v, _)| *v == variant) {
+            return Err(Error::at(
+                ErrorKind::Collision(format!(
+                    "values `{first}` and `{name}` are both `{}::{variant}`",
+                    e.name
+                )),
+                &e.span,
+            ));
+        }
+        variants.push((variant, name));
+    }
+    if !e.allow_alias {
+        for (i, (name, number)) in e.fields.iter().enumerate() {
+            if let Some((first, _)) = e.fields[..i].iter().find(|(_, n)| n == number) {
+                return Err(Error::at(
+                    ErrorKind::InvalidEnum(format!(
+                        "`{name}` reuses {number}, the number of `{first}`; \
+                         aliases need `option allow_alias = true;`"
+                    )),
+                    &e.span,
+                ));
+            }
+        }
+    }
+    let values: Vec<EnumValue> = e
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, (name, number))| EnumValue {
+            name: name.clone(),
+            number: *number,
+            doc: e.value_comments.get(i).and_then(doc),
+        })
+        .collect();
+    Ok(enum_items_with(
+        &e.name,
+        doc(&e.comments).as_deref(),
+        &values,
+        e.closed,
+        attrs,
+    ))
+}
+
+/// One value of an enum, for [`enum_items`].
+pub struct EnumValue {
+    pub name: String,
+    pub number: i32,
+    /// The value's comments, for its docs: see [`doc_comment`](crate::doc_comment).
+    pub doc: Option<String>,
+}
+
+/// An enum with a variant per value, and its `i32` conversions. A name reusing an earlier
+/// one's number is an alias: an associated const for that variant. `doc` goes on the enum.
+///
+/// An open enum converts from any `i32`, keeping numbers it doesn't declare in an
+/// `__Unrecognized` variant. A `closed` one, as proto2 declares them, has no such variant
+/// and converts with `TryFrom<i32>`; decoders skip the undeclared numbers.
+pub fn enum_items(
+    name: &str,
+    doc: Option<&str>,
+    values: &[EnumValue],
+    closed: bool,
+) -> TokenStream {
+    enum_items_with(name, doc, values, closed, &Attributes::default())
+}
+
+/// [`enum_items`], with the attributes `options` sets for the enum by path: `full_name` is
+/// its fully-qualified proto name (`.pkg.Kind`).
+pub fn enum_items_for(
+    options: &PackageOptions,
+    full_name: &str,
+    doc: Option<&str>,
+    values: &[EnumValue],
+    closed: bool,
+) -> Result<TokenStream, Error> {
+    let attrs = Attributes::enum_by_path(options, full_name)?;
+    let name = full_name.rsplit('.').next().unwrap_or(full_name);
+    Ok(enum_items_with(name, doc, values, closed, &attrs))
+}
+
+fn enum_items_with(
+    name: &str,
+    doc: Option<&str>,
+    values: &[EnumValue],
+    closed: bool,
+    attrs: &Attributes,
+) -> TokenStream {
+    let name_ident = ident(name);
+    let doc = doc_attrs(doc);
+    let type_attrs = &attrs.ty;
+    let variant = variant_ident;
+
+    // The first name for each number is its variant; later ones alias it.
+    let mut canonical: Vec<(&str, i32)> = Vec::new();
+    let mut aliases: Vec<(&EnumValue, &str)> = Vec::new();
+    let mut variant_docs = Vec::new();
+    for value in values {
+        let (name, number) = (value.name.as_str(), value.number);
+        match canonical.iter().find(|(_, n)| *n == number) {
+            Some((first, _)) => aliases.push((value, first)),
+            None => {
+                canonical.push((name, number));
+                variant_docs.push(doc_attrs(value.doc.as_deref()));
+            }
+        }
+    }
+
+    let variants = canonical.iter().map(|(name, _)| variant(name));
+    let values_list = canonical.iter().map(|(name, _)| variant(name));
+    let alias_consts = aliases.iter().map(|(alias, of)| {
+        let doc = doc_attrs(alias.doc.as_deref());
+        let (alias, of) = (variant(&alias.name), variant(of));
+        quote! {
+            #doc
+            #[allow(non_upper_case_globals)]
+            pub const #alias: #name_ident = #name_ident::#of;
+        }
+    });
+    let str_names = canonical.iter().map(|(name, _)| {
+        let ident = variant(name);
+        quote!(#name_ident::#ident => Some(#name))
+    });
+    let from_str_names = values.iter().map(|EnumValue { name, .. }| {
+        let ident = variant(name);
+        quote!(#name => Some(#name_ident::#ident))
+    });
+    let from_i32_matches = canonical.iter().map(|(name, number)| {
+        let ident = variant(name);
+        if closed {
+            quote!(#number => Ok(#name_ident::#ident))
+        } else {
+            quote!(#number => #name_ident::#ident)
+        }
+    });
+    let into_i32_matches = canonical.iter().map(|(name, number)| {
+        let ident = variant(name);
+        quote!(#name_ident::#ident => #number)
+    });
+
+    let (unrecognized, unrecognized_name, unrecognized_i32, from_i32) = if closed {
+        (
+            quote!(),
+            quote!(),
+            quote!(),
+            quote! {
+                impl ::core::convert::TryFrom<i32> for #name_ident {
+                    type Error = tacky::UnknownEnumValue;
+                    fn try_from(value: i32) -> ::core::result::Result<Self, tacky::UnknownEnumValue> {
+                        match value {
+                            #(#from_i32_matches,)*
+                            v => Err(tacky::UnknownEnumValue(v)),
+                        }
+                    }
+                }
+                impl tacky::PbEnumType for #name_ident {
+                    fn from_wire(n: i32) -> ::core::option::Option<Self> {
+                        <Self as ::core::convert::TryFrom<i32>>::try_from(n).ok()
+                    }
+                }
+            },
+        )
+    } else {
+        (
+            quote!(__Unrecognized(i32),),
+            quote!(#name_ident::__Unrecognized(_) => None,),
+            quote!(#name_ident::__Unrecognized(v) => v,),
+            quote! {
+                impl ::core::convert::From<i32> for #name_ident {
+                    fn from(value: i32) -> Self {
+                        match value {
+                            #(#from_i32_matches,)*
+                            v => #name_ident::__Unrecognized(v),
+                        }
+                    }
+                }
+            },
+        )
+    };
+
+    quote! {
+        #doc
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+        #(#type_attrs)*
+        pub enum #name_ident {
+            #[default]
+            #(#variant_docs #variants,)*
+            #unrecognized
+        }
+
+        impl #name_ident {
+            #(#alias_consts)*
+
+            /// Every value declared, aliases aside, in declaration order.
+            pub const VALUES: &'static [#name_ident] = &[#(#name_ident::#values_list),*];
+
+            /// The value's name as declared, or `None` for a number the enum doesn't declare.
+            pub fn as_str_name(&self) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#str_names,)*
+                    #unrecognized_name
+                }
+            }
+
+            /// The value declared as `name`, aliases included.
+            pub fn from_str_name(name: &str) -> ::core::option::Option<Self> {
+                match name {
+                    #(#from_str_names,)*
+                    _ => None,
+                }
+            }
+        }
+
+        #from_i32
+        impl ::core::convert::From<#name_ident> for i32 {
+            fn from(value: #name_ident) -> i32 {
+                match value {
+                    #(#into_i32_matches,)*
+                    #unrecognized_i32
+                }
+            }
+        }
+    }
+}
+
+fn message_schema(
+    name: &str,
+    doc: Option<&str>,
+    fields: &[Field],
+    oneofs: &[OneOfGroup],
+    attrs: &Attributes,
+) -> TokenStream {
+    let name_ident = format_ident!("{name}");
+    let doc = doc_attrs(doc);
+    let type_attrs = &attrs.ty;
+    let field_defs = fields.iter().map(|f| {
+        let field_attrs = attrs.field(&f.name);
+        let def = field_type(f);
+        quote!(#(#field_attrs)* #def)
+    });
+    let oneof_defs = oneofs.iter().map(|o| {
+        let field_attrs = attrs.field(&o.name);
+        let field_name = ident(&o.name);
+        let marker_name = format_ident!("{}{}", name, heck::AsUpperCamelCase(&o.name).to_string());
+        quote!(#(#field_attrs)* pub #field_name: #marker_name)
+    });
+    let k = format_ident!("{name}Fields");
+    quote! {
+        #doc
+        #[derive(Debug, Copy, Clone)]
+        #(#type_attrs)*
+        pub struct #name_ident {
+            #(#field_defs,)*
+            #(#oneof_defs,)*
+        }
+        impl tacky::MessageSchema for #name_ident {}
+        impl tacky::DecodeMessage for #name_ident {
+            type Fields<'a> = #k<'a>;
+        }
+        impl #name_ident {
+            pub fn schema() -> Self {
+                <Self as tacky::MessageSchema>::schema()
+            }
+            pub fn decode(buf: &[u8])-> #k<'_> {
+                #k::new(buf)
+            }
+        }
+    }
+}
+
+fn write_oneof(msg_name: &str, group: &OneOfGroup) -> TokenStream {
+    let marker_name = format_ident!(
+        "{}{}",
+        msg_name,
+        heck::AsUpperCamelCase(&group.name).to_string()
+    );
+
+    let write_methods: Vec<TokenStream> = group
+        .fields
+        .iter()
+        .map(|f| {
+            let method_name = format_ident!("write_{}", f.name);
+            let number = f.number as u32;
+            let doc = doc_attrs(f.doc.as_deref());
+            let deprecated = f.deprecation();
+
+            match &f.ty {
+                PbType::Scalar(s) => {
+                    let tacky_ty = s.tacky_path();
+                    quote! {
+                        #doc
+                        #deprecated
+                        pub fn #method_name<B: tacky::WriteBuf>(self, buf: &mut B, value: impl tacky::ProtoEncode<#tacky_ty>) -> Self {
+                            let t = const { tacky::EncodedTag::new(#number, <#tacky_ty as tacky::ProtobufScalar>::WIRE_TYPE) };
+                            if B::REVERSE {
+                                <#tacky_ty as tacky::ProtobufScalar>::write_value(value.as_scalar(), buf);
+                                t.write(buf);
+                            } else {
+                                t.write(buf);
+                                <#tacky_ty as tacky::ProtobufScalar>::write_value(value.as_scalar(), buf);
+                            }
+                            Self
+                        }
+                    }
+                }
+                PbType::Enum((name, _)) => {
+                    let enum_ident = parse_ty(name);
+                    quote! {
+                        #doc
+                        #deprecated
+                        pub fn #method_name<B: tacky::WriteBuf>(self, buf: &mut B, value: impl tacky::ProtoEncode<tacky::PbEnum<#enum_ident>>) -> Self {
+                            let t = const { tacky::EncodedTag::new(#number, tacky::WireType::VARINT) };
+                            if B::REVERSE {
+                                <tacky::PbEnum<#enum_ident> as tacky::ProtobufScalar>::write_value(value.as_scalar(), buf);
+                                t.write(buf);
+                            } else {
+                                t.write(buf);
+                                <tacky::PbEnum<#enum_ident> as tacky::ProtobufScalar>::write_value(value.as_scalar(), buf);
+                            }
+                            Self
+                        }
+                    }
+                }
+                PbType::Message(msg) => {
+                    let msg_ident = parse_ty(msg);
+                    let method_name = format_ident!("write_{}_msg", f.name);
+                    quote! {
+                        #doc
+                        #deprecated
+                        pub fn #method_name<B: tacky::WriteBuf>(self, buf: &mut B, mut f: impl ::core::ops::FnMut(&mut B, #msg_ident)) -> Self {
+                            let t = const { tacky::EncodedTag::new(#number, tacky::WireType::LEN) };
+                            buf.put_msg(t, |buf| f(buf, #msg_ident::schema()));
+                            Self
+                        }
+                    }
+                }
+                PbType::SimpleMap(..) | PbType::Map(..) => {
+                    unreachable!("oneof maps are rejected by `check_field`")
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        #[derive(Default, Debug, Copy, Clone)]
+        pub struct #marker_name;
+
+        impl #marker_name {
+            #(#write_methods)*
+        }
+    }
+}
+
+pub fn write_proto(file: &str, output: &str) {
+    write_proto_with_includes(file, output, &["."])
+}
+
+/// Generates `file` and what it imports into `output`. Imports are looked for as protoc
+/// looks for them, under each of `includes` in turn, relative ones taken from the working
+/// directory, and nowhere else.
+pub fn write_proto_with_includes(file: &str, output: &str, includes: &[&str]) {
+    generate(file, output, includes, false)
+}
+
+/// Like [`write_proto_with_includes`], but also generates a `<Message>Owned` struct per
+/// message: a plain owned copy of the message with `from_bytes` and `encode`, for the
+/// places that want one in memory rather than streamed through the schema.
+pub fn write_proto_with_owned(file: &str, output: &str, includes: &[&str]) {
+    generate(file, output, includes, true)
+}
+
+fn generate(file: &str, output: &str, includes: &[&str], owned: bool) {
+    let test_file = read_proto_file(file, includes).unwrap_or_else(|e| panic!("{e}"));
+    let token_stream =
+        file_module(&test_file, &test_file.module, owned).unwrap_or_else(|e| panic!("{e}"));
+
+    // eprintln!("GENERATED CODE:\n{}", token_stream.to_string());
+
+    write_formatted(token_stream, output);
+}
+
+/// Generates several files, and everything they import, into the one file at `output`,
+/// with a module per proto package rather than per file. Each message and enum is
+/// generated once, in its own package's module, and the others refer to it by relative
+/// path, so files that share imports share the types too. Include `output` once; the
+/// package modules hang off wherever it is included.
+///
+/// Extensions are generated for the listed files, into the module of the package that
+/// declares them.
+pub fn write_protos(files: &[&str], output: &str, includes: &[&str]) {
+    write_protos_with_extern(files, output, includes, &[]);
+}
+
+/// Like [`write_protos`], but the packages in `extern_paths` are left to the crate that
+/// already generates them: `(".company.common", "::common_protos")` makes every reference
+/// into `company.common`, or a package under it, a path into `::common_protos`, and nothing
+/// in them is generated here. The other crate's module for the package goes on the right.
+///
+/// Extending a message from an extern package is unsupported, as the extension constants
+/// are inherent consts on its schema.
+pub fn write_protos_with_extern(
+    files: &[&str],
+    output: &str,
+    includes: &[&str],
+    extern_paths: &[(&str, &str)],
+) {
+    for (proto, _) in extern_paths {
+        assert!(
+            proto.starts_with('.'),
+            "extern path `{proto}` should be a fully-qualified package, with a leading `.`"
+        );
+    }
+    let files: Vec<&Path> = files.iter().map(Path::new).collect();
+    let options = PackageOptions {
+        extern_paths: extern_paths
+            .iter()
+            .map(|(proto, rust)| (proto.to_string(), rust.to_string()))
+            .collect(),
+        ..Default::default()
+    };
+    let expansion = expand_packages(&files, includes, &options).unwrap_or_else(|e| panic!("{e}"));
+    write_formatted(expansion.tokens, output);
+}
+
+/// Everything [`expand_packages`] can be asked for beyond the schemas themselves: what
+/// `tacky_build::Config` collects.
+///
+/// Attributes are `(path, attribute)` pairs, the path being a fully-qualified proto name
+/// the attribute applies to along with everything under it: `.` for everything,
+/// `.company.common` for a package, `.company.common.Event` for a message and those nested
+/// in it, and, for fields, `.company.common.Event.id`.
+#[derive(Debug, Clone, Default)]
+pub struct PackageOptions {
+    /// Also generate `<Message>Owned` structs, as [`write_proto_with_owned`] does.
+    pub owned: bool,
+    /// Also generate `<Message>Owned::random`, a random message and an encoding of it for
+    /// property tests. Implies `owned`: it is every message or none, since a message's
+    /// `random` calls those of the messages in it.
+    pub random: bool,
+    /// Packages another crate generates, as in [`write_protos_with_extern`].
+    pub extern_paths: Vec<(String, String)>,
+    /// The path generated code reaches the runtime through, when it isn't `::tacky`: a
+    /// crate that re-exports it, say.
+    pub runtime_path: Option<String>,
+    /// On the schema struct of each matching message, and each matching enum.
+    pub type_attributes: Vec<(String, String)>,
+    /// On each matching field of a schema struct; a oneof's field goes by the oneof's name.
+    pub field_attributes: Vec<(String, String)>,
+    /// On each matching enum.
+    pub enum_attributes: Vec<(String, String)>,
+    /// Made from the options messages, fields, oneofs and enums set, rather than by path.
+    pub option_attributes: Vec<OptionAttribute>,
+    /// The [style lints](crate::lint::Style) to check, and how seriously.
+    pub style_lints: Vec<(Style, Level)>,
+    /// How each matching `string` field decodes, the last match winning. Map keys and
+    /// values are always checked.
+    pub string_decoding: Vec<(String, StringDecoding)>,
+}
+
+/// How a `string` field's UTF-8 is checked when it is decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StringDecoding {
+    /// Checked as it is decoded, into a `&str`.
+    #[default]
+    Strict,
+    /// Not checked at all: the field decodes to `&[u8]`, as `utf8_validation = NONE`
+    /// makes it in an edition.
+    Unchecked,
+    /// Checked when it is read as text: the field decodes to a `tacky::PbStr`, whose
+    /// `as_str` fails on what isn't UTF-8.
+    Lazy,
+}
+
+/// Makes the attribute for an option's value, if there is one for it, such as
+/// `#[serde(skip)]`.
+pub type OptionAttributeFn = dyn Fn(&OptionValue) -> Option<String> + Send + Sync;
+
+/// An attribute made from an option's value, for whatever sets the option: the schema
+/// struct of a message, a schema struct field (a oneof's, for a oneof option) or an enum.
+#[derive(Clone)]
+pub struct OptionAttribute {
+    /// The option as it is written where it is set, without whitespace: `deprecated`,
+    /// `(my.ext)`, `(my.ext).flag`.
+    pub option: String,
+    pub attribute: Arc<OptionAttributeFn>,
+}
+
+impl fmt::Debug for OptionAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptionAttribute")
+            .field("option", &self.option)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether `path` covers the fully-qualified proto name `name`.
+fn path_matches(path: &str, name: &str) -> bool {
+    path == "."
+        || name
+            .strip_prefix(path)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// The attributes in `by_path` that apply to `name`, parsed.
+fn attributes_for(by_path: &[(String, String)], name: &str) -> Result<Vec<TokenStream>, Error> {
+    by_path
+        .iter()
+        .filter(|(path, _)| path_matches(path, name))
+        .map(|(path, attr)| parse_attribute(path, attr))
+        .collect()
+}
+
+/// The attributes `hooks` make of the options `set` on the declaration called `name`.
+fn option_attributes_for(
+    hooks: &[OptionAttribute],
+    set: &[ProtoOption],
+    name: &str,
+) -> Result<Vec<TokenStream>, Error> {
+    let mut attrs = Vec::new();
+    for hook in hooks {
+        for option in set.iter().filter(|o| o.name == hook.option) {
+            if let Some(attr) = (hook.attribute)(&option.value) {
+                attrs.push(parse_attribute(name, &attr)?);
+            }
+        }
+    }
+    Ok(attrs)
+}
+
+fn parse_attribute(path: &str, attr: &str) -> Result<TokenStream, Error> {
+    syn::parse::Parser::parse_str(syn::Attribute::parse_outer, attr)
+        .map(|attrs| quote!(#(#attrs)*))
+        .map_err(|_| {
+            Error::from(ErrorKind::InvalidAttribute {
+                path: path.to_string(),
+                attribute: attr.to_string(),
+            })
+        })
+}
+
+/// Attributes [`PackageOptions`] adds to one message or enum.
+#[derive(Default)]
+struct Attributes {
+    /// On the schema struct, or the enum.
+    ty: Vec<TokenStream>,
+    /// On schema struct fields, by proto field name.
+    fields: Vec<(String, TokenStream)>,
+    /// The fields with a [`StringDecoding`] other than the default, by proto field name.
+    strings: Vec<(String, StringDecoding)>,
+    /// Whether the owned struct gets a `random` constructor.
+    random: bool,
+}
+
+impl Attributes {
+    fn message(options: &PackageOptions, m: &Message) -> Result<Self, Error> {
+        let name = proto_name(&m.package, &m.name);
+        let fields: Vec<&str> = m.all_fields().map(|f| f.name.as_str()).collect();
+        let oneofs: Vec<&str> = m.oneofs.iter().map(|o| o.name.as_str()).collect();
+        let mut attrs = Attributes::message_by_path(options, &name, &fields, &oneofs)?;
+        for (field, set) in m
+            .all_fields()
+            .map(|f| (&f.name, &f.options))
+            .chain(m.oneofs.iter().map(|o| (&o.name, &o.options)))
+        {
+            let path = format!("{name}.{field}");
+            for attr in option_attributes_for(&options.option_attributes, set, &path)? {
+                attrs.fields.push((field.clone(), attr));
+            }
+        }
+        attrs.ty.extend(option_attributes_for(
+            &options.option_attributes,
+            &m.options,
+            &name,
+        )?);
+        Ok(attrs)
+    }
+
+    /// What `options` sets by path for the message `name`, fully-qualified, whose fields
+    /// are `fields` and whose oneofs are `oneofs`.
+    fn message_by_path(
+        options: &PackageOptions,
+        name: &str,
+        fields: &[&str],
+        oneofs: &[&str],
+    ) -> Result<Self, Error> {
+        let mut strings = Vec::new();
+        for field in fields {
+            let path = format!("{name}.{field}");
+            if let Some((_, decoding)) = options
+                .string_decoding
+                .iter()
+                .rev()
+                .find(|(p, _)| path_matches(p, &path))
+            {
+                strings.push((field.to_string(), *decoding));
+            }
+        }
+        let mut field_attrs = Vec::new();
+        for field in fields.iter().chain(oneofs) {
+            let path = format!("{name}.{field}");
+            for attr in attributes_for(&options.field_attributes, &path)? {
+                field_attrs.push((field.to_string(), attr));
+            }
+        }
+        Ok(Attributes {
+            ty: attributes_for(&options.type_attributes, name)?,
+            fields: field_attrs,
+            strings,
+            random: options.random,
+        })
+    }
+
+    fn enumeration(options: &PackageOptions, e: &Enumerator) -> Result<Self, Error> {
+        let name = proto_name(&e.package, &e.name);
+        let mut attrs = Attributes::enum_by_path(options, &name)?;
+        attrs.ty.extend(option_attributes_for(
+            &options.option_attributes,
+            &e.options,
+            &name,
+        )?);
+        Ok(attrs)
+    }
+
+    /// What `options` sets by path for the enum `name`, fully-qualified.
+    fn enum_by_path(options: &PackageOptions, name: &str) -> Result<Self, Error> {
+        let mut ty = attributes_for(&options.type_attributes, name)?;
+        ty.extend(attributes_for(&options.enum_attributes, name)?);
+        Ok(Attributes {
+            ty,
+            ..Attributes::default()
+        })
+    }
+
+    /// What a `string` field decodes to, once it is converted.
+    fn decode_string(&self, field: &mut Field) {
+        if !matches!(field.ty, PbType::Scalar(Scalar::String)) {
+            return;
+        }
+        match self.strings.iter().find(|(name, _)| *name == field.name) {
+            Some((_, StringDecoding::Unchecked)) => field.ty = PbType::Scalar(Scalar::Bytes),
+            Some((_, StringDecoding::Lazy)) => field.ty = PbType::Scalar(Scalar::LazyString),
+            Some((_, StringDecoding::Strict)) | None => {}
+        }
+    }
+
+    fn field<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a TokenStream> {
+        self.fields
+            .iter()
+            .filter(move |(field, _)| field == name)
+            .map(|(_, attr)| attr)
+    }
+}
+
+/// `.package.Name`, or `.Name` outside any package.
+fn proto_name(package: &str, name: &str) -> String {
+    if package.is_empty() {
+        format!(".{name}")
+    } else {
+        format!(".{package}.{name}")
+    }
+}
+
+/// Generated items by the module they go in, a dotted path: one per package and, within
+/// it, one per message that has messages or enums nested in it (`outer` for `Outer`).
+#[derive(Default)]
+pub struct ModuleTree {
+    items: Vec<TokenStream>,
+    extends: Vec<(String, Vec<Field>)>,
+    children: BTreeMap<String, ModuleTree>,
+}
+
+impl ModuleTree {
+    fn at(&mut self, module: &str) -> &mut ModuleTree {
+        dotted(module).into_iter().fold(self, |m, part| {
+            m.children.entry(part.to_string()).or_default()
+        })
+    }
+
+    /// Adds `item`, generated for a message or enum nested in `parents` (the messages'
+    /// names, outermost first), to the module it goes in.
+    pub fn push(&mut self, parents: &[&str], item: TokenStream) {
+        self.at(&nest(parents)).items.push(item);
+    }
+
+    /// The items, then a module per child, each starting with `prelude`.
+    pub fn into_tokens(self, prelude: &TokenStream) -> Result<TokenStream, Error> {
+        let items = self.items;
+        let extends = extension_items(&self.extends)?;
+        let children = self
+            .children
+            .into_iter()
+            .map(|(name, child)| {
+                let mod_name = ident(&name);
+                let inner = child.into_tokens(prelude)?;
+                Ok(quote! {
+                    pub mod #mod_name {
+                        #prelude
+                        #inner
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(quote! {
+            #(#items)*
+            #extends
+            #(#children)*
+        })
+    }
+}
+
+/// Generates the proto files at `files` and everything they import, a module per proto
+/// package, as [`write_protos`] writes them; imports resolve against `includes`.
+pub fn expand_packages(
+    files: &[&Path],
+    includes: &[&str],
+    options: &PackageOptions,
+) -> Result<Expansion, Error> {
+    let search_path = search_path(includes);
+    let mut descs = Vec::new();
+    let mut read = Vec::new();
+    for file in files {
+        if read.iter().any(|f| f == file) {
+            continue;
+        }
+        let desc = FileDescriptor::read_proto(file, &search_path)?;
+        read.push(file.to_path_buf());
+        descs.push(desc);
+    }
+    for desc in &descs {
+        for import in &desc.resolved_imports {
+            if !read.contains(import) {
+                read.push(import.clone());
+            }
+        }
+    }
+    let warnings = lint::enforce(&descs, &options.style_lints)?;
+    Ok(Expansion {
+        tokens: package_tree(&descs, options)?,
+        files: read,
+        warnings,
+    })
+}
+
+fn package_tree(descs: &[FileDescriptor], options: &PackageOptions) -> Result<TokenStream, Error> {
+    let outside = Scope {
+        module: None,
+        nest: "",
+        extern_paths: &options.extern_paths,
+    };
+    let mut root = ModuleTree::default();
+    // Imports shared between files come back with each of them; keyed by module, the
+    // messages nested in, and name.
+    let mut generated = HashSet::new();
+    for desc in descs {
+        for top in &desc.messages {
+            let module = top.module.as_str();
+            if outside.extern_module(module).is_some() {
+                continue;
+            }
+            for (m, parents) in collect_all_messages(std::slice::from_ref(top), &[]) {
+                if generated.insert(format!("{module}.{}.{}", parents.join("."), m.name)) {
+                    let attrs = Attributes::message(options, m)?;
+                    let nest = nest(&parents);
+                    let scope = outside.within(module).nested(&nest);
+                    let owned = options.owned || options.random;
+                    let item = write_message(m, desc, scope, owned, &attrs)?;
+                    root.at(module).push(&parents, item);
+                }
+            }
+        }
+        let nested = desc.messages.iter().map(|m| {
+            let enums = collect_all_enums(&m.messages, &m.enums, &[&m.name]);
+            (m.module.as_str(), enums)
+        });
+        let top = desc
+            .enums
+            .iter()
+            .map(|e| (e.module.as_str(), vec![(e, Vec::new())]));
+        for (module, enums) in top.chain(nested) {
+            if outside.extern_module(module).is_some() {
+                continue;
+            }
+            for (e, parents) in enums {
+                if generated.insert(format!("{module}.{}.{}", parents.join("."), e.name)) {
+                    let attrs = Attributes::enumeration(options, e)?;
+                    root.at(module).push(&parents, write_enum(e, &attrs)?);
+                }
+            }
+        }
+        let module = desc.module.as_str();
+        if outside.extern_module(module).is_none() {
+            for s in &desc.rpc_services {
+                let service = write_service(s, desc, outside.within(module))?;
+                root.at(module).items.push(service);
+            }
+        }
+        group_extends(
+            &desc.message_extends,
+            desc,
+            outside.within(module),
+            &mut root.at(module).extends,
+        )?;
+    }
+
+    let prelude = runtime_prelude(options.runtime_path.as_deref())?;
+    let packages = root
+        .children
+        .into_iter()
+        .map(|(name, package)| {
+            let inner = package.into_tokens(&prelude)?;
+            Ok(in_package(&name, quote!(#prelude #inner)))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let tokens = quote!(#(#packages)*);
+    check_names(&tokens)?;
+    Ok(tokens)
+}
+
+/// What starts every generated module: the runtime is reached as `tacky` from inside the
+/// generated code, and a runtime at `runtime_path` is aliased to that name.
+pub fn runtime_prelude(runtime_path: Option<&str>) -> Result<TokenStream, Error> {
+    Ok(match runtime_path {
+        None => quote!(
+            use ::tacky;
+        ),
+        Some(path) => {
+            let path: syn::Path = syn::parse_str(path)
+                .map_err(|_| Error::from(ErrorKind::InvalidPath(path.to_string())))?;
+            quote! {
+                use #path as tacky;
+            }
+        }
+    })
+}
+
+pub fn write_formatted(token_stream: TokenStream, output: &str) {
+    let formatted = format_tokens(token_stream);
+    let mut file = std::fs::File::create(output).unwrap();
+    file.write_all(formatted.as_bytes()).unwrap();
+}
+
+/// Generated code as source text, the way every output file is written.
+pub fn format_tokens(token_stream: TokenStream) -> String {
+    let syntax_tree = syn::parse2(token_stream).unwrap();
+    prettyplease::unparse(&syntax_tree)
+}
+
+/// Everything generated for one file in the module `module`, checked for names that
+/// collide.
+fn file_module(desc: &FileDescriptor, module: &str, owned: bool) -> Result<TokenStream, Error> {
+    lint::enforce(std::slice::from_ref(desc), &[])?;
+    let tokens = in_package(module, file_items(desc, owned)?);
+    check_names(&tokens)?;
+    Ok(tokens)
+}
+
+/// Everything generated for one file, unwrapped: the messages, enums, services and
+/// extensions, with a module per message that has others nested in it.
+fn file_items(test_file: &FileDescriptor, owned: bool) -> Result<TokenStream, Error> {
+    let mut root = ModuleTree::default();
+    for (m, parents) in collect_all_messages(&test_file.messages, &[]) {
+        let nest = nest(&parents);
+        let scope = Scope::FLAT.nested(&nest);
+        let item = write_message(m, test_file, scope, owned, &Attributes::default())?;
+        root.push(&parents, item);
+    }
+    for (e, parents) in collect_all_enums(&test_file.messages, &test_file.enums, &[]) {
+        root.push(&parents, write_enum(e, &Attributes::default())?);
+    }
+    for s in &test_file.rpc_services {
+        root.push(&[], write_service(s, test_file, Scope::FLAT)?);
+    }
+    group_extends(
+        &test_file.message_extends,
+        test_file,
+        Scope::FLAT,
+        &mut root.extends,
+    )?;
+
+    let prelude = quote!(
+        use ::tacky;
+    );
+    let inner = root.into_tokens(&prelude)?;
+    Ok(quote!(#prelude #inner))
+}
+
+/// Wraps `inner` in nested modules for a dotted package name (e.g. "perftools.profiles").
+pub fn in_package(package: &str, mut inner: TokenStream) -> TokenStream {
+    for part in package.rsplit('.') {
+        let mod_name = ident(part);
+        inner = quote! {
+            pub mod #mod_name {
+                #inner
+            }
+        };
+    }
+    quote! {
+        #[allow(unused, dead_code, clippy::all)]
+        #inner
+    }
+}
+
+/// Code generated in-process rather than written out, for `tacky::include_proto!` and
+/// `tacky::proto!`, and for `tacky_build::Config` to write itself.
+pub struct Expansion {
+    /// The same items [`write_proto_with_includes`] or [`write_protos`] writes, in the
+    /// same modules.
+    pub tokens: TokenStream,
+    /// The proto files, if there are any, and every file they import: what the expansion
+    /// has to be redone for when one changes.
+    pub files: Vec<PathBuf>,
+    /// What the style lints found at [`Level::Warn`].
+    pub warnings: Vec<Violation>,
+}
+
+/// Generates code for the proto file at `file`, resolving imports as
+/// [`write_proto_with_includes`] does.
+pub fn expand_proto_file(file: &Path, includes: &[&str]) -> Result<Expansion, Error> {
+    let desc = FileDescriptor::read_proto(file, &search_path(includes))?;
+    let mut files = vec![file.to_path_buf()];
+    files.extend(desc.resolved_imports.iter().cloned());
+    Ok(Expansion {
+        tokens: file_module(&desc, &desc.module, false)?,
+        files,
+        warnings: Vec::new(),
+    })
+}
+
+/// Generates code for proto source text, as if it were the file at `file`: imports
+/// resolve relative to that path, which need not exist.
+///
+/// Without a `package`, the items are re-exported into the enclosing scope from a
+/// private `__tacky_inline` module, rather than landing in a module named after the file.
+pub fn expand_proto_source(
+    source: &str,
+    file: &Path,
+    includes: &[&str],
+) -> Result<Expansion, Error> {
+    let desc = FileDescriptor::read_proto_str(source, file, &search_path(includes))?;
+    let tokens = if desc.package.is_empty() {
+        let inner = file_module(&desc, "__tacky_inline", false)?;
+        quote! {
+            #inner
+            pub use __tacky_inline::*;
+        }
+    } else {
+        file_module(&desc, &desc.package, false)?
+    };
+    Ok(Expansion {
+        tokens,
+        files: desc.resolved_imports,
+        warnings: Vec::new(),
+    })
+}
+
+/// The import search path: `includes`, and no more. Like protoc, nothing looks in `.` or
+/// next to the importing file unless an include says so.
+fn search_path(includes: &[&str]) -> Vec<PathBuf> {
+    includes.iter().map(PathBuf::from).collect()
+}
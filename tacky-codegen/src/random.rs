@@ -0,0 +1,231 @@
+//! `<Message>Owned::random`, for property tests: a random value of the owned struct and an
+//! encoding of it, which `from_bytes` must decode back to that value.
+//!
+//! Each field's occurrences are written through the schema's own writers into a chunk of
+//! their own, and `tacky::random::Chunks` interleaves the chunks at random, along with a
+//! few unknown fields. Nested messages are generated first, to `depth` levels, and written
+//! as the bytes they came with, so every level's encoding is as scrambled as the top's.
+//! A packed field is written partly unpacked, as decoders must accept; an unpacked one is
+//! never packed, since its decoder needn't. A singular scalar is sometimes written twice,
+//! the first occurrence losing.
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::names::ident;
+use crate::owned::{element_type, map_value, oneof_ident, variant_ident};
+use crate::parser::{parse_ty, Field, Label, OneOfGroup, PbType};
+
+pub fn random_impl(name: &str, fields: &[Field], oneofs: &[OneOfGroup]) -> TokenStream {
+    let owned_ident = format_ident!("{name}Owned");
+    let schema_ident = format_ident!("{name}");
+
+    let generates = fields.iter().map(generate_stmt);
+    let oneof_generates = oneofs.iter().map(|o| generate_oneof(name, o));
+    let declared = fields
+        .iter()
+        .chain(oneofs.iter().flat_map(|o| &o.fields))
+        .map(|f| Literal::u32_unsuffixed(f.number as u32));
+
+    quote! {
+        #[allow(deprecated)]
+        impl #owned_ident {
+            /// A random message, with nested messages `depth` levels deep at most, and an
+            /// encoding of it: valid, but with its fields in any order, packed fields partly
+            /// unpacked and unknown fields mixed in. Decoding it gives the message back.
+            #[allow(unused_mut, unused_variables)]
+            pub fn random(r: &mut tacky::random::Rng, depth: u32) -> (Self, ::std::vec::Vec<u8>) {
+                let schema = #schema_ident::schema();
+                let mut out = Self::default();
+                let mut chunks = tacky::random::Chunks::new();
+                #(#generates)*
+                #(#oneof_generates)*
+                chunks.unknown(r, &[#(#declared),*]);
+                (out, chunks.shuffle(r))
+            }
+        }
+    }
+}
+
+/// An expression for a random element of `ty`, which is not a message.
+fn random_value(ty: &PbType) -> TokenStream {
+    match ty {
+        PbType::Enum((_, values)) => quote!(r.enum_value(&[#(#values),*])),
+        _ => quote!(r.value()),
+    }
+}
+
+/// The tacky type of an element of `ty`, which is not a message.
+fn tacky_type(ty: &PbType) -> TokenStream {
+    match ty {
+        PbType::Scalar(s) => s.tacky_path(),
+        PbType::Enum((name, _)) => {
+            let ident = parse_ty(name);
+            quote!(tacky::PbEnum<#ident>)
+        }
+        _ => panic!("not a scalar"),
+    }
+}
+
+/// A random nested message as `(m, bytes)`, one level down.
+fn random_message(m: &str) -> TokenStream {
+    let owned = parse_ty(&format!("{m}Owned"));
+    quote!(#owned::random(r, depth.saturating_sub(1)))
+}
+
+fn generate_stmt(f: &Field) -> TokenStream {
+    let ident = ident(&f.name);
+    let number = Literal::u32_unsuffixed(f.number as u32);
+    match (&f.ty, &f.label) {
+        (PbType::SimpleMap(k, v), _) => {
+            let (k_ty, v_ty) = (k.tacky_path(), v.tacky_path());
+            quote! {
+                for _ in 0..r.count() {
+                    let k = r.value();
+                    let v = (!r.one_in(8)).then(|| r.value());
+                    chunks.push(#number, tacky::random::map_entry::<#k_ty, #v_ty>(r, #number, &k, v.as_ref()));
+                    out.#ident.insert(k, v.unwrap_or_default());
+                }
+            }
+        }
+        (PbType::Map(k, v), _) => {
+            let k_ty = k.tacky_path();
+            let message = random_message(map_value(v));
+            // The value is never left out: it would decode to the default message, which
+            // isn't one to encode again if it has required fields.
+            quote! {
+                for _ in 0..if depth > 0 { r.count() } else { 0 } {
+                    let k = r.value();
+                    let (v, bytes) = #message;
+                    chunks.push(#number, tacky::random::map_entry::<#k_ty, tacky::PbBytes>(r, #number, &k, Some(bytes)));
+                    out.#ident.insert(k, v);
+                }
+            }
+        }
+        (PbType::Message(m), label) => {
+            let message = random_message(m);
+            let (present, store) = match label {
+                Label::Repeated => (
+                    quote!(0..if depth > 0 { r.count() } else { 0 }),
+                    quote!(out.#ident.push(m)),
+                ),
+                // Leaving it out wouldn't be valid, so depth is no excuse.
+                Label::Required => (
+                    quote!(0..1),
+                    quote!(out.#ident = Some(::std::boxed::Box::new(m))),
+                ),
+                _ => (
+                    quote!(0..(depth > 0 && r.value()) as usize),
+                    quote!(out.#ident = Some(::std::boxed::Box::new(m))),
+                ),
+            };
+            quote! {
+                for _ in #present {
+                    let (m, bytes) = #message;
+                    chunks.push(#number, tacky::random::len_field(#number, &bytes));
+                    #store;
+                }
+            }
+        }
+        (ty, Label::Packed) => {
+            let elem = element_type(ty);
+            let value = random_value(ty);
+            let tacky_ty = tacky_type(ty);
+            quote! {
+                let v: ::std::vec::Vec<#elem> = (0..r.count()).map(|_| #value).collect();
+                for run in r.runs(&v) {
+                    let chunk = match r.value() {
+                        true => tacky::random::chunk(|c| schema.#ident.write(c, run)),
+                        false => tacky::random::chunk(|c| tacky::Field::<#number, tacky::Repeated<#tacky_ty>>::new().write(c, run)),
+                    };
+                    chunks.push(#number, chunk);
+                }
+                out.#ident = v;
+            }
+        }
+        (ty, Label::Repeated) => {
+            let elem = element_type(ty);
+            let value = random_value(ty);
+            quote! {
+                for _ in 0..r.count() {
+                    let v: #elem = #value;
+                    chunks.push(#number, tacky::random::chunk(|c| schema.#ident.write_single(c, &v)));
+                    out.#ident.push(v);
+                }
+            }
+        }
+        (ty, Label::Optional | Label::Required) => {
+            let elem = element_type(ty);
+            let value = random_value(ty);
+            let (present, write, store) = match f.label {
+                Label::Optional => (
+                    quote!(r.value()),
+                    quote!(schema.#ident.write(c, Some(&v))),
+                    quote!(Some(v)),
+                ),
+                _ => (quote!(true), quote!(schema.#ident.write(c, &v)), quote!(v)),
+            };
+            quote! {
+                if #present {
+                    if r.one_in(8) {
+                        let v: #elem = #value;
+                        chunks.push(#number, tacky::random::chunk(|c| #write));
+                    }
+                    let v: #elem = #value;
+                    chunks.push(#number, tacky::random::chunk(|c| #write));
+                    out.#ident = #store;
+                }
+            }
+        }
+        (ty, Label::Plain) => {
+            let elem = element_type(ty);
+            let value = random_value(ty);
+            quote! {
+                let v: #elem = #value;
+                chunks.push(#number, tacky::random::chunk(|c| schema.#ident.write(c, &v)));
+                out.#ident = v;
+            }
+        }
+    }
+}
+
+fn generate_oneof(name: &str, o: &OneOfGroup) -> TokenStream {
+    let enum_ident = oneof_ident(name, o);
+    let group = ident(&o.name);
+    let count = Literal::usize_unsuffixed(o.fields.len() + 1);
+    let arms = o.fields.iter().enumerate().map(|(i, f)| {
+        let variant = variant_ident(f);
+        let i = Literal::usize_unsuffixed(i);
+        let number = Literal::u32_unsuffixed(f.number as u32);
+        match &f.ty {
+            PbType::Message(m) => {
+                let message = random_message(m);
+                quote! {
+                    #i if depth > 0 => {
+                        let (m, bytes) = #message;
+                        chunks.push(#number, tacky::random::len_field(#number, &bytes));
+                        out.#group = Some(#enum_ident::#variant(::std::boxed::Box::new(m)));
+                    }
+                }
+            }
+            ty => {
+                let elem = element_type(ty);
+                let value = random_value(ty);
+                let method = format_ident!("write_{}", f.name);
+                quote! {
+                    #i => {
+                        let v: #elem = #value;
+                        chunks.push(#number, tacky::random::chunk(|c| schema.#group.#method(c, &v)));
+                        out.#group = Some(#enum_ident::#variant(v));
+                    }
+                }
+            }
+        }
+    });
+    quote! {
+        match r.below(#count) {
+            #(#arms)*
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! `service` blocks. Each becomes a trait named after the service, with a handler per rpc
+//! and a provided `dispatch` that routes by method name or path, plus a unit struct per
+//! method implementing `tacky::Method`. Nothing here picks a transport: requests come in
+//! as buffers and responses go out through a `ResponseSink`.
+//!
+//! Handlers take the request message's `Fields` iterator, or an iterator of them for a
+//! client stream, and a `WriteBuf` for the response, or the sink itself for a server
+//! stream, which may send any number.
+//!
+//! A handler is the rpc's name in `snake_case`, so `GetFoo` and `get_foo` can't share a
+//! service. An rpc called `Dispatch` gets `dispatch_`, leaving `dispatch` to the trait.
+
+use pb_rs::types::Span;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::docs::doc_attrs;
+use crate::error::{Error, ErrorKind};
+use crate::names::ident;
+use crate::parser::parse_ty;
+
+/// A service, for [`service_items`].
+pub struct Service {
+    /// As declared: `Greeter`.
+    pub name: String,
+    /// With its package, without a leading dot: `helloworld.Greeter`.
+    pub full_name: String,
+    /// The service's comments, for the trait's docs: see
+    /// [`doc_comment`](crate::doc_comment).
+    pub doc: Option<String>,
+    pub methods: Vec<ServiceMethod>,
+}
+
+/// One rpc of a [`Service`].
+pub struct ServiceMethod {
+    /// As declared: `SayHello`.
+    pub name: String,
+    /// The request message's schema type, as generated code names it from the service's
+    /// module.
+    pub input: String,
+    /// The response message's, likewise.
+    pub output: String,
+    pub client_streaming: bool,
+    pub server_streaming: bool,
+    /// The rpc's comments, for the handler's docs.
+    pub doc: Option<String>,
+    /// Where the rpc is declared, for errors about it: `None` when it came from a
+    /// descriptor set.
+    pub span: Option<Span>,
+}
+
+/// The trait and method types for one service. Fails on two rpcs with the same handler.
+pub fn service_items(service: &Service) -> Result<TokenStream, Error> {
+    let trait_ident = ident(&service.name);
+    let full_name = &service.full_name;
+
+    let mut method_types = Vec::new();
+    let mut handlers = Vec::new();
+    let mut handler_names: Vec<(String, &str)> = Vec::new();
+    let mut arms = Vec::new();
+    let mut descriptors = Vec::new();
+    for m in &service.methods {
+        let method_ident = format_ident!("{}{}", service.name, m.name);
+        let handler = match heck::AsSnakeCase(&m.name).to_string() {
+            // The trait's own.
+            name if name == "dispatch" => "dispatch_".to_string(),
+            name => name,
+        };
+        if let Some((_, first)) = handler_names.iter().find(|(h, _)| *h == handler) {
+            let message = format!(
+                "rpcs `{first}` and `{}` of `{}` both make the handler `{handler}`",
+                m.name, service.name
+            );
+            return Err(Error::new(ErrorKind::Collision(message), m.span.clone()));
+        }
+        handler_names.push((handler.clone(), &m.name));
+        let handler = ident(&handler);
+        let name = &m.name;
+        let path = format!("/{}/{}", service.full_name, m.name);
+        let input = parse_ty(&m.input);
+        let output = parse_ty(&m.output);
+        let (client_streaming, server_streaming) = (m.client_streaming, m.server_streaming);
+        let doc = doc_attrs(m.doc.as_deref());
+
+        method_types.push(quote! {
+            #doc
+            #[derive(Debug, Copy, Clone)]
+            pub struct #method_ident;
+            impl tacky::Method for #method_ident {
+                type Request = #input;
+                type Response = #output;
+                const DESCRIPTOR: tacky::MethodDescriptor = tacky::MethodDescriptor {
+                    name: #name,
+                    path: #path,
+                    client_streaming: #client_streaming,
+                    server_streaming: #server_streaming,
+                };
+            }
+        });
+        descriptors.push(quote!(<#method_ident as tacky::Method>::DESCRIPTOR));
+
+        let (generics, request_param, request_arg) = if m.client_streaming {
+            let fields = parse_ty(&format!("{}Fields<'a>", m.input));
+            (
+                quote!(<'a, B: tacky::WriteBuf>),
+                quote!(requests: impl ::core::iter::Iterator<Item = #fields>),
+                quote!(requests.map(#input::decode)),
+            )
+        } else {
+            let fields = parse_ty(&format!("{}Fields<'_>", m.input));
+            (
+                quote!(<B: tacky::WriteBuf>),
+                quote!(request: #fields),
+                quote!(#input::decode(requests.next().unwrap_or_default())),
+            )
+        };
+        let (response_param, call) = if m.server_streaming {
+            (
+                quote!(responses: &mut impl tacky::ResponseSink<B>),
+                quote!(self.#handler(request, responses)),
+            )
+        } else {
+            (
+                quote!(response: &mut B),
+                quote!(responses.send(|response| self.#handler(request, response))),
+            )
+        };
+        handlers.push(quote! {
+            #doc
+            fn #handler #generics(
+                &self,
+                #request_param,
+                #response_param,
+            ) -> ::core::result::Result<(), Self::Error>;
+        });
+        arms.push(quote! {
+            #name | #path => {
+                let request = #request_arg;
+                #call.map_err(tacky::DispatchError::Handler)
+            }
+        });
+    }
+
+    let doc = doc_attrs(service.doc.as_deref());
+    Ok(quote! {
+        #(#method_types)*
+
+        #doc
+        pub trait #trait_ident {
+            type Error;
+
+            const NAME: &'static str = #full_name;
+            const METHODS: &'static [tacky::MethodDescriptor] = &[#(#descriptors),*];
+
+            #(#handlers)*
+
+            /// Calls the handler for `method`, by name or path, with the request messages
+            /// in `requests`: the first one for an rpc that takes a single request, or an
+            /// empty message if there is none.
+            fn dispatch<'a, B: tacky::WriteBuf>(
+                &self,
+                method: &str,
+                requests: impl ::core::iter::IntoIterator<Item = &'a [u8]>,
+                responses: &mut impl tacky::ResponseSink<B>,
+            ) -> ::core::result::Result<(), tacky::DispatchError<Self::Error>> {
+                let mut requests = requests.into_iter();
+                match method {
+                    #(#arms)*
+                    _ => Err(tacky::DispatchError::UnknownMethod),
+                }
+            }
+        }
+    })
+}
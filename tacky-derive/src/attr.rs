@@ -0,0 +1,72 @@
+//! `#[tacky(...)]` attributes, on the struct and on its fields.
+
+use syn::{Attribute, Ident, LitStr, Path, Result};
+
+pub struct ContainerAttrs {
+    /// The generated schema struct the fields are mapped onto.
+    pub schema: Path,
+}
+
+impl ContainerAttrs {
+    pub fn parse(ident: &Ident, attrs: &[Attribute]) -> Result<Self> {
+        let mut schema = None;
+        for attr in attrs.iter().filter(|a| a.path().is_ident("tacky")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("schema") {
+                    schema = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown tacky attribute, expected `schema`"))
+                }
+            })?;
+        }
+        let schema = schema.ok_or_else(|| {
+            syn::Error::new_spanned(ident, "missing `#[tacky(schema = path::to::Schema)]`")
+        })?;
+        Ok(Self { schema })
+    }
+}
+
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// The schema field's name, when it differs from the struct field's.
+    pub rename: Option<LitStr>,
+    /// Not part of the message.
+    pub skip: bool,
+    /// A module whose functions take over this field: `encode` for `TackyEncode`, `decode`
+    /// for `TackyDecode`.
+    pub with: Option<Path>,
+    /// A nested message, written through the field type's own impl.
+    pub msg: bool,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut out = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("tacky")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    out.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    out.skip = true;
+                } else if meta.path.is_ident("with") {
+                    out.with = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("msg") {
+                    out.msg = true;
+                } else {
+                    return Err(meta.error(
+                        "unknown tacky attribute, expected `rename`, `skip`, `with` or `msg`",
+                    ));
+                }
+                Ok(())
+            })?;
+            if out.with.is_some() && out.msg {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`with` and `msg` cannot be combined: `with` writes the field itself",
+                ));
+            }
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,178 @@
+//! `#[derive(TackyDecode)]`: one pass over the message's `Fields` iterator, a slot per
+//! struct field, and the struct built from the slots at the end.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{DeriveInput, GenericParam, Lifetime, LifetimeParam, Path, Result};
+
+use crate::attr::{ContainerAttrs, FieldAttrs};
+use crate::{generic_arg, generic_args, named_fields, option_inner, schema_field};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let ContainerAttrs { schema } = ContainerAttrs::parse(&input.ident, &input.attrs)?;
+    let field_enum = suffixed(&schema, "Field");
+    let fields_iter = suffixed(&schema, "Fields");
+
+    // The buffer's lifetime: the struct's own, if it borrows, so `&'a str` fields decode
+    // zero-copy; otherwise a fresh one the struct does not mention.
+    let mut generics = input.generics.clone();
+    let lt = match input.generics.lifetimes().next() {
+        Some(l) => l.lifetime.clone(),
+        None => {
+            let l = Lifetime::new("'__tacky", Span::call_site());
+            generics
+                .params
+                .insert(0, GenericParam::Lifetime(LifetimeParam::new(l.clone())));
+            l
+        }
+    };
+
+    let mut slots = Vec::new();
+    let mut arms = Vec::new();
+    let mut withs = Vec::new();
+    let mut inits = Vec::new();
+    for (i, field) in named_fields(input)?.named.iter().enumerate() {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        let ident = field.ident.as_ref().expect("named field");
+        if attrs.skip {
+            inits.push(quote!(#ident: ::core::default::Default::default()));
+            continue;
+        }
+        let ty = &field.ty;
+        let slot = format_ident!("__slot{i}");
+        let sf = schema_field(field, attrs.rename.as_ref());
+        let name = sf.unraw().to_string();
+        let variant = variant_ident(&name);
+
+        if let Some(with) = &attrs.with {
+            slots.push(quote!(let mut #slot: #ty = ::core::default::Default::default();));
+            withs.push(quote!(#with::decode(other, &mut #slot)?;));
+            inits.push(quote!(#ident: #slot));
+            continue;
+        }
+
+        let (init, store, finish) = if attrs.msg {
+            let decode = |t: &syn::Type| quote!(<#t as ::tacky::TackyDecode<#lt>>::from_fields(v)?);
+            if let Some(inner) = option_inner(ty) {
+                let value = decode(inner);
+                (
+                    quote!(let mut #slot: #ty = ::core::option::Option::None;),
+                    quote!(#slot = ::core::option::Option::Some(#value);),
+                    quote!(#slot),
+                )
+            } else if let Some(inner) = generic_arg(ty, "Vec") {
+                let value = decode(inner);
+                (
+                    quote!(let mut #slot: #ty = ::core::default::Default::default();),
+                    quote!(#slot.push(#value);),
+                    quote!(#slot),
+                )
+            } else if let Some([_, inner]) = map_args(ty) {
+                (
+                    quote!(let mut #slot: #ty = ::core::default::Default::default();),
+                    quote! {
+                        let (k, v) = v;
+                        let v = match v {
+                            ::core::option::Option::Some(v) => {
+                                <#inner as ::tacky::TackyDecode<#lt>>::from_fields(v)?
+                            }
+                            ::core::option::Option::None => {
+                                <#inner as ::tacky::TackyDecode<#lt>>::decode(&[])?
+                            }
+                        };
+                        #slot.insert(::core::convert::Into::into(k), v);
+                    },
+                    quote!(#slot),
+                )
+            } else {
+                let value = decode(ty);
+                (
+                    quote!(let mut #slot: ::core::option::Option<#ty> = ::core::option::Option::None;),
+                    quote!(#slot = ::core::option::Option::Some(#value);),
+                    quote!(#slot.ok_or(::tacky::DecodeError::MissingField(#name))?),
+                )
+            }
+        } else if let Some(inner) = option_inner(ty) {
+            (
+                quote!(let mut #slot: #ty = ::core::option::Option::None;),
+                quote! {
+                    #slot = ::core::option::Option::Some(::core::convert::Into::<#inner>::into(v));
+                },
+                quote!(#slot),
+            )
+        } else {
+            (
+                quote!(let mut #slot = ::tacky::DecodeField::<#ty>::slot(schema.#sf);),
+                quote!(::tacky::CollectField::<#ty, _>::collect(schema.#sf, &mut #slot, v)?;),
+                quote!(::tacky::DecodeField::<#ty>::finish(schema.#sf, #slot, #name)?),
+            )
+        };
+        slots.push(init);
+        arms.push(quote!(#field_enum::#variant(v) => { #store }));
+        inits.push(quote!(#ident: #finish));
+    }
+
+    let fallback = if withs.is_empty() {
+        quote!(_ => {})
+    } else {
+        quote!(other => { #(#withs)* })
+    };
+
+    let name = &input.ident;
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tacky::TackyDecode<#lt> for #name #ty_generics #where_clause {
+            type Fields = #fields_iter<#lt>;
+
+            fn decode(buf: &#lt [u8]) -> ::core::result::Result<Self, ::tacky::DecodeError> {
+                Self::from_fields(#fields_iter::new(buf))
+            }
+
+            fn from_fields(
+                fields: Self::Fields,
+            ) -> ::core::result::Result<Self, ::tacky::DecodeError> {
+                let schema = <#schema as ::tacky::MessageSchema>::schema();
+                #(#slots)*
+                for field in fields {
+                    #[allow(unreachable_patterns)]
+                    match field? {
+                        #(#arms)*
+                        #fallback
+                    }
+                }
+                ::core::result::Result::Ok(Self {
+                    #(#inits,)*
+                })
+            }
+        }
+    })
+}
+
+/// `path::to::User` with the last segment renamed, e.g. to `path::to::UserFields`.
+fn suffixed(path: &Path, suffix: &str) -> Path {
+    let mut path = path.clone();
+    let last = path.segments.last_mut().expect("non-empty path");
+    last.ident = format_ident!("{}{}", last.ident, suffix);
+    path
+}
+
+/// The key and value types of a `HashMap<K, V>` or `BTreeMap<K, V>`.
+fn map_args(ty: &syn::Type) -> Option<[&syn::Type; 2]> {
+    ["HashMap", "BTreeMap"]
+        .iter()
+        .find_map(|name| generic_args(ty, name))
+        .and_then(|args| args.try_into().ok())
+}
+
+/// The `Field` variant for the schema field `name`, as codegen names it: `foo_bar` is
+/// `FooBar`, escaped by codegen's rules, so `self_` is `Self_` and not the keyword.
+fn variant_ident(name: &str) -> Ident {
+    let name = heck::AsUpperCamelCase(name).to_string();
+    match name.as_str() {
+        "self" | "super" | "crate" | "Self" | "_" => format_ident!("{name}_"),
+        _ if syn::parse_str::<Ident>(&name).is_err() => format_ident!("r#{name}"),
+        _ => format_ident!("{name}"),
+    }
+}
@@ -0,0 +1,63 @@
+//! `#[derive(TackyEncode)]`: one schema struct literal, built the way a hand-written
+//! encoder builds it, so a field added to or dropped from the schema breaks the build.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Result};
+
+use crate::attr::{ContainerAttrs, FieldAttrs};
+use crate::{generic_arg, named_fields, option_inner, schema_field};
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let ContainerAttrs { schema } = ContainerAttrs::parse(&input.ident, &input.attrs)?;
+    let mut writes = Vec::new();
+    for field in &named_fields(input)?.named {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        let sf = schema_field(field, attrs.rename.as_ref());
+        let write = if let Some(with) = &attrs.with {
+            quote!(#with::encode(&self.#ident, buf, schema.#sf))
+        } else if attrs.msg {
+            if option_inner(&field.ty).is_some() {
+                quote! {
+                    match &self.#ident {
+                        Some(v) => schema.#sf.write_msg(buf, |buf, s| v.write_fields(buf, s)),
+                        None => schema.#sf,
+                    }
+                }
+            } else if generic_arg(&field.ty, "Vec").is_some() {
+                quote!(schema.#sf.write_msgs(buf, &self.#ident, |buf, s, v| v.write_fields(buf, s)))
+            } else {
+                quote!(schema.#sf.write_msg(buf, |buf, s| self.#ident.write_fields(buf, s)))
+            }
+        } else if option_inner(&field.ty).is_some() {
+            quote!(schema.#sf.write(buf, self.#ident.as_ref()))
+        } else {
+            quote!(schema.#sf.write_field(buf, &self.#ident))
+        };
+        writes.push(quote!(#sf: #write));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tacky::TackyEncode for #name #ty_generics #where_clause {
+            type Schema = #schema;
+
+            fn write_fields<__B: ::tacky::WriteBuf>(
+                &self,
+                buf: &mut ::tacky::AnyDir<__B>,
+                schema: Self::Schema,
+            ) {
+                #[allow(unused_imports)]
+                use ::tacky::{TackyEncode as _, WriteField as _};
+                let _ = #schema {
+                    #(#writes,)*
+                };
+            }
+        }
+    })
+}
@@ -0,0 +1,131 @@
+//! `include_proto!` and `proto!`: tacky-build's codegen, run by the compiler instead of a
+//! build script.
+
+use std::path::PathBuf;
+
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{bracketed, LitStr, Result, Token};
+
+/// `"path/to/file.proto"`, optionally followed by `, includes = ["dir", ...]`.
+pub struct IncludeArgs {
+    file: LitStr,
+    includes: Vec<LitStr>,
+}
+
+impl Parse for IncludeArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let file = input.parse()?;
+        let mut includes = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: syn::Ident = input.parse()?;
+            if key != "includes" {
+                return Err(syn::Error::new(key.span(), "expected `includes = [...]`"));
+            }
+            input.parse::<Token![=]>()?;
+            let content;
+            bracketed!(content in input);
+            includes = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(IncludeArgs { file, includes })
+    }
+}
+
+/// Paths are relative to the invoking crate's root, as they are for a build script.
+fn manifest_dir() -> PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+}
+
+pub fn include_proto(args: IncludeArgs) -> Result<TokenStream> {
+    let file = manifest_dir().join(args.file.value());
+    // The compiler's working directory could be anywhere: the workspace's root, say.
+    let includes: Vec<String> = if args.includes.is_empty() {
+        vec![manifest_dir().to_string_lossy().into_owned()]
+    } else {
+        args.includes
+            .iter()
+            .map(|dir| {
+                manifest_dir()
+                    .join(dir.value())
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    };
+    let includes: Vec<&str> = includes.iter().map(String::as_str).collect();
+    let expansion = tacky_codegen::expand_proto_file(&file, &includes).map_err(|e| {
+        // A located error already names its file, which may be an import.
+        let message = match e.span() {
+            Some(_) => e.to_string(),
+            None => format!("{}: {e}", file.display()),
+        };
+        syn::Error::new(args.file.span(), message)
+    })?;
+    Ok(with_dependencies(expansion))
+}
+
+pub fn proto(input: TokenStream) -> Result<TokenStream> {
+    let mut source = String::new();
+    write_source(input, &mut source);
+    // A file that doesn't exist, in the crate root, which imports resolve from.
+    let file = manifest_dir().join("proto!.proto");
+    let root = manifest_dir().to_string_lossy().into_owned();
+    let expansion = tacky_codegen::expand_proto_source(&source, &file, &[&root])
+        .map_err(|e| syn::Error::new(Span::call_site(), e.to_string()))?;
+    Ok(with_dependencies(expansion))
+}
+
+/// The generated code, plus an `include_bytes!` per file it came from: the only stable
+/// way for a proc macro to have cargo rebuild when a file it read changes.
+fn with_dependencies(expansion: tacky_codegen::Expansion) -> TokenStream {
+    let tokens = expansion.tokens;
+    let files = expansion
+        .files
+        .iter()
+        .filter_map(|f| f.canonicalize().ok())
+        .map(|f| f.display().to_string());
+    quote! {
+        #(const _: &[u8] = include_bytes!(#files);)*
+        #tokens
+    }
+}
+
+/// Turns the macro's tokens back into proto source. Rust's lexer got there first, so
+/// spacing is gone; tokens are space-separated except around `.` in qualified names and
+/// after a `-` sign.
+fn write_source(tokens: TokenStream, out: &mut String) {
+    let mut glue = false;
+    for tt in tokens {
+        let dot = matches!(&tt, TokenTree::Punct(p) if p.as_char() == '.');
+        if !glue && !dot && !out.is_empty() {
+            out.push(' ');
+        }
+        glue = false;
+        match tt {
+            TokenTree::Group(g) => {
+                let (open, close) = match g.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                out.push_str(open);
+                write_source(g.stream(), out);
+                out.push_str(close);
+            }
+            TokenTree::Punct(p) => {
+                out.push(p.as_char());
+                glue = matches!(p.as_char(), '.' | '-') || p.spacing() == Spacing::Joint;
+            }
+            TokenTree::Ident(i) => out.push_str(&i.to_string()),
+            TokenTree::Literal(l) => out.push_str(&l.to_string()),
+        }
+    }
+}
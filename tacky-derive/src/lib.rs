@@ -0,0 +1,129 @@
+//! Derive macros mapping domain structs onto `tacky-build`'s generated schemas. Re-exported
+//! by `tacky` under its `derive` feature; see `tacky::TackyEncode` and `tacky::TackyDecode`
+//! for the attributes.
+//!
+//! With the `codegen` feature (`tacky`'s `macros`), also `include_proto!` and `proto!`,
+//! which run tacky-build's code generation at compile time.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod attr;
+mod decode;
+mod encode;
+#[cfg(feature = "codegen")]
+mod include;
+
+#[proc_macro_derive(TackyEncode, attributes(tacky))]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    encode::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(TackyDecode, attributes(tacky))]
+pub fn derive_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    decode::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates a proto file's schemas in place, as a build script calling
+/// `tacky_build::write_proto_with_includes` and `include!`ing the result would:
+///
+/// ```ignore
+/// tacky::include_proto!("protos/user.proto");
+/// tacky::include_proto!("protos/api.proto", includes = ["../shared"]);
+/// ```
+///
+/// Paths are relative to the crate root, includes too. Imports are looked for under each
+/// include, or the crate root without any.
+/// The crate is rebuilt when the file or anything it imports changes.
+#[cfg(feature = "codegen")]
+#[proc_macro]
+pub fn include_proto(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as include::IncludeArgs);
+    include::include_proto(args)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates schemas from proto source written inline:
+///
+/// ```ignore
+/// mod wire {
+///     tacky::proto! {
+///         syntax = "proto3";
+///         message Ping {
+///             uint64 id = 1;
+///             repeated string tags = 2;
+///         }
+///     }
+/// }
+/// ```
+///
+/// With a `package`, the items land in a module named after it, as for a file. Without one
+/// they land in the invoking module, so give each package-less `proto!` a module of its own.
+/// The source has to lex as Rust: strings in double quotes, and no `'` anywhere.
+#[cfg(feature = "codegen")]
+#[proc_macro]
+pub fn proto(input: TokenStream) -> TokenStream {
+    include::proto(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The struct's named fields, or an error for any other shape of type.
+fn named_fields(input: &DeriveInput) -> syn::Result<&syn::FieldsNamed> {
+    match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => Ok(fields),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "tacky derives need a struct with named fields",
+        )),
+    }
+}
+
+/// The schema field a struct field maps to: its own name, or the `rename`d one. Keywords
+/// become raw identifiers, the way tacky-build names the schema's fields.
+fn schema_field(field: &syn::Field, rename: Option<&syn::LitStr>) -> syn::Ident {
+    match rename {
+        Some(lit) => syn::parse_str::<syn::Ident>(&lit.value())
+            .unwrap_or_else(|_| syn::Ident::new_raw(&lit.value(), lit.span())),
+        None => field.ident.clone().expect("named field"),
+    }
+}
+
+/// `Some(T)` if `ty` is written `Option<T>`. Purely syntactic, like every derive that asks.
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_arg(ty, "Option")
+}
+
+/// The first type argument of `ty`, if it is written `name<..>`.
+fn generic_arg<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    generic_args(ty, name)?.first().copied()
+}
+
+/// The type arguments of `ty`, if it is written `name<..>`.
+fn generic_args<'a>(ty: &'a syn::Type, name: &str) -> Option<Vec<&'a syn::Type>> {
+    let syn::Type::Path(p) = ty else {
+        return None;
+    };
+    let last = p.path.segments.last()?;
+    if last.ident != name {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    let types = args.args.iter().filter_map(|a| match a {
+        syn::GenericArgument::Type(t) => Some(t),
+        _ => None,
+    });
+    Some(types.collect())
+}
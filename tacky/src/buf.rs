@@ -392,7 +392,7 @@ pub(crate) const SMALL_COPY_MAX: usize = 48;
 /// buffer use this after claiming exactly `n`.
 #[inline(always)]
 pub(crate) unsafe fn copy_small(dst: *mut u8, src: *const u8, n: usize) {
-    debug_assert!(n >= 1 && n <= SMALL_COPY_MAX);
+    debug_assert!((1..=SMALL_COPY_MAX).contains(&n));
     if n >= 16 {
         // Three overlapping 16-byte pairs, branchless. Ends cover [0,16) and [n-16,n),
         // middle closes the gap. 48 is the reach of this form (n/2-8 <= 16 and
@@ -629,6 +629,7 @@ impl WriteBuf for RevBuf<'_> {
         let n = crate::scalars::encoded_len_varint(value);
         let dst = self.claim(n);
         let mut v = value;
+        #[allow(clippy::needless_range_loop)]
         for i in 0..n - 1 {
             dst[i] = ((v & 0x7F) | 0x80) as u8;
             v >>= 7;
@@ -853,6 +854,7 @@ impl WriteBuf for SliceBuf<'_> {
         // SAFETY: as in `put_slice`, and `n >= 1` for every `u64`.
         let dst = unsafe { self.buf.get_unchecked_mut(self.pos..end) };
         let mut v = value;
+        #[allow(clippy::needless_range_loop)]
         for i in 0..n - 1 {
             dst[i] = ((v & 0x7F) | 0x80) as u8;
             v >>= 7;
@@ -1008,6 +1010,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn fmt_writer_with_slice_buf() {
         let mut backing = [0u8; 64];
         let mut sb = SliceBuf::new(&mut backing);
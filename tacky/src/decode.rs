@@ -0,0 +1,175 @@
+//! Support for `#[derive(TackyDecode)]`, which builds a domain struct from a message's
+//! generated `Fields` iterator.
+//!
+//! The derive sees the domain struct but not the schema, so what a field's label means for
+//! decoding — keep the last value, collect every element, insist it was present — is
+//! decided here, by [`DecodeField`] impls on the schema's field types. Values convert with
+//! [`Into`], so a `string` field lands in a `String`, a `Cow<str>` or, zero-copy, a `&'a str`.
+
+use crate::{
+    packed::PackedIter, DecodeError, Field, Optional, Packable, Packed, PbMap, Plain,
+    ProtobufScalar, Repeated, Required,
+};
+
+/// A type built from the fields of one message. Usually derived:
+///
+/// ```ignore
+/// #[derive(TackyDecode)]
+/// #[tacky(schema = proto::User)]
+/// struct User<'a> {
+///     id: u64,                      // absent: 0, like any optional or implicit field
+///     name: &'a str,                // borrowed straight out of the buffer
+///     #[tacky(msg)]
+///     address: Option<Address>,     // Address: TackyDecode<'a>
+///     tags: Vec<String>,            // repeated, packed and map fields collect
+///     #[tacky(with = timestamp)]
+///     created: Option<SystemTime>,  // timestamp::decode(field, &mut self.created)
+///     #[tacky(skip)]
+///     cache: Cache,                 // Default::default()
+/// }
+/// ```
+pub trait TackyDecode<'a>: Sized {
+    /// The message's generated `<Message>Fields<'a>` iterator.
+    type Fields;
+
+    fn from_fields(fields: Self::Fields) -> Result<Self, DecodeError>;
+
+    fn decode(buf: &'a [u8]) -> Result<Self, DecodeError>;
+}
+
+/// How a field with this label accumulates into a `T`: the in-progress state, and what it
+/// amounts to once the message is done. Implemented on the schema's field types, so the
+/// derive's calls read `schema.name.slot()`.
+pub trait DecodeField<T> {
+    type Slot;
+
+    fn slot(self) -> Self::Slot;
+
+    fn finish(self, slot: Self::Slot, name: &'static str) -> Result<T, DecodeError>;
+}
+
+/// Folds one decoded occurrence of the field, as the `Fields` iterator yields it, into the slot.
+pub trait CollectField<T, V>: DecodeField<T> {
+    fn collect(self, slot: &mut Self::Slot, value: V) -> Result<(), DecodeError>;
+}
+
+macro_rules! singular {
+    ($label:ident, $($bound:ident)?, |$name:ident| $missing:expr) => {
+        impl<const N: u32, P: ProtobufScalar, T $(: $bound)?> DecodeField<T> for Field<N, $label<P>> {
+            type Slot = Option<T>;
+
+            #[inline]
+            fn slot(self) -> Option<T> {
+                None
+            }
+
+            #[inline]
+            fn finish(self, slot: Option<T>, $name: &'static str) -> Result<T, DecodeError> {
+                match slot {
+                    Some(v) => Ok(v),
+                    None => $missing,
+                }
+            }
+        }
+
+        impl<const N: u32, P: ProtobufScalar, T $(: $bound)?, V> CollectField<T, V>
+            for Field<N, $label<P>>
+        where
+            V: Into<T>,
+        {
+            /// Last one wins, as the wire format has it for a repeated singular field.
+            #[inline]
+            fn collect(self, slot: &mut Option<T>, value: V) -> Result<(), DecodeError> {
+                *slot = Some(value.into());
+                Ok(())
+            }
+        }
+    };
+}
+
+singular!(Optional, Default, |_name| Ok(T::default()));
+singular!(Plain, Default, |_name| Ok(T::default()));
+singular!(Required, , |name| Err(DecodeError::MissingField(name)));
+
+macro_rules! collection {
+    ($label:ident, $bound:ident) => {
+        impl<const N: u32, P: $bound, T> DecodeField<T> for Field<N, $label<P>>
+        where
+            T: Default + IntoIterator + Extend<T::Item>,
+        {
+            type Slot = T;
+
+            #[inline]
+            fn slot(self) -> T {
+                T::default()
+            }
+
+            #[inline]
+            fn finish(self, slot: T, _name: &'static str) -> Result<T, DecodeError> {
+                Ok(slot)
+            }
+        }
+    };
+}
+
+collection!(Repeated, ProtobufScalar);
+collection!(Packed, Packable);
+
+impl<const N: u32, P: ProtobufScalar, T, V> CollectField<T, V> for Field<N, Repeated<P>>
+where
+    T: Default + IntoIterator + Extend<T::Item>,
+    V: Into<T::Item>,
+{
+    #[inline]
+    fn collect(self, slot: &mut T, value: V) -> Result<(), DecodeError> {
+        slot.extend(Some(value.into()));
+        Ok(())
+    }
+}
+
+impl<'a, const N: u32, P: Packable, T> CollectField<T, PackedIter<'a, P>> for Field<N, Packed<P>>
+where
+    T: Default + IntoIterator + Extend<T::Item>,
+    P::RustType<'a>: Into<T::Item>,
+{
+    fn collect(self, slot: &mut T, values: PackedIter<'a, P>) -> Result<(), DecodeError> {
+        for v in values {
+            slot.extend(Some(v?.into()));
+        }
+        Ok(())
+    }
+}
+
+impl<const N: u32, K: ProtobufScalar, V: ProtobufScalar, T, A, B> DecodeField<T>
+    for Field<N, PbMap<K, V>>
+where
+    T: Default + IntoIterator<Item = (A, B)> + Extend<(A, B)>,
+{
+    type Slot = T;
+
+    #[inline]
+    fn slot(self) -> T {
+        T::default()
+    }
+
+    #[inline]
+    fn finish(self, slot: T, _name: &'static str) -> Result<T, DecodeError> {
+        Ok(slot)
+    }
+}
+
+impl<const N: u32, K: ProtobufScalar, V: ProtobufScalar, T, A, B, DK, DV>
+    CollectField<T, (DK, Option<DV>)> for Field<N, PbMap<K, V>>
+where
+    T: Default + IntoIterator<Item = (A, B)> + Extend<(A, B)>,
+    B: Default,
+    DK: Into<A>,
+    DV: Into<B>,
+{
+    /// A key-only entry maps to the value type's default, as it does in proto3.
+    #[inline]
+    fn collect(self, slot: &mut T, (k, v): (DK, Option<DV>)) -> Result<(), DecodeError> {
+        slot.extend(Some((k.into(), v.map(Into::into).unwrap_or_default())));
+        Ok(())
+    }
+}
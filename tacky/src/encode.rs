@@ -0,0 +1,112 @@
+//! Support for `#[derive(TackyEncode)]`, which maps a domain struct's fields onto a schema.
+//!
+//! The derive writes the same struct literal a hand-written encoder would — one
+//! `schema.field.write(...)` per schema field — so a schema change still breaks the build.
+//! What it cannot know is each field's label, since it only sees the domain struct, so
+//! every field goes through [`WriteField`] and the label picks the impl.
+
+use crate::{
+    AnyDir, Field, MessageSchema, Optional, OrderedIter, Packable, Packed, PbMap, Plain,
+    ProtoEncode, ProtobufScalar, Repeated, Required, WriteBuf,
+};
+
+/// A type that writes itself through the schema `Self::Schema`. Usually derived:
+///
+/// ```ignore
+/// #[derive(TackyEncode)]
+/// #[tacky(schema = proto::User)]
+/// struct User {
+///     id: u64,
+///     #[tacky(rename = "display_name")]
+///     name: String,
+///     #[tacky(msg)]
+///     address: Option<Address>,   // Address: TackyEncode<Schema = proto::Address>
+///     #[tacky(with = timestamp)]
+///     created: SystemTime,        // timestamp::encode(&self.created, buf, schema.created)
+///     #[tacky(skip)]
+///     cache: Cache,
+/// }
+/// ```
+pub trait TackyEncode {
+    type Schema: MessageSchema;
+
+    /// Writes every field through `schema`. The buffer is direction-erased so that one
+    /// impl serves forward buffers and [`RevBuf`](`crate::RevBuf`) alike; `encode` wraps it.
+    fn write_fields<B: WriteBuf>(&self, buf: &mut AnyDir<B>, schema: Self::Schema);
+
+    fn encode<B: WriteBuf>(&self, buf: &mut B) {
+        self.write_fields(AnyDir::from_mut(buf), Self::Schema::schema());
+    }
+}
+
+/// Writes a value that is always present, whatever the field's label: `Some` of it for
+/// an optional field, the value itself for a required or implicit-presence one, and the
+/// elements or entries for a repeated, packed or map field.
+///
+/// An `Option` field in a domain struct does not go through here — the derive calls the
+/// optional field's own `write`, which takes one.
+pub trait WriteField<B: WriteBuf, V> {
+    fn write_field(self, buf: &mut B, value: V) -> Self;
+}
+
+impl<const N: u32, P: ProtobufScalar, B: WriteBuf, V: ProtoEncode<P>> WriteField<B, V>
+    for Field<N, Optional<P>>
+{
+    #[inline]
+    fn write_field(self, buf: &mut B, value: V) -> Self {
+        self.write(buf, Some(value))
+    }
+}
+
+impl<const N: u32, P: ProtobufScalar, B: WriteBuf, V: ProtoEncode<P>> WriteField<B, V>
+    for Field<N, Required<P>>
+{
+    #[inline]
+    fn write_field(self, buf: &mut B, value: V) -> Self {
+        self.write(buf, value)
+    }
+}
+
+impl<const N: u32, P: ProtobufScalar, B: WriteBuf, V: ProtoEncode<P>> WriteField<B, V>
+    for Field<N, Plain<P>>
+{
+    #[inline]
+    fn write_field(self, buf: &mut B, value: V) -> Self {
+        self.write(buf, value)
+    }
+}
+
+impl<const N: u32, P: ProtobufScalar, B: WriteBuf, I> WriteField<B, I> for Field<N, Repeated<P>>
+where
+    I: IntoIterator<Item: ProtoEncode<P>> + OrderedIter<B::Order>,
+{
+    #[inline]
+    fn write_field(self, buf: &mut B, values: I) -> Self {
+        self.write(buf, values)
+    }
+}
+
+impl<const N: u32, P: Packable, B: WriteBuf, I> WriteField<B, I> for Field<N, Packed<P>>
+where
+    I: IntoIterator<Item: ProtoEncode<P>> + OrderedIter<B::Order>,
+{
+    #[inline]
+    fn write_field(self, buf: &mut B, values: I) -> Self {
+        self.write(buf, values)
+    }
+}
+
+impl<const N: u32, K, V, Buf, I, A, C> WriteField<Buf, I> for Field<N, PbMap<K, V>>
+where
+    K: ProtobufScalar,
+    V: ProtobufScalar,
+    Buf: WriteBuf,
+    I: IntoIterator<Item = (A, C)>,
+    A: ProtoEncode<K>,
+    C: ProtoEncode<V>,
+{
+    #[inline]
+    fn write_field(self, buf: &mut Buf, entries: I) -> Self {
+        self.write(buf, entries)
+    }
+}
@@ -114,13 +114,13 @@ pub mod repeated {
         /// Only that direction constrains the iterator: a forward buffer takes any of them,
         /// a `HashSet`'s included.
         #[inline]
-        pub fn write<B: WriteBuf, V: ProtoEncode<P>, I: IntoIterator<Item = V>>(
+        pub fn write<B: WriteBuf, V: ProtoEncode<P>, I>(
             self,
             buf: &mut B,
             values: I,
         ) -> Field<N, Repeated<P>>
         where
-            I: OrderedIter<B::Order>,
+            I: IntoIterator<Item = V> + OrderedIter<B::Order>,
         {
             let t = const { EncodedTag::new(N, P::WIRE_TYPE) };
             for value in values.ordered(B::REVERSE) {
@@ -191,14 +191,14 @@ pub mod repeated {
         ///
         /// The closure takes the element as a third argument, which is the only difference
         /// from `write_msg` at the call site.
-        pub fn write_msgs<B: WriteBuf, I: IntoIterator>(
+        pub fn write_msgs<B: WriteBuf, I>(
             self,
             buf: &mut B,
             values: I,
             mut f: impl FnMut(&mut B, M, I::Item),
         ) -> Self
         where
-            I: OrderedIter<B::Order>,
+            I: IntoIterator + OrderedIter<B::Order>,
         {
             let tag = const { EncodedTag::new(N, WireType::LEN) };
             for value in values.ordered(B::REVERSE) {
@@ -217,13 +217,13 @@ pub mod packed {
         /// two-pass length calculation, so a payload of 128 bytes or more is rescaled once.
         /// Skips the field entirely if the iterator is empty.
         #[inline]
-        pub fn write<B: WriteBuf, V: ProtoEncode<P>, I: IntoIterator<Item = V>>(
+        pub fn write<B: WriteBuf, V: ProtoEncode<P>, I>(
             self,
             buf: &mut B,
             values: I,
         ) -> Field<N, Packed<P>>
         where
-            I: OrderedIter<B::Order>,
+            I: IntoIterator<Item = V> + OrderedIter<B::Order>,
         {
             let mut iter = values.ordered(B::REVERSE);
             let Some(first) = iter.next() else {
@@ -303,16 +303,19 @@ pub mod packed {
 
         #[inline]
         fn next(&mut self) -> Option<Self::Item> {
-            if self.buf.is_empty() {
-                return None;
-            }
-            match T::read(&mut self.buf) {
-                Ok(v) => Some(Ok(v)),
-                Err(e) => {
-                    self.buf = &[];
-                    Some(Err(e))
+            // Only a closed enum's undeclared numbers come back `None`, to be skipped; for
+            // every other element type the loop runs once.
+            while !self.buf.is_empty() {
+                match T::read_known(&mut self.buf) {
+                    Ok(Some(v)) => return Some(Ok(v)),
+                    Ok(None) => continue,
+                    Err(e) => {
+                        self.buf = &[];
+                        return Some(Err(e));
+                    }
                 }
             }
+            None
         }
 
         #[inline]
@@ -420,7 +423,7 @@ impl<K: ProtobufScalar, M: MessageSchema> PbMap<K, M> {
                 (2, wt) => {
                     check_wire_type(wt, WireType::LEN, "value")?;
                     let msg_buf = decode_len(&mut entry_buf)?;
-                    val = Some(decoder(&msg_buf));
+                    val = Some(decoder(msg_buf));
                 }
                 (_, wt) => {
                     skip_field(wt, &mut entry_buf)?;
@@ -602,6 +605,129 @@ pub mod maps {
     }
 }
 
+/// A field from one of a message's `extensions` ranges, as the generated `Fields` iterator
+/// hands it out: the number and wire type from its key, and the encoded value with the key
+/// stripped. A `LEN` value keeps its length prefix, so [`ProtobufScalar::read`] takes
+/// `value` exactly as it would take the message buffer.
+///
+/// The iterator cannot decode these itself — which extensions exist depends on which
+/// `.proto` files declared them, and those need not be the one that declared the message.
+/// Decode one through the generated `<Message>Extension::decode` registry, or ask a single
+/// extension for its value with `extract`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExtensionField<'a> {
+    pub number: u32,
+    pub wire_type: WireType,
+    pub value: &'a [u8],
+}
+
+impl<'a> ExtensionField<'a> {
+    /// Splits the value of the field whose key was just decoded off the front of `buf`.
+    pub fn read(number: u32, wire_type: WireType, buf: &mut &'a [u8]) -> Result<Self, DecodeError> {
+        let start = *buf;
+        skip_field(wire_type, buf)?;
+        Ok(ExtensionField {
+            number,
+            wire_type,
+            value: &start[..start.len() - buf.len()],
+        })
+    }
+}
+
+pub mod extension {
+    use super::*;
+
+    fn extract<'a, const N: u32, P: ProtobufScalar>(
+        ext: &ExtensionField<'a>,
+    ) -> Option<Result<P::RustType<'a>, DecodeError>> {
+        if ext.number != N {
+            return None;
+        }
+        let mut buf = ext.value;
+        Some(
+            check_wire_type(ext.wire_type, P::WIRE_TYPE, "extension")
+                .and_then(|()| P::read(&mut buf)),
+        )
+    }
+
+    impl<const N: u32, P: ProtobufScalar> Field<N, Optional<P>> {
+        /// Decodes `ext` if it is this extension, `None` if it carries another field number.
+        pub fn extract<'a>(
+            self,
+            ext: &ExtensionField<'a>,
+        ) -> Option<Result<P::RustType<'a>, DecodeError>> {
+            extract::<N, P>(ext)
+        }
+    }
+
+    /// A repeated scalar extension's elements in `ext`: a packed run if it is `LEN`, or
+    /// else the one element it holds, checked when the iterator reaches it.
+    fn extract_packed<'a, const N: u32, P: Packable>(
+        ext: &ExtensionField<'a>,
+    ) -> Option<Result<packed::PackedIter<'a, P>, DecodeError>> {
+        if ext.number != N {
+            return None;
+        }
+        let mut buf = ext.value;
+        Some(if ext.wire_type == WireType::LEN {
+            decode_len(&mut buf).map(packed::PackedIter::new)
+        } else {
+            check_wire_type(ext.wire_type, P::WIRE_TYPE, "extension")
+                .map(|()| packed::PackedIter::new(buf))
+        })
+    }
+
+    impl<const N: u32, P: Packable> Field<N, Repeated<P>> {
+        /// Decodes `ext` if it holds elements of this extension. Writers may pack them or
+        /// not, and either way they may come in several [`ExtensionField`]s, each decoded
+        /// on its own.
+        pub fn extract<'a>(
+            self,
+            ext: &ExtensionField<'a>,
+        ) -> Option<Result<packed::PackedIter<'a, P>, DecodeError>> {
+            extract_packed::<N, P>(ext)
+        }
+    }
+
+    impl<const N: u32, P: Packable> Field<N, Packed<P>> {
+        /// Decodes `ext` if it holds elements of this extension, packed or not.
+        pub fn extract<'a>(
+            self,
+            ext: &ExtensionField<'a>,
+        ) -> Option<Result<packed::PackedIter<'a, P>, DecodeError>> {
+            extract_packed::<N, P>(ext)
+        }
+    }
+
+    macro_rules! extract_len_elements {
+        ($($p:ty),*) => {
+            $(
+                impl<const N: u32> Field<N, Repeated<$p>> {
+                    /// Decodes `ext` if it is an element of this extension. These can't be
+                    /// packed, so every element arrives as its own [`ExtensionField`].
+                    pub fn extract<'a>(
+                        self,
+                        ext: &ExtensionField<'a>,
+                    ) -> Option<Result<<$p as ProtobufScalar>::RustType<'a>, DecodeError>> {
+                        extract::<N, $p>(ext)
+                    }
+                }
+            )*
+        };
+    }
+    extract_len_elements!(PbString, PbLazyString, PbBytes);
+
+    impl<const N: u32, M: MessageSchema> Field<N, Optional<M>> {
+        /// Returns the payload of a message extension, ready for the generated `decode`.
+        pub fn extract_msg<'a>(
+            self,
+            ext: &ExtensionField<'a>,
+        ) -> Option<Result<&'a [u8], DecodeError>> {
+            extract::<N, PbBytes>(ext)
+        }
+    }
+}
+
 /// Marker trait for generated message schema types. Implemented by `tacky-build`
 /// on every generated schema struct. Used as a bound on `Field`'s `write_msg`
 /// methods to distinguish nested message fields from scalar fields.
@@ -621,6 +747,28 @@ pub trait MessageSchema: Sized {
     }
 }
 
+/// A generated schema, as what decodes its message: `Fields` is its `<Message>Fields`
+/// iterator. For code generic over the message, which the schema's inherent `decode` can't
+/// serve.
+pub trait DecodeMessage: MessageSchema {
+    type Fields<'a>: MessageFields<'a, Schema = Self>;
+
+    fn fields(buf: &[u8]) -> Self::Fields<'_> {
+        Self::Fields::new(buf)
+    }
+}
+
+/// A generated `<Message>Fields` iterator, from the other side: the schema it decodes, and
+/// the bytes it has still to decode, which for a nested message not yet iterated are all of
+/// that message's.
+pub trait MessageFields<'a>: Iterator + Clone {
+    type Schema: DecodeMessage;
+
+    fn new(buf: &'a [u8]) -> Self;
+
+    fn as_bytes(&self) -> &'a [u8];
+}
+
 /// Bridges domain types to protobuf scalars for serialization.
 ///
 /// Implement this for your own types to make them directly writable through tacky.
@@ -684,6 +832,19 @@ impl<T: AsRef<[u8]>> ProtoEncode<PbBytes> for T {
     }
 }
 
+/// Bytes, as for [`PbBytes`]: a lazily checked string is written as it came, checked or
+/// not, and `&str` is bytes too.
+impl<T: AsRef<[u8]>> ProtoEncode<PbLazyString> for T {
+    #[inline]
+    fn as_scalar(&self) -> <PbLazyString as ProtobufScalar>::RustType<'_> {
+        PbStr::new(self.as_ref())
+    }
+
+    fn is_default(&self) -> bool {
+        self.as_ref().is_empty()
+    }
+}
+
 macro_rules! gen_encodes {
     ($src:ty => $($dst:ty),*) => {
         $(
@@ -835,6 +996,21 @@ mod tests {
         assert!(buf.is_empty());
     }
 
+    #[test]
+    fn test_lazy_string() {
+        let mut buf = Vec::new();
+        let _ = Field::<1, Plain<PbLazyString>>::new().write(&mut buf, "hello");
+        let _ = Field::<2, Plain<PbLazyString>>::new().write(&mut buf, b"\xff");
+        let mut slice = buf.as_slice();
+        crate::scalars::decode_key(&mut slice).unwrap();
+        let s = PbLazyString::read(&mut slice).unwrap();
+        assert_eq!(s.as_str().unwrap(), "hello");
+        crate::scalars::decode_key(&mut slice).unwrap();
+        let s = PbLazyString::read(&mut slice).unwrap();
+        assert_eq!(s.as_bytes(), b"\xff");
+        assert!(matches!(s.as_str(), Err(DecodeError::InvalidUtf8)));
+    }
+
     #[test]
     fn test_repeated_string_and_bytes() {
         let mut buf = Vec::new();
@@ -866,6 +1042,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_required_numeric_types() {
         let mut buf = Vec::new();
         // i32
@@ -938,6 +1115,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_optional_numeric_types() {
         let mut buf = Vec::new();
         // i32
@@ -1025,4 +1203,56 @@ mod tests {
         let values: Result<Vec<_>, _> = packed.collect();
         assert_eq!(values.unwrap(), vec![true, false, true]);
     }
+
+    #[test]
+    fn test_extension_field_read_and_extract() {
+        let mut buf = Vec::new();
+        let _ = Field::<7, Optional<PbString>>::new().write(&mut buf, Some("ext"));
+        let _ = Field::<8, Optional<Fixed32>>::new().write(&mut buf, Some(9u32));
+        let mut slice = buf.as_slice();
+
+        let (tag, wire) = decode_key(&mut slice).unwrap();
+        let ext = ExtensionField::read(tag, wire, &mut slice).unwrap();
+        assert_eq!(ext.value, b"\x03ext");
+        let (tag, wire) = decode_key(&mut slice).unwrap();
+        let fixed = ExtensionField::read(tag, wire, &mut slice).unwrap();
+        assert!(slice.is_empty());
+
+        assert_eq!(
+            Field::<7, Optional<PbString>>::new()
+                .extract(&ext)
+                .unwrap()
+                .unwrap(),
+            "ext"
+        );
+        assert!(Field::<8, Optional<PbString>>::new()
+            .extract(&ext)
+            .is_none());
+        assert!(matches!(
+            Field::<8, Optional<PbString>>::new().extract(&fixed),
+            Some(Err(DecodeError::WireTypeMismatch { .. }))
+        ));
+        let unpacked: Result<Vec<_>, _> = Field::<8, Repeated<Fixed32>>::new()
+            .extract(&fixed)
+            .unwrap()
+            .unwrap()
+            .collect();
+        assert_eq!(unpacked.unwrap(), [9]);
+    }
+
+    #[test]
+    fn test_extension_extract_packed() {
+        let mut buf = Vec::new();
+        let _ = Field::<9, Packed<Sint64>>::new().write(&mut buf, [-1i64, 2]);
+        let _ = Field::<9, Repeated<Sint64>>::new().write(&mut buf, [3i64]);
+        let mut slice = buf.as_slice();
+        let mut values = Vec::new();
+        while !slice.is_empty() {
+            let (tag, wire) = decode_key(&mut slice).unwrap();
+            let ext = ExtensionField::read(tag, wire, &mut slice).unwrap();
+            let elements = Field::<9, Repeated<Sint64>>::new().extract(&ext).unwrap();
+            values.extend(elements.unwrap().map(Result::unwrap));
+        }
+        assert_eq!(values, [-1, 2, 3]);
+    }
 }
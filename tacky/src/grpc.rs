@@ -0,0 +1,484 @@
+//! gRPC's message framing, whatever carries it. Each message on a call's stream goes out
+//! behind a 5-byte prefix: a flag byte saying whether the payload is compressed, then the
+//! payload's length as a big-endian `u32`.
+//!
+//! [`write_frame`] writes one framed message through a closure, the way
+//! [`WriteBuf::put_msg`] writes a submessage. The length is fixed-width, so a forward
+//! buffer patches it in place once the closure returns, with no overflow path at all. A
+//! [`RevBuf`](`crate::RevBuf`) prepends it directly, since it knows the length by then.
+//!
+//! [`Deframer`] goes the other way over bytes as a transport hands them over, in chunks of
+//! any size, and yields each message once all of it has arrived:
+//!
+//! ```
+//! # use tacky::grpc::{write_frame, Deframer};
+//! let mut wire = Vec::new();
+//! write_frame(&mut wire, |buf| buf.extend_from_slice(b"first"));
+//! write_frame(&mut wire, |buf| buf.extend_from_slice(b"second"));
+//!
+//! let mut deframer = Deframer::new();
+//! let mut messages = Vec::new();
+//! for chunk in wire.chunks(3) {
+//!     deframer.push(chunk);
+//!     while let Some(message) = deframer.next_message(None) {
+//!         messages.push(message.unwrap().to_vec());
+//!     }
+//! }
+//! deframer.finish().unwrap();
+//! assert_eq!(messages, [&b"first"[..], b"second"]);
+//! ```
+//!
+//! Compression is a [`Compression`] hook, given to [`write_frame_compressed`] and
+//! [`Deframer::next_message`]. The `gzip` feature provides `Gzip`.
+
+use crate::WriteBuf;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Bytes in front of every message: the compressed flag and the length.
+pub const HEADER_LEN: usize = 5;
+
+/// What a [`Deframer`] accepts unless told otherwise: 4 MiB, gRPC's own default for
+/// received messages.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+/// Writes one uncompressed framed message: the prefix, then whatever `f` writes.
+///
+/// # Panics
+///
+/// If `f` writes 4 GiB or more, which no frame can hold.
+#[inline]
+pub fn write_frame<B: WriteBuf>(buf: &mut B, f: impl FnOnce(&mut B)) {
+    if B::REVERSE {
+        let end = buf.len();
+        f(buf);
+        let header = header(false, buf.len() - end);
+        buf.put_slice(&header);
+    } else {
+        let start = buf.len();
+        buf.put_slice(&[0; HEADER_LEN]);
+        f(buf);
+        let header = header(false, buf.len() - start - HEADER_LEN);
+        buf.as_mut_slice()[start..start + HEADER_LEN].copy_from_slice(&header);
+    }
+}
+
+/// Writes one framed message compressed with `codec`. The message has to be whole before
+/// it can be compressed, so `f` writes it into a scratch buffer rather than into `buf`.
+///
+/// # Panics
+///
+/// As [`write_frame`], if the compressed payload is 4 GiB or more.
+#[cfg(feature = "alloc")]
+pub fn write_frame_compressed<B: WriteBuf, C: Compression + ?Sized>(
+    buf: &mut B,
+    codec: &mut C,
+    f: impl FnOnce(&mut Vec<u8>),
+) {
+    let mut message = Vec::new();
+    f(&mut message);
+    let mut payload = Vec::new();
+    codec.compress(&message, &mut payload);
+    let header = header(true, payload.len());
+    if B::REVERSE {
+        buf.put_slice(&payload);
+        buf.put_slice(&header);
+    } else {
+        buf.put_slice(&header);
+        buf.put_slice(&payload);
+    }
+}
+
+fn header(compressed: bool, len: usize) -> [u8; HEADER_LEN] {
+    let len = u32::try_from(len).expect("gRPC message of 4 GiB or more");
+    let [a, b, c, d] = len.to_be_bytes();
+    [compressed as u8, a, b, c, d]
+}
+
+/// A message encoding for compressed frames, named as in the `grpc-encoding` header.
+///
+/// Which one a call uses is negotiated by the transport, out of band; the frame itself only
+/// says whether its payload is compressed, not how.
+#[cfg(feature = "alloc")]
+pub trait Compression {
+    /// As in `grpc-encoding`: `gzip`, `deflate`, ...
+    fn name(&self) -> &str;
+
+    /// Appends `message`, compressed, to `out`.
+    fn compress(&mut self, message: &[u8], out: &mut Vec<u8>);
+
+    /// Appends `payload`, decompressed, to `out`. A small payload can inflate to anything,
+    /// so this must stop and return [`FrameError::DecompressedTooLarge`] once more than
+    /// `limit` bytes come out, rather than decompress it all and check after. A payload it
+    /// can't make sense of is [`FrameError::Decompress`].
+    fn decompress(
+        &mut self,
+        payload: &[u8],
+        out: &mut Vec<u8>,
+        limit: usize,
+    ) -> Result<(), FrameError>;
+}
+
+/// gRPC's `gzip` encoding, at flate2's default level.
+#[cfg(feature = "gzip")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Gzip;
+
+#[cfg(feature = "gzip")]
+impl Compression for Gzip {
+    fn name(&self) -> &str {
+        "gzip"
+    }
+
+    fn compress(&mut self, message: &[u8], out: &mut Vec<u8>) {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(out, flate2::Compression::default());
+        // Writing into a `Vec` can't fail.
+        encoder.write_all(message).unwrap();
+        encoder.finish().unwrap();
+    }
+
+    fn decompress(
+        &mut self,
+        payload: &[u8],
+        out: &mut Vec<u8>,
+        limit: usize,
+    ) -> Result<(), FrameError> {
+        use std::io::Read;
+        let start = out.len();
+        // One byte past the limit is enough to know it was exceeded.
+        flate2::read::GzDecoder::new(payload)
+            .take(limit as u64 + 1)
+            .read_to_end(out)
+            .map_err(|_| FrameError::Decompress)?;
+        if out.len() - start > limit {
+            return Err(FrameError::DecompressedTooLarge { max: limit });
+        }
+        Ok(())
+    }
+}
+
+/// One frame as it arrived, from [`Deframer::next_frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame<'a> {
+    /// Whether the payload is compressed, with whatever the call negotiated.
+    pub compressed: bool,
+    pub payload: &'a [u8],
+}
+
+/// Splits a byte stream back into messages, however the transport chunks it.
+///
+/// [`push`](Deframer::push) each chunk as it arrives, then take messages until there are
+/// no more complete ones. Only an unfinished frame is kept between chunks. A frame whose
+/// header declares more than the maximum message size is rejected from the header alone,
+/// before any of its payload is buffered.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct Deframer {
+    buf: Vec<u8>,
+    /// How much of `buf` has been handed out already. Dropped on the next `push`, so the
+    /// slices handed out stay valid until then.
+    consumed: usize,
+    max_message_size: usize,
+    /// Where compressed messages are decompressed to.
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl Deframer {
+    /// A deframer accepting messages up to [`DEFAULT_MAX_MESSAGE_SIZE`].
+    pub fn new() -> Self {
+        Deframer::with_max_message_size(DEFAULT_MAX_MESSAGE_SIZE)
+    }
+
+    /// A deframer accepting messages up to `max` bytes, compressed or not.
+    pub fn with_max_message_size(max: usize) -> Self {
+        Deframer {
+            buf: Vec::new(),
+            consumed: 0,
+            max_message_size: max,
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Adds the next chunk of the stream.
+    pub fn push(&mut self, chunk: &[u8]) {
+        if self.consumed > 0 {
+            self.buf.drain(..self.consumed);
+            self.consumed = 0;
+        }
+        self.buf.extend_from_slice(chunk);
+    }
+
+    /// The next complete frame, as it is on the wire, or `None` until more of it arrives.
+    ///
+    /// An error leaves the stream where it is: there is no finding the next frame after a
+    /// bad header, so every later call returns the same error.
+    pub fn next_frame(&mut self) -> Option<Result<Frame<'_>, FrameError>> {
+        match self.next_range()? {
+            Ok((compressed, range)) => Some(Ok(Frame {
+                compressed,
+                payload: &self.buf[range],
+            })),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// The next complete message, decompressed with `codec` if its frame is compressed, or
+    /// `None` until more of it arrives. Without a codec, a compressed frame is
+    /// [`FrameError::Compressed`].
+    pub fn next_message(
+        &mut self,
+        codec: Option<&mut dyn Compression>,
+    ) -> Option<Result<&[u8], FrameError>> {
+        let (compressed, range) = match self.next_range()? {
+            Ok(frame) => frame,
+            Err(e) => return Some(Err(e)),
+        };
+        if !compressed {
+            return Some(Ok(&self.buf[range]));
+        }
+        let Some(codec) = codec else {
+            return Some(Err(FrameError::Compressed));
+        };
+        self.scratch.clear();
+        let decompressed =
+            codec.decompress(&self.buf[range], &mut self.scratch, self.max_message_size);
+        Some(decompressed.map(|()| &self.scratch[..]))
+    }
+
+    /// Checks the stream ended between frames, once the transport says it is over.
+    pub fn finish(&self) -> Result<(), FrameError> {
+        match self.buf.len() - self.consumed {
+            0 => Ok(()),
+            buffered => Err(FrameError::Truncated { buffered }),
+        }
+    }
+
+    fn next_range(&mut self) -> Option<Result<(bool, core::ops::Range<usize>), FrameError>> {
+        let pending = &self.buf[self.consumed..];
+        let header: [u8; HEADER_LEN] = pending.get(..HEADER_LEN)?.try_into().unwrap();
+        let compressed = match header[0] {
+            0 => false,
+            1 => true,
+            flag => return Some(Err(FrameError::InvalidFlag(flag))),
+        };
+        let len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+        if len > self.max_message_size {
+            return Some(Err(FrameError::TooLarge {
+                len,
+                max: self.max_message_size,
+            }));
+        }
+        if pending.len() < HEADER_LEN + len {
+            return None;
+        }
+        let start = self.consumed + HEADER_LEN;
+        self.consumed = start + len;
+        Some(Ok((compressed, start..start + len)))
+    }
+}
+
+/// Why a stream of frames couldn't be read back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameError {
+    /// A frame's header declares a payload longer than the maximum message size.
+    TooLarge { len: usize, max: usize },
+    /// A compressed payload that decompresses to more than the maximum message size.
+    DecompressedTooLarge { max: usize },
+    /// A flag byte other than 0 or 1.
+    InvalidFlag(u8),
+    /// A compressed frame, with no [`Compression`] to undo it.
+    Compressed,
+    /// A compressed payload its [`Compression`] couldn't decompress.
+    Decompress,
+    /// The stream ended partway through a frame, with this many bytes of it buffered.
+    Truncated { buffered: usize },
+}
+
+impl core::fmt::Display for FrameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FrameError::TooLarge { len, max } => {
+                write!(f, "message of {len} bytes is over the {max}-byte limit")
+            }
+            FrameError::DecompressedTooLarge { max } => {
+                write!(f, "message decompresses past the {max}-byte limit")
+            }
+            FrameError::InvalidFlag(flag) => write!(f, "invalid compressed flag {flag}"),
+            FrameError::Compressed => f.write_str("compressed message with no compression set"),
+            FrameError::Decompress => f.write_str("message could not be decompressed"),
+            FrameError::Truncated { buffered } => {
+                write!(f, "stream ended {buffered} bytes into a frame")
+            }
+        }
+    }
+}
+
+impl core::error::Error for FrameError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RevBuf, SliceBuf};
+    use alloc::vec;
+
+    fn framed(message: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_frame(&mut buf, |buf| buf.extend_from_slice(message));
+        buf
+    }
+
+    #[test]
+    fn test_write_frame_every_buffer() {
+        let message = [7u8; 300];
+        let expected = framed(&message);
+        assert_eq!(expected[..HEADER_LEN], [0, 0, 0, 1, 44]);
+        assert_eq!(expected[HEADER_LEN..], message);
+
+        let mut store = [0u8; 400];
+        let mut rev = RevBuf::new(&mut store);
+        write_frame(&mut rev, |buf| buf.put_slice(&message));
+        assert_eq!(rev.written(), &expected[..]);
+
+        let mut store = [0u8; 400];
+        let mut slice = SliceBuf::new(&mut store);
+        slice.put_slice(b"ahead");
+        write_frame(&mut slice, |buf| buf.put_slice(&message));
+        let n = slice.len();
+        assert_eq!(&store[5..n], &expected[..]);
+    }
+
+    #[test]
+    fn test_deframer_chunks() {
+        let mut wire = framed(b"");
+        wire.extend(framed(b"one"));
+        wire.extend(framed(&[9; 1000]));
+        for size in [1, 2, 5, 7, 4096] {
+            let mut deframer = Deframer::new();
+            let mut messages = Vec::new();
+            for chunk in wire.chunks(size) {
+                deframer.push(chunk);
+                while let Some(frame) = deframer.next_frame() {
+                    let frame = frame.unwrap();
+                    assert!(!frame.compressed);
+                    messages.push(frame.payload.to_vec());
+                }
+            }
+            deframer.finish().unwrap();
+            assert_eq!(messages, [vec![], b"one".to_vec(), vec![9; 1000]], "{size}");
+        }
+    }
+
+    #[test]
+    fn test_deframer_errors() {
+        // Rejected from the header alone, with none of the payload there.
+        let mut deframer = Deframer::with_max_message_size(16);
+        deframer.push(&[0, 0, 0, 0, 17]);
+        let err = deframer.next_frame().unwrap().unwrap_err();
+        assert_eq!(err, FrameError::TooLarge { len: 17, max: 16 });
+        assert_eq!(deframer.next_frame(), Some(Err(err)));
+
+        let mut deframer = Deframer::new();
+        deframer.push(&[2, 0, 0, 0, 0]);
+        assert_eq!(deframer.next_frame(), Some(Err(FrameError::InvalidFlag(2))));
+
+        let mut deframer = Deframer::new();
+        deframer.push(&[1, 0, 0, 0, 1, 0]);
+        assert_eq!(
+            deframer.next_message(None),
+            Some(Err(FrameError::Compressed))
+        );
+
+        let mut deframer = Deframer::new();
+        deframer.push(&framed(b"whole"));
+        deframer.push(&[0, 0, 0, 0, 3, b'h']);
+        assert_eq!(deframer.next_message(None), Some(Ok(&b"whole"[..])));
+        assert_eq!(deframer.next_message(None), None);
+        assert_eq!(
+            deframer.finish(),
+            Err(FrameError::Truncated { buffered: 6 })
+        );
+    }
+
+    /// Reverses the message: enough to tell compressed frames from plain ones.
+    struct Backwards;
+
+    impl Compression for Backwards {
+        fn name(&self) -> &str {
+            "backwards"
+        }
+        fn compress(&mut self, message: &[u8], out: &mut Vec<u8>) {
+            out.extend(message.iter().rev());
+        }
+        fn decompress(
+            &mut self,
+            payload: &[u8],
+            out: &mut Vec<u8>,
+            limit: usize,
+        ) -> Result<(), FrameError> {
+            if payload.len() > limit {
+                return Err(FrameError::DecompressedTooLarge { max: limit });
+            }
+            out.extend(payload.iter().rev());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_compression_hook() {
+        let mut forward = Vec::new();
+        write_frame_compressed(&mut forward, &mut Backwards, |buf| {
+            buf.extend_from_slice(b"abc")
+        });
+        assert_eq!(forward, [1, 0, 0, 0, 3, b'c', b'b', b'a']);
+
+        let mut store = [0u8; 8];
+        let mut rev = RevBuf::new(&mut store);
+        write_frame_compressed(&mut rev, &mut Backwards, |buf| {
+            buf.extend_from_slice(b"abc")
+        });
+        assert_eq!(rev.written(), &forward[..]);
+
+        let mut deframer = Deframer::new();
+        deframer.push(&forward);
+        let message = deframer.next_message(Some(&mut Backwards));
+        assert_eq!(message, Some(Ok(&b"abc"[..])));
+
+        let mut deframer = Deframer::with_max_message_size(2);
+        deframer.push(&[1, 0, 0, 0, 2, b'x', b'y']);
+        deframer.push(&forward[..HEADER_LEN]);
+        assert_eq!(
+            deframer.next_message(Some(&mut Backwards)),
+            Some(Ok(&b"yx"[..]))
+        );
+        let err = deframer.next_message(Some(&mut Backwards)).unwrap();
+        assert_eq!(err, Err(FrameError::TooLarge { len: 3, max: 2 }));
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip() {
+        let message = [b'z'; 10_000];
+        let mut wire = Vec::new();
+        write_frame_compressed(&mut wire, &mut Gzip, |buf| buf.extend_from_slice(&message));
+        assert!(wire.len() < 200, "{}", wire.len());
+
+        let mut deframer = Deframer::new();
+        deframer.push(&wire);
+        let decompressed = deframer.next_message(Some(&mut Gzip)).unwrap().unwrap();
+        assert_eq!(decompressed, message);
+
+        // Small on the wire, over the limit once inflated.
+        let mut deframer = Deframer::with_max_message_size(1000);
+        deframer.push(&wire);
+        let err = deframer.next_message(Some(&mut Gzip)).unwrap();
+        assert_eq!(err, Err(FrameError::DecompressedTooLarge { max: 1000 }));
+
+        let mut deframer = Deframer::new();
+        deframer.push(&[1, 0, 0, 0, 3, 1, 2, 3]);
+        let err = deframer.next_message(Some(&mut Gzip)).unwrap();
+        assert_eq!(err, Err(FrameError::Decompress));
+    }
+}
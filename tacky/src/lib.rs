@@ -11,7 +11,16 @@
 //! - [Buffers](`buf`) — `Vec<u8>`, [`SliceBuf`] for no-alloc, and [`RevBuf`], which writes
 //!   backwards so nested lengths are exact and need no placeholder at all
 //!
-//! Schema structs and field enums are generated by `tacky-build` at build time.
+//! - [Services](`service`) — what generated service traits dispatch through, transport-agnostic
+//! - [gRPC framing](`grpc`) — the length-prefixed frames gRPC sends messages in, both ways
+//! - [Owned messages](`owned`) — with the `bytes` feature, decoding out of a `Bytes` whose
+//!   strings and bytes can be kept without copying
+//! - [Random messages](`random`) — what generated `random` constructors for property tests
+//!   are made of
+//!
+//! Schema structs and field enums are generated by `tacky-build` at build time. With the
+//! `derive` feature, [`TackyEncode`] and [`TackyDecode`] map a domain struct onto one. With
+//! the `macros` feature, `include_proto!` and `proto!` generate them without a build script.
 
 #![no_std]
 #![allow(clippy::new_without_default)]
@@ -23,10 +32,28 @@ extern crate alloc;
 extern crate std;
 
 pub mod buf;
+pub mod decode;
+pub mod encode;
 pub mod field;
+pub mod grpc;
+#[cfg(feature = "bytes")]
+pub mod owned;
+#[cfg(feature = "alloc")]
+pub mod random;
 pub mod scalars;
+pub mod service;
 pub mod tack;
 pub use buf::*;
+pub use decode::*;
+pub use encode::*;
 pub use field::*;
+#[cfg(feature = "bytes")]
+pub use owned::{BytesStr, Owned};
 pub use scalars::*;
+pub use service::*;
 pub use tack::*;
+
+#[cfg(feature = "macros")]
+pub use tacky_derive::{include_proto, proto};
+#[cfg(feature = "derive")]
+pub use tacky_derive::{TackyDecode, TackyEncode};
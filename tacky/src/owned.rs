@@ -0,0 +1,171 @@
+//! Decoding that outlives the receive buffer, with the `bytes` feature.
+//!
+//! A generated `Fields` iterator borrows the buffer it decodes, and so does everything it
+//! yields, which is what makes it free and what keeps a decoded value from being sent on
+//! to another task. [`Owned`] holds the buffer as a [`Bytes`] instead and decodes out of
+//! that; the strings, bytes and nested messages it yields can then be turned into handles
+//! of their own, each sharing the one allocation rather than copying out of it:
+//!
+//! ```ignore
+//! let request: Owned<proto::Upload> = Owned::new(frame);   // frame: Bytes off the socket
+//! for field in request.fields() {
+//!     match field? {
+//!         UploadField::Name(name) => names.push(request.string(name)),  // BytesStr
+//!         UploadField::Data(data) => tx.send(request.bytes(data)).await?, // Bytes
+//!         UploadField::Meta(meta) => metas.push(request.message(&meta)), // Owned<Meta>
+//!         _ => {}
+//!     }
+//! }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+pub use bytes::Bytes;
+
+use crate::{DecodeMessage, MessageFields};
+
+/// One message of `M`'s, encoded in a buffer it owns. Cloning it clones the handle, not
+/// the buffer.
+pub struct Owned<M> {
+    buf: Bytes,
+    schema: PhantomData<fn() -> M>,
+}
+
+impl<M: DecodeMessage> Owned<M> {
+    /// The message encoded in `buf`, which isn't checked until it is decoded.
+    pub fn new(buf: impl Into<Bytes>) -> Owned<M> {
+        Owned {
+            buf: buf.into(),
+            schema: PhantomData,
+        }
+    }
+
+    /// Decodes the message, as the schema's own `decode` would, borrowing from this.
+    pub fn fields(&self) -> M::Fields<'_> {
+        M::fields(&self.buf)
+    }
+
+    /// A `bytes` value decoded from this message, as a handle on the same allocation.
+    ///
+    /// # Panics
+    ///
+    /// If `value` isn't part of this message's buffer.
+    pub fn bytes(&self, value: &[u8]) -> Bytes {
+        self.buf.slice_ref(value)
+    }
+
+    /// A `string` value decoded from this message, as a handle on the same allocation.
+    ///
+    /// # Panics
+    ///
+    /// If `value` isn't part of this message's buffer.
+    pub fn string(&self, value: &str) -> BytesStr {
+        BytesStr(self.buf.slice_ref(value.as_bytes()))
+    }
+
+    /// A nested message decoded from this one, to keep apart from it. `fields` must not
+    /// have been advanced, or the fields it has already read are missing.
+    ///
+    /// # Panics
+    ///
+    /// If `fields` didn't come from this message's buffer.
+    pub fn message<'a, F: MessageFields<'a>>(&'a self, fields: &F) -> Owned<F::Schema> {
+        Owned::new(self.buf.slice_ref(fields.as_bytes()))
+    }
+
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.buf
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.buf
+    }
+}
+
+impl<M> Clone for Owned<M> {
+    fn clone(&self) -> Owned<M> {
+        Owned {
+            buf: self.buf.clone(),
+            schema: PhantomData,
+        }
+    }
+}
+
+impl<M> fmt::Debug for Owned<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Owned").field(&self.buf).finish()
+    }
+}
+
+/// A `str` in a [`Bytes`]: a decoded string field that shares its message's buffer.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BytesStr(Bytes);
+
+impl BytesStr {
+    /// `bytes` as a string, if they are UTF-8.
+    pub fn from_utf8(bytes: Bytes) -> Result<BytesStr, core::str::Utf8Error> {
+        core::str::from_utf8(&bytes)?;
+        Ok(BytesStr(bytes))
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: every constructor checks the bytes are UTF-8, or takes them from a `str`.
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+}
+
+impl Deref for BytesStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for BytesStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&'static str> for BytesStr {
+    fn from(s: &'static str) -> BytesStr {
+        BytesStr(Bytes::from_static(s.as_bytes()))
+    }
+}
+
+impl From<alloc::string::String> for BytesStr {
+    fn from(s: alloc::string::String) -> BytesStr {
+        BytesStr(Bytes::from(s))
+    }
+}
+
+impl PartialEq<str> for BytesStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for BytesStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for BytesStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for BytesStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,295 @@
+//! Random messages for property tests, as generated code makes them: `tacky-build`'s
+//! `Config::random` adds a `random` constructor to each `<Message>Owned`, returning a value
+//! and an encoding of it that decodes back to that value.
+//!
+//! The encodings are valid, not canonical: fields come in any order, packed fields come
+//! partly unpacked, map entries put the value before the key, and unknown fields turn up
+//! between the known ones. Each field's own occurrences keep their order, since that is
+//! what decides which occurrence wins.
+//!
+//! There are no dependencies here: [`Rng`] is a seeded splitmix64, so a failing seed can be
+//! replayed as it is.
+
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::scalars::{write_varint, PbBytes, PbEnumType, ProtobufScalar, WireType};
+use crate::ProtoEncode;
+
+/// A seeded pseudo-random generator, and the choices generated code makes with it.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub const fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`; `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    /// How many elements a repeated field or map gets: mostly a few, sometimes none.
+    pub fn count(&mut self) -> usize {
+        self.below(5)
+    }
+
+    pub fn value<T: Generate>(&mut self) -> T {
+        T::generate(self)
+    }
+
+    /// One of an enum's `declared` numbers, or, for an open enum, sometimes a number it
+    /// doesn't declare, which it keeps. A closed enum would drop that, so it never gets one.
+    pub fn enum_value<E: PbEnumType>(&mut self, declared: &[i32]) -> E {
+        if declared.is_empty() || self.one_in(8) {
+            if let Some(e) = E::from_wire(self.value()) {
+                return e;
+            }
+        }
+        match declared {
+            [] => E::default(),
+            _ => E::from_wire(declared[self.below(declared.len())]).unwrap_or_default(),
+        }
+    }
+
+    /// `items` cut into runs of at least one element, in order.
+    pub fn runs<'a, T>(&mut self, mut items: &'a [T]) -> Vec<&'a [T]> {
+        let mut runs = Vec::new();
+        while !items.is_empty() {
+            let (run, rest) = items.split_at(1 + self.below(items.len()));
+            runs.push(run);
+            items = rest;
+        }
+        runs
+    }
+}
+
+/// A random value of an owned field type. Integers favour their edge cases; floats are
+/// always finite, so that a decoded value compares equal to the one written.
+pub trait Generate {
+    fn generate(r: &mut Rng) -> Self;
+}
+
+macro_rules! generate_ints {
+    ($($t:ty),*) => {
+        $(
+            impl Generate for $t {
+                fn generate(r: &mut Rng) -> $t {
+                    match r.below(8) {
+                        0 => 0,
+                        1 => <$t>::MIN,
+                        2 => <$t>::MAX,
+                        3 => r.below(128) as $t,
+                        _ => r.next_u64() as $t,
+                    }
+                }
+            }
+        )*
+    };
+}
+generate_ints!(i32, i64, u32, u64);
+
+impl Generate for bool {
+    fn generate(r: &mut Rng) -> bool {
+        r.one_in(2)
+    }
+}
+
+impl Generate for f32 {
+    fn generate(r: &mut Rng) -> f32 {
+        let f = f32::from_bits(r.next_u64() as u32);
+        if f.is_finite() {
+            f
+        } else {
+            r.below(1000) as f32 / 8.0
+        }
+    }
+}
+
+impl Generate for f64 {
+    fn generate(r: &mut Rng) -> f64 {
+        let f = f64::from_bits(r.next_u64());
+        if f.is_finite() {
+            f
+        } else {
+            r.below(1000) as f64 / 8.0
+        }
+    }
+}
+
+impl Generate for String {
+    /// Mostly ASCII, with characters of every encoded width.
+    fn generate(r: &mut Rng) -> String {
+        const WIDE: [char; 4] = ['é', '€', '𝄞', '\0'];
+        (0..r.below(12))
+            .map(|_| match r.below(6) {
+                0 => WIDE[r.below(WIDE.len())],
+                _ => (b' ' + r.below(95) as u8) as char,
+            })
+            .collect()
+    }
+}
+
+impl Generate for Vec<u8> {
+    fn generate(r: &mut Rng) -> Vec<u8> {
+        (0..r.below(12)).map(|_| r.next_u64() as u8).collect()
+    }
+}
+
+/// What `f` writes, on its own.
+pub fn chunk<R>(f: impl FnOnce(&mut Vec<u8>) -> R) -> Vec<u8> {
+    let mut buf = Vec::new();
+    f(&mut buf);
+    buf
+}
+
+/// Field `number` holding `bytes`: how a nested message is written here, its encoding
+/// being made up beforehand.
+pub fn len_field(number: u32, bytes: &[u8]) -> Vec<u8> {
+    chunk(|buf| PbBytes::write(number, bytes, buf))
+}
+
+/// A map entry of field `number`, its key and value in either order. Without a value, the
+/// entry decodes to the value type's default.
+pub fn map_entry<K: ProtobufScalar, V: ProtobufScalar>(
+    r: &mut Rng,
+    number: u32,
+    key: impl ProtoEncode<K>,
+    value: Option<impl ProtoEncode<V>>,
+) -> Vec<u8> {
+    let mut entry = Vec::new();
+    let value_first = r.one_in(2);
+    if let (true, Some(v)) = (value_first, &value) {
+        V::write(2, v.as_scalar(), &mut entry);
+    }
+    K::write(1, key.as_scalar(), &mut entry);
+    if let (false, Some(v)) = (value_first, &value) {
+        V::write(2, v.as_scalar(), &mut entry);
+    }
+    len_field(number, &entry)
+}
+
+/// A message's encoding, put together from each field's occurrences.
+#[derive(Debug, Default)]
+pub struct Chunks {
+    fields: Vec<(u32, VecDeque<Vec<u8>>)>,
+}
+
+impl Chunks {
+    pub fn new() -> Chunks {
+        Chunks::default()
+    }
+
+    /// The next occurrence of field `number`, which comes after those pushed before it.
+    pub fn push(&mut self, number: u32, chunk: Vec<u8>) {
+        if chunk.is_empty() {
+            return;
+        }
+        match self.fields.iter_mut().find(|(n, _)| *n == number) {
+            Some((_, chunks)) => chunks.push_back(chunk),
+            None => self.fields.push((number, VecDeque::from([chunk]))),
+        }
+    }
+
+    /// A few fields of every wire type, numbered as none of `declared` is, for the decoder
+    /// to skip.
+    pub fn unknown(&mut self, r: &mut Rng, declared: &[u32]) {
+        for _ in 0..r.below(3) {
+            let number = loop {
+                let n = match r.one_in(4) {
+                    true => 1 + r.below((1 << 29) - 1) as u32,
+                    false => 1 + r.below(200) as u32,
+                };
+                if !declared.contains(&n) {
+                    break n;
+                }
+            };
+            let mut buf = Vec::new();
+            let wire_type = [
+                WireType::VARINT,
+                WireType::I64,
+                WireType::LEN,
+                WireType::I32,
+            ][r.below(4)];
+            write_varint(((number << 3) | wire_type as u32) as u64, &mut buf);
+            match wire_type {
+                WireType::VARINT => write_varint(r.value(), &mut buf),
+                WireType::I64 => buf.extend_from_slice(&r.next_u64().to_le_bytes()),
+                WireType::I32 => buf.extend_from_slice(&(r.next_u64() as u32).to_le_bytes()),
+                _ => {
+                    let bytes: Vec<u8> = r.value();
+                    write_varint(bytes.len() as u64, &mut buf);
+                    buf.extend_from_slice(&bytes);
+                }
+            }
+            self.push(number, buf);
+        }
+    }
+
+    /// The whole encoding: the fields interleaved at random, each in its own order.
+    pub fn shuffle(mut self, r: &mut Rng) -> Vec<u8> {
+        let mut out = Vec::new();
+        while !self.fields.is_empty() {
+            let i = r.below(self.fields.len());
+            let chunks = &mut self.fields[i].1;
+            if let Some(chunk) = chunks.pop_front() {
+                out.extend_from_slice(&chunk);
+            }
+            if chunks.is_empty() {
+                self.fields.swap_remove(i);
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_shuffle_keeps_field_order() {
+        let mut r = Rng::new(1);
+        for _ in 0..100 {
+            let mut chunks = Chunks::new();
+            for i in 0..4u8 {
+                chunks.push(1, vec![i]);
+                chunks.push(2, vec![10 + i]);
+            }
+            chunks.push(3, Vec::new());
+            let out = chunks.shuffle(&mut r);
+            assert_eq!(out.len(), 8);
+            let ones: Vec<u8> = out.iter().copied().filter(|b| *b < 10).collect();
+            let twos: Vec<u8> = out.iter().copied().filter(|b| *b >= 10).collect();
+            assert_eq!((ones, twos), (vec![0, 1, 2, 3], vec![10, 11, 12, 13]));
+        }
+    }
+
+    #[test]
+    fn test_runs() {
+        let mut r = Rng::new(2);
+        let items = [1, 2, 3, 4, 5];
+        for _ in 0..100 {
+            let runs = r.runs(&items);
+            assert!(runs.iter().all(|run| !run.is_empty()));
+            assert_eq!(runs.concat(), items);
+        }
+        assert!(r.runs::<u8>(&[]).is_empty());
+    }
+}
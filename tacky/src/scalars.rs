@@ -33,13 +33,73 @@ protobuf_types!(
     Double
     PbString
     PbBytes
+    PbLazyString
 );
 
+/// A decoded `string` whose UTF-8 is checked when it is read as text, rather than when it
+/// is decoded: what a [`PbLazyString`] field holds. Reading fields that are mostly skipped
+/// costs nothing, and a producer sending Latin-1 fails only where the text is used.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PbStr<'a>(&'a [u8]);
+
+impl<'a> PbStr<'a> {
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        PbStr(bytes)
+    }
+
+    /// The text, or [`DecodeError::InvalidUtf8`] if it isn't UTF-8.
+    pub fn as_str(&self) -> Result<&'a str, DecodeError> {
+        Ok(core::str::from_utf8(self.0)?)
+    }
+
+    /// The bytes as they came, checked or not.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for PbStr<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.0
+    }
+}
+
+impl<'a> From<&'a str> for PbStr<'a> {
+    fn from(s: &'a str) -> Self {
+        PbStr(s.as_bytes())
+    }
+}
+
 /// Constraint for types that can be used as protobuf enums.
-/// Protobuf enums are i32 on the wire, so this requires bidirectional i32 conversion.
+/// Protobuf enums are i32 on the wire, so this requires conversion to and from i32.
 /// Generated enum types implement this automatically.
-pub trait PbEnumType: Copy + Into<i32> + From<i32> + Default + PartialEq {}
-impl<T: Copy + Into<i32> + From<i32> + Default + PartialEq> PbEnumType for T {}
+///
+/// An open enum (proto3's) converts from every `i32`, keeping numbers it doesn't declare,
+/// and gets this from its `From<i32>`. A closed one (proto2's) implements it directly:
+/// a number it doesn't declare isn't one of its values, and decoders skip the field it
+/// came in, as they would an unknown one.
+pub trait PbEnumType: Copy + Into<i32> + Default + PartialEq {
+    /// The value numbered `n`, or `None` if this is a closed enum that doesn't declare it.
+    fn from_wire(n: i32) -> Option<Self>;
+}
+impl<T: Copy + Into<i32> + From<i32> + Default + PartialEq> PbEnumType for T {
+    #[inline]
+    fn from_wire(n: i32) -> Option<Self> {
+        Some(T::from(n))
+    }
+}
+
+/// A number a closed enum doesn't declare: the error of its `TryFrom<i32>`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnknownEnumValue(pub i32);
+
+impl core::fmt::Display for UnknownEnumValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown enum value {}", self.0)
+    }
+}
+
+impl core::error::Error for UnknownEnumValue {}
 
 /// ZST marker for protobuf enum fields, generic over the generated Rust enum type.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
@@ -74,6 +134,13 @@ pub trait ProtobufScalar {
     }
     /// Reads one value from the buffer, advancing the cursor past it.
     fn read<'a>(buf: &mut &'a [u8]) -> Result<Self::RustType<'a>, DecodeError>;
+    /// Like [`read`](Self::read), but `None` for a value that is well-formed on the wire and
+    /// still not one of this type's: a number a closed enum doesn't declare. Decoders skip
+    /// those rather than fail, as protobuf treats them as unknown fields.
+    #[inline]
+    fn read_known<'a>(buf: &mut &'a [u8]) -> Result<Option<Self::RustType<'a>>, DecodeError> {
+        Self::read(buf).map(Some)
+    }
     /// Total wire length of a field (tag + value). Used for map entries
     /// where the entry length must be known before writing.
     fn len(field_nr: u32, value: Self::RustType<'_>) -> usize {
@@ -433,6 +500,27 @@ impl ProtobufScalar for PbString {
     }
 }
 
+/// A `string` decoded as a [`PbStr`], unchecked until it is used.
+impl ProtobufScalar for PbLazyString {
+    type RustType<'a> = PbStr<'a>;
+    const WIRE_TYPE: WireType = WireType::LEN;
+
+    #[inline]
+    fn write_value(value: Self::RustType<'_>, buf: &mut impl WriteBuf) {
+        buf.put_len_delimited(value.as_bytes());
+    }
+
+    #[inline]
+    fn value_len(value: Self::RustType<'_>) -> usize {
+        encoded_len_varint(value.0.len() as u64) + value.0.len()
+    }
+
+    #[inline]
+    fn read<'a>(buf: &mut &'a [u8]) -> Result<Self::RustType<'a>, DecodeError> {
+        decode_len(buf).map(PbStr)
+    }
+}
+
 impl ProtobufScalar for PbBytes {
     type RustType<'a> = &'a [u8];
     const WIRE_TYPE: WireType = WireType::LEN;
@@ -468,7 +556,12 @@ impl<T: PbEnumType> ProtobufScalar for PbEnum<T> {
 
     fn read<'a>(buf: &mut &'a [u8]) -> Result<Self::RustType<'a>, DecodeError> {
         let n = decode_varint(buf)? as i32;
-        Ok(T::from(n))
+        T::from_wire(n).ok_or(DecodeError::UnknownEnumValue(n))
+    }
+
+    #[inline]
+    fn read_known<'a>(buf: &mut &'a [u8]) -> Result<Option<Self::RustType<'a>>, DecodeError> {
+        Ok(T::from_wire(decode_varint(buf)? as i32))
     }
 }
 /// Protobuf wire types. The wire type tells the decoder how many bytes a field
@@ -487,7 +580,10 @@ pub enum WireType {
     I32 = 5, //	fixed32, sfixed32, float
 }
 
+/// Why a decode failed. Checks the runtime adds come with variants of their own, so match
+/// with a `_` arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum DecodeError {
     Truncated,
     InvalidWireType(u32),
@@ -498,6 +594,10 @@ pub enum DecodeError {
     },
     InvalidUtf8,
     InvalidMapEntry,
+    /// A `required` field, or a field the decoding type cannot do without, never appeared.
+    MissingField(&'static str),
+    /// A number a closed enum doesn't declare, where the value can't just be skipped.
+    UnknownEnumValue(i32),
 }
 
 impl core::fmt::Display for DecodeError {
@@ -517,12 +617,20 @@ impl core::fmt::Display for DecodeError {
             DecodeError::InvalidMapEntry => {
                 write!(f, "invalid map entry, tag isnt 1 or 2")
             }
+            DecodeError::MissingField(field) => write!(f, "missing field \"{field}\""),
+            DecodeError::UnknownEnumValue(n) => write!(f, "unknown enum value {n}"),
         }
     }
 }
 
 impl core::error::Error for DecodeError {}
 
+impl From<UnknownEnumValue> for DecodeError {
+    fn from(e: UnknownEnumValue) -> Self {
+        DecodeError::UnknownEnumValue(e.0)
+    }
+}
+
 impl From<core::str::Utf8Error> for DecodeError {
     fn from(_: core::str::Utf8Error) -> Self {
         DecodeError::InvalidUtf8
@@ -0,0 +1,103 @@
+//! What generated service code is built on, with no transport assumed.
+//!
+//! For each `service`, `tacky-build` generates a trait with a handler per rpc, plus a unit
+//! struct per method implementing [`Method`]. A handler gets its request as the request
+//! message's `Fields` iterator and writes its response through the response's schema
+//! into a [`WriteBuf`], the same as any other message. The trait's provided `dispatch`
+//! routes a call by method name or path, and that is all a transport has to call, whether
+//! it speaks gRPC or HTTP or just passes buffers in-process:
+//!
+//! ```ignore
+//! struct Hello;
+//!
+//! impl proto::Greeter for Hello {
+//!     type Error = tacky::DecodeError;
+//!
+//!     fn say_hello<B: WriteBuf>(
+//!         &self,
+//!         request: proto::HelloRequestFields<'_>,
+//!         response: &mut B,
+//!     ) -> Result<(), Self::Error> {
+//!         for field in request {
+//!             let proto::HelloRequestField::Name(name) = field?;
+//!             proto::HelloReply::schema().message.write(response, name);
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut responses: Vec<Vec<u8>> = Vec::new();
+//! Hello.dispatch("SayHello", [&request[..]], &mut responses)?;
+//! ```
+//!
+//! A streaming request reaches the handler as an iterator of `Fields`, one per message; a
+//! streaming response is written as any number of messages into a [`ResponseSink`].
+
+use crate::{MessageSchema, WriteBuf};
+
+/// One rpc of a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MethodDescriptor {
+    /// As declared: `SayHello`.
+    pub name: &'static str,
+    /// `/package.Service/Method`: what gRPC calls it on the wire.
+    pub path: &'static str,
+    /// Whether the request is a `stream`.
+    pub client_streaming: bool,
+    /// Whether the response is a `stream`.
+    pub server_streaming: bool,
+}
+
+/// An rpc as a type: generated as a unit struct named after its service and itself
+/// (`GreeterSayHello`), for code that is generic over methods.
+pub trait Method {
+    type Request: MessageSchema;
+    type Response: MessageSchema;
+    const DESCRIPTOR: MethodDescriptor;
+}
+
+/// Where a call's responses go, each into a buffer of its own: exactly one for a unary or
+/// client-streaming rpc, any number for a server-streaming one.
+pub trait ResponseSink<B: WriteBuf> {
+    /// Has `write` write one response message into a fresh buffer, then takes the buffer.
+    fn send<R>(&mut self, write: impl FnOnce(&mut B) -> R) -> R;
+}
+
+/// Collects responses in memory, in order: for tests, and for anything that buffers a
+/// whole call anyway.
+#[cfg(feature = "alloc")]
+impl ResponseSink<alloc::vec::Vec<u8>> for alloc::vec::Vec<alloc::vec::Vec<u8>> {
+    fn send<R>(&mut self, write: impl FnOnce(&mut alloc::vec::Vec<u8>) -> R) -> R {
+        let mut buf = alloc::vec::Vec::new();
+        let result = write(&mut buf);
+        self.push(buf);
+        result
+    }
+}
+
+/// Why a generated `dispatch` failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DispatchError<E> {
+    /// The service has no method by that name or path.
+    UnknownMethod,
+    /// The handler's own error.
+    Handler(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for DispatchError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DispatchError::UnknownMethod => f.write_str("unknown method"),
+            DispatchError::Handler(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for DispatchError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            DispatchError::UnknownMethod => None,
+            DispatchError::Handler(e) => Some(e),
+        }
+    }
+}
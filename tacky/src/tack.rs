@@ -162,6 +162,7 @@ fn write_wide_varint_slice(width: usize, value: u64, buf: &mut [u8]) {
         buf[0] = value as u8;
         return;
     }
+    #[allow(clippy::needless_range_loop)]
     for i in 0..(width - 1) {
         buf[i] = (((value >> (7 * i)) & 0x7F) | 0x80) as u8;
     }
@@ -27,6 +27,12 @@ pub fn tacky_encode<B: tacky::WriteBuf>(
         .write_msgs(buf, &set.file, |buf, _, f| write_file(buf, f));
 }
 
+/// prost's enum value as the tacky enum. descriptor.proto is proto2, so those are closed,
+/// and protoc only writes numbers they declare.
+fn known<E: TryFrom<i32>>(value: Option<i32>) -> Option<E> {
+    value.and_then(|v| E::try_from(v).ok())
+}
+
 fn write_file<B: tacky::WriteBuf>(
     buf: &mut tacky::AnyDir<B>,
     f: &prost_types::FileDescriptorProto,
@@ -47,7 +53,7 @@ fn write_file<B: tacky::WriteBuf>(
             t.java_outer_classname
                 .write(buf, o.java_outer_classname.as_deref());
             t.optimize_for
-                .write(buf, o.optimize_for.map(td::FileOptionsOptimizeMode::from));
+                .write(buf, known::<td::file_options::OptimizeMode>(o.optimize_for));
             t.java_multiple_files.write(buf, o.java_multiple_files);
             t.go_package.write(buf, o.go_package.as_deref());
             t.cc_generic_services.write(buf, o.cc_generic_services);
@@ -137,19 +143,20 @@ fn write_field<B: tacky::WriteBuf>(
     s.extendee.write(buf, f.extendee.as_deref());
     s.number.write(buf, f.number);
     s.label
-        .write(buf, f.label.map(td::FieldDescriptorProtoLabel::from));
+        .write(buf, known::<td::field_descriptor_proto::Label>(f.label));
     s.r#type
-        .write(buf, f.r#type.map(td::FieldDescriptorProtoType::from));
+        .write(buf, known::<td::field_descriptor_proto::Type>(f.r#type));
     s.type_name.write(buf, f.type_name.as_deref());
     s.default_value.write(buf, f.default_value.as_deref());
     if let Some(o) = &f.options {
         s.options.write_msg(buf, |buf, t| {
-            t.ctype.write(buf, o.ctype.map(td::FieldOptionsCType::from));
+            t.ctype
+                .write(buf, known::<td::field_options::CType>(o.ctype));
             t.packed.write(buf, o.packed);
             t.deprecated.write(buf, o.deprecated);
             t.lazy.write(buf, o.lazy);
             t.jstype
-                .write(buf, o.jstype.map(td::FieldOptionsJSType::from));
+                .write(buf, known::<td::field_options::JSType>(o.jstype));
             t.weak.write(buf, o.weak);
         });
     }
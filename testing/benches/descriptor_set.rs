@@ -41,10 +41,9 @@
 //! `SourceCodeInfo` included. It does *not* cover `ServiceDescriptorProto`,
 //! `UninterpretedOption`, or the option messages with no set fields here
 //! (`EnumOptions`, `EnumValueOptions`, `OneofOptions`, `ExtensionRangeOptions`): none
-//! appear in any fixture. Services are absent because tacky does not generate service
-//! definitions — RPC is not what it is for — not because they were overlooked. The rest
-//! is not a silent gap either: the round-trip assert below compares whole messages, so
-//! a fixture that grew one of them would fail rather than quietly measure less work.
+//! appear in any fixture. None of that is a silent gap: the round-trip assert below
+//! compares whole messages, so a fixture that grew one of them would fail rather than
+//! quietly measure less work.
 //!
 //! Wire output is checked by decoding tacky's bytes with prost and comparing messages
 //! rather than by comparing byte strings, because a reverse writer emits fields in the
@@ -127,7 +126,11 @@ fn read_file(fields: td::FileDescriptorProtoFields<'_>) -> prost_types::FileDesc
                             o.php_metadata_namespace = Some(v.to_string())
                         }
                         O::RubyPackage(v) => o.ruby_package = Some(v.to_string()),
-                        O::JavaGenerateEqualsAndHash(_) | O::UninterpretedOption(_) => {
+                        // Deprecated in descriptor.proto, so in the generated enum too.
+                        #[allow(deprecated)]
+                        O::JavaGenerateEqualsAndHash(_)
+                        | O::UninterpretedOption(_)
+                        | O::Extension(_) => {
                             unimplemented!("FileOptions field absent from both fixtures")
                         }
                     }
@@ -140,7 +143,7 @@ fn read_file(fields: td::FileDescriptorProtoFields<'_>) -> prost_types::FileDesc
                 for field in sci {
                     match field.unwrap() {
                         S::Location(loc) => {
-                            use td::SourceCodeInfoLocationField as L;
+                            use td::source_code_info::LocationField as L;
                             let mut l = prost_types::source_code_info::Location::default();
                             for field in loc {
                                 match field.unwrap() {
@@ -163,7 +166,7 @@ fn read_file(fields: td::FileDescriptorProtoFields<'_>) -> prost_types::FileDesc
                 }
             }
             F::Service(_) => {
-                unimplemented!("tacky does not generate service definitions; see the module docs")
+                unimplemented!("no fixture declares a service; see the module docs")
             }
         }
     }
@@ -182,7 +185,7 @@ fn read_message(fields: td::DescriptorProtoFields<'_>) -> prost_types::Descripto
             F::NestedType(n) => m.nested_type.push(read_message(n)),
             F::EnumType(e) => m.enum_type.push(read_enum(e)),
             F::ExtensionRange(r) => {
-                use td::DescriptorProtoExtensionRangeField as R;
+                use td::descriptor_proto::ExtensionRangeField as R;
                 let mut range = prost_types::descriptor_proto::ExtensionRange::default();
                 for f in r {
                     match f.unwrap() {
@@ -219,14 +222,14 @@ fn read_message(fields: td::DescriptorProtoFields<'_>) -> prost_types::Descripto
                         }
                         O::Deprecated(v) => o.deprecated = Some(v),
                         O::MapEntry(v) => o.map_entry = Some(v),
-                        O::UninterpretedOption(_) => {
-                            unimplemented!("UninterpretedOption absent from both fixtures")
+                        O::UninterpretedOption(_) | O::Extension(_) => {
+                            unimplemented!("MessageOptions field absent from both fixtures")
                         }
                     }
                 }
             }
             F::ReservedRange(r) => {
-                use td::DescriptorProtoReservedRangeField as R;
+                use td::descriptor_proto::ReservedRangeField as R;
                 let mut range = prost_types::descriptor_proto::ReservedRange::default();
                 for f in r {
                     match f.unwrap() {
@@ -272,7 +275,7 @@ fn read_field(fields: td::FieldDescriptorProtoFields<'_>) -> prost_types::FieldD
                         // `unverified_lazy` landed in protobuf 3.21, after the
                         // descriptor.proto prost-types is generated from, so prost has
                         // nowhere to put it. Neither fixture sets it.
-                        O::UnverifiedLazy(_) | O::UninterpretedOption(_) => {
+                        O::UnverifiedLazy(_) | O::UninterpretedOption(_) | O::Extension(_) => {
                             unimplemented!("FieldOptions field absent from both fixtures")
                         }
                     }
@@ -305,7 +308,7 @@ fn read_enum(fields: td::EnumDescriptorProtoFields<'_>) -> prost_types::EnumDesc
                 e.value.push(val);
             }
             F::ReservedRange(r) => {
-                use td::EnumDescriptorProtoEnumReservedRangeField as R;
+                use td::enum_descriptor_proto::EnumReservedRangeField as R;
                 let mut range = prost_types::enum_descriptor_proto::EnumReservedRange::default();
                 for f in r {
                     match f.unwrap() {
@@ -382,7 +385,10 @@ fn walk_file(fields: td::FileDescriptorProtoFields<'_>) -> u64 {
                         O::PhpNamespace(v) => add!(v.len()),
                         O::PhpMetadataNamespace(v) => add!(v.len()),
                         O::RubyPackage(v) => add!(v.len()),
-                        O::JavaGenerateEqualsAndHash(_) | O::UninterpretedOption(_) => {
+                        #[allow(deprecated)]
+                        O::JavaGenerateEqualsAndHash(_)
+                        | O::UninterpretedOption(_)
+                        | O::Extension(_) => {
                             unimplemented!("FileOptions field absent from both fixtures")
                         }
                     }
@@ -394,7 +400,7 @@ fn walk_file(fields: td::FileDescriptorProtoFields<'_>) -> u64 {
                 for field in sci {
                     match field.unwrap() {
                         S::Location(loc) => {
-                            use td::SourceCodeInfoLocationField as L;
+                            use td::source_code_info::LocationField as L;
                             for field in loc {
                                 match field.unwrap() {
                                     L::Path(iter) | L::Span(iter) => {
@@ -412,7 +418,7 @@ fn walk_file(fields: td::FileDescriptorProtoFields<'_>) -> u64 {
                 }
             }
             F::Service(_) => {
-                unimplemented!("tacky does not generate service definitions; see the module docs")
+                unimplemented!("no fixture declares a service; see the module docs")
             }
         }
     }
@@ -436,7 +442,7 @@ fn walk_message(fields: td::DescriptorProtoFields<'_>) -> u64 {
             F::NestedType(n) => add!(walk_message(n)),
             F::EnumType(e) => add!(walk_enum(e)),
             F::ExtensionRange(r) => {
-                use td::DescriptorProtoExtensionRangeField as R;
+                use td::descriptor_proto::ExtensionRangeField as R;
                 for f in r {
                     match f.unwrap() {
                         R::Start(v) => add!(v),
@@ -466,14 +472,14 @@ fn walk_message(fields: td::DescriptorProtoFields<'_>) -> u64 {
                         O::NoStandardDescriptorAccessor(v) => add!(v),
                         O::Deprecated(v) => add!(v),
                         O::MapEntry(v) => add!(v),
-                        O::UninterpretedOption(_) => {
-                            unimplemented!("UninterpretedOption absent from both fixtures")
+                        O::UninterpretedOption(_) | O::Extension(_) => {
+                            unimplemented!("MessageOptions field absent from both fixtures")
                         }
                     }
                 }
             }
             F::ReservedRange(r) => {
-                use td::DescriptorProtoReservedRangeField as R;
+                use td::descriptor_proto::ReservedRangeField as R;
                 for f in r {
                     match f.unwrap() {
                         R::Start(v) => add!(v),
@@ -518,7 +524,7 @@ fn walk_field(fields: td::FieldDescriptorProtoFields<'_>) -> u64 {
                         O::Lazy(v) => add!(v),
                         O::Deprecated(v) => add!(v),
                         O::Weak(v) => add!(v),
-                        O::UnverifiedLazy(_) | O::UninterpretedOption(_) => {
+                        O::UnverifiedLazy(_) | O::UninterpretedOption(_) | O::Extension(_) => {
                             unimplemented!("FieldOptions field absent from both fixtures")
                         }
                     }
@@ -554,7 +560,7 @@ fn walk_enum(fields: td::EnumDescriptorProtoFields<'_>) -> u64 {
                 }
             }
             F::ReservedRange(r) => {
-                use td::EnumDescriptorProtoEnumReservedRangeField as R;
+                use td::enum_descriptor_proto::EnumReservedRangeField as R;
                 for f in r {
                     match f.unwrap() {
                         R::Start(v) => add!(v),
@@ -38,8 +38,8 @@ mod corpus_gen;
 use corpus_gen::{bytes, mix, scattered};
 
 #[allow(dead_code)]
-mod tacky_otlp_logs {
-    include!(concat!(env!("OUT_DIR"), "/tacky_otlp_logs.rs"));
+mod tacky_otlp {
+    include!(concat!(env!("OUT_DIR"), "/tacky_otlp.rs"));
 }
 #[allow(dead_code)]
 mod otlp {
@@ -50,7 +50,11 @@ use otlp::opentelemetry::proto::collector::logs::v1 as pcol;
 use otlp::opentelemetry::proto::common::v1 as pcommon;
 use otlp::opentelemetry::proto::logs::v1 as plogs;
 use otlp::opentelemetry::proto::resource::v1 as presource;
-use tacky_otlp_logs::opentelemetry::proto::collector::logs::v1 as t;
+mod t {
+    pub use super::tacky_otlp::opentelemetry::proto::{
+        collector::logs::v1::*, common::v1::*, logs::v1::*,
+    };
+}
 
 // ---------------------------------------------------------------------------
 // Corpus
@@ -66,9 +66,13 @@ mod otlp {
     include!(concat!(env!("OUT_DIR"), "/otlp.rs"));
 }
 
-// tacky-build inlines a file's imports, so the whole tree lives in one module.
-use tacky_otlp::opentelemetry::proto::collector::trace::v1 as t;
-// prost keeps one module per proto package.
+// Both generators keep one module per proto package; the bench names tacky's types
+// through one flat namespace.
+mod t {
+    pub use super::tacky_otlp::opentelemetry::proto::{
+        collector::trace::v1::*, common::v1::*, resource::v1::*, trace::v1::*,
+    };
+}
 use otlp::opentelemetry::proto::collector::trace::v1 as pcol;
 use otlp::opentelemetry::proto::common::v1 as pcommon;
 use otlp::opentelemetry::proto::resource::v1 as presource;
@@ -417,7 +421,7 @@ fn write_span<B: tacky::WriteBuf>(buf: &mut tacky::AnyDir<B>, span: &ptrace::Spa
     s.trace_state.write(buf, span.trace_state.as_str());
     s.parent_span_id.write(buf, span.parent_span_id.as_slice());
     s.name.write(buf, span.name.as_str());
-    s.kind.write(buf, t::SpanSpanKind::from(span.kind));
+    s.kind.write(buf, t::span::SpanKind::from(span.kind));
     s.start_time_unix_nano.write(buf, span.start_time_unix_nano);
     s.end_time_unix_nano.write(buf, span.end_time_unix_nano);
     s.attributes
@@ -447,7 +451,7 @@ fn write_span<B: tacky::WriteBuf>(buf: &mut tacky::AnyDir<B>, span: &ptrace::Spa
     if let Some(st) = &span.status {
         s.status.write_msg(buf, |buf, s| {
             s.message.write(buf, st.message.as_str());
-            s.code.write(buf, t::StatusStatusCode::from(st.code));
+            s.code.write(buf, t::status::StatusCode::from(st.code));
         });
     }
     s.flags.write(buf, span.flags);
@@ -583,7 +587,7 @@ fn read_span(fields: t::SpanFields<'_>) -> ptrace::Span {
             F::Attributes(kv) => span.attributes.push(read_kv(kv)),
             F::DroppedAttributesCount(v) => span.dropped_attributes_count = v,
             F::Events(ev) => {
-                use t::SpanEventField as E;
+                use t::span::EventField as E;
                 let mut e = ptrace::span::Event::default();
                 for f in ev {
                     match f.unwrap() {
@@ -597,7 +601,7 @@ fn read_span(fields: t::SpanFields<'_>) -> ptrace::Span {
             }
             F::DroppedEventsCount(v) => span.dropped_events_count = v,
             F::Links(ln) => {
-                use t::SpanLinkField as L;
+                use t::span::LinkField as L;
                 let mut l = ptrace::span::Link::default();
                 for f in ln {
                     match f.unwrap() {
@@ -764,7 +768,7 @@ fn walk_span(fields: t::SpanFields<'_>) -> u64 {
             F::Attributes(kv) => add!(walk_kv(kv)),
             F::DroppedAttributesCount(v) => add!(v),
             F::Events(ev) => {
-                use t::SpanEventField as E;
+                use t::span::EventField as E;
                 for f in ev {
                     match f.unwrap() {
                         E::TimeUnixNano(v) => add!(v),
@@ -776,7 +780,7 @@ fn walk_span(fields: t::SpanFields<'_>) -> u64 {
             }
             F::DroppedEventsCount(v) => add!(v),
             F::Links(ln) => {
-                use t::SpanLinkField as L;
+                use t::span::LinkField as L;
                 for f in ln {
                     match f.unwrap() {
                         L::TraceId(v) => add!(v.len()),
@@ -1,3 +1,5 @@
+use tacky_build::{OptionValue, StringDecoding};
+
 /// The from-source C++ prefix, if one exists. `TACKY_PROTOBUF_PREFIX` wins, else the
 /// repo-local path `scripts/build_cpp_static.sh` installs to. Tests for the lib rather than
 /// the directory: a half-built tree isn't usable.
@@ -44,11 +46,107 @@ fn main() {
     println!("cargo:rerun-if-changed={simple_file}");
     println!("cargo:rerun-if-changed={importing_file}");
     tacky_build::write_proto(simple_file, &simple_out);
-    tacky_build::write_proto_with_includes(importing_file, &importing_out, &["."]);
+    tacky_build::write_proto_with_includes(importing_file, &importing_out, &["protos"]);
+    tacky_build::write_proto_with_owned(simple_file, &format!("{out_dir}/simple_owned.rs"), &["."]);
+
+    let extensions_file = "protos/extensions.proto";
+    println!("cargo:rerun-if-changed={extensions_file}");
+    tacky_build::write_proto(extensions_file, &format!("{out_dir}/extensions.rs"));
+    let extensions_fds = format!("{out_dir}/extensions.fds");
+    let status = std::process::Command::new(prost_build::protoc_from_env())
+        .arg("-Iprotos")
+        .arg(format!("--descriptor_set_out={extensions_fds}"))
+        .arg("extensions.proto")
+        .status()
+        .unwrap();
+    assert!(status.success(), "protoc failed on {extensions_file}");
+    tacky_build::write_fds(
+        &extensions_fds,
+        "extensions.proto",
+        &format!("{out_dir}/extensions_fds.rs"),
+    );
+
+    let defaults_file = "protos/defaults.proto";
+    println!("cargo:rerun-if-changed={defaults_file}");
+    tacky_build::write_proto(defaults_file, &format!("{out_dir}/defaults.rs"));
 
     let proto3_file = "protos/proto3_message.proto";
     println!("cargo:rerun-if-changed={proto3_file}");
     tacky_build::write_proto(proto3_file, &format!("{out_dir}/proto3.rs"));
+    tacky_build::write_proto_with_owned(proto3_file, &format!("{out_dir}/proto3_owned.rs"), &["."]);
+
+    // The same file through `Config`, with everything it can set that changes the output.
+    tacky_build::Config::new()
+        .file(proto3_file)
+        .include_file("config.rs")
+        .owned(true)
+        .runtime_path("crate::config::runtime")
+        .type_attribute(".proto3test.ScalarMessage", "#[derive(PartialEq, Eq)]")
+        .enum_attribute(".proto3test", "#[derive(Hash)]")
+        .field_attribute(
+            ".proto3test.Nested.label",
+            "#[doc = \"What the nested message is called.\"]",
+        )
+        .string_decoding(".proto3test.RepeatedMessage", StringDecoding::Lazy)
+        .string_decoding(".proto3test.WithOneof", StringDecoding::Lazy)
+        .string_decoding(".proto3test.WithOneof.id", StringDecoding::Unchecked)
+        .compile()
+        .unwrap();
+
+    // Random messages for the property tests: proto2 and proto3, every label and map.
+    let random_file = "protos/random.proto";
+    println!("cargo:rerun-if-changed={random_file}");
+    tacky_build::Config::new()
+        .file(proto3_file)
+        .file(simple_file)
+        .file(random_file)
+        .include_file("random.rs")
+        .random(true)
+        .string_decoding(".proto3test.RepeatedMessage", StringDecoding::Lazy)
+        .compile()
+        .unwrap();
+
+    // Messages named after what the generated code uses, google.protobuf's `Field`,
+    // `Option` and `Method` among them.
+    let shadowing_file = "protos/shadowing.proto";
+    println!("cargo:rerun-if-changed={shadowing_file}");
+    tacky_build::Config::new()
+        .file(shadowing_file)
+        .file("protos/google/protobuf/api.proto")
+        .include("protos")
+        .include_file("shadowing.rs")
+        .random(true)
+        .compile()
+        .unwrap();
+
+    // Options through `Config`: `deprecated` and `json_name` need nothing of it, options of
+    // the file's own become attributes.
+    let options_file = "protos/options.proto";
+    tacky_build::Config::new()
+        .file(options_file)
+        .include("protos")
+        .include_file("options.rs")
+        .owned(true)
+        .option_attribute("(table)", |table| {
+            let table = table.as_str()?;
+            Some(format!("#[doc = \" Stored in `{table}`.\"]"))
+        })
+        .option_attribute("(redact)", |redact| {
+            (redact.as_bool() == Some(true)).then(|| "#[doc = \" Redacted.\"]".to_string())
+        })
+        .option_attribute("(range)", |range| {
+            match (range.get("min"), range.get("max")) {
+                (Some(OptionValue::Number(min)), Some(OptionValue::Number(max))) => {
+                    Some(format!("#[doc = \" From {min} to {max}.\"]"))
+                }
+                _ => None,
+            }
+        })
+        .option_attribute("(exhaustive)", |exhaustive| {
+            (exhaustive.as_bool() == Some(false)).then(|| "#[non_exhaustive]".to_string())
+        })
+        .compile()
+        .unwrap();
 
     let pprof_file = "protos/pprof.proto";
     println!("cargo:rerun-if-changed={pprof_file}");
@@ -67,19 +165,64 @@ fn main() {
     println!("cargo:rerun-if-changed={m1p2_file}");
     println!("cargo:rerun-if-changed={m1p3_file}");
     println!("cargo:rerun-if-changed={dataset_file}");
-    tacky_build::write_proto(
-        &strip_custom_defaults(m1p2_file, &out_dir),
-        &format!("{out_dir}/tacky_message1_proto2.rs"),
-    );
+    tacky_build::write_proto(m1p2_file, &format!("{out_dir}/tacky_message1_proto2.rs"));
     tacky_build::write_proto(m1p3_file, &format!("{out_dir}/tacky_message1_proto3.rs"));
 
     // descriptor.proto, for `benches/descriptor_set.rs`. Only tacky needs codegen
     // here — prost's side of that bench comes from prost-types.
     let descriptor_file = "protos/descriptor.proto";
     println!("cargo:rerun-if-changed={descriptor_file}");
-    tacky_build::write_proto(
-        &strip_custom_defaults(descriptor_file, &out_dir),
-        &format!("{out_dir}/tacky_descriptor.rs"),
+    tacky_build::write_proto(descriptor_file, &format!("{out_dir}/tacky_descriptor.rs"));
+    // plugin.proto, which imports it, with owned structs: what tacky-build checks in to
+    // decode `.fds` input and plugin requests. The plugin protocol is vendored from
+    // protoc 36.2's include dir.
+    let plugin_file = "protos/plugin.proto";
+    println!("cargo:rerun-if-changed={plugin_file}");
+    tacky_build::write_proto_with_owned(
+        plugin_file,
+        &format!("{out_dir}/tacky_plugin_owned.rs"),
+        &["protos"],
+    );
+
+    // `.fds` input, from the bench fixture `scripts/gen_bench_fixtures.sh` writes, for
+    // comparison with what the `.proto` source generates. The registry-scale one, as it
+    // has the source info the docs come from.
+    let fds_file = "data/registry.fds";
+    println!("cargo:rerun-if-changed={fds_file}");
+    tacky_build::write_fds(
+        fds_file,
+        "simple_message.proto",
+        &format!("{out_dir}/simple_fds.rs"),
+    );
+    tacky_build::write_fds(
+        fds_file,
+        "importing.proto",
+        &format!("{out_dir}/importing_fds.rs"),
+    );
+    tacky_build::write_fds(
+        fds_file,
+        "proto3_message.proto",
+        &format!("{out_dir}/proto3_fds.rs"),
+    );
+
+    // A service with one rpc of each kind, from source and, through protoc, as a
+    // descriptor set: the two should generate the same.
+    let services_file = "protos/services.proto";
+    println!("cargo:rerun-if-changed={services_file}");
+    tacky_build::write_proto(services_file, &format!("{out_dir}/services.rs"));
+    let services_fds = format!("{out_dir}/services.fds");
+    let status = std::process::Command::new(prost_build::protoc_from_env())
+        .arg("-Iprotos")
+        .arg("--include_source_info")
+        .arg(format!("--descriptor_set_out={services_fds}"))
+        .arg("services.proto")
+        .status()
+        .unwrap();
+    assert!(status.success(), "protoc failed on {services_file}");
+    tacky_build::write_fds(
+        &services_fds,
+        "services.proto",
+        &format!("{out_dir}/services_fds.rs"),
     );
 
     // `btree_map` for the access log's headers: prost defaults a map field to `HashMap`,
@@ -116,27 +259,26 @@ fn main() {
     for f in otlp {
         println!("cargo:rerun-if-changed={f}");
     }
-    // tacky-build inlines a file's imports into the generated module, so pointing
-    // it at the collector service file yields `ExportTraceServiceRequest` and the
-    // whole `ResourceSpans` tree in one place. The include path has to be absolute:
-    // pb-rs resolves a relative one against the *importing file's* directory, C
-    // preprocessor style, which never matches a deep tree like this one.
-    let protos_root = std::fs::canonicalize("protos").unwrap();
-    tacky_build::write_proto_with_includes(
-        otlp[3],
+    // Both collector services, one module per proto package: common/resource come out
+    // once, and traces and logs share them. Imports name their files from `protos`, as
+    // protoc's `-Iprotos` would.
+    tacky_build::write_protos(
+        &[otlp[3], otlp[5]],
         &format!("{out_dir}/tacky_otlp.rs"),
-        &[protos_root.to_str().unwrap()],
+        &["protos"],
     );
-    // The logs signal, for `benches/otlp_logs.rs`. Same tree, same tag; a separate
-    // generated module because the two collector services share only common/resource.
-    tacky_build::write_proto_with_includes(
-        otlp[5],
-        &format!("{out_dir}/tacky_otlp_logs.rs"),
-        &[protos_root.to_str().unwrap()],
+    // Traces again, as a crate would that gets common from the one above instead of
+    // generating it over again.
+    tacky_build::write_protos_with_extern(
+        &[otlp[3]],
+        &format!("{out_dir}/tacky_otlp_extern.rs"),
+        &["protos"],
+        &[(
+            ".opentelemetry.proto.common",
+            "crate::packages::tacky_otlp::opentelemetry::proto::common",
+        )],
     );
-    // prost spreads one module per proto package and cross-references them with
-    // `super::super::`, so ask for the module tree in a single includable file
-    // instead of stitching it together by hand in the bench.
+    // prost lays out the same module tree; ask for it in a single includable file too.
     prost_build::Config::new()
         .include_file("otlp.rs")
         .compile_protos(&otlp, &["protos"])
@@ -179,40 +321,6 @@ fn main() {
     );
 }
 
-/// Writes a copy of `src` into `OUT_DIR` with proto2 `[default = ...]` field
-/// options removed, and returns its path.
-///
-/// tacky-build rejects custom defaults outright, and they make no difference to
-/// what this bench measures: a default only decides what a *reader* sees for an
-/// absent field, so every byte on the wire is unchanged. Keeping the vendored
-/// proto pristine matters more than avoiding the copy — the file is upstream's.
-///
-/// Only handles a `[default = ...]` that is the whole option list. A field
-/// carrying another option alongside it would need real option parsing.
-fn strip_custom_defaults(src: &str, out_dir: &str) -> String {
-    let text = std::fs::read_to_string(src).unwrap();
-    let mut out = String::with_capacity(text.len());
-    for line in text.lines() {
-        match (line.find("[default ="), line.rfind(']')) {
-            (Some(open), Some(close)) if close > open => {
-                out.push_str(&line[..open]);
-                out.push_str(&line[close + 1..]);
-            }
-            _ => out.push_str(line),
-        }
-        out.push('\n');
-    }
-
-    let name = std::path::Path::new(src)
-        .file_name()
-        .unwrap()
-        .to_str()
-        .unwrap();
-    let dst = format!("{out_dir}/{name}");
-    std::fs::write(&dst, out).unwrap();
-    dst
-}
-
 /// Writes an edition-2023 copy of a proto3 file into `OUT_DIR/noutf8/`, mirroring
 /// its path under `protos/`, with UTF-8 validation switched off. Returns the
 /// copy's path relative to `OUT_DIR`.
@@ -0,0 +1,285 @@
+//! `tacky_build::breaking`: what counts as breaking the wire between two versions of a
+//! schema, and what doesn't.
+
+#[cfg(test)]
+mod tests {
+    use crate::scratch;
+    use std::path::Path;
+    use tacky_build::breaking::{check, read_schema, Breaking, Change};
+
+    fn compare(old: &Path, new: &Path) -> Vec<Breaking> {
+        check(
+            &read_schema(old, &[]).unwrap(),
+            &read_schema(new, &[]).unwrap(),
+        )
+    }
+
+    const OLD: &str = r#"syntax = "proto3";
+package shop;
+
+message Order {
+    int64 id = 1;
+    string note = 2;
+    repeated string tags = 3;
+    int32 count = 4;
+    bytes blob = 5;
+    Item item = 6;
+    oneof payment {
+        string card = 7;
+        string cash = 8;
+    }
+    uint32 legacy = 9;
+    sint32 delta = 10;
+    int32 single = 11;
+    int32 lone = 12;
+}
+
+message Item {
+    string sku = 1;
+    reserved 3;
+}
+
+enum Status {
+    STATUS_UNKNOWN = 0;
+    STATUS_OPEN = 1;
+    STATUS_CLOSED = 2;
+    STATUS_GONE = 3;
+}
+"#;
+
+    #[test]
+    fn test_compatible_changes() {
+        let old = scratch::tree("breaking/compatible_old", &[("shop.proto", OLD)]);
+        let new = scratch::tree(
+            "breaking/compatible_new",
+            &[(
+                "shop.proto",
+                r#"syntax = "proto3";
+package shop;
+
+// Renamed, retyped within an encoding, reordered, added to: none of it shows on the wire.
+message Order {
+    reserved 9, 12;
+    uint64 order_id = 1;
+    bytes note = 2;
+    repeated string labels = 3;
+    uint64 count = 4;
+    string blob = 5;
+    Item item = 6;
+    oneof payment {
+        string card = 7;
+        string cash = 8;
+        string voucher = 13;
+    }
+    sint64 delta = 10;
+    oneof single_choice {
+        int32 single = 11;
+        int32 other = 14;
+    }
+}
+
+message Item {
+    string sku = 1;
+    int32 quantity = 2;
+}
+
+enum Status {
+    reserved 3;
+    STATUS_UNKNOWN = 0;
+    STATUS_OPEN = 1;
+    STATUS_DONE = 2;
+}
+"#,
+            )],
+        );
+        let breaking = compare(&old, &new);
+        assert!(breaking.is_empty(), "{breaking:#?}");
+    }
+
+    #[test]
+    fn test_breaking_changes() {
+        let old = scratch::tree("breaking/breaking_old", &[("shop.proto", OLD)]);
+        let new = scratch::tree(
+            "breaking/breaking_new",
+            &[(
+                "shop.proto",
+                r#"syntax = "proto3";
+package shop;
+
+message Order {
+    int64 id = 1;
+    int32 note = 2;
+    string tags = 3;
+    fixed32 count = 4;
+    Item blob = 5;
+    oneof payment {
+        string card = 7;
+    }
+    string cash = 8;
+    uint32 legacy = 9;
+    int32 delta = 10;
+    oneof either {
+        int32 single = 11;
+        int32 lone = 12;
+    }
+}
+
+message Item {
+    string sku = 1;
+}
+
+enum Status {
+    STATUS_UNKNOWN = 0;
+    STATUS_OPEN = 1;
+    STATUS_GONE = 3;
+}
+"#,
+            )],
+        );
+        let breaking = compare(&old, &new);
+        let found: Vec<_> = breaking
+            .iter()
+            .map(|b| (b.change, &b.message[..]))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    Change::FieldType,
+                    "field `note` = 2 changed type from `string` to `int32`"
+                ),
+                (Change::FieldLabel, "field `tags` = 3 is now singular"),
+                (
+                    Change::FieldType,
+                    "field `count` = 4 changed type from `int32` to `fixed32`"
+                ),
+                (
+                    Change::FieldType,
+                    "field `blob` = 5 changed type from `bytes` to `shop.Item`"
+                ),
+                (
+                    Change::FieldDeleted,
+                    "field `item` = 6 of `Order` was deleted without reserving 6"
+                ),
+                (Change::Oneof, "field `cash` = 8 moved out of oneof `payment`"),
+                (
+                    Change::FieldType,
+                    "field `delta` = 10 changed type from `sint32` to `int32`"
+                ),
+                (
+                    Change::Oneof,
+                    "field `single` = 11 moved into oneof `either`, with `lone`, which it wasn't exclusive with"
+                ),
+                (
+                    Change::Oneof,
+                    "field `lone` = 12 moved into oneof `either`, with `single`, which it wasn't exclusive with"
+                ),
+                (
+                    Change::EnumValueDeleted,
+                    "value `STATUS_CLOSED` = 2 of `Status` was deleted without reserving 2"
+                ),
+            ]
+        );
+        // Deleted things are shown where they were; the rest where they are now.
+        let shown = breaking[4].to_string();
+        assert!(shown.contains("breaking_old/shop.proto:10:5:"), "{shown}");
+        assert!(shown.contains("10 |     Item item = 6;"), "{shown}");
+        assert!(breaking[0]
+            .to_string()
+            .contains("breaking_new/shop.proto:6:5:"));
+    }
+
+    #[test]
+    fn test_packed_and_required() {
+        let old = scratch::tree(
+            "breaking/labels_old",
+            &[(
+                "labels.proto",
+                "syntax = \"proto2\";\nmessage Sample {\n    repeated int32 ids = 1;\n    repeated int32 packed = 2 [packed = true];\n    optional int32 id = 3;\n}\n",
+            )],
+        );
+        let new = scratch::tree(
+            "breaking/labels_new",
+            &[(
+                "labels.proto",
+                "syntax = \"proto2\";\nmessage Sample {\n    repeated int32 ids = 1 [packed = true];\n    repeated int32 packed = 2;\n    required int32 id = 3;\n}\n",
+            )],
+        );
+        let breaking = compare(&old, &new);
+        let found: Vec<_> = breaking
+            .iter()
+            .map(|b| (b.change, &b.message[..]))
+            .collect();
+        // Packed fields still read unpacked ones, but not the other way round.
+        assert_eq!(
+            found,
+            [
+                (
+                    Change::Packed,
+                    "field `ids` = 1 is now packed, which readers of the old schema reject"
+                ),
+                (Change::FieldLabel, "field `id` = 3 is now required"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_moved_across_packages() {
+        let old = scratch::tree(
+            "breaking/moved_old",
+            &[(
+                "api/user.proto",
+                "syntax = \"proto3\";\npackage api;\nmessage User {\n    Profile profile = 1;\n    message Profile {\n        string name = 1;\n    }\n}\nmessage Team {\n    User lead = 1;\n}\n",
+            )],
+        );
+        let new = scratch::tree(
+            "breaking/moved_new",
+            &[
+                (
+                    "api/team.proto",
+                    "syntax = \"proto3\";\npackage api;\nimport \"accounts/user.proto\";\nmessage Team {\n    accounts.User lead = 1;\n}\n",
+                ),
+                (
+                    "accounts/user.proto",
+                    "syntax = \"proto3\";\npackage accounts;\nmessage User {\n    Profile profile = 1;\n    message Profile {\n        int32 name = 1;\n    }\n}\n",
+                ),
+            ],
+        );
+        let breaking = compare(&old, &new);
+        let found: Vec<_> = breaking
+            .iter()
+            .map(|b| (b.change, &b.message[..]))
+            .collect();
+        // Reported once, and followed: the fields of a moved message are still compared,
+        // and a field of its type is still the same type.
+        assert_eq!(
+            found,
+            [
+                (
+                    Change::Moved,
+                    "message `User` moved from package `api` to `accounts`"
+                ),
+                (
+                    Change::FieldType,
+                    "field `name` = 1 changed type from `string` to `int32`"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_schema_files() {
+        let root = scratch::tree(
+            "breaking/read_files",
+            &[
+                ("a.proto", "syntax = \"proto3\";\nmessage A {}\n"),
+                ("b.proto", "syntax = \"proto3\";\nmessage B {}\n"),
+            ],
+        );
+        let descs = read_schema(&root, &[Path::new("b.proto")]).unwrap();
+        assert_eq!(descs.len(), 1);
+        assert_eq!(descs[0].messages[0].name, "B");
+        assert_eq!(read_schema(&root, &[]).unwrap().len(), 2);
+        assert!(read_schema(&root, &[Path::new("c.proto")]).is_err());
+    }
+}
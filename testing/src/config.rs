@@ -0,0 +1,448 @@
+//! `tacky_build::Config`: what it generates, and how `compile` fails.
+
+/// The runtime the `Config` output is pointed at, rather than `::tacky`.
+mod runtime {
+    pub use tacky::*;
+}
+
+#[allow(dead_code)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/config.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generated::proto3test::{
+        RepeatedMessage, RepeatedMessageField, ScalarMessage, Status, WithEnum, WithEnumField,
+        WithEnumOwned, WithOneof, WithOneofField, WithOneofOwned, WithOneofPayloadOwned,
+    };
+    use crate::scratch;
+    use std::collections::HashSet;
+    use std::time::{Duration, SystemTime};
+    use tacky_build::error::ErrorKind;
+    use tacky_build::lint::{Level, Lint, Style};
+    use tacky_build::{expand_packages, Config, Error, PackageOptions};
+
+    const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/config.rs"));
+
+    #[test]
+    fn test_config_runtime_path() {
+        assert!(GENERATED.contains("use crate::config::runtime as tacky;"));
+        assert!(!GENERATED.contains("use ::tacky"));
+        assert!(!GENERATED.contains(" ::tacky::"));
+
+        let mut buf = Vec::new();
+        let s = WithEnum::schema();
+        WithEnum {
+            status: s.status.write(&mut buf, Status::Active),
+            history: s.history.write(&mut buf, &[Status::Inactive]),
+        };
+        let owned = WithEnumOwned::from_bytes(&buf).unwrap();
+        assert_eq!(owned.status, Status::Active);
+        assert_eq!(owned.history, [Status::Inactive]);
+        let fields: Vec<_> = WithEnum::decode(&buf).map(Result::unwrap).collect();
+        assert!(matches!(fields[0], WithEnumField::Status(Status::Active)));
+    }
+
+    #[test]
+    fn test_config_attributes() {
+        // `enum_attribute(".proto3test", "#[derive(Hash)]")`
+        let seen: HashSet<Status> = [Status::Active, Status::Active, Status::Unknown].into();
+        assert_eq!(seen.len(), 2);
+        // `type_attribute(".proto3test.ScalarMessage", "#[derive(PartialEq, Eq)]")`
+        assert_eq!(ScalarMessage::schema(), ScalarMessage::schema());
+        assert_eq!(GENERATED.matches("derive(PartialEq, Eq)").count(), 1);
+        // `field_attribute(".proto3test.Nested.label", ...)`
+        assert!(GENERATED.contains("What the nested message is called."));
+    }
+
+    #[test]
+    fn test_config_string_decoding() {
+        // `WithOneof` is `Lazy`, but its `id` `Unchecked`: the last match wins.
+        let latin1 = b"caf\xe9";
+        let mut buf = Vec::new();
+        let s = WithOneof::schema();
+        s.id.write(&mut buf, latin1);
+        s.payload.write_text(&mut buf, latin1);
+        let fields: Vec<_> = WithOneof::decode(&buf).map(Result::unwrap).collect();
+        let [WithOneofField::Id(id), WithOneofField::Text(text)] = fields[..] else {
+            panic!("{fields:?}");
+        };
+        assert_eq!(id, latin1);
+        assert_eq!(text.as_bytes(), latin1);
+        assert!(text.as_str().is_err());
+
+        // Unchecked, the owned copy can only keep bytes.
+        let owned = WithOneofOwned::from_bytes(&buf).unwrap();
+        assert_eq!(
+            owned.payload,
+            Some(WithOneofPayloadOwned::Text(latin1.to_vec()))
+        );
+        let mut encoded = Vec::new();
+        owned.encode(&mut encoded);
+        assert_eq!(encoded, buf);
+
+        let mut buf = Vec::new();
+        RepeatedMessage::schema()
+            .strings
+            .write(&mut buf, ["fine", "too"]);
+        let strings: Vec<_> = RepeatedMessage::decode(&buf)
+            .map(|f| match f.unwrap() {
+                RepeatedMessageField::Strings(s) => s.as_str().unwrap(),
+                f => panic!("{f:?}"),
+            })
+            .collect();
+        assert_eq!(strings, ["fine", "too"]);
+    }
+
+    #[test]
+    fn test_config_unchanged_output_not_rewritten() {
+        let dir = scratch::dir("config_unchanged");
+        let mut config = Config::new();
+        config
+            .file("protos/proto3_message.proto")
+            .out_dir(&dir)
+            .emit_rerun_if_changed(false);
+        config.compile().unwrap();
+
+        let output = dir.join("protos.rs");
+        let long_ago = SystemTime::UNIX_EPOCH + Duration::from_secs(1);
+        let file = std::fs::File::options().write(true).open(&output).unwrap();
+        file.set_modified(long_ago).unwrap();
+        drop(file);
+        config.compile().unwrap();
+        let modified = std::fs::metadata(&output).unwrap().modified().unwrap();
+        assert_eq!(modified, long_ago);
+
+        config.owned(true).compile().unwrap();
+        let modified = std::fs::metadata(&output).unwrap().modified().unwrap();
+        assert_ne!(modified, long_ago);
+    }
+
+    #[test]
+    fn test_config_errors() {
+        let dir = scratch::dir("config_errors");
+        let compile = |f: &dyn Fn(&mut Config)| {
+            let mut config = Config::new();
+            config.out_dir(&dir).emit_rerun_if_changed(false);
+            f(&mut config);
+            config.compile().unwrap_err()
+        };
+
+        let err = compile(&|c| {
+            c.file("protos/missing.proto");
+        });
+        assert!(
+            matches!(&err, Error::Codegen(e) if matches!(e.kind(), ErrorKind::Read(_))),
+            "{err}"
+        );
+
+        let err = compile(&|c| {
+            c.file("protos/proto3_message.proto").include("no/such/dir");
+        });
+        assert!(matches!(err, Error::Io { .. }), "{err}");
+
+        let err = compile(&|c| {
+            c.file("protos/proto3_message.proto")
+                .extern_path("proto3test", "::elsewhere");
+        });
+        assert!(matches!(err, Error::ExternPath(_)), "{err}");
+
+        let err = compile(&|c| {
+            c.file("protos/proto3_message.proto")
+                .type_attribute(".", "derive(Hash)");
+        });
+        let Error::Codegen(e) = err else {
+            panic!("{err}");
+        };
+        assert!(
+            matches!(e.kind(), ErrorKind::InvalidAttribute { .. }),
+            "{e}"
+        );
+        assert_eq!(e.to_string(), "invalid attribute `derive(Hash)` for `.`");
+
+        let err = compile(&|c| {
+            c.file("protos/proto3_message.proto")
+                .runtime_path("crate::");
+        });
+        assert!(
+            matches!(&err, Error::Codegen(e) if matches!(e.kind(), ErrorKind::InvalidPath(_))),
+            "{err}"
+        );
+    }
+
+    /// Compiles `source` as `name` in a scratch directory, expecting it to fail.
+    fn compile_err(name: &str, source: &str) -> tacky_build::error::Error {
+        let dir = scratch::dir("config_diagnostics");
+        let file = dir.join(name);
+        std::fs::write(&file, source).unwrap();
+        let err = Config::new()
+            .file(&file)
+            .include(&dir)
+            .out_dir(&dir)
+            .emit_rerun_if_changed(false)
+            .compile()
+            .unwrap_err();
+        let Error::Codegen(e) = err else {
+            panic!("{err}");
+        };
+        e
+    }
+
+    #[test]
+    fn test_config_diagnostics() {
+        let e = compile_err(
+            "unknown_type.proto",
+            "syntax = \"proto3\";\n\nmessage Foo {\n    Bar bar = 1;\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::UnresolvedType(name) if name == "Bar"));
+        let span = e.span().unwrap();
+        assert!(span.file.ends_with("unknown_type.proto"));
+        assert_eq!((span.line, span.column), (4, 5));
+        let shown = e.to_string();
+        let (first, snippet) = shown.split_once('\n').unwrap();
+        assert!(
+            first.ends_with("unknown_type.proto:4:5: unknown type Bar"),
+            "{first}"
+        );
+        assert_eq!(snippet, "  |\n4 |     Bar bar = 1;\n  |     ^");
+
+        let e = compile_err(
+            "float_key.proto",
+            "syntax = \"proto3\";\nmessage Foo {\n  map<float, string> by_weight = 1;\n}\n",
+        );
+        let ErrorKind::Lint(violations) = e.kind() else {
+            panic!("{e}");
+        };
+        assert_eq!(violations[0].lint, Lint::MapKey);
+        assert_eq!(violations[0].span.line, 3);
+
+        let e = compile_err(
+            "enum_values.proto",
+            "syntax = \"proto3\";\nenum Kind { A = 0; }\nmessage Foo {\n  map<string, Kind> kinds = 1;\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::Unsupported(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(4));
+
+        let e = compile_err(
+            "syntax_error.proto",
+            "syntax = \"proto3\";\nmessage Ok {}\nmessage Foo {\n  int32 a = ;\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::Parse(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(3));
+
+        let e = compile_err(
+            "alias.proto",
+            "syntax = \"proto3\";\nenum Kind {\n  A = 0;\n  B = 0;\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::InvalidEnum(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(2));
+
+        let e = compile_err(
+            "bad_default.proto",
+            "syntax = \"proto2\";\nmessage Foo {\n  optional int32 a = 1 [default = abc];\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::InvalidDefault(_)), "{e}");
+        let first = e.to_string().lines().next().unwrap().to_string();
+        assert!(
+            first.ends_with(
+                "bad_default.proto:3:3: invalid default: `abc` for field `a` of type int32"
+            ),
+            "{first}"
+        );
+
+        let e = compile_err(
+            "variants.proto",
+            "syntax = \"proto3\";\nmessage Foo {\n  int32 foo_bar = 1;\n  int32 fooBar = 2;\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::Collision(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(4));
+
+        let e = compile_err(
+            "handlers.proto",
+            "syntax = \"proto3\";\nmessage M {}\nservice S {\n  rpc GetFoo(M) returns (M);\n  rpc get_foo(M) returns (M);\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::Collision(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(5));
+        let first = e.to_string().lines().next().unwrap().to_string();
+        assert!(
+            first.ends_with(
+                "handlers.proto:5:3: name collision: \
+                 rpcs `GetFoo` and `get_foo` of `S` both make the handler `get_foo`"
+            ),
+            "{first}"
+        );
+
+        // Inherited, as in `editions.rs`, it is left be.
+        let e = compile_err(
+            "implicit_message.proto",
+            "edition = \"2023\";\nmessage Foo {\n  Foo next = 1 [features.field_presence = IMPLICIT];\n}\n",
+        );
+        let first = e.to_string().lines().next().unwrap().to_string();
+        assert!(
+            first.ends_with(
+                "implicit_message.proto:3:3: \
+                 invalid feature: message field `next` can't have implicit presence"
+            ),
+            "{first}"
+        );
+
+        // `Foo`'s own `Field` enum is `FooField`.
+        let e = compile_err(
+            "companion.proto",
+            "syntax = \"proto3\";\nmessage Foo {}\nmessage FooField {}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::Collision(_)), "{e}");
+        assert!(e.to_string().contains("`FooField`"), "{e}");
+
+        let e = compile_err(
+            "shadowing.proto",
+            "syntax = \"proto3\";\npackage shadowing;\nmessage tacky {}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::Collision(_)), "{e}");
+        assert!(e.to_string().contains("`tacky`"), "{e}");
+    }
+
+    /// Protos codegen used to panic on come back from `compile` as errors.
+    #[test]
+    fn test_config_malformed_protos() {
+        let e = compile_err(
+            "group.proto",
+            "syntax = \"proto2\";\nmessage Foo {\n  optional group Old = 1 {\n    optional int32 a = 2;\n  }\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::Parse(_)), "{e}");
+
+        let e = compile_err(
+            "oneof_map.proto",
+            "syntax = \"proto3\";\nmessage Foo {\n  oneof o {\n    map<string, int32> a = 1;\n  }\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::InvalidMap(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(4));
+
+        let e = compile_err(
+            "map_of_maps.proto",
+            "syntax = \"proto3\";\nmessage Foo {\n  map<string, map<string, int32>> a = 1;\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::InvalidMap(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(3));
+
+        let e = compile_err(
+            "float_default.proto",
+            "syntax = \"proto2\";\nmessage Foo {\n  optional float f = 1 [default = x1];\n}\n",
+        );
+        assert!(matches!(e.kind(), ErrorKind::InvalidDefault(_)), "{e}");
+        assert_eq!(e.span().map(|s| s.line), Some(3));
+
+        let e = compile_err(
+            "enum_default.proto",
+            "syntax = \"proto2\";\nenum E { A = 0; }\nmessage Foo {\n  optional E e = 1 [default = Z];\n}\n",
+        );
+        assert!(e.to_string().contains("'Foo.e = Z'"), "{e}");
+    }
+
+    #[test]
+    fn test_config_lints() {
+        let e = compile_err(
+            "lints.proto",
+            r#"syntax = "proto3";
+message Foo {
+  reserved 2, 8;
+  reserved "old";
+  int32 old = 1;
+  int32 two = 2;
+  int32 internal = 19001;
+  int32 huge = 536870912;
+  oneof choice {
+    string name = 5;
+    string title = 5;
+  }
+  map<bytes, string> by_hash = 6;
+}
+enum Kind {
+  KIND_ONE = 1;
+}
+"#,
+        );
+        let ErrorKind::Lint(violations) = e.kind() else {
+            panic!("{e}");
+        };
+        let found: Vec<_> = violations.iter().map(|v| (v.lint, v.span.line)).collect();
+        assert_eq!(
+            found,
+            [
+                (Lint::ReservedConflict, 5),
+                (Lint::ReservedConflict, 6),
+                (Lint::ImplementationReserved, 7),
+                (Lint::NumberOutOfRange, 8),
+                (Lint::DuplicateNumber, 11),
+                (Lint::MapKey, 13),
+                (Lint::EnumZero, 16),
+            ]
+        );
+        // All of them, each where it is.
+        let shown = e.to_string();
+        assert_eq!(shown.matches("lints.proto:").count(), 7, "{shown}");
+        assert!(shown.contains("11 |     string title = 5;"), "{shown}");
+        assert!(shown.contains("field `title` has number 5, as `name` does"));
+        assert_eq!(
+            violations[5].message,
+            "map `by_hash` has bytes keys: only integral types, `bool` and `string` can be map keys"
+        );
+    }
+
+    #[test]
+    fn test_style_lints() {
+        let dir = scratch::dir("style_lints");
+        let file = dir.join("style.proto");
+        std::fs::write(
+            &file,
+            "syntax = \"proto3\";\nmessage user_info {\n  int32 userId = 1;\n}\nenum Kind {\n  kind_unknown = 0;\n}\n",
+        )
+        .unwrap();
+        let dir = dir.to_str().unwrap();
+        let expand = |style_lints| {
+            let options = PackageOptions {
+                style_lints,
+                ..Default::default()
+            };
+            expand_packages(&[&file], &[dir], &options)
+        };
+
+        // Off unless asked for.
+        assert!(expand(vec![]).unwrap().warnings.is_empty());
+
+        let expansion = expand(vec![
+            (Style::FieldName, Level::Warn),
+            (Style::EnumValueName, Level::Warn),
+        ])
+        .unwrap();
+        let warnings: Vec<_> = expansion.warnings.iter().map(|w| &w.message).collect();
+        assert_eq!(
+            warnings,
+            [
+                "field `userId` should be snake_case: `user_id`",
+                "enum value `kind_unknown` should be UPPER_SNAKE_CASE: `KIND_UNKNOWN`",
+            ]
+        );
+
+        // The last level given wins.
+        let Err(e) = expand(vec![
+            (Style::TypeName, Level::Warn),
+            (Style::TypeName, Level::Deny),
+        ]) else {
+            panic!("denied, but generated");
+        };
+        let ErrorKind::Lint(violations) = e.kind() else {
+            panic!("{e}");
+        };
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].lint, Lint::Style(Style::TypeName));
+        let message = "message `user_info` should be UpperCamelCase: `UserInfo`";
+        assert_eq!(violations[0].message, message);
+        assert!(
+            e.to_string()
+                .contains(&format!("style.proto:2:1: {message}")),
+            "{e}"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+//! Proto2 custom defaults: typed constants on the schema, and the `_or_default` helpers
+//! that fill in whatever the wire left out.
+
+mod tacky_defaults {
+    include!(concat!(env!("OUT_DIR"), "/defaults.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tacky_defaults::defaults::*;
+
+    #[test]
+    fn test_default_constants() {
+        assert_eq!(Settings::DEFAULT_TIMEOUT, 30u32);
+        assert_eq!(Settings::DEFAULT_OFFSET, -16i32);
+        assert_eq!(Settings::DEFAULT_MASK, 0o777i64);
+        assert_eq!(Settings::DEFAULT_ENABLED, true);
+        assert_eq!(Settings::DEFAULT_RATIO, -2.5f64);
+        assert_eq!(Settings::DEFAULT_CEILING, f32::INFINITY);
+        assert_eq!(Settings::DEFAULT_FLOOR, f32::NEG_INFINITY);
+        assert!(Settings::DEFAULT_MISSING.is_nan());
+        assert_eq!(Settings::DEFAULT_GREETING, "hi \"there\"\n\u{e9}");
+        assert_eq!(Settings::DEFAULT_MAGIC, b"\x00\x01\xffab\\");
+        assert_eq!(Settings::DEFAULT_LEVEL, Level::High);
+    }
+
+    #[test]
+    fn test_value_or_default() {
+        let mut buf = Vec::new();
+        let schema = Settings::schema();
+        schema.timeout.write(&mut buf, Some(5));
+        schema.level.write(&mut buf, Some(Level::Low));
+
+        let mut timeout = None;
+        let mut greeting = None;
+        let mut level = None;
+        for field in Settings::decode(&buf) {
+            match field.unwrap() {
+                SettingsField::Timeout(v) => timeout = Some(v),
+                SettingsField::Greeting(v) => greeting = Some(v),
+                SettingsField::Level(v) => level = Some(v),
+                _ => {}
+            }
+        }
+        assert_eq!(Settings::timeout_or_default(timeout), 5);
+        assert_eq!(
+            Settings::greeting_or_default(greeting),
+            "hi \"there\"\n\u{e9}"
+        );
+        assert_eq!(Settings::level_or_default(level), Level::Low);
+        assert_eq!(Settings::level_or_default(None), Level::High);
+    }
+}
@@ -0,0 +1,443 @@
+//! `#[derive(TackyEncode)]` and `#[derive(TackyDecode)]`: domain structs written through
+//! the example schemas and read back from their `Fields` iterators, checked against prost.
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use std::collections::{BTreeMap, HashMap};
+    use std::collections::{BTreeSet, HashSet};
+    use tacky::{AnyDir, DecodeError, TackyDecode, TackyEncode, WriteBuf};
+
+    use crate::prost_proto;
+    use crate::tacky_proto::example::{self, ApiResponseField, ApiResponseResult, SimpleEnum};
+
+    #[derive(TackyEncode, TackyDecode, Debug, PartialEq)]
+    #[tacky(schema = example::MixedSmallMessage)]
+    struct Small {
+        #[tacky(rename = "label")]
+        name: String,
+        count: Option<i32>,
+        active: bool,
+        #[tacky(skip)]
+        _cached_len: usize,
+    }
+
+    fn small(name: &str, count: Option<i32>) -> Small {
+        Small {
+            name: name.into(),
+            count,
+            active: true,
+            _cached_len: 0,
+        }
+    }
+
+    /// Writes nothing: for schema fields this domain type has no counterpart for.
+    mod absent {
+        use tacky::{AnyDir, WriteBuf};
+        pub fn encode<T, B: WriteBuf, F>(_: &T, _: &mut AnyDir<B>, field: F) -> F {
+            field
+        }
+    }
+
+    mod unix_millis {
+        use std::time::{Duration, SystemTime};
+        use tacky::{AnyDir, Field, Int64, Optional, WriteBuf};
+        pub fn encode<B: WriteBuf>(
+            t: &SystemTime,
+            buf: &mut AnyDir<B>,
+            field: Field<8, Optional<Int64>>,
+        ) -> Field<8, Optional<Int64>> {
+            let millis = t
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or(Duration::ZERO);
+            field.write(buf, Some(millis.as_millis() as i64))
+        }
+    }
+
+    #[derive(TackyEncode)]
+    #[tacky(schema = example::MixedUsageMessage)]
+    struct Session {
+        session_id: String,
+        user_id: i32,
+        client_version: Option<&'static str>,
+        #[tacky(msg)]
+        small_payload: Option<Small>,
+        #[tacky(with = absent)]
+        large_payload: (),
+        #[tacky(msg)]
+        history: Vec<Small>,
+        related_ids: Vec<String>,
+        #[tacky(with = unix_millis)]
+        created_at: std::time::SystemTime,
+        #[tacky(with = absent)]
+        updated_at: (),
+        priority: f64,
+        is_test: bool,
+        status: SimpleEnum,
+    }
+
+    fn session() -> Session {
+        Session {
+            session_id: "s-1".into(),
+            user_id: 42,
+            client_version: None,
+            small_payload: Some(small("payload", Some(3))),
+            large_payload: (),
+            history: vec![small("a", None), small("b", Some(2))],
+            related_ids: vec!["x".into(), "y".into()],
+            created_at: std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(1500),
+            updated_at: (),
+            priority: 0.5,
+            is_test: false,
+            status: SimpleEnum::Second,
+        }
+    }
+
+    fn check_session(decoded: prost_proto::MixedUsageMessage) {
+        assert_eq!(decoded.session_id.as_deref(), Some("s-1"));
+        assert_eq!(decoded.user_id, Some(42));
+        assert_eq!(decoded.client_version, None);
+        let payload = decoded.small_payload.unwrap();
+        assert_eq!(payload.label.as_deref(), Some("payload"));
+        assert_eq!(payload.count, Some(3));
+        assert_eq!(payload.active, Some(true));
+        assert!(decoded.large_payload.is_none());
+        let history: Vec<_> = decoded.history.iter().map(|h| h.label.as_deref()).collect();
+        assert_eq!(history, [Some("a"), Some("b")]);
+        assert_eq!(decoded.history[0].count, None);
+        assert_eq!(decoded.related_ids, ["x", "y"]);
+        assert_eq!(decoded.created_at, Some(1500));
+        assert_eq!(decoded.updated_at, None);
+        assert_eq!(decoded.priority, Some(0.5));
+        assert_eq!(decoded.is_test, Some(false));
+        assert_eq!(decoded.status, Some(prost_proto::SimpleEnum::Second.into()));
+    }
+
+    #[test]
+    fn test_derive_encode() {
+        let mut buf = Vec::new();
+        session().encode(&mut buf);
+        check_session(prost_proto::MixedUsageMessage::decode(&*buf).unwrap());
+    }
+
+    /// The same impl through a `RevBuf`: repeated fields still come out in list order.
+    #[test]
+    fn test_derive_encode_rev_buf() {
+        let mut backing = [0u8; 256];
+        let mut rb = tacky::RevBuf::new(&mut backing);
+        session().encode(&mut rb);
+        check_session(prost_proto::MixedUsageMessage::decode(rb.written()).unwrap());
+    }
+
+    #[derive(TackyEncode, TackyDecode, Debug, PartialEq)]
+    #[tacky(schema = example::MsgWithMaps)]
+    struct Maps {
+        map1: BTreeMap<String, i32>,
+        map2: HashMap<i32, f64>,
+    }
+
+    enum Outcome {
+        Failed(String),
+        Code(i32),
+    }
+
+    /// A oneof has no single value to write, so its field is always a `with`.
+    mod outcome {
+        use super::{ApiResponseResult, Outcome};
+        use tacky::{AnyDir, WriteBuf};
+
+        pub fn encode<B: WriteBuf>(
+            o: &Outcome,
+            buf: &mut AnyDir<B>,
+            marker: ApiResponseResult,
+        ) -> ApiResponseResult {
+            match o {
+                Outcome::Failed(e) => marker.write_error(buf, e),
+                Outcome::Code(c) => marker.write_code(buf, c),
+            }
+        }
+    }
+
+    #[derive(TackyEncode)]
+    #[tacky(schema = example::ApiResponse)]
+    struct Response<'a> {
+        request_id: &'a str,
+        #[tacky(with = outcome)]
+        result: Outcome,
+        cached: Option<bool>,
+    }
+
+    #[test]
+    fn test_derive_maps_and_oneof() {
+        let maps = Maps {
+            map1: BTreeMap::from_iter([("one".into(), 1), ("two".into(), 2)]),
+            map2: HashMap::from_iter([(7, 0.25)]),
+        };
+        let mut buf = Vec::new();
+        maps.encode(&mut buf);
+        let decoded = prost_proto::MsgWithMaps::decode(&*buf).unwrap();
+        assert_eq!(decoded.map1.len(), 2);
+        assert_eq!(decoded.map1["two"], 2);
+        assert_eq!(decoded.map2[&7], 0.25);
+
+        let response = Response {
+            request_id: "r",
+            result: Outcome::Failed("nope".into()),
+            cached: None,
+        };
+        let mut buf = Vec::new();
+        response.encode(&mut buf);
+        let decoded = prost_proto::ApiResponse::decode(&*buf).unwrap();
+        assert_eq!(decoded.request_id.as_deref(), Some("r"));
+        assert_eq!(
+            decoded.result,
+            Some(prost_proto::api_response::Result::Error("nope".into()))
+        );
+        assert_eq!(decoded.cached, None);
+
+        let response = Response {
+            result: Outcome::Code(404),
+            ..response
+        };
+        let mut buf = Vec::new();
+        response.encode(&mut buf);
+        let decoded = prost_proto::ApiResponse::decode(&*buf).unwrap();
+        assert_eq!(
+            decoded.result,
+            Some(prost_proto::api_response::Result::Code(404))
+        );
+    }
+
+    /// `write_fields` composes: one derived type written as a nested message by hand.
+    #[test]
+    fn test_derive_nested_by_hand() {
+        fn write<B: WriteBuf>(buf: &mut AnyDir<B>, s: &Small) {
+            example::MixedUsageMessage::schema()
+                .small_payload
+                .write_msg(buf, |buf, schema| s.write_fields(buf, schema));
+        }
+        let mut buf = Vec::new();
+        write(AnyDir::from_mut(&mut buf), &small("n", Some(1)));
+        let decoded = prost_proto::MixedUsageMessage::decode(&*buf).unwrap();
+        assert_eq!(decoded.small_payload.unwrap().label.as_deref(), Some("n"));
+    }
+
+    #[derive(TackyDecode, Debug)]
+    #[tacky(schema = example::MixedSmallMessage)]
+    struct SmallView<'a> {
+        label: &'a str,
+        count: i32,
+    }
+
+    /// Zero-copy: every string borrows from the buffer. `large_payload` and the rest of
+    /// the schema go unmapped, and are skipped.
+    #[derive(TackyDecode, Debug)]
+    #[tacky(schema = example::MixedUsageMessage)]
+    struct SessionView<'a> {
+        session_id: &'a str,
+        user_id: i32,
+        client_version: Option<&'a str>,
+        #[tacky(msg)]
+        small_payload: Option<SmallView<'a>>,
+        #[tacky(msg)]
+        history: Vec<SmallView<'a>>,
+        related_ids: Vec<&'a str>,
+        status: SimpleEnum,
+        #[tacky(skip)]
+        seen: u32,
+    }
+
+    #[test]
+    fn test_derive_decode_borrowed() {
+        let mut buf = Vec::new();
+        session().encode(&mut buf);
+        let view = SessionView::decode(&buf).unwrap();
+        assert_eq!(view.session_id, "s-1");
+        assert_eq!(view.user_id, 42);
+        assert_eq!(view.client_version, None);
+        let payload = view.small_payload.unwrap();
+        assert_eq!((payload.label, payload.count), ("payload", 3));
+        let history: Vec<_> = view.history.iter().map(|h| (h.label, h.count)).collect();
+        assert_eq!(history, [("a", 0), ("b", 2)]);
+        assert_eq!(view.related_ids, ["x", "y"]);
+        assert_eq!(view.status, SimpleEnum::Second);
+        assert_eq!(view.seen, 0);
+    }
+
+    #[test]
+    fn test_derive_round_trip() {
+        let original = small("round", Some(9));
+        let mut buf = Vec::new();
+        original.encode(&mut buf);
+        assert_eq!(Small::decode(&buf).unwrap(), original);
+
+        let maps = Maps {
+            map1: BTreeMap::from_iter([("one".into(), 1), ("two".into(), 2)]),
+            map2: HashMap::from_iter([(7, 0.25), (-1, 1.0)]),
+        };
+        let mut buf = Vec::new();
+        maps.encode(&mut buf);
+        assert_eq!(Maps::decode(&buf).unwrap(), maps);
+    }
+
+    /// Packed and unpacked repeated fields collect into any `Default + Extend` container.
+    #[derive(TackyDecode)]
+    #[tacky(schema = example::SimpleMessage)]
+    struct Numbers {
+        manynumbers: Vec<i64>,
+        manynumbers_unpacked: BTreeSet<i32>,
+        packed_enum: Vec<SimpleEnum>,
+        #[tacky(rename = "manystrings")]
+        strings: HashSet<String>,
+        packed_doubles: Vec<f64>,
+    }
+
+    #[test]
+    fn test_derive_decode_collections() {
+        let msg = prost_proto::SimpleMessage {
+            manynumbers: vec![3, 1, 2],
+            manynumbers_unpacked: vec![5, 4, 5],
+            packed_enum: vec![1, 0],
+            manystrings: vec!["a".into(), "b".into(), "a".into()],
+            packed_doubles: vec![0.5],
+            ..Default::default()
+        };
+        let buf = msg.encode_to_vec();
+        let n = Numbers::decode(&buf).unwrap();
+        assert_eq!(n.manynumbers, [3, 1, 2]);
+        assert_eq!(n.manynumbers_unpacked, BTreeSet::from([4, 5]));
+        assert_eq!(n.packed_enum, [SimpleEnum::Second, SimpleEnum::First]);
+        assert_eq!(n.strings, HashSet::from(["a".into(), "b".into()]));
+        assert_eq!(n.packed_doubles, [0.5]);
+    }
+
+    #[derive(TackyDecode, Debug)]
+    #[tacky(schema = example::MapsWithMsg)]
+    struct Directory<'a> {
+        #[tacky(msg, rename = "map1")]
+        entries: HashMap<String, Entry<'a>>,
+    }
+
+    #[derive(TackyDecode, Debug)]
+    #[tacky(schema = example::SimpleMessage)]
+    struct Entry<'a> {
+        astring: Option<&'a str>,
+    }
+
+    #[test]
+    fn test_derive_decode_msg_map() {
+        let msg = prost_proto::MapsWithMsg {
+            map1: HashMap::from_iter([(
+                "k".into(),
+                prost_proto::SimpleMessage {
+                    astring: Some("v".into()),
+                    ..Default::default()
+                },
+            )]),
+        };
+        let buf = msg.encode_to_vec();
+        let dir = Directory::decode(&buf).unwrap();
+        assert_eq!(dir.entries["k"].astring, Some("v"));
+    }
+
+    /// A message field that isn't an `Option` has to be there.
+    #[derive(TackyDecode, Debug)]
+    #[tacky(schema = example::MixedUsageMessage)]
+    struct NeedsPayload<'a> {
+        #[tacky(msg)]
+        small_payload: SmallView<'a>,
+    }
+
+    #[test]
+    fn test_derive_decode_missing_msg() {
+        let buf = prost_proto::MixedUsageMessage::default().encode_to_vec();
+        assert!(matches!(
+            NeedsPayload::decode(&buf),
+            Err(DecodeError::MissingField("small_payload"))
+        ));
+
+        let mut buf = Vec::new();
+        session().encode(&mut buf);
+        assert_eq!(
+            NeedsPayload::decode(&buf).unwrap().small_payload.label,
+            "payload"
+        );
+    }
+
+    mod outcome_decode {
+        use super::{ApiResponseField, Outcome};
+        use tacky::DecodeError;
+
+        /// Sees every field no other struct field claimed, and keeps the oneof's.
+        pub fn decode(
+            field: ApiResponseField<'_>,
+            value: &mut Option<Outcome>,
+        ) -> Result<(), DecodeError> {
+            match field {
+                ApiResponseField::Error(e) => *value = Some(Outcome::Failed(e.into())),
+                ApiResponseField::Code(c) => *value = Some(Outcome::Code(c)),
+                _ => {}
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(TackyDecode)]
+    #[tacky(schema = example::ApiResponse)]
+    struct ResponseView<'a> {
+        request_id: &'a str,
+        #[tacky(with = outcome_decode)]
+        result: Option<Outcome>,
+        cached: Option<bool>,
+    }
+
+    #[test]
+    fn test_derive_decode_oneof() {
+        let response = Response {
+            request_id: "r",
+            result: Outcome::Code(7),
+            cached: Some(true),
+        };
+        let mut buf = Vec::new();
+        response.encode(&mut buf);
+        let view = ResponseView::decode(&buf).unwrap();
+        assert_eq!(view.request_id, "r");
+        assert!(matches!(view.result, Some(Outcome::Code(7))));
+        assert_eq!(view.cached, Some(true));
+    }
+
+    mod keywords {
+        tacky::proto! {
+            syntax = "proto3";
+            package keywords;
+            message Keywords {
+                int32 self = 1;
+                string type = 2;
+            }
+        }
+    }
+
+    /// Fields named after keywords decode through the variants codegen escapes them to.
+    #[derive(TackyDecode, Debug, PartialEq)]
+    #[tacky(schema = keywords::keywords::Keywords)]
+    struct Keywords<'a> {
+        self_: i32,
+        r#type: &'a str,
+    }
+
+    #[test]
+    fn test_derive_decode_keywords() {
+        let schema = keywords::keywords::Keywords::schema();
+        let mut buf = Vec::new();
+        schema.self_.write(&mut buf, 3);
+        schema.r#type.write(&mut buf, "enum");
+        let decoded = Keywords::decode(&buf).unwrap();
+        assert_eq!(
+            decoded,
+            Keywords {
+                self_: 3,
+                r#type: "enum"
+            }
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Proto comments, carried over as docs on what is generated for each declaration.
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use tacky_build::{expand_proto_source, format_tokens};
+
+    const SOURCE: &str = r#"
+syntax = "proto3";
+package docs;
+
+// A point on the map.
+message Point {
+    int32 x = 1; // Eastward.
+    // Northward.
+    int32 y = 2;
+    oneof label {
+        // By name.
+        string name = 3;
+    }
+}
+
+/* What kind of point. */
+enum Kind {
+    // Not said.
+    KIND_UNSPECIFIED = 0;
+    KIND_CITY = 1; // A city.
+}
+
+// Finds points.
+service Atlas {
+    // The point called this.
+    rpc Find(Point) returns (Point);
+}
+
+// Reads like markdown, which it isn't:
+//     indented(like_code);
+// ```
+// fenced(code);
+// ```
+// and [brackets].
+message Tricky {}
+"#;
+
+    /// What each `/// {doc}` line documents: the first line after it that isn't a doc or
+    /// another attribute.
+    fn documented<'a>(generated: &'a str, doc: &str) -> Vec<&'a str> {
+        let lines: Vec<&str> = generated.lines().map(str::trim).collect();
+        let doc = format!("/// {doc}");
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| **line == doc)
+            .filter_map(|(i, _)| {
+                lines[i..]
+                    .iter()
+                    .find(|l| !l.starts_with("///") && !l.starts_with("#["))
+            })
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_comments_become_docs() {
+        let expansion = expand_proto_source(SOURCE, Path::new("docs.proto"), &[]).unwrap();
+        let generated = format_tokens(expansion.tokens);
+
+        assert_eq!(
+            documented(&generated, "A point on the map."),
+            ["pub struct Point {"]
+        );
+        // The schema field, then the decode variant.
+        assert_eq!(
+            documented(&generated, "Eastward."),
+            [
+                "pub x: tacky::Field<1, tacky::Plain<tacky::Int32>>,",
+                "X(i32),"
+            ]
+        );
+        assert_eq!(documented(&generated, "Northward.").len(), 2);
+        // The decode variant, then the oneof's `write_*` method.
+        let name = documented(&generated, "By name.");
+        assert_eq!(name[0], "Name(&'a str),");
+        assert!(name[1].starts_with("pub fn write_name<"), "{}", name[1]);
+
+        assert_eq!(
+            documented(&generated, "What kind of point."),
+            ["pub enum Kind {"]
+        );
+        assert_eq!(documented(&generated, "Not said."), ["KindUnspecified,"]);
+        assert_eq!(documented(&generated, "A city."), ["KindCity,"]);
+
+        assert_eq!(
+            documented(&generated, "Finds points."),
+            ["pub trait Atlas {"]
+        );
+        let find = documented(&generated, "The point called this.");
+        assert_eq!(find[0], "pub struct AtlasFind;");
+        assert!(find[1].starts_with("fn find<"), "{}", find[1]);
+
+        // Nothing in there runs as a doctest or links anywhere.
+        assert_eq!(documented(&generated, "indented(like_code);").len(), 1);
+        assert_eq!(documented(&generated, "```text").len(), 1);
+        assert_eq!(documented(&generated, "and \\[brackets\\].").len(), 1);
+    }
+}
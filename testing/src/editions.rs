@@ -0,0 +1,172 @@
+//! Editions: features resolved from the file down to each field, generating what the proto2
+//! or proto3 they stand for would.
+
+mod text {
+    tacky::proto! {
+        edition = "2023";
+        package text;
+        message Note {
+            string checked = 1;
+            string unchecked = 2 [features.utf8_validation = NONE];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::text::text::{Note, NoteField};
+    use tacky_build::error::ErrorKind;
+    use tacky_build::{expand_proto_source, format_tokens};
+
+    fn generate(source: &str) -> String {
+        let expansion = expand_proto_source(source, Path::new("editions.proto"), &[]).unwrap();
+        format_tokens(expansion.tokens)
+    }
+
+    #[test]
+    fn test_edition_as_proto3() {
+        let proto3 = generate(
+            r#"syntax = "proto3";
+package same;
+message Sample {
+    int32 plain = 1;
+    optional int32 present = 2;
+    repeated int32 packed = 3;
+    repeated int32 expanded = 4 [packed = false];
+    Sample nested = 5;
+    map<string, int32> counts = 6;
+    oneof choice {
+        string name = 7;
+    }
+    Kind kind = 8;
+}
+enum Kind {
+    KIND_UNKNOWN = 0;
+}
+"#,
+        );
+        let edition = generate(
+            r#"edition = "2023";
+package same;
+option features.field_presence = IMPLICIT;
+message Sample {
+    int32 plain = 1;
+    int32 present = 2 [features.field_presence = EXPLICIT];
+    repeated int32 packed = 3;
+    repeated int32 expanded = 4 [features.repeated_field_encoding = EXPANDED];
+    Sample nested = 5;
+    map<string, int32> counts = 6;
+    oneof choice {
+        string name = 7;
+    }
+    Kind kind = 8;
+}
+enum Kind {
+    KIND_UNKNOWN = 0;
+}
+"#,
+        );
+        assert_eq!(edition, proto3);
+    }
+
+    #[test]
+    fn test_edition_as_proto2() {
+        let proto2 = generate(
+            r#"syntax = "proto2";
+package same;
+message Sample {
+    optional int32 present = 1;
+    required int32 needed = 2;
+    repeated int32 expanded = 3;
+    repeated int32 packed = 4 [packed = true];
+    optional Kind kind = 5;
+    message Inner {
+        repeated Kind kinds = 1;
+    }
+}
+enum Kind {
+    KIND_ONE = 1;
+}
+"#,
+        );
+        let edition = generate(
+            r#"edition = "2023";
+package same;
+option features.enum_type = CLOSED;
+message Sample {
+    option features.repeated_field_encoding = EXPANDED;
+    int32 present = 1;
+    int32 needed = 2 [features.field_presence = LEGACY_REQUIRED];
+    repeated int32 expanded = 3;
+    repeated int32 packed = 4 [features.repeated_field_encoding = PACKED];
+    Kind kind = 5;
+    message Inner {
+        repeated Kind kinds = 1;
+    }
+}
+enum Kind {
+    KIND_ONE = 1;
+}
+"#,
+        );
+        assert_eq!(edition, proto2);
+    }
+
+    #[test]
+    fn test_utf8_validation() {
+        let mut buf = Vec::new();
+        let s = Note::schema();
+        s.checked.write(&mut buf, Some("fine"));
+        s.unchecked.write(&mut buf, Some(&[0xff, 0xfe][..]));
+        let fields: Vec<_> = Note::decode(&buf).map(Result::unwrap).collect();
+        assert!(matches!(
+            fields[..],
+            [
+                NoteField::Checked("fine"),
+                NoteField::Unchecked([0xff, 0xfe])
+            ]
+        ));
+
+        // Where it is checked, it fails.
+        let mut buf = Vec::new();
+        Note::schema().unchecked.write(&mut buf, Some(&[0xff][..]));
+        buf[0] = 0x0a; // as field 1, `checked`
+        assert!(Note::decode(&buf).next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_delimited_unsupported() {
+        let source = r#"edition = "2023";
+message Outer {
+    Outer inner = 1 [features.message_encoding = DELIMITED];
+}
+"#;
+        let Err(e) = expand_proto_source(source, Path::new("groups.proto"), &[]) else {
+            panic!("generated groups");
+        };
+        assert!(matches!(e.kind(), ErrorKind::Unsupported(_)), "{e}");
+        assert!(e.to_string().contains("groups.proto:3:5:"), "{e}");
+    }
+
+    #[test]
+    fn test_invalid_feature_located() {
+        let source = r#"edition = "2023";
+enum Kind {
+    option features.enum_type = AJAR;
+    KIND_UNKNOWN = 0;
+}
+"#;
+        let Err(e) = expand_proto_source(source, Path::new("ajar.proto"), &[]) else {
+            panic!("generated an enum that is neither");
+        };
+        let shown = e.to_string();
+        assert!(
+            shown.contains(
+                "ajar.proto:2:1: invalid feature: `features.enum_type` must be one of OPEN, CLOSED"
+            ),
+            "{shown}"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Generated enums: aliases, names, and how closed (proto2) and open (proto3) enums treat
+//! numbers they don't declare.
+
+mod closed {
+    tacky::proto! {
+        syntax = "proto2";
+        package closed;
+
+        enum Phase {
+            option allow_alias = true;
+            PHASE_IDLE = 1;
+            PHASE_RUNNING = 2;
+            PHASE_STARTED = 2;
+            PHASE_DONE = 3;
+        }
+
+        message Job {
+            optional Phase phase = 1;
+            repeated Phase history = 2 [packed = true];
+            repeated Phase steps = 3;
+            optional string name = 4;
+        }
+    }
+}
+
+mod open {
+    tacky::proto! {
+        syntax = "proto3";
+        package open;
+
+        enum Phase {
+            option allow_alias = true;
+            PHASE_UNSPECIFIED = 0;
+            PHASE_RUNNING = 1;
+            PHASE_STARTED = 1;
+        }
+
+        message Job {
+            Phase phase = 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::closed::closed::{Job, JobField, Phase};
+    use super::open::open;
+    use tacky::{DecodeError, UnknownEnumValue, WriteBuf};
+
+    #[test]
+    fn test_enum_aliases_and_names() {
+        assert_eq!(Phase::PhaseStarted, Phase::PhaseRunning);
+        assert_eq!(i32::from(Phase::PhaseStarted), 2);
+        assert_eq!(
+            Phase::VALUES,
+            [Phase::PhaseIdle, Phase::PhaseRunning, Phase::PhaseDone]
+        );
+        assert_eq!(Phase::PhaseRunning.as_str_name(), Some("PHASE_RUNNING"));
+        assert_eq!(
+            Phase::from_str_name("PHASE_STARTED"),
+            Some(Phase::PhaseRunning)
+        );
+        assert_eq!(Phase::from_str_name("PHASE_LOST"), None);
+        // An alias is a const, so it matches as a pattern too.
+        assert!(matches!(Phase::PhaseRunning, Phase::PhaseStarted));
+
+        assert_eq!(open::Phase::VALUES.len(), 2);
+        assert_eq!(open::Phase::from(7), open::Phase::__Unrecognized(7));
+        assert_eq!(open::Phase::from(7).as_str_name(), None);
+        assert_eq!(open::Phase::from(1), open::Phase::PhaseStarted);
+    }
+
+    #[test]
+    fn test_closed_enum_conversions() {
+        assert_eq!(Phase::try_from(3), Ok(Phase::PhaseDone));
+        assert_eq!(Phase::try_from(9), Err(UnknownEnumValue(9)));
+        assert_eq!(Phase::default(), Phase::PhaseIdle);
+        assert_eq!(
+            DecodeError::from(UnknownEnumValue(9)).to_string(),
+            "unknown enum value 9"
+        );
+    }
+
+    /// A closed enum's undeclared number is skipped like an unknown field, whether it
+    /// is a singular field, an unpacked element or a packed one; an open enum keeps it.
+    #[test]
+    fn test_closed_enum_skips_unknown_numbers() {
+        let mut buf = Vec::new();
+        buf.put_slice(&[0x08, 9]); // phase = 9
+        buf.put_slice(&[0x12, 3, 1, 9, 3]); // history = [1, 9, 3], packed
+        buf.put_slice(&[0x18, 9, 0x18, 2]); // steps = 9, then 2
+        Job::schema().name.write(&mut buf, Some("nightly"));
+
+        let mut history = Vec::new();
+        let mut steps = Vec::new();
+        let mut name = None;
+        for f in Job::decode(&buf) {
+            match f.unwrap() {
+                JobField::Phase(p) => panic!("undeclared phase decoded as {p:?}"),
+                JobField::History(h) => history.extend(h.map(Result::unwrap)),
+                JobField::Steps(s) => steps.push(s),
+                JobField::Name(n) => name = Some(n),
+            }
+        }
+        assert_eq!(history, [Phase::PhaseIdle, Phase::PhaseDone]);
+        assert_eq!(steps, [Phase::PhaseRunning]);
+        assert_eq!(name, Some("nightly"));
+
+        let fields: Vec<_> = open::Job::decode(&[0x08, 9]).map(Result::unwrap).collect();
+        assert_eq!(
+            fields,
+            [open::JobField::Phase(open::Phase::__Unrecognized(9))]
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Extension fields: written through the extended message's schema constants, read back
+//! through its `Fields` iterator and the generated registry.
+
+mod tacky_extensions {
+    include!(concat!(env!("OUT_DIR"), "/extensions.rs"));
+}
+
+/// The same, from a descriptor set.
+mod tacky_extensions_fds {
+    include!(concat!(env!("OUT_DIR"), "/extensions_fds.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tacky_extensions::extensions::*;
+    use tacky::{Field, Int32, Optional, Packed, PbString, Sint64};
+
+    fn encode_options() -> Vec<u8> {
+        let mut buf = Vec::new();
+        let schema = Options::schema();
+        Options {
+            name: schema.name.write(&mut buf, Some("opts")),
+        };
+        Options::LABEL.write(&mut buf, Some("hello"));
+        Options::WEIGHT.write(&mut buf, Some(7));
+        Options::OFFSETS.write(&mut buf, [-1i64, 2]);
+        // Packed, as another writer may well have put them.
+        Field::<102, Packed<Sint64>>::new().write(&mut buf, [-3i64, 4]);
+        Options::EXTRA.write_msg(&mut buf, |buf, scm| {
+            scm.level.write(buf, Some(3));
+        });
+        Options::COLOR.write(&mut buf, Some(Color::Green));
+        buf
+    }
+
+    #[test]
+    fn test_extensions_decode_through_registry() {
+        let buf = encode_options();
+
+        let mut name = None;
+        let mut decoded = Vec::new();
+        for field in Options::decode(&buf) {
+            match field.unwrap() {
+                OptionsField::Name(v) => name = Some(v),
+                OptionsField::Extension(ext) => {
+                    decoded.push(OptionsExtension::decode(ext).unwrap().unwrap())
+                }
+            }
+        }
+        assert_eq!(name, Some("opts"));
+        assert_eq!(decoded.len(), 7);
+        assert_eq!(decoded[0], OptionsExtension::Label("hello"));
+        assert_eq!(decoded[1], OptionsExtension::Weight(7));
+        let offsets: Vec<i64> = decoded[2..5]
+            .iter()
+            .flat_map(|ext| match ext {
+                OptionsExtension::Offsets(elements) => elements.map(Result::unwrap),
+                other => panic!("expected Offsets, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(offsets, [-1, 2, -3, 4]);
+        let OptionsExtension::Extra(extra) = decoded[5] else {
+            panic!("expected Extra, got {:?}", decoded[5]);
+        };
+        let levels: Vec<_> = extra.map(|f| f.unwrap()).collect();
+        assert_eq!(levels, [ExtraField::Level(3)]);
+        assert_eq!(decoded[6], OptionsExtension::Color(Color::Green));
+    }
+
+    #[test]
+    fn test_extension_extract() {
+        let buf = encode_options();
+        let exts: Vec<_> = Options::decode(&buf)
+            .filter_map(|f| match f.unwrap() {
+                OptionsField::Extension(ext) => Some(ext),
+                _ => None,
+            })
+            .collect();
+
+        let labels: Vec<_> = exts
+            .iter()
+            .filter_map(|e| Options::LABEL.extract(e))
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(labels, ["hello"]);
+        let offsets: Vec<_> = exts
+            .iter()
+            .filter_map(|e| Options::OFFSETS.extract(e))
+            .flat_map(Result::unwrap)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(offsets, [-1, 2, -3, 4]);
+        let extra = exts
+            .iter()
+            .find_map(|e| Options::EXTRA.extract_msg(e))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            Extra::decode(extra).next().unwrap().unwrap(),
+            ExtraField::Level(3)
+        );
+    }
+
+    #[test]
+    fn test_unregistered_extension() {
+        // An extension some other file declared: still yielded, just unknown to this registry.
+        let mut buf = Vec::new();
+        Field::<500, Optional<PbString>>::new().write(&mut buf, Some("elsewhere"));
+        let ext = match Options::decode(&buf).next().unwrap().unwrap() {
+            OptionsField::Extension(ext) => ext,
+            other => panic!("expected an extension, got {other:?}"),
+        };
+        assert_eq!(ext.number, 500);
+        assert!(OptionsExtension::decode(ext).is_none());
+        assert!(Options::LABEL.extract(&ext).is_none());
+    }
+
+    #[test]
+    fn test_extension_wire_type_mismatch() {
+        let mut buf = Vec::new();
+        Field::<101, Optional<PbString>>::new().write(&mut buf, Some("not an int"));
+        let Some(Ok(OptionsField::Extension(ext))) = Options::decode(&buf).next() else {
+            panic!("expected an extension");
+        };
+        assert!(matches!(
+            OptionsExtension::decode(ext),
+            Some(Err(tacky::DecodeError::WireTypeMismatch { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_extension_ranges() {
+        use super::tacky_extensions_fds::extensions as fds;
+
+        let mut buf = Vec::new();
+        Field::<10, Optional<Int32>>::new().write(&mut buf, Some(10));
+        Field::<19, Optional<Int32>>::new().write(&mut buf, Some(19));
+        Field::<25, Optional<Int32>>::new().write(&mut buf, Some(25));
+        Field::<30, Optional<Int32>>::new().write(&mut buf, Some(30));
+        Field::<40, Optional<Int32>>::new().write(&mut buf, Some(40));
+        Field::<55, Optional<Int32>>::new().write(&mut buf, Some(55));
+        let numbers: Vec<u32> = Ranged::decode(&buf)
+            .map(|f| match f.unwrap() {
+                RangedField::Extension(ext) => ext.number,
+                RangedField::Between(v) => 1000 + v as u32,
+            })
+            .collect();
+        // 40 is in no range, so skipped as unknown; every range is, not just the first.
+        assert_eq!(numbers, [10, 19, 1025, 30, 55]);
+
+        let numbers: Vec<u32> = fds::Ranged::decode(&buf)
+            .map(|f| match f.unwrap() {
+                fds::RangedField::Extension(ext) => ext.number,
+                fds::RangedField::Between(v) => 1000 + v as u32,
+            })
+            .collect();
+        assert_eq!(numbers, [10, 19, 1025, 30, 55]);
+    }
+}
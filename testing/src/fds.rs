@@ -0,0 +1,59 @@
+//! `.fds` input: generated from `data/registry.fds`, and compared with what the
+//! `.proto` source of the same files generates.
+
+mod tacky_fds {
+    include!(concat!(env!("OUT_DIR"), "/simple_fds.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::tacky_fds::example::{SimpleEnum, SimpleMessage, SimpleMessageField};
+    use crate::prost_proto;
+
+    /// Same input, same output, to the byte. The fixture is protoc's reading of these
+    /// files; if one of them changes, rerun `scripts/gen_bench_fixtures.sh`.
+    #[test]
+    fn test_fds_matches_proto_source() {
+        macro_rules! generated {
+            ($file:literal) => {
+                include_str!(concat!(env!("OUT_DIR"), "/", $file))
+            };
+        }
+        assert_eq!(generated!("simple_fds.rs"), generated!("simple.rs"));
+        assert_eq!(generated!("importing_fds.rs"), generated!("importing.rs"));
+        assert_eq!(generated!("proto3_fds.rs"), generated!("proto3.rs"));
+    }
+
+    #[test]
+    fn test_fds_round_trip() {
+        let mut buf = Vec::new();
+        let schema = SimpleMessage::schema();
+        SimpleMessage {
+            normal_int: schema.normal_int.write(&mut buf, Some(7)),
+            packed_enum: schema.packed_enum.write(&mut buf, &[SimpleEnum::Second]),
+            astring: schema.astring.write(&mut buf, Some("fds")),
+            ..schema
+        };
+        let decoded = prost_proto::SimpleMessage::decode(&*buf).unwrap();
+        assert_eq!(decoded.normal_int, Some(7));
+        assert_eq!(decoded.packed_enum, [1]);
+        let fields: Vec<_> = SimpleMessage::decode(&buf).map(Result::unwrap).collect();
+        assert!(matches!(fields[2], SimpleMessageField::Astring("fds")));
+    }
+
+    /// tacky-build decodes descriptor sets and plugin requests with its checked-in copy of
+    /// the plugin.proto codegen; it has to stay what the current generator makes of it.
+    #[test]
+    fn test_descriptor_current() {
+        let checked_in = include_str!("../../tacky-build/src/descriptor.rs");
+        let body = checked_in.split_once("\n\n").unwrap().1;
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/tacky_plugin_owned.rs"));
+        assert!(
+            body == generated,
+            "tacky-build/src/descriptor.rs is stale: regenerate it from \
+             testing/protos/plugin.proto with tacky_build::write_proto_with_owned"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Imports resolved as protoc resolves them: by their path from an include root, with
+//! `import public` passing types on and `import weak` allowed to be missing.
+
+#[cfg(test)]
+mod tests {
+    use crate::scratch;
+    use std::path::{Path, PathBuf};
+    use tacky_build::error::ErrorKind;
+    use tacky_build::{expand_packages, expand_proto_file, PackageOptions};
+
+    #[test]
+    fn test_relative_include_root() {
+        // From the package root, as a build script runs: every OTLP file imports the others
+        // by their path from `protos`, never from its own directory.
+        let trace = Path::new("protos/opentelemetry/proto/collector/trace/v1/trace_service.proto");
+        let expansion = expand_packages(&[trace], &["protos"], &PackageOptions::default()).unwrap();
+        assert!(expansion.files.contains(&PathBuf::from(
+            "protos/opentelemetry/proto/common/v1/common.proto"
+        )));
+    }
+
+    #[test]
+    fn test_import_public() {
+        let root = scratch::tree(
+            "imports/public",
+            &[
+                (
+                    "app/app.proto",
+                    "syntax = \"proto3\";\npackage app;\nimport \"lib/facade.proto\";\nmessage App {\n    core.Core core = 1;\n}\n",
+                ),
+                (
+                    "lib/facade.proto",
+                    "syntax = \"proto3\";\npackage facade;\nimport public \"lib/core/core.proto\";\n",
+                ),
+                (
+                    "lib/core/core.proto",
+                    "syntax = \"proto3\";\npackage core;\nmessage Core {\n    int32 id = 1;\n}\n",
+                ),
+            ],
+        );
+        let include = root.to_str().unwrap();
+        expand_proto_file(&root.join("app/app.proto"), &[include]).unwrap();
+
+        // Without `public`, the facade keeps the import to itself.
+        let facade = root.join("lib/facade.proto");
+        let source = std::fs::read_to_string(&facade).unwrap();
+        std::fs::write(&facade, source.replace("import public", "import")).unwrap();
+        let Err(e) = expand_proto_file(&root.join("app/app.proto"), &[include]) else {
+            panic!("used a type it can't see");
+        };
+        assert!(matches!(e.kind(), ErrorKind::Read(_)), "{e}");
+        let shown = e.to_string();
+        assert!(shown.contains("app.proto:5:5:"), "{shown}");
+        assert!(
+            shown.contains(
+                "`core.Core` is declared in \"lib/core/core.proto\", which isn't imported here"
+            ),
+            "{shown}"
+        );
+    }
+
+    #[test]
+    fn test_import_not_found() {
+        let root = scratch::tree(
+            "imports/not_found",
+            &[(
+                "a/a.proto",
+                "syntax = \"proto3\";\nimport weak \"gone.proto\";\nimport \"missing.proto\";\nmessage A {}\n",
+            )],
+        );
+        let shared = root.join("shared");
+        let Err(e) = expand_proto_file(&root.join("a/a.proto"), &[shared.to_str().unwrap()]) else {
+            panic!("found a file that isn't there");
+        };
+        // The weak import went by quietly; the other names every place it was looked for.
+        let shown = e.to_string();
+        assert!(shown.contains("a.proto:3:1:"), "{shown}");
+        let searched = format!("import \"missing.proto\" not found in {}", shared.display());
+        assert!(shown.contains(&searched), "{shown}");
+    }
+
+    #[test]
+    fn test_import_next_to_importer() {
+        // Like protoc, nothing looks next to the importing file unless an include is there.
+        let root = scratch::tree(
+            "imports/next_to_importer",
+            &[
+                (
+                    "sub/a.proto",
+                    "syntax = \"proto3\";\nimport \"b.proto\";\nmessage A {\n    B b = 1;\n}\n",
+                ),
+                ("sub/b.proto", "syntax = \"proto3\";\nmessage B {}\n"),
+            ],
+        );
+        let a = root.join("sub/a.proto");
+        let Err(e) = expand_proto_file(&a, &[root.to_str().unwrap()]) else {
+            panic!("found an import beside its importer");
+        };
+        assert!(matches!(e.kind(), ErrorKind::Read(_)), "{e}");
+        let shown = e.to_string();
+        assert!(shown.contains("import \"b.proto\" not found"), "{shown}");
+
+        let Err(e) = expand_proto_file(&a, &[]) else {
+            panic!("found an import with no include paths");
+        };
+        assert!(e.to_string().contains("there are no include paths"), "{e}");
+
+        expand_proto_file(&a, &[root.join("sub").to_str().unwrap()]).unwrap();
+    }
+}
@@ -10,8 +10,29 @@ mod tacky_importing {
     include!(concat!(env!("OUT_DIR"), "/importing.rs"));
 }
 
+mod breaking;
+mod config;
+mod defaults;
+mod derive;
+mod docs;
+mod editions;
+mod enums;
+mod extensions;
+mod fds;
+mod imports;
+mod macros;
+mod names;
+mod options;
+mod owned;
+mod packages;
+mod plugin;
 mod prost_to_tacky;
 mod proto3;
+mod random;
+#[cfg(test)]
+mod scratch;
+mod services;
+mod shared;
 
 #[cfg(feature = "cpp")]
 pub mod cpp;
@@ -752,15 +773,14 @@ mod tests {
 
     #[test]
     fn test_nested_definitions_encode_decode() {
-        use crate::tacky_proto::example::{
-            Outer, OuterField, OuterFields, OuterInnerField, OuterStatus,
-        };
+        use crate::tacky_proto::example::outer::{InnerField, Status};
+        use crate::tacky_proto::example::{Outer, OuterField, OuterFields};
 
         // Encode a message with nested enum and nested message fields
         let mut buf = Vec::new();
         let schema = Outer::schema();
         Outer {
-            status: schema.status.write(&mut buf, Some(OuterStatus::Active)),
+            status: schema.status.write(&mut buf, Some(Status::Active)),
             inner: schema.inner.write_msg(&mut buf, |buf, scm| {
                 scm.value.write(buf, Some("nested_value"));
                 scm.num.write(buf, Some(42));
@@ -782,8 +802,8 @@ mod tests {
                     for f in fields {
                         let f = f.unwrap();
                         match f {
-                            OuterInnerField::Value(v) => inner_value = Some(v),
-                            OuterInnerField::Num(v) => inner_num = Some(v),
+                            InnerField::Value(v) => inner_value = Some(v),
+                            InnerField::Num(v) => inner_num = Some(v),
                         }
                     }
                 }
@@ -791,7 +811,7 @@ mod tests {
             }
         }
 
-        assert_eq!(status, Some(OuterStatus::Active));
+        assert_eq!(status, Some(Status::Active));
         assert_eq!(inner_value, Some("nested_value"));
         assert_eq!(inner_num, Some(42));
         assert_eq!(name, Some("outer_name"));
@@ -0,0 +1,128 @@
+//! `include_proto!` and `proto!`: the same codegen as the build script, at compile time.
+
+#[allow(dead_code)]
+mod included {
+    tacky::include_proto!("protos/importing.proto", includes = ["protos"]);
+}
+
+mod inline {
+    tacky::proto! {
+        syntax = "proto2";
+        import "protos/simple_message.proto";
+
+        message Envelope {
+            required string id = 1;
+            optional int32 retries = 2 [default = -3];
+            repeated uint64 ids = 3 [packed = true];
+            map<string, int32> flags = 4;
+            optional example.SimpleEnum status = 8;
+            optional example.SimpleMessage body = 5;
+            oneof route {
+                string topic = 6;
+                int64 partition = 7;
+            }
+        }
+    }
+}
+
+mod inline_package {
+    tacky::proto! {
+        syntax = "proto3";
+        package wire.v1;
+
+        message Ping {
+            uint64 seq = 1;
+            bytes payload = 2;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Imported messages are generated alongside, as they are by the build script.
+    use super::inline::{Envelope, EnvelopeField, SimpleEnum, SimpleMessageField};
+    use super::inline_package::wire::v1::{Ping, PingField};
+
+    /// Encoded through the macro's schema, decoded by the build script's `Fields`.
+    #[test]
+    fn test_include_proto_matches_build_script() {
+        use super::included::importing::{SimpleEnum, Wrapper};
+        use crate::tacky_importing::importing as built;
+
+        let mut buf = Vec::new();
+        let schema = Wrapper::schema();
+        Wrapper {
+            msg: schema.msg.write_msg(&mut buf, |buf, scm| {
+                scm.normal_int.write(buf, Some(99));
+            }),
+            status: schema.status.write(&mut buf, Some(SimpleEnum::Second)),
+            label: schema.label.write(&mut buf, Some("w")),
+        };
+
+        let mut seen = 0;
+        for field in built::Wrapper::decode(&buf) {
+            match field.unwrap() {
+                built::WrapperField::Msg(_) => seen += 1,
+                built::WrapperField::Status(built::SimpleEnum::Second) => seen += 1,
+                built::WrapperField::Label("w") => seen += 1,
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn test_inline_proto() {
+        let mut buf = Vec::new();
+        let schema = Envelope::schema();
+        Envelope {
+            id: schema.id.write(&mut buf, "e-1"),
+            retries: schema.retries.write(&mut buf, None::<i32>),
+            ids: schema.ids.write(&mut buf, &[7u64, 8]),
+            flags: schema.flags.write(&mut buf, [("a", 1)]),
+            status: schema.status.write(&mut buf, Some(SimpleEnum::Second)),
+            body: schema.body.write_msg(&mut buf, |buf, scm| {
+                scm.astring.write(buf, Some("inner"));
+            }),
+            route: schema.route.write_partition(&mut buf, 9),
+        };
+        assert_eq!(Envelope::DEFAULT_RETRIES, -3);
+
+        let mut seen = Vec::new();
+        for field in Envelope::decode(&buf) {
+            match field.unwrap() {
+                EnvelopeField::Id(id) => seen.push(id.to_string()),
+                EnvelopeField::Ids(ids) => {
+                    let ids: Vec<u64> = ids.map(Result::unwrap).collect();
+                    assert_eq!(ids, [7, 8]);
+                }
+                EnvelopeField::Flags((k, v)) => {
+                    assert_eq!((k, v), ("a", Some(1)));
+                }
+                EnvelopeField::Body(body) => {
+                    for f in body {
+                        if let SimpleMessageField::Astring(s) = f.unwrap() {
+                            seen.push(s.to_string());
+                        }
+                    }
+                }
+                EnvelopeField::Status(s) => assert_eq!(s, SimpleEnum::Second),
+                EnvelopeField::Partition(p) => seen.push(p.to_string()),
+                _ => {}
+            }
+        }
+        assert_eq!(seen, ["e-1", "inner", "9"]);
+
+        let mut buf = Vec::new();
+        let schema = Ping::schema();
+        Ping {
+            seq: schema.seq.write(&mut buf, 5u64),
+            payload: schema.payload.write(&mut buf, b"hi"),
+        };
+        let fields: Vec<_> = Ping::decode(&buf).map(Result::unwrap).collect();
+        assert!(matches!(
+            fields[..],
+            [PingField::Seq(5), PingField::Payload(b"hi")]
+        ));
+    }
+}
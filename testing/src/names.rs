@@ -0,0 +1,121 @@
+//! Generated names: nested types in a module per enclosing message, proto names that are
+//! Rust keywords, and messages named after what the generated code itself uses.
+
+mod nested {
+    tacky::proto! {
+        syntax = "proto3";
+        package type.self;
+
+        message Outer {
+            message Inner {
+                int32 value = 1;
+                Outer parent = 2;
+                Kind kind = 3;
+            }
+            enum Kind {
+                KIND_UNSPECIFIED = 0;
+                KIND_LEAF = 1;
+            }
+            Inner inner = 1;
+            Kind kind = 2;
+        }
+
+        // Flattened, this was `Outer` + `Inner` all over again.
+        message OuterInner {
+            string name = 1;
+        }
+
+        message Type {
+            Outer.Inner inner = 1;
+            int32 type = 2;
+            int32 self = 3;
+        }
+    }
+}
+
+#[allow(dead_code)]
+mod shadowing {
+    include!(concat!(env!("OUT_DIR"), "/shadowing.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nested::r#type::self_::{outer, Outer, OuterField, OuterInner, Type, TypeField};
+
+    #[test]
+    fn test_nested_types_in_modules() {
+        let mut buf = Vec::new();
+        let schema = Outer::schema();
+        schema.inner.write_msg(&mut buf, |buf, inner| {
+            inner.value.write(buf, 7);
+            inner.kind.write(buf, outer::Kind::KindLeaf);
+            inner.parent.write_msg(buf, |buf, parent| {
+                parent.kind.write(buf, outer::Kind::KindLeaf);
+            });
+        });
+
+        let Some(Ok(OuterField::Inner(inner))) = Outer::decode(&buf).next() else {
+            panic!("expected the inner message");
+        };
+        let fields: Vec<_> = inner.map(Result::unwrap).collect();
+        assert!(matches!(fields[0], outer::InnerField::Value(7)));
+        assert!(matches!(
+            fields[1],
+            outer::InnerField::Kind(outer::Kind::KindLeaf)
+        ));
+        let outer::InnerField::Parent(parent) = fields[2] else {
+            panic!("expected the parent");
+        };
+        let parent: Vec<_> = parent.map(Result::unwrap).collect();
+        assert_eq!(parent, [OuterField::Kind(outer::Kind::KindLeaf)]);
+
+        // A top-level message that used to share the nested one's flattened name.
+        let mut buf = Vec::new();
+        OuterInner::schema().name.write(&mut buf, "flat");
+        assert_eq!(OuterInner::decode(&buf).count(), 1);
+    }
+
+    #[test]
+    fn test_keyword_names() {
+        let mut buf = Vec::new();
+        let schema = Type::schema();
+        schema.r#type.write(&mut buf, 1);
+        schema.self_.write(&mut buf, 2);
+        let fields: Vec<_> = Type::decode(&buf).map(Result::unwrap).collect();
+        assert_eq!(fields, [TypeField::Type(1), TypeField::Self_(2)]);
+    }
+
+    #[test]
+    fn test_shadowing_names() {
+        use super::shadowing::google::protobuf::{ApiOwned, TypeOwned};
+        use super::shadowing::shadowing::{BoxOwned, OptionOwned, ResultOwned, VecOwned};
+        use tacky::random::Rng;
+
+        macro_rules! round_trip {
+            ($($owned:ty),*) => {
+                $(
+                    for seed in 0..50 {
+                        let (model, bytes) = <$owned>::random(&mut Rng::new(seed), 3);
+                        assert_eq!(<$owned>::from_bytes(&bytes).unwrap(), model, "seed {seed}");
+                    }
+                )*
+            };
+        }
+        round_trip!(ResultOwned, BoxOwned, TypeOwned, ApiOwned);
+
+        let values = VecOwned {
+            values: vec![1, -2],
+        };
+        let result = ResultOwned {
+            options: vec![OptionOwned {
+                name: Some("shadowed".into()),
+                id: 7,
+            }],
+            by_name: [("v".to_string(), values)].into(),
+            ..Default::default()
+        };
+        let mut buf = Vec::new();
+        result.encode(&mut buf);
+        assert_eq!(ResultOwned::from_bytes(&buf).unwrap(), result);
+    }
+}
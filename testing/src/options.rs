@@ -0,0 +1,91 @@
+//! Proto options: `deprecated` and `json_name` on fields, and the attributes
+//! `Config::option_attribute` makes of the rest.
+
+#[allow(dead_code)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/options.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generated::options::{Account, AccountField, AccountOwned};
+
+    const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/options.rs"));
+
+    /// The `options` package, without the `descriptor.proto` it imports.
+    fn options_module() -> &'static str {
+        &GENERATED[GENERATED.find("pub mod options").unwrap()..]
+    }
+
+    /// The first line after each `#[deprecated]` that isn't a doc or another attribute.
+    fn deprecated() -> Vec<&'static str> {
+        let lines: Vec<&str> = options_module().lines().map(str::trim).collect();
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| **line == "#[deprecated]")
+            .filter_map(|(i, _)| {
+                lines[i..]
+                    .iter()
+                    .find(|l| !l.starts_with("///") && !l.starts_with("#["))
+            })
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_deprecated_fields() {
+        assert_eq!(
+            deprecated(),
+            [
+                "pub legacy_id: tacky::Field<3, tacky::Optional<tacky::Int64>>,",
+                "LegacyId(i64),",
+                "Phone(&'a str),",
+                "pub fn write_phone<B: tacky::WriteBuf>(",
+                "pub legacy_id: ::core::option::Option<i64>,",
+            ]
+        );
+
+        // Still there to use, for whoever says so.
+        #[allow(deprecated)]
+        {
+            let mut buf = Vec::new();
+            let s = Account::schema();
+            s.legacy_id.write(&mut buf, Some(7));
+            let owned = AccountOwned::from_bytes(&buf).unwrap();
+            assert_eq!(owned.legacy_id, Some(7));
+            let fields: Vec<_> = Account::decode(&buf).map(Result::unwrap).collect();
+            assert!(matches!(fields[..], [AccountField::LegacyId(7)]));
+        }
+    }
+
+    #[test]
+    fn test_json_names() {
+        let mut buf = Vec::new();
+        let s = Account::schema();
+        s.user_name.write(&mut buf, Some("ada"));
+        s.e_mail.write(&mut buf, Some("ada@example.com"));
+        s.age.write(&mut buf, Some(36));
+        s.contact.write_pager(&mut buf, "555");
+        let names: Vec<_> = Account::decode(&buf)
+            .map(|f| f.unwrap().json_name())
+            .collect();
+        assert_eq!(
+            names,
+            [Some("userName"), Some("email"), Some("age"), Some("pager")]
+        );
+    }
+
+    #[test]
+    fn test_option_attributes() {
+        let account = options_module();
+        // `(table)`, on the message.
+        assert!(account.contains("/// Stored in `accounts`.\n    pub struct Account {"));
+        // `(redact)` and `(range)`, on fields; only where the hook has something to say.
+        assert!(account.contains("/// Redacted.\n        pub password:"));
+        assert!(account.contains("/// From 0 to 150.\n        pub age:"));
+        assert_eq!(account.matches("Redacted.").count(), 1);
+        // `(exhaustive) = false`, on the enum.
+        assert!(account.contains("#[non_exhaustive]\n    pub enum Role {"));
+    }
+}
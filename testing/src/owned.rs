@@ -0,0 +1,177 @@
+//! Owned structs: decoded from prost's bytes, re-encoded, and decoded by prost again.
+
+mod tacky_owned {
+    include!(concat!(env!("OUT_DIR"), "/simple_owned.rs"));
+}
+mod tacky_proto3_owned {
+    include!(concat!(env!("OUT_DIR"), "/proto3_owned.rs"));
+}
+#[allow(dead_code)]
+mod prost_proto3 {
+    include!(concat!(env!("OUT_DIR"), "/proto3test.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use std::collections::HashMap;
+
+    use super::tacky_owned::example::*;
+    use crate::prost_proto;
+
+    fn prost_simple() -> prost_proto::SimpleMessage {
+        prost_proto::SimpleMessage {
+            normal_int: Some(-5),
+            zigzag_int: Some(-300),
+            manynumbers: vec![1, 2, 300],
+            manynumbers_unpacked: vec![4, 5],
+            packed_enum: vec![1, 0],
+            astring: Some("hello".into()),
+            manystrings: vec!["a".into(), "b".into()],
+            abytes: Some(vec![0, 255]),
+            packed_doubles: vec![1.5, -2.5],
+            repeated_floats: vec![0.25],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_owned_round_trip() {
+        let original = prost_simple();
+        let owned = SimpleMessageOwned::from_bytes(&original.encode_to_vec()).unwrap();
+        assert_eq!(owned.normal_int, Some(-5));
+        assert_eq!(owned.manynumbers, [1, 2, 300]);
+        assert_eq!(owned.packed_enum, [SimpleEnum::Second, SimpleEnum::First]);
+        assert_eq!(owned.astring.as_deref(), Some("hello"));
+        assert_eq!(owned.abytes, Some(vec![0, 255]));
+        assert_eq!(owned.yesno, None);
+
+        let mut buf = Vec::new();
+        owned.encode(&mut buf);
+        assert_eq!(prost_proto::SimpleMessage::decode(&*buf).unwrap(), original);
+    }
+
+    #[test]
+    fn test_owned_nested_maps_and_oneof() {
+        let original = prost_proto::MsgWithNesting {
+            enums: Some(prost_proto::MsgWithEnums {
+                enum1: Some(1),
+                enum2: vec![1, 1, 0],
+            }),
+            nested: vec![prost_simple(), Default::default()],
+        };
+        let owned = MsgWithNestingOwned::from_bytes(&original.encode_to_vec()).unwrap();
+        assert_eq!(
+            owned.enums.as_ref().unwrap().enum1,
+            Some(SimpleEnum::Second)
+        );
+        assert_eq!(owned.nested.len(), 2);
+        let mut buf = Vec::new();
+        owned.encode(&mut buf);
+        assert_eq!(
+            prost_proto::MsgWithNesting::decode(&*buf).unwrap(),
+            original
+        );
+
+        let original = prost_proto::MapsWithMsg {
+            map1: HashMap::from_iter([
+                ("k".into(), prost_simple()),
+                ("e".into(), Default::default()),
+            ]),
+        };
+        let owned = MapsWithMsgOwned::from_bytes(&original.encode_to_vec()).unwrap();
+        assert_eq!(owned.map1["k"].normal_int, Some(-5));
+        let mut buf = Vec::new();
+        owned.encode(&mut buf);
+        assert_eq!(prost_proto::MapsWithMsg::decode(&*buf).unwrap(), original);
+
+        let original = prost_proto::ApiResponse {
+            request_id: Some("r1".into()),
+            cached: Some(true),
+            result: Some(prost_proto::api_response::Result::Data(prost_simple())),
+        };
+        let owned = ApiResponseOwned::from_bytes(&original.encode_to_vec()).unwrap();
+        let Some(ApiResponseResultOwned::Data(data)) = &owned.result else {
+            panic!("expected data, got {:?}", owned.result);
+        };
+        assert_eq!(data.astring.as_deref(), Some("hello"));
+        let mut buf = Vec::new();
+        owned.encode(&mut buf);
+        assert_eq!(prost_proto::ApiResponse::decode(&*buf).unwrap(), original);
+    }
+
+    /// A singular message that comes more than once is merged, as prost decodes it.
+    #[test]
+    fn test_owned_merges_messages() {
+        let first = prost_proto::MsgWithNesting {
+            enums: Some(prost_proto::MsgWithEnums {
+                enum1: Some(1),
+                enum2: vec![1],
+            }),
+            nested: vec![],
+        };
+        let second = prost_proto::MsgWithNesting {
+            enums: Some(prost_proto::MsgWithEnums {
+                enum1: None,
+                enum2: vec![0],
+            }),
+            nested: vec![],
+        };
+        let mut buf = first.encode_to_vec();
+        second.encode(&mut buf).unwrap();
+
+        let owned = MsgWithNestingOwned::from_bytes(&buf).unwrap();
+        let enums = owned.enums.as_deref().unwrap();
+        assert_eq!(enums.enum1, Some(SimpleEnum::Second));
+        assert_eq!(enums.enum2, [AnotherEnum::B, AnotherEnum::A]);
+        let mut reencoded = Vec::new();
+        owned.encode(&mut reencoded);
+        assert_eq!(
+            prost_proto::MsgWithNesting::decode(&*reencoded).unwrap(),
+            prost_proto::MsgWithNesting::decode(&*buf).unwrap()
+        );
+    }
+
+    /// The same struct through a `RevBuf`: fields land in reverse order, repeated elements
+    /// in list order, and it decodes back to an equal struct.
+    #[test]
+    fn test_owned_into_rev_buf() {
+        let owned = SimpleMessageOwned::from_bytes(&prost_simple().encode_to_vec()).unwrap();
+        let mut backing = [0u8; 256];
+        let mut rb = tacky::RevBuf::new(&mut backing);
+        owned.encode(&mut rb);
+        assert_eq!(SimpleMessageOwned::from_bytes(rb.written()).unwrap(), owned);
+    }
+
+    #[test]
+    fn test_owned_proto3() {
+        use super::prost_proto3 as p;
+        use super::tacky_proto3_owned::proto3test::*;
+
+        let original = p::WithOneof {
+            id: "x".into(),
+            payload: Some(p::with_oneof::Payload::Nested(p::Nested {
+                label: "n".into(),
+                value: 0,
+            })),
+        };
+        let owned = WithOneofOwned::from_bytes(&original.encode_to_vec()).unwrap();
+        assert_eq!(owned.id, "x");
+        let mut buf = Vec::new();
+        owned.encode(&mut buf);
+        assert_eq!(p::WithOneof::decode(&*buf).unwrap(), original);
+
+        // Implicit presence: defaults stay off the wire both ways.
+        let owned = ScalarMessageOwned {
+            a_int32: 7,
+            a_string: "s".into(),
+            ..Default::default()
+        };
+        let mut buf = Vec::new();
+        owned.encode(&mut buf);
+        let decoded = p::ScalarMessage::decode(&*buf).unwrap();
+        assert_eq!((decoded.a_int32, decoded.a_string.as_str()), (7, "s"));
+        assert_eq!(buf.len(), 5);
+        assert_eq!(ScalarMessageOwned::from_bytes(&buf).unwrap(), owned);
+    }
+}
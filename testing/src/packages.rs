@@ -0,0 +1,139 @@
+//! Several files generated together, one module per proto package: the OTLP trace and
+//! logs collector services, which share common/resource. Then traces on their own, with
+//! common as an extern path into the first.
+
+#[allow(dead_code, clippy::enum_variant_names)]
+mod tacky_otlp {
+    include!(concat!(env!("OUT_DIR"), "/tacky_otlp.rs"));
+}
+#[allow(dead_code, clippy::enum_variant_names)]
+mod tacky_otlp_extern {
+    include!(concat!(env!("OUT_DIR"), "/tacky_otlp_extern.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tacky_otlp::opentelemetry::proto::{
+        collector, common::v1 as common, logs::v1 as logs, resource::v1 as resource,
+        trace::v1 as trace,
+    };
+
+    fn write_kv(buf: &mut Vec<u8>, kv: common::KeyValue, (k, v): (&str, &str)) {
+        kv.key.write(buf, k);
+        kv.value.write_msg(buf, |buf, any| {
+            any.value.write_string_value(buf, v);
+        });
+    }
+
+    fn read_kv(fields: common::KeyValueFields<'_>) -> (&str, &str) {
+        let (mut key, mut value) = ("", "");
+        for f in fields {
+            match f.unwrap() {
+                common::KeyValueField::Key(k) => key = k,
+                common::KeyValueField::Value(any) => {
+                    for f in any {
+                        if let common::AnyValueField::StringValue(s) = f.unwrap() {
+                            value = s;
+                        }
+                    }
+                }
+            }
+        }
+        (key, value)
+    }
+
+    /// One writer for both signals: there is only one `Resource`.
+    fn write_resource(buf: &mut Vec<u8>, schema: resource::Resource) {
+        schema.attributes.write_msgs(
+            buf,
+            &[("service.name", "checkout")],
+            |buf, kv, &kv_value| write_kv(buf, kv, kv_value),
+        );
+    }
+
+    /// And one reader.
+    fn read_resource(fields: resource::ResourceFields<'_>) -> Vec<(&str, &str)> {
+        let mut out = Vec::new();
+        for f in fields {
+            if let resource::ResourceField::Attributes(kv) = f.unwrap() {
+                out.push(read_kv(kv));
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_packages_share_types() {
+        let mut traces = Vec::new();
+        collector::trace::v1::ExportTraceServiceRequest::schema()
+            .resource_spans
+            .write_msg(&mut traces, |buf, spans: trace::ResourceSpans| {
+                spans.resource.write_msg(buf, write_resource);
+            });
+        let mut logs = Vec::new();
+        collector::logs::v1::ExportLogsServiceRequest::schema()
+            .resource_logs
+            .write_msg(&mut logs, |buf, logs: logs::ResourceLogs| {
+                logs.resource.write_msg(buf, write_resource);
+            });
+
+        let mut seen = Vec::new();
+        for f in collector::trace::v1::ExportTraceServiceRequest::decode(&traces) {
+            let collector::trace::v1::ExportTraceServiceRequestField::ResourceSpans(spans) =
+                f.unwrap();
+            for f in spans {
+                if let trace::ResourceSpansField::Resource(r) = f.unwrap() {
+                    seen.push(read_resource(r));
+                }
+            }
+        }
+        for f in collector::logs::v1::ExportLogsServiceRequest::decode(&logs) {
+            let collector::logs::v1::ExportLogsServiceRequestField::ResourceLogs(logs) = f.unwrap();
+            for f in logs {
+                if let logs::ResourceLogsField::Resource(r) = f.unwrap() {
+                    seen.push(read_resource(r));
+                }
+            }
+        }
+        let expected = vec![("service.name", "checkout")];
+        assert_eq!(seen, [expected.clone(), expected]);
+    }
+
+    #[test]
+    fn test_packages_generated_once() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/tacky_otlp.rs"));
+        for item in [
+            "pub struct KeyValue {",
+            "pub struct AnyValue {",
+            "pub struct Resource {",
+            "pub struct InstrumentationScope {",
+        ] {
+            assert_eq!(generated.matches(item).count(), 1, "{item}");
+        }
+    }
+
+    #[test]
+    fn test_packages_extern_path() {
+        use super::tacky_otlp_extern::opentelemetry::proto::trace::v1 as ext;
+
+        // Attributes are the other crate's `KeyValue`: written and read by the same helpers.
+        let mut buf = Vec::new();
+        ext::Span::schema().attributes.write_msgs(
+            &mut buf,
+            &[("http.route", "/cart")],
+            |buf, kv, &kv_value| write_kv(buf, kv, kv_value),
+        );
+        let mut seen = Vec::new();
+        for f in ext::Span::decode(&buf) {
+            if let ext::SpanField::Attributes(kv) = f.unwrap() {
+                seen.push(read_kv(kv));
+            }
+        }
+        assert_eq!(seen, [("http.route", "/cart")]);
+
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/tacky_otlp_extern.rs"));
+        assert!(!generated.contains("pub mod common"));
+        assert!(!generated.contains("pub struct KeyValue {"));
+        assert!(generated.contains("pub struct Resource {"));
+    }
+}
@@ -0,0 +1,256 @@
+//! `protoc-gen-tacky`: a request recorded from protoc by `scripts/record_plugin_request.sh`,
+//! answered with the same code the build script generates from the `.proto` source.
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use prost_types::compiler::{CodeGeneratorRequest, CodeGeneratorResponse};
+    use prost_types::field_descriptor_proto::{Label, Type};
+    use prost_types::source_code_info::Location;
+    use prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, MessageOptions, SourceCodeInfo,
+    };
+
+    const REQUEST: &[u8] = include_bytes!("../data/plugin_request.pb");
+
+    macro_rules! generated {
+        ($file:literal) => {
+            include_str!(concat!(env!("OUT_DIR"), "/", $file))
+        };
+    }
+
+    fn respond(parameter: Option<&str>) -> CodeGeneratorResponse {
+        let mut request = CodeGeneratorRequest::decode(REQUEST).unwrap();
+        request.parameter = parameter.map(Into::into);
+        let response = tacky_build::plugin::run(&request.encode_to_vec());
+        CodeGeneratorResponse::decode(&*response).unwrap()
+    }
+
+    #[test]
+    fn test_plugin_matches_build_script() {
+        let response = respond(None);
+        assert_eq!(response.error, None);
+        assert_eq!(response.supported_features, Some(1));
+        let files: Vec<(&str, &str)> = response
+            .file
+            .iter()
+            .map(|f| (f.name(), f.content()))
+            .collect();
+        assert_eq!(
+            files,
+            [
+                ("simple_message.rs", generated!("simple.rs")),
+                ("importing.rs", generated!("importing.rs")),
+                ("proto3_message.rs", generated!("proto3.rs")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plugin_parameters() {
+        let response = respond(Some("owned"));
+        assert_eq!(response.file[0].content(), generated!("simple_owned.rs"));
+        assert_eq!(response.file[2].content(), generated!("proto3_owned.rs"));
+
+        // Everything `build.rs` sets for `config.rs`, attributes with commas in them included.
+        let response = respond(Some(
+            "owned,runtime=crate::config::runtime,\
+             type_attribute=.proto3test.ScalarMessage=#[derive(PartialEq, Eq)],\
+             enum_attribute=.proto3test=#[derive(Hash)],\
+             field_attribute=.proto3test.Nested.label=#[doc = \"What the nested message is called.\"],\
+             string_decoding=.proto3test.RepeatedMessage=lazy,\
+             string_decoding=.proto3test.WithOneof=lazy,\
+             string_decoding=.proto3test.WithOneof.id=unchecked",
+        ));
+        assert_eq!(response.error, None);
+        assert_eq!(response.file[2].content(), generated!("config.rs"));
+
+        let response = respond(Some("random"));
+        assert_eq!(response.error, None);
+        let random = generated!("random.rs");
+        assert!(random.contains(response.file[0].content()));
+        assert!(response.file[2].content().contains("pub fn random("));
+
+        // Reported the protocol's way, in the response, and nothing generated.
+        let response = respond(Some("owned,boxed"));
+        assert!(
+            response.error().contains("\"boxed\""),
+            "{}",
+            response.error()
+        );
+        assert!(response.file.is_empty());
+        for (parameter, error) in [
+            (
+                "string_decoding=.example=loose",
+                "invalid parameter \"string_decoding=.example=loose\"; \
+                 expected `strict`, `unchecked` or `lazy`",
+            ),
+            (
+                "type_attribute=#[derive(Hash)]",
+                "invalid parameter \"type_attribute=#[derive(Hash)]\"; \
+                 expected `<path>=<value>`",
+            ),
+            (
+                "style_lint=type_name=loud",
+                "invalid parameter \"style_lint=type_name=loud\"; \
+                 expected `allow`, `warn` or `deny`",
+            ),
+            (
+                "type_attribute=.example=derive(Hash)",
+                "simple_message.proto: invalid attribute `derive(Hash)` for `.example`",
+            ),
+            (
+                "runtime=crate::",
+                "simple_message.proto: invalid Rust path `crate::`",
+            ),
+        ] {
+            let response = respond(Some(parameter));
+            assert_eq!(response.error(), error);
+            assert!(response.file.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_plugin_style_lints() {
+        let mut file = FileDescriptorProto {
+            name: Some("style.proto".into()),
+            syntax: Some("proto2".into()),
+            message_type: vec![message("Foo", vec![field("fooBar", 1, Type::Int32, None)])],
+            ..Default::default()
+        };
+        let request = |file: &FileDescriptorProto, parameter: &str| {
+            let request = CodeGeneratorRequest {
+                file_to_generate: vec!["style.proto".into()],
+                parameter: Some(parameter.into()),
+                proto_file: vec![file.clone()],
+                ..Default::default()
+            };
+            let response = tacky_build::plugin::run(&request.encode_to_vec());
+            CodeGeneratorResponse::decode(&*response).unwrap()
+        };
+
+        // Warnings leave the code be.
+        let response = request(&file, "style_lint=field_name=warn");
+        assert_eq!(response.error, None);
+        assert_eq!(response.file.len(), 1);
+
+        let response = request(&file, "style_lint=field_name=deny");
+        assert_eq!(
+            response.error(),
+            "style.proto: field `fooBar` should be snake_case: `foo_bar`"
+        );
+        assert!(response.file.is_empty());
+
+        // With source info, at the field; the last level given for a lint wins.
+        file.source_code_info = Some(SourceCodeInfo {
+            location: vec![Location {
+                path: vec![4, 0, 2, 0],
+                span: vec![3, 2, 30],
+                ..Default::default()
+            }],
+        });
+        let response = request(
+            &file,
+            "style_lint=field_name=allow,style_lint=field_name=deny",
+        );
+        assert_eq!(
+            response.error(),
+            "style.proto:4:3: field `fooBar` should be snake_case: `foo_bar`"
+        );
+        let response = request(
+            &file,
+            "style_lint=field_name=deny,style_lint=field_name=allow",
+        );
+        assert_eq!(response.error, None);
+    }
+
+    /// What the response to a request for `bad.proto`, holding only the message `foo`,
+    /// says is wrong; it generates nothing.
+    fn respond_to(foo: DescriptorProto) -> String {
+        let file = FileDescriptorProto {
+            name: Some("bad.proto".into()),
+            syntax: Some("proto2".into()),
+            message_type: vec![foo],
+            ..Default::default()
+        };
+        let request = CodeGeneratorRequest {
+            file_to_generate: vec!["bad.proto".into()],
+            proto_file: vec![file],
+            ..Default::default()
+        };
+        let response = tacky_build::plugin::run(&request.encode_to_vec());
+        let response = CodeGeneratorResponse::decode(&*response).unwrap();
+        assert!(response.file.is_empty());
+        response.error().to_string()
+    }
+
+    fn message(name: &str, field: Vec<FieldDescriptorProto>) -> DescriptorProto {
+        DescriptorProto {
+            name: Some(name.into()),
+            field,
+            ..Default::default()
+        }
+    }
+
+    fn field(name: &str, number: i32, ty: Type, type_name: Option<&str>) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.into()),
+            number: Some(number),
+            label: Some(Label::Optional.into()),
+            r#type: Some(ty.into()),
+            type_name: type_name.map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    /// `Foo` with the map `by_id`, whose entry has `entry_fields`.
+    fn map_message(entry_fields: Vec<FieldDescriptorProto>) -> DescriptorProto {
+        let mut by_id = field("by_id", 1, Type::Message, Some(".Foo.ByIdEntry"));
+        by_id.label = Some(Label::Repeated.into());
+        let mut entry = message("ByIdEntry", entry_fields);
+        entry.options = Some(MessageOptions {
+            map_entry: Some(true),
+            ..Default::default()
+        });
+        let mut foo = message("Foo", vec![by_id]);
+        foo.nested_type.push(entry);
+        foo.enum_type.push(EnumDescriptorProto {
+            name: Some("Kind".into()),
+            value: vec![EnumValueDescriptorProto {
+                name: Some("KIND_NONE".into()),
+                number: Some(0),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        foo
+    }
+
+    #[test]
+    fn test_plugin_codegen_errors() {
+        let mut foo = message("Foo", vec![field("old", 1, Type::Group, Some(".Foo.Old"))]);
+        foo.nested_type.push(message("Old", vec![]));
+        assert_eq!(
+            respond_to(foo),
+            "bad.proto: unsupported: `old` is a group, which is proto2's delimited message encoding"
+        );
+
+        let key = field("key", 1, Type::Int32, None);
+        assert_eq!(
+            respond_to(map_message(vec![key.clone()])),
+            "bad.proto: invalid map: `by_id`'s entry needs a key and a value"
+        );
+        let value = field("value", 2, Type::Enum, Some(".Foo.Kind"));
+        assert_eq!(
+            respond_to(map_message(vec![key, value])),
+            "bad.proto: unsupported: map fields with enum values"
+        );
+
+        let gone = field("gone", 1, Type::Message, Some(".Gone"));
+        assert_eq!(
+            respond_to(message("Foo", vec![gone])),
+            "bad.proto: unknown type .Gone"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! `Config::random`: every generated message decodes from the scrambled encodings its
+//! `random` makes up, back to the value that came with them.
+
+#[allow(dead_code)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/random.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generated::example::{
+        ApiResponseOwned, MapsWithMsgOwned, MixedUsageMessageOwned, MsgWithNestingOwned,
+        SimpleMessageOwned,
+    };
+    use super::generated::proto3test::{
+        RepeatedMessageOwned, ScalarMessageOwned, WithEnumOwned, WithMapsOwned, WithNestingOwned,
+        WithOneofOwned,
+    };
+    use super::generated::random::{Shade, TreeOwned};
+    use tacky::random::Rng;
+
+    /// For `SEEDS` seeds each: `random` decodes to its model, and so does the model encoded
+    /// the plain way.
+    macro_rules! round_trip {
+        ($($owned:ty),* $(,)?) => {
+            $(
+                for seed in 0..SEEDS {
+                    let (model, bytes) = <$owned>::random(&mut Rng::new(seed), 3);
+                    let decoded = <$owned>::from_bytes(&bytes)
+                        .unwrap_or_else(|e| panic!("{} seed {seed}: {e}", stringify!($owned)));
+                    assert_eq!(decoded, model, "{} seed {seed}", stringify!($owned));
+                    let mut buf = Vec::new();
+                    model.encode(&mut buf);
+                    assert_eq!(<$owned>::from_bytes(&buf).unwrap(), model);
+                }
+            )*
+        };
+    }
+
+    const SEEDS: u64 = 300;
+
+    #[test]
+    fn test_random_round_trip() {
+        round_trip!(
+            ScalarMessageOwned,
+            RepeatedMessageOwned,
+            WithEnumOwned,
+            WithNestingOwned,
+            WithMapsOwned,
+            WithOneofOwned,
+            SimpleMessageOwned,
+            MapsWithMsgOwned,
+            MsgWithNestingOwned,
+            MixedUsageMessageOwned,
+            ApiResponseOwned,
+            TreeOwned,
+        );
+    }
+
+    #[test]
+    fn test_random_is_scrambled() {
+        // Over enough seeds, the canonical encoding is the exception, and each thing the
+        // encodings are meant to have turns up.
+        let (mut reordered, mut deep, mut closed) = (0, 0, true);
+        for seed in 0..SEEDS {
+            let (model, bytes) = TreeOwned::random(&mut Rng::new(seed), 3);
+            let mut canonical = Vec::new();
+            model.encode(&mut canonical);
+            if bytes != canonical {
+                reordered += 1;
+            }
+            if model.children.iter().any(|c| !c.children.is_empty()) {
+                deep += 1;
+            }
+            closed &= model
+                .shades
+                .iter()
+                .all(|s| matches!(s, Shade::ShadeLight | Shade::ShadeDark | Shade::ShadeDarker));
+        }
+        assert!(reordered > SEEDS * 9 / 10, "{reordered}");
+        assert!(deep > 0);
+        assert!(closed);
+
+        // The same seed, the same message.
+        let once = TreeOwned::random(&mut Rng::new(7), 3);
+        assert_eq!(TreeOwned::random(&mut Rng::new(7), 3), once);
+    }
+
+    #[test]
+    fn test_owned_required() {
+        use super::generated::random::{Leaf, LeafOwned, Tree};
+        use tacky::DecodeError;
+
+        let mut buf = Vec::new();
+        Leaf::schema().data.write(&mut buf, Some(&b"d"[..]));
+        assert!(matches!(
+            LeafOwned::from_bytes(&buf),
+            Err(DecodeError::MissingField("id"))
+        ));
+
+        // The leaf comes twice, its required field only in the first: merged, it has it.
+        let mut buf = Vec::new();
+        let schema = Tree::schema();
+        schema.name.write(&mut buf, "t");
+        schema.leaf.write_msg(&mut buf, |buf, leaf| {
+            leaf.id.write(buf, 3u64);
+        });
+        schema.leaf.write_msg(&mut buf, |buf, leaf| {
+            leaf.data.write(buf, Some(&b"d"[..]));
+        });
+        let leaf = TreeOwned::from_bytes(&buf).unwrap().leaf.unwrap();
+        assert_eq!((leaf.id, leaf.data.as_deref()), (3, Some(&b"d"[..])));
+
+        let mut buf = Vec::new();
+        schema.name.write(&mut buf, "t");
+        assert!(matches!(
+            TreeOwned::from_bytes(&buf),
+            Err(DecodeError::MissingField("leaf"))
+        ));
+    }
+
+    #[test]
+    fn test_random_depth() {
+        fn depth(t: &TreeOwned) -> u32 {
+            let nested = t
+                .children
+                .iter()
+                .chain(t.parent.as_deref())
+                .chain(t.by_id.values())
+                .map(depth)
+                .max();
+            nested.map_or(0, |d| d + 1)
+        }
+        for seed in 0..SEEDS {
+            let (model, _) = TreeOwned::random(&mut Rng::new(seed), 2);
+            assert!(depth(&model) <= 2, "seed {seed}");
+        }
+    }
+}
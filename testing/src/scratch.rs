@@ -0,0 +1,22 @@
+//! Scratch directories under `OUT_DIR`, for tests that need files on disk.
+
+use std::path::PathBuf;
+
+/// `OUT_DIR/<name>`, created if it isn't there yet. Whatever is already in it stays.
+pub fn dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("OUT_DIR")).join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// `files`, each a path and its source, as the only contents of `OUT_DIR/<name>`.
+pub fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = PathBuf::from(env!("OUT_DIR")).join(name);
+    let _ = std::fs::remove_dir_all(&root);
+    for (file, source) in files {
+        let path = root.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, source).unwrap();
+    }
+    root
+}
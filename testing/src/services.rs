@@ -0,0 +1,363 @@
+//! Services: a generated trait implemented once, and called in-process through its
+//! `dispatch`, for each kind of rpc. Then the same code from a descriptor set.
+
+#[allow(dead_code)]
+mod tacky_services {
+    include!(concat!(env!("OUT_DIR"), "/services.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tacky_services::routes::*;
+    use tacky::{DecodeError, DispatchError, Method, MethodDescriptor, ResponseSink, WriteBuf};
+
+    const FEATURES: [(&str, (i32, i32)); 3] = [
+        ("harbour", (1, 1)),
+        ("lighthouse", (2, 5)),
+        ("pier", (9, 9)),
+    ];
+
+    struct Guide;
+
+    fn read_point(fields: PointFields<'_>) -> Result<(i32, i32), DecodeError> {
+        let mut point = (0, 0);
+        for f in fields {
+            match f? {
+                PointField::Latitude(v) => point.0 = v,
+                PointField::Longitude(v) => point.1 = v,
+            }
+        }
+        Ok(point)
+    }
+
+    fn write_point<B: WriteBuf>(buf: &mut B, schema: Point, (lat, long): (i32, i32)) {
+        schema.latitude.write(buf, lat);
+        schema.longitude.write(buf, long);
+    }
+
+    fn write_feature<B: WriteBuf>(buf: &mut B, (name, at): (&str, (i32, i32))) {
+        let schema = Feature::schema();
+        schema.name.write(buf, name);
+        schema
+            .location
+            .write_msg(buf, |buf, p| write_point(buf, p, at));
+    }
+
+    impl RouteGuide for Guide {
+        type Error = DecodeError;
+
+        fn get_feature<B: WriteBuf>(
+            &self,
+            request: PointFields<'_>,
+            response: &mut B,
+        ) -> Result<(), DecodeError> {
+            let at = read_point(request)?;
+            if let Some(&feature) = FEATURES.iter().find(|(_, p)| *p == at) {
+                write_feature(response, feature);
+            }
+            Ok(())
+        }
+
+        fn list_features<B: WriteBuf>(
+            &self,
+            request: RectangleFields<'_>,
+            responses: &mut impl ResponseSink<B>,
+        ) -> Result<(), DecodeError> {
+            let (mut lo, mut hi) = ((0, 0), (0, 0));
+            for f in request {
+                match f? {
+                    RectangleField::Lo(p) => lo = read_point(p)?,
+                    RectangleField::Hi(p) => hi = read_point(p)?,
+                }
+            }
+            let inside = |(lat, long): (i32, i32)| {
+                (lo.0..=hi.0).contains(&lat) && (lo.1..=hi.1).contains(&long)
+            };
+            for &feature in FEATURES.iter().filter(|(_, p)| inside(*p)) {
+                responses.send(|buf| write_feature(buf, feature));
+            }
+            Ok(())
+        }
+
+        fn record_route<'a, B: WriteBuf>(
+            &self,
+            requests: impl Iterator<Item = PointFields<'a>>,
+            response: &mut B,
+        ) -> Result<(), DecodeError> {
+            let mut count = 0;
+            for point in requests {
+                read_point(point)?;
+                count += 1;
+            }
+            RouteSummary::schema().point_count.write(response, count);
+            Ok(())
+        }
+
+        fn route_chat<'a, B: WriteBuf>(
+            &self,
+            requests: impl Iterator<Item = RouteNoteFields<'a>>,
+            responses: &mut impl ResponseSink<B>,
+        ) -> Result<(), DecodeError> {
+            for note in requests {
+                for f in note {
+                    if let RouteNoteField::Message(text) = f? {
+                        responses.send(|buf| RouteNote::schema().message.write(buf, text));
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn point(at: (i32, i32)) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_point(&mut buf, Point::schema(), at);
+        buf
+    }
+
+    fn feature_names(responses: &[Vec<u8>]) -> Vec<&str> {
+        let mut names = Vec::new();
+        for response in responses {
+            for f in Feature::decode(response) {
+                if let FeatureField::Name(name) = f.unwrap() {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    #[test]
+    fn test_service_unary() {
+        let mut responses = Vec::new();
+        Guide
+            .dispatch("GetFeature", [&point((2, 5))[..]], &mut responses)
+            .unwrap();
+        assert_eq!(feature_names(&responses), ["lighthouse"]);
+
+        // By path, as a gRPC router sees it, to the same handler.
+        let mut responses = Vec::new();
+        Guide
+            .dispatch(
+                "/routes.RouteGuide/GetFeature",
+                [&point((9, 9))[..]],
+                &mut responses,
+            )
+            .unwrap();
+        assert_eq!(feature_names(&responses), ["pier"]);
+
+        // Nothing found is an empty message, still a response.
+        let mut responses = Vec::new();
+        Guide.dispatch("GetFeature", [], &mut responses).unwrap();
+        assert_eq!(responses, [Vec::<u8>::new()]);
+    }
+
+    #[test]
+    fn test_service_server_streaming() {
+        let mut request = Vec::new();
+        let schema = Rectangle::schema();
+        schema
+            .lo
+            .write_msg(&mut request, |buf, p| write_point(buf, p, (0, 0)));
+        schema
+            .hi
+            .write_msg(&mut request, |buf, p| write_point(buf, p, (5, 5)));
+        let mut responses = Vec::new();
+        Guide
+            .dispatch("ListFeatures", [&request[..]], &mut responses)
+            .unwrap();
+        assert_eq!(feature_names(&responses), ["harbour", "lighthouse"]);
+    }
+
+    #[test]
+    fn test_service_client_streaming() {
+        let points = [point((1, 1)), point((2, 2)), point((3, 3))];
+        let mut responses = Vec::new();
+        Guide
+            .dispatch(
+                "RecordRoute",
+                points.iter().map(Vec::as_slice),
+                &mut responses,
+            )
+            .unwrap();
+        let [summary] = &responses[..] else {
+            panic!("{responses:?}");
+        };
+        let fields: Vec<_> = RouteSummary::decode(summary).map(Result::unwrap).collect();
+        assert!(matches!(fields[..], [RouteSummaryField::PointCount(3)]));
+    }
+
+    #[test]
+    fn test_service_bidi_streaming() {
+        let notes: Vec<Vec<u8>> = ["ahoy", "land"]
+            .iter()
+            .map(|text| {
+                let mut buf = Vec::new();
+                RouteNote::schema().message.write(&mut buf, *text);
+                buf
+            })
+            .collect();
+        let mut responses = Vec::new();
+        Guide
+            .dispatch("RouteChat", notes.iter().map(Vec::as_slice), &mut responses)
+            .unwrap();
+        assert_eq!(responses, notes);
+    }
+
+    #[test]
+    fn test_service_errors() {
+        let mut responses: Vec<Vec<u8>> = Vec::new();
+        let err = Guide.dispatch("Teleport", [], &mut responses).unwrap_err();
+        assert!(matches!(err, DispatchError::UnknownMethod), "{err:?}");
+
+        // A truncated varint: the handler's decode error comes back as its own.
+        let err = Guide
+            .dispatch("GetFeature", [&[0x08, 0x80][..]], &mut responses)
+            .unwrap_err();
+        assert!(matches!(err, DispatchError::Handler(_)), "{err:?}");
+    }
+
+    /// `Relay`'s `Dispatch` rpc, which sends back the message of each note.
+    struct Echo;
+
+    impl Relay for Echo {
+        type Error = DecodeError;
+
+        fn dispatch_<B: WriteBuf>(
+            &self,
+            request: RouteNoteFields<'_>,
+            response: &mut B,
+        ) -> Result<(), DecodeError> {
+            for f in request {
+                if let RouteNoteField::Message(text) = f? {
+                    RouteNote::schema().message.write(response, text);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_service_rpc_named_dispatch() {
+        let mut note = Vec::new();
+        RouteNote::schema().message.write(&mut note, "ahoy");
+        let mut responses = Vec::new();
+        Echo.dispatch("Dispatch", [&note[..]], &mut responses)
+            .unwrap();
+        assert_eq!(responses, [note]);
+        assert_eq!(RelayDispatch::DESCRIPTOR.path, "/routes.Relay/Dispatch");
+    }
+
+    /// Two handlers of one name, as `service_items` rejects before they are generated,
+    /// fail the name check over the finished code too.
+    #[test]
+    fn test_service_handler_names_checked() {
+        let generated = |handlers: &str| -> proc_macro2::TokenStream {
+            let provided = "fn dispatch(&self) {}";
+            format!("pub trait RouteGuide {{ type Error; {handlers} {provided} }}")
+                .parse()
+                .unwrap()
+        };
+        let e = tacky_build::check_names(&generated("fn get_foo(&self); fn get_foo(&self);"))
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "name collision: `get_foo` is generated twice in `RouteGuide`"
+        );
+        let e = tacky_build::check_names(&generated("fn dispatch(&self);")).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "name collision: `dispatch` is generated twice in `RouteGuide`"
+        );
+        tacky_build::check_names(&generated("fn dispatch_(&self);")).unwrap();
+    }
+
+    #[test]
+    fn test_service_descriptors() {
+        assert_eq!(<Guide as RouteGuide>::NAME, "routes.RouteGuide");
+        let paths: Vec<_> = Guide::METHODS.iter().map(|m| m.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/routes.RouteGuide/GetFeature",
+                "/routes.RouteGuide/ListFeatures",
+                "/routes.RouteGuide/RecordRoute",
+                "/routes.RouteGuide/RouteChat",
+            ]
+        );
+        assert_eq!(
+            RouteGuideRouteChat::DESCRIPTOR,
+            MethodDescriptor {
+                name: "RouteChat",
+                path: "/routes.RouteGuide/RouteChat",
+                client_streaming: true,
+                server_streaming: true,
+            }
+        );
+        let streaming = |m: &MethodDescriptor| (m.client_streaming, m.server_streaming);
+        let flags: Vec<_> = Guide::METHODS.iter().map(streaming).collect();
+        assert_eq!(
+            flags,
+            [(false, false), (false, true), (true, false), (true, true)]
+        );
+
+        // Request and response schemas, for code generic over methods.
+        fn request_of<M: Method>(_: M) -> M::Request {
+            <M::Request as tacky::MessageSchema>::schema()
+        }
+        let _: Rectangle = request_of(RouteGuideListFeatures);
+    }
+
+    #[test]
+    fn test_service_over_grpc_frames() {
+        use tacky::grpc::{write_frame, write_frame_compressed, Deframer, Gzip};
+
+        // A client stream as it comes off the wire: framed, some frames gzipped, and
+        // chunked without regard for where frames end.
+        let mut wire = Vec::new();
+        write_frame(&mut wire, |buf| write_point(buf, Point::schema(), (1, 1)));
+        write_frame_compressed(&mut wire, &mut Gzip, |buf| {
+            write_point(buf, Point::schema(), (2, 2))
+        });
+        write_frame(&mut wire, |buf| write_point(buf, Point::schema(), (3, 3)));
+        let mut deframer = Deframer::new();
+        let mut requests = Vec::new();
+        for chunk in wire.chunks(4) {
+            deframer.push(chunk);
+            while let Some(message) = deframer.next_message(Some(&mut Gzip)) {
+                requests.push(message.unwrap().to_vec());
+            }
+        }
+        deframer.finish().unwrap();
+        assert_eq!(requests, [point((1, 1)), point((2, 2)), point((3, 3))]);
+
+        let mut responses = Vec::new();
+        Guide
+            .dispatch(
+                "RecordRoute",
+                requests.iter().map(Vec::as_slice),
+                &mut responses,
+            )
+            .unwrap();
+
+        // And the response framed straight into the reply, header and all.
+        let mut reply = Vec::new();
+        write_frame(&mut reply, |buf| buf.extend_from_slice(&responses[0]));
+        let mut deframer = Deframer::new();
+        deframer.push(&reply);
+        let summary = deframer.next_frame().unwrap().unwrap();
+        assert!(!summary.compressed);
+        let fields: Vec<_> = RouteSummary::decode(summary.payload)
+            .map(Result::unwrap)
+            .collect();
+        assert!(matches!(fields[..], [RouteSummaryField::PointCount(3)]));
+    }
+
+    #[test]
+    fn test_service_fds_matches_proto_source() {
+        assert_eq!(
+            include_str!(concat!(env!("OUT_DIR"), "/services_fds.rs")),
+            include_str!(concat!(env!("OUT_DIR"), "/services.rs"))
+        );
+    }
+}
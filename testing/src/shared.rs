@@ -0,0 +1,99 @@
+//! `tacky::Owned`: decoded strings, bytes and nested messages kept past the message they
+//! came in, sharing its buffer.
+
+mod upload {
+    tacky::proto! {
+        syntax = "proto3";
+        package upload;
+        message Upload {
+            string name = 1;
+            bytes data = 2;
+            Meta meta = 3;
+            repeated string tags = 4;
+        }
+        message Meta {
+            string kind = 1;
+            int64 size = 2;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::upload::upload::{MetaField, Upload, UploadField};
+    use tacky::owned::Bytes;
+    use tacky::{BytesStr, DecodeMessage, Owned};
+
+    fn frame() -> Bytes {
+        let mut buf = Vec::new();
+        let s = Upload::schema();
+        s.name.write(&mut buf, "report.csv");
+        s.data.write(&mut buf, &b"a,b\n1,2\n"[..]);
+        s.meta.write_msg(&mut buf, |buf, m| {
+            m.kind.write(buf, "text/csv");
+            m.size.write(buf, 8i64);
+        });
+        s.tags.write(&mut buf, ["q3", "finance"]);
+        Bytes::from(buf)
+    }
+
+    #[test]
+    fn test_owned_shares_buffer() {
+        let frame = frame();
+        let range = frame.as_ptr_range();
+        let request = Owned::<Upload>::new(frame.clone());
+
+        let (mut name, mut data, mut meta, mut tags) = (None, None, None, Vec::new());
+        for field in request.fields() {
+            match field.unwrap() {
+                UploadField::Name(v) => name = Some(request.string(v)),
+                UploadField::Data(v) => data = Some(request.bytes(v)),
+                UploadField::Meta(v) => meta = Some(request.message(&v)),
+                UploadField::Tags(v) => tags.push(request.string(v)),
+            }
+        }
+        drop(request);
+
+        let (name, data, meta) = (name.unwrap(), data.unwrap(), meta.unwrap());
+        assert_eq!(name, "report.csv");
+        assert_eq!(&data[..], b"a,b\n1,2\n");
+        assert_eq!(tags, [BytesStr::from("q3"), BytesStr::from("finance")]);
+        // None of them is a copy.
+        for kept in [name.as_bytes(), &data[..], meta.as_bytes()] {
+            assert!(range.contains(&kept.as_ptr()));
+        }
+
+        // A nested message goes where its parent doesn't.
+        let kind = std::thread::spawn(move || {
+            meta.fields()
+                .find_map(|f| match f.unwrap() {
+                    MetaField::Kind(k) => Some(meta.string(k)),
+                    _ => None,
+                })
+                .unwrap()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(kind.to_string(), "text/csv");
+    }
+
+    #[test]
+    fn test_decode_message_generic() {
+        fn count<M: DecodeMessage>(buf: &[u8]) -> usize {
+            M::fields(buf).count()
+        }
+        let frame = frame();
+        assert_eq!(count::<Upload>(&frame), 5);
+        assert_eq!(
+            Owned::<Upload>::new(frame.clone()).fields().count(),
+            Upload::decode(&frame).count()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_owned_foreign_value() {
+        let request = Owned::<Upload>::new(frame());
+        request.bytes(b"not from the frame");
+    }
+}